@@ -0,0 +1,405 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Lookup of known vulnerabilities in the RustSec advisory database, see <https://rustsec.org>.
+//!
+//! The database is the same git checkout that `cargo audit` uses. It is cloned if it does not
+//! exist yet, and updated unless running offline.
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use cargo_metadata::Package;
+use cyclonedx_bom::external_models::date_time::DateTime;
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::advisory::{Advisories, Advisory};
+use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+use cyclonedx_bom::models::vulnerability_rating::{
+    Score, ScoreMethod, Severity, VulnerabilityRating, VulnerabilityRatings,
+};
+use cyclonedx_bom::models::vulnerability_reference::{
+    VulnerabilityReference, VulnerabilityReferences,
+};
+use cyclonedx_bom::models::vulnerability_source::VulnerabilitySource;
+use cyclonedx_bom::models::vulnerability_target::{
+    self, Versions, VulnerabilityTarget, VulnerabilityTargets,
+};
+use rustsec::database::Query;
+use rustsec::{Database, Repository};
+use thiserror::Error;
+
+pub const ADVISORY_DB_URL: &str = "https://github.com/rustsec/advisory-db";
+
+/// A database that was not updated for this long likely misses recent advisories
+pub const MAX_AGE: Duration = Duration::from_secs(90 * 24 * 60 * 60);
+
+/// How long to wait for another process, e.g. `cargo audit`, that is updating the database
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Creates a vulnerability for a RustSec advisory that affects the given packages
+pub fn to_vulnerability(advisory: &rustsec::Advisory, affected: &[&Package]) -> Vulnerability {
+    let id = advisory.id().as_str();
+    let source = VulnerabilitySource::new(
+        Some("RustSec".to_owned()),
+        Uri::try_from(format!("https://rustsec.org/advisories/{}.html", id)).ok(),
+    );
+
+    let mut vulnerability = Vulnerability::new(Some(id.to_owned()));
+    vulnerability.id = Some(NormalizedString::new(id));
+    vulnerability.vulnerability_source = Some(source.clone());
+    let aliases = &advisory.metadata.aliases;
+    if !aliases.is_empty() {
+        vulnerability.vulnerability_references = Some(VulnerabilityReferences(
+            aliases
+                .iter()
+                .map(|alias| VulnerabilityReference::new(alias.as_str(), alias_source(alias)))
+                .collect(),
+        ));
+    }
+    if let Some(cvss) = &advisory.metadata.cvss {
+        let method = if cvss.minor_version == 0 {
+            ScoreMethod::CVSSv3
+        } else {
+            ScoreMethod::CVSSv31
+        };
+        let mut rating = VulnerabilityRating::new(
+            Score::from_f32(cvss.score().value() as f32),
+            Some(severity(cvss.severity())),
+            Some(method),
+        );
+        rating.vulnerability_source = Some(source);
+        rating.vector = Some(NormalizedString::new(&cvss.to_string()));
+        vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(vec![rating]));
+    }
+    if !advisory.title().is_empty() {
+        vulnerability.description = Some(advisory.title().to_owned());
+    }
+    if !advisory.description().is_empty() {
+        vulnerability.detail = Some(advisory.description().to_owned());
+    }
+    let patched = advisory.versions.patched();
+    vulnerability.recommendation = Some(if patched.is_empty() {
+        "No patched version is available".to_owned()
+    } else {
+        let patched: Vec<String> = patched.iter().map(|req| req.to_string()).collect();
+        format!("Upgrade to {}", patched.join(" or "))
+    });
+    if let Some(url) = advisory
+        .metadata
+        .url
+        .as_ref()
+        .and_then(|url| Uri::try_from(url.to_string()).ok())
+    {
+        vulnerability.advisories = Some(Advisories(vec![Advisory::new(url)]));
+    }
+    vulnerability.published =
+        DateTime::try_from(format!("{}T00:00:00Z", advisory.date().as_str())).ok();
+    vulnerability.vulnerability_targets = Some(VulnerabilityTargets(
+        affected
+            .iter()
+            .map(|package| VulnerabilityTarget {
+                bom_ref: package.id.to_string(),
+                versions: Some(Versions(vec![vulnerability_target::Version::new(
+                    &package.version.to_string(),
+                    "affected",
+                )])),
+            })
+            .collect(),
+    ));
+    vulnerability
+}
+
+/// A checkout of the RustSec advisory database
+pub struct AdvisoryDatabase {
+    db: Database,
+}
+
+impl AdvisoryDatabase {
+    /// Where `cargo audit` keeps the database: `$CARGO_HOME/advisory-db`, or
+    /// `~/.cargo/advisory-db` if `CARGO_HOME` is not set
+    pub fn default_path() -> Option<PathBuf> {
+        match std::env::var_os("CARGO_HOME") {
+            Some(cargo_home) => Some(PathBuf::from(cargo_home).join("advisory-db")),
+            None => std::env::var_os("HOME")
+                .map(|home| PathBuf::from(home).join(".cargo").join("advisory-db")),
+        }
+    }
+
+    /// Loads the database, cloning it first if the directory does not exist, or else pulling
+    /// the latest advisories. Offline, an existing checkout is loaded as it is.
+    /// Warns if the database was not updated for [`MAX_AGE`].
+    pub fn open_or_fetch(path: &Path, offline: bool) -> Result<Self, AdvisoryError> {
+        let db = if offline {
+            if !path.exists() {
+                return Err(AdvisoryError::Offline(path.to_owned()));
+            }
+            Self::open(path)?
+        } else if path.exists() && Repository::open(path).is_err() {
+            log::warn!(
+                "The advisory database at {} is not a git checkout and cannot be updated",
+                path.display()
+            );
+            Self::open(path)?
+        } else {
+            log::info!("Fetching the advisory database into {}", path.display());
+            let repo = Repository::fetch(ADVISORY_DB_URL, path, true, LOCK_TIMEOUT)?;
+            Self {
+                db: Database::load_from_repo(&repo)?,
+            }
+        };
+
+        if let Some(commit) = db.db.latest_commit() {
+            let updated = SystemTime::from(commit.timestamp);
+            if is_stale(updated, SystemTime::now()) {
+                log::warn!(
+                    "The advisory database at {} was last updated more than {} days ago, \
+                    run without `--offline` to update it",
+                    path.display(),
+                    MAX_AGE.as_secs() / (24 * 60 * 60)
+                );
+            }
+        }
+        Ok(db)
+    }
+
+    /// Loads the advisories of a checkout, or of a directory with the same layout, without
+    /// updating it
+    pub fn open(path: &Path) -> Result<Self, AdvisoryError> {
+        let db = match Repository::open(path) {
+            Ok(repo) => Database::load_from_repo(&repo)?,
+            Err(_) => Database::open(path)?,
+        };
+        log::debug!(
+            "Loaded {} advisories from {}",
+            db.iter().count(),
+            path.display()
+        );
+        Ok(Self { db })
+    }
+
+    /// Returns the vulnerabilities of the packages published on crates.io, ordered by advisory
+    /// id. Informational and withdrawn advisories are left out.
+    pub fn vulnerabilities<'a>(
+        &self,
+        packages: impl IntoIterator<Item = &'a Package>,
+    ) -> Option<Vulnerabilities> {
+        let mut affected: BTreeMap<&str, (&rustsec::Advisory, Vec<&Package>)> = BTreeMap::new();
+        for package in packages {
+            if !package.source.as_ref().is_some_and(|s| s.is_crates_io()) {
+                continue;
+            }
+            let Ok(name) = package.name.parse() else {
+                continue;
+            };
+            let query = Query::crate_scope()
+                .package_name(name)
+                .package_version(package.version.clone());
+            for advisory in self.db.query(&query) {
+                log::debug!("{} is affected by {}", package.id, advisory.id());
+                affected
+                    .entry(advisory.id().as_str())
+                    .or_insert_with(|| (advisory, Vec::new()))
+                    .1
+                    .push(package);
+            }
+        }
+
+        let vulnerabilities: Vec<Vulnerability> = affected
+            .into_values()
+            .map(|(advisory, packages)| to_vulnerability(advisory, &packages))
+            .collect();
+        (!vulnerabilities.is_empty()).then_some(Vulnerabilities(vulnerabilities))
+    }
+}
+
+/// Returns `true` if a database last updated at `updated` is older than [`MAX_AGE`] at `now`
+fn is_stale(updated: SystemTime, now: SystemTime) -> bool {
+    now.duration_since(updated).is_ok_and(|age| age > MAX_AGE)
+}
+
+/// Combines vulnerabilities with the same `bom-ref`, as found when merging the SBOMs of
+/// several workspace members, keeping the targets of all of them
+pub fn dedupe_vulnerabilities(vulnerabilities: &mut Vec<Vulnerability>) {
+    let mut deduped: Vec<Vulnerability> = Vec::with_capacity(vulnerabilities.len());
+    for vulnerability in vulnerabilities.drain(..) {
+        let existing = deduped
+            .iter_mut()
+            .find(|v| v.bom_ref.is_some() && v.bom_ref == vulnerability.bom_ref);
+        match existing {
+            Some(existing) => {
+                let targets = existing
+                    .vulnerability_targets
+                    .get_or_insert_with(|| VulnerabilityTargets(Vec::new()));
+                for target in vulnerability
+                    .vulnerability_targets
+                    .into_iter()
+                    .flat_map(|t| t.0)
+                {
+                    if !targets.0.contains(&target) {
+                        targets.0.push(target);
+                    }
+                }
+            }
+            None => deduped.push(vulnerability),
+        }
+    }
+    *vulnerabilities = deduped;
+}
+
+fn alias_source(alias: &rustsec::advisory::Id) -> VulnerabilitySource {
+    let (name, url) = if alias.is_cve() {
+        ("NVD", format!("https://nvd.nist.gov/vuln/detail/{alias}"))
+    } else if alias.is_ghsa() {
+        ("GitHub", format!("https://github.com/advisories/{alias}"))
+    } else {
+        return VulnerabilitySource::new(None, None);
+    };
+    VulnerabilitySource::new(Some(name.to_owned()), Uri::try_from(url).ok())
+}
+
+fn severity(severity: rustsec::advisory::Severity) -> Severity {
+    match severity {
+        rustsec::advisory::Severity::Critical => Severity::Critical,
+        rustsec::advisory::Severity::High => Severity::High,
+        rustsec::advisory::Severity::Medium => Severity::Medium,
+        rustsec::advisory::Severity::Low => Severity::Low,
+        rustsec::advisory::Severity::None => Severity::None,
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AdvisoryError {
+    #[error(
+        "Neither CARGO_HOME nor HOME is set, use `--advisory-db` to locate the advisory database"
+    )]
+    NoDefaultPath,
+
+    #[error("Could not fetch or read the advisory database")]
+    Database(#[from] rustsec::Error),
+
+    #[error("There is no advisory database at {}, and it cannot be fetched offline", .0.display())]
+    Offline(PathBuf),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn advisory_db() -> AdvisoryDatabase {
+        AdvisoryDatabase::open(Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/advisory-db"
+        )))
+        .unwrap()
+    }
+
+    fn package() -> Package {
+        serde_json::from_str(include_str!("../tests/fixtures/crates_io_package.json")).unwrap()
+    }
+
+    #[test]
+    fn it_should_find_vulnerabilities_of_crates_io_packages() {
+        let package = package();
+
+        let vulnerabilities = advisory_db().vulnerabilities([&package]).unwrap();
+
+        // The informational and the patched advisories are left out
+        assert_eq!(vulnerabilities.0.len(), 1);
+        let vulnerability = &vulnerabilities.0[0];
+        assert_eq!(vulnerability.bom_ref.as_deref(), Some("RUSTSEC-2024-9991"));
+        assert_eq!(
+            vulnerability.recommendation.as_deref(),
+            Some("Upgrade to >=1.1.3")
+        );
+        let rating = &vulnerability.vulnerability_ratings.as_ref().unwrap().0[0];
+        assert_eq!(rating.score, Score::from_f32(7.5));
+        assert_eq!(rating.severity, Some(Severity::High));
+        assert_eq!(rating.score_method, Some(ScoreMethod::CVSSv31));
+        let targets = &vulnerability.vulnerability_targets.as_ref().unwrap().0;
+        assert_eq!(targets[0].bom_ref, package.id.to_string());
+    }
+
+    #[test]
+    fn it_should_skip_packages_not_from_crates_io() {
+        let mut package = package();
+        package.source = None;
+
+        assert_eq!(advisory_db().vulnerabilities([&package]), None);
+    }
+
+    #[test]
+    fn it_should_dedupe_vulnerabilities() {
+        let package = package();
+        let mut other = package.clone();
+        other.id.repr =
+            "aho-corasick 1.1.1 (registry+https://github.com/rust-lang/crates.io-index)".to_owned();
+        let db = advisory_db();
+        let mut vulnerabilities = db.vulnerabilities([&package]).unwrap().0;
+        vulnerabilities.extend(db.vulnerabilities([&package, &other]).unwrap().0);
+
+        dedupe_vulnerabilities(&mut vulnerabilities);
+
+        assert_eq!(vulnerabilities.len(), 1);
+        let targets = vulnerabilities[0].vulnerability_targets.as_ref().unwrap();
+        assert_eq!(targets.0.len(), 2);
+    }
+
+    #[test]
+    fn it_should_describe_the_advisory() {
+        let package = package();
+
+        let vulnerabilities = advisory_db().vulnerabilities([&package]).unwrap();
+
+        let vulnerability = &vulnerabilities.0[0];
+        assert_eq!(
+            vulnerability.description.as_deref(),
+            Some("Quadratic search time with overlapping patterns")
+        );
+        assert!(vulnerability
+            .detail
+            .as_deref()
+            .unwrap()
+            .starts_with("Searching with"));
+        let references = &vulnerability.vulnerability_references.as_ref().unwrap().0;
+        assert_eq!(references.len(), 2);
+        let rating = &vulnerability.vulnerability_ratings.as_ref().unwrap().0[0];
+        assert_eq!(
+            rating.vector.as_ref().map(|v| v.to_string()).as_deref(),
+            Some("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:H")
+        );
+    }
+
+    #[test]
+    fn it_should_leave_out_unaffected_versions() {
+        let mut package = package();
+        package.version = "1.1.3".parse().unwrap();
+
+        assert_eq!(advisory_db().vulnerabilities([&package]), None);
+    }
+
+    #[test]
+    fn it_should_detect_stale_databases() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        assert!(!is_stale(now - 10 * day, now));
+        assert!(is_stale(now - 100 * day, now));
+        assert!(!is_stale(now + day, now));
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! in-toto attestations of written SBOMs, for provenance pipelines such as SLSA.
+//! See <https://github.com/in-toto/attestation/tree/main/spec/v1> and
+//! <https://github.com/secure-systems-lab/dsse/blob/master/protocol.md>.
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cyclonedx_bom::models::hash::{HashAlgorithm, HashError, Hashes};
+use cyclonedx_bom::operations::signature::{SignatureError, SigningKey};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+pub const STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
+pub const CYCLONEDX_PREDICATE_TYPE: &str = "https://cyclonedx.org/bom";
+pub const PAYLOAD_TYPE: &str = "application/vnd.in-toto+json";
+
+/// Creates an in-toto statement about the SBOM file `name` with the contents `sbom`,
+/// which must be a JSON SBOM. The SBOM is also the predicate of the statement.
+pub fn statement(name: &str, sbom: &[u8]) -> Result<Value, AttestationError> {
+    let predicate: Value = serde_json::from_slice(sbom)?;
+    let hashes = Hashes::from_reader(sbom, &[HashAlgorithm::SHA_256])?;
+    Ok(json!({
+        "_type": STATEMENT_TYPE,
+        "subject": [{
+            "name": name,
+            "digest": { "sha256": hashes.0[0].content.0 }
+        }],
+        "predicateType": CYCLONEDX_PREDICATE_TYPE,
+        "predicate": predicate
+    }))
+}
+
+/// Wraps a statement into a DSSE envelope, signed if a key is given.
+/// The envelope has no signatures otherwise.
+pub fn envelope(statement: &Value, key: Option<&SigningKey>) -> Result<Value, AttestationError> {
+    let payload = serde_json::to_vec(statement)?;
+    let mut signatures = Vec::new();
+    if let Some(key) = key {
+        let signature = key.sign(&pre_authentication_encoding(PAYLOAD_TYPE, &payload))?;
+        signatures.push(json!({ "sig": STANDARD.encode(signature) }));
+    }
+    Ok(json!({
+        "payloadType": PAYLOAD_TYPE,
+        "payload": STANDARD.encode(payload),
+        "signatures": signatures
+    }))
+}
+
+/// The data that is signed in a DSSE envelope, binding the payload to its type
+fn pre_authentication_encoding(payload_type: &str, payload: &[u8]) -> Vec<u8> {
+    let mut encoding = format!(
+        "DSSEv1 {} {} {} ",
+        payload_type.len(),
+        payload_type,
+        payload.len()
+    )
+    .into_bytes();
+    encoding.extend_from_slice(payload);
+    encoding
+}
+
+#[derive(Debug, Error)]
+pub enum AttestationError {
+    #[error("Error reading the SBOM")]
+    JsonError(#[from] serde_json::Error),
+
+    #[error("Error hashing the SBOM")]
+    HashError(#[from] HashError),
+
+    #[error("Could not sign the attestation")]
+    SignatureError(#[from] SignatureError),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SBOM: &[u8] = br#"{"bomFormat":"CycloneDX","specVersion":"1.5","version":1}"#;
+
+    #[test]
+    fn it_should_create_a_statement_about_the_sbom() {
+        let statement = statement("pkg.cdx.json", SBOM).unwrap();
+
+        assert_eq!(statement["_type"], STATEMENT_TYPE);
+        assert_eq!(statement["subject"][0]["name"], "pkg.cdx.json");
+        assert_eq!(
+            statement["subject"][0]["digest"]["sha256"],
+            Hashes::from_reader(SBOM, &[HashAlgorithm::SHA_256])
+                .unwrap()
+                .0[0]
+                .content
+                .0
+        );
+        assert_eq!(statement["predicateType"], CYCLONEDX_PREDICATE_TYPE);
+        assert_eq!(statement["predicate"]["specVersion"], "1.5");
+    }
+
+    #[test]
+    fn it_should_wrap_the_statement_into_an_envelope() {
+        let statement = statement("pkg.cdx.json", SBOM).unwrap();
+        let key = SigningKey::from_pem(include_str!("../tests/fixtures/ed25519_signing_key.pem"))
+            .unwrap();
+
+        let unsigned = envelope(&statement, None).unwrap();
+        let signed = envelope(&statement, Some(&key)).unwrap();
+
+        assert_eq!(unsigned["payloadType"], PAYLOAD_TYPE);
+        let payload = STANDARD
+            .decode(unsigned["payload"].as_str().unwrap())
+            .unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&payload).unwrap(),
+            statement
+        );
+        assert_eq!(unsigned["signatures"], json!([]));
+
+        let payload = STANDARD
+            .decode(signed["payload"].as_str().unwrap())
+            .unwrap();
+        let signature = STANDARD
+            .decode(signed["signatures"][0]["sig"].as_str().unwrap())
+            .unwrap();
+        assert_eq!(
+            signature,
+            key.sign(&pre_authentication_encoding(PAYLOAD_TYPE, &payload))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_encode_the_payload_type_and_length() {
+        assert_eq!(
+            pre_authentication_encoding("http://example.com/HelloWorld", b"hello world"),
+            b"DSSEv1 29 http://example.com/HelloWorld 11 hello world"
+        );
+    }
+}
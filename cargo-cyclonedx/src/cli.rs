@@ -1,8 +1,9 @@
 use cargo_cyclonedx::{
     config::{
-        Describe, Features, FilenameOverride, FilenameOverrideError, FilenamePattern,
-        IncludedDependencies, LicenseParserOptions, OutputOptions, ParseMode, PlatformSuffix,
-        SbomConfig, Target,
+        parse_spec_version, Describe, Features, FilenameOverride, FilenameOverrideError,
+        FilenamePattern, FilenameTemplate, FilenameTemplateError, IncludedDependencies,
+        LicenseParserOptions, OutputOptions, PackageFilter, ParseMode, PlatformSuffix, SbomConfig,
+        Target,
     },
     format::Format,
     platform::host_platform,
@@ -30,9 +31,15 @@ pub struct Args {
     #[clap(long = "manifest-path", value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
     pub manifest_path: Option<path::PathBuf>,
 
-    /// Output BOM format: json, xml
-    #[clap(long = "format", short = 'f', value_name = "FORMAT")]
-    pub format: Option<Format>,
+    /// Output BOM format: json, xml. Several formats can be written at once, e.g. `json,xml`
+    #[clap(
+        long = "format",
+        short = 'f',
+        value_name = "FORMAT",
+        value_delimiter = ',',
+        action = ArgAction::Append
+    )]
+    pub format: Vec<Format>,
 
     // the ValueEnum derive provides ample help text
     #[clap(long = "describe")]
@@ -63,11 +70,13 @@ pub struct Args {
     /// The target platform to generate the SBOM for, or 'all' for all targets.
     #[clap(
         long = "target",
+        action = ArgAction::Append,
         long_help = "The target to generate the SBOM for, e.g. 'x86_64-unknown-linux-gnu'.
 Use 'all' to include dependencies for all possible targets.
+Can be given multiple times to write one SBOM per target, with the target in the filename.
 Defaults to the host target, as printed by 'rustc -vV'"
     )]
-    pub target: Option<String>,
+    pub target: Vec<String>,
 
     /// Include the target platform of the BOM in the filename
     #[clap(long = "target-in-filename")]
@@ -89,6 +98,48 @@ Defaults to the host target, as printed by 'rustc -vV'"
     )]
     pub filename_override: Option<String>,
 
+    /// Pattern for the output filename, with the placeholders {name}, {version}, {kind}, {target} and {ext}, e.g. `{name}-{version}.cdx.{ext}`
+    #[clap(
+        long = "output-pattern",
+        value_name = "PATTERN",
+        conflicts_with = "filename_override"
+    )]
+    pub output_pattern: Option<String>,
+
+    /// Write the SBOM to this file, or to stdout if `-`. Requires that a single SBOM is generated
+    #[clap(
+        long = "output",
+        short = 'o',
+        value_name = "FILE",
+        conflicts_with_all = ["describe", "filename_override", "output_pattern", "output_dir"]
+    )]
+    pub output: Option<path::PathBuf>,
+
+    /// Compare the SBOM with a baseline SBOM after writing it, printing the crates that were added, removed or changed. Exits with an error if there are any. The changes are printed to stderr if the SBOM is written to stdout
+    #[clap(
+        long = "diff",
+        value_name = "BASELINE",
+        value_hint = clap::ValueHint::FilePath,
+        conflicts_with = "describe"
+    )]
+    pub diff: Option<path::PathBuf>,
+
+    /// Write the SBOMs into this directory instead of next to each Cargo.toml
+    #[clap(long = "output-dir", value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    pub output_dir: Option<path::PathBuf>,
+
+    /// Leave out packages matching a glob, their dependencies depend on their dependents instead
+    #[clap(long = "exclude", value_name = "GLOB", action=ArgAction::Append)]
+    pub exclude: Vec<String>,
+
+    /// Leave out packages matching a glob and the dependencies only they pull in
+    #[clap(long = "exclude-tree", value_name = "GLOB", action=ArgAction::Append)]
+    pub exclude_tree: Vec<String>,
+
+    /// Only list packages matching a glob, the dependencies of others depend on their dependents
+    #[clap(long = "only", value_name = "GLOB", action=ArgAction::Append)]
+    pub only: Vec<String>,
+
     /// Reject the deprecated '/' separator for licenses, treating 'MIT/Apache-2.0' as an error
     #[clap(long = "license-strict")]
     pub license_strict: bool,
@@ -97,13 +148,88 @@ Defaults to the host target, as printed by 'rustc -vV'"
     #[clap(long = "license-accept-named", action=ArgAction::Append)]
     pub license_accept_named: Vec<String>,
 
-    /// The CycloneDX specification version to output: `1.3`, `1.4` or `1.5`. Defaults to 1.3
-    #[clap(long = "spec-version")]
+    /// Embed the license files found in the source directory of every package
+    #[clap(long = "license-texts")]
+    pub license_texts: bool,
+
+    /// The CycloneDX specification version to output: `1.3`, `1.4` or `1.5`, `1.6` is not supported yet. Defaults to 1.3
+    #[clap(long = "spec-version", value_parser = parse_spec_version)]
     pub spec_version: Option<SpecVersion>,
 
     /// Do not include build-time dependencies in the SBOM
     #[clap(long = "no-build-deps")]
     pub no_build_deps: bool,
+
+    /// Include dev-dependencies in the SBOM, with scope `excluded`
+    #[clap(long = "dev-deps")]
+    pub dev_deps: bool,
+
+    /// Do not include dev-dependencies in the SBOM (default)
+    #[clap(long = "no-dev-deps", conflicts_with = "dev_deps")]
+    pub no_dev_deps: bool,
+
+    /// Attach hashes of the binaries and libraries built into this directory, e.g. target/release
+    #[clap(long = "artifact-dir", value_name = "DIR", value_hint = clap::ValueHint::DirPath)]
+    pub artifact_dir: Option<path::PathBuf>,
+
+    /// Hash the source directories of path dependencies and vendored crates without a checksum
+    #[clap(long = "hash-sources")]
+    pub hash_sources: bool,
+
+    /// Look up the description, publication date and keywords of every crate on crates.io
+    #[clap(long = "crates-io")]
+    pub crates_io: bool,
+
+    /// Also look up the owners of every crate on crates.io, which takes another request per crate
+    #[clap(long = "crates-io-owners", requires = "crates_io")]
+    pub crates_io_owners: bool,
+
+    /// Do not access the network, crates.io metadata is skipped
+    #[clap(long = "offline")]
+    pub offline: bool,
+
+    /// Add the vulnerabilities of crates.io crates known to the RustSec advisory database. Requires spec version 1.4 or later
+    #[clap(long = "advisories")]
+    pub advisories: bool,
+
+    /// Checkout of the RustSec advisory database, cloned if missing and updated unless offline [default: $CARGO_HOME/advisory-db]
+    #[clap(
+        long = "advisory-db",
+        value_name = "DIR",
+        value_hint = clap::ValueHint::DirPath,
+        requires = "advisories"
+    )]
+    pub advisory_db: Option<path::PathBuf>,
+
+    /// Write the vulnerabilities to a separate VEX document instead of the SBOM, linking to the SBOM with BOM-Links. Requires that a single SBOM is generated
+    #[clap(
+        long = "vex",
+        value_name = "FILE",
+        requires = "advisories",
+        conflicts_with_all = ["describe", "diff"]
+    )]
+    pub vex: Option<path::PathBuf>,
+
+    /// Sign the SBOM with the Ed25519 or RSA private key in this PEM file, using JSON Signature Format. Requires JSON output with spec version 1.5
+    #[clap(long = "sign-key", value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    pub sign_key: Option<path::PathBuf>,
+
+    /// Write an in-toto attestation next to every JSON SBOM, with the SBOM as a CycloneDX predicate about the SBOM file. Signed with --sign-key as a DSSE envelope if given
+    #[clap(long = "attestation", conflicts_with = "output")]
+    pub attestation: bool,
+
+    /// Produce byte-identical output for the same inputs: the timestamp is taken from
+    /// SOURCE_DATE_EPOCH or left out, the serial number is derived from Cargo.lock
+    #[clap(long = "reproducible")]
+    pub reproducible: bool,
+
+    /// Describe all workspace members (default)
+    #[clap(long = "workspace")]
+    pub workspace: bool,
+
+    /// Write a single SBOM for the workspace, with every member as a top-level component
+    #[clap(long = "merged", requires = "workspace", conflicts_with = "describe")]
+    pub merged: bool,
 }
 
 impl Args {
@@ -139,7 +265,7 @@ impl Args {
                 })
             };
 
-        let target_string = self.target.clone().unwrap_or_else(host_platform);
+        let target_string = self.target.first().cloned().unwrap_or_else(host_platform);
         let target = Some(if &target_string == "all" {
             Target::AllTargets
         } else {
@@ -151,18 +277,29 @@ impl Args {
             false => PlatformSuffix::NotIncluded,
         };
 
-        let filename_pattern = match &self.filename_override {
-            Some(string) => {
+        let filename_pattern = match (&self.filename_override, &self.output_pattern) {
+            (Some(string), _) => {
                 let name_override = FilenameOverride::new(string)?;
                 FilenamePattern::Custom(name_override)
             }
-            None => FilenamePattern::CrateName,
+            (None, Some(pattern)) => FilenamePattern::Template(FilenameTemplate::new(pattern)?),
+            (None, None) => FilenamePattern::CrateName,
         };
 
-        let output_options = Some(OutputOptions {
-            filename: filename_pattern,
-            platform_suffix,
-        });
+        // Leave the output options unset unless given, so they can come from package metadata
+        let output_options = (filename_pattern != FilenamePattern::CrateName
+            || platform_suffix != PlatformSuffix::NotIncluded)
+            .then_some(OutputOptions {
+                filename: filename_pattern,
+                platform_suffix,
+            });
+
+        let package_filter = Some(PackageFilter {
+            exclude: self.exclude.clone(),
+            exclude_tree: self.exclude_tree.clone(),
+            only: self.only.clone(),
+        })
+        .filter(|filter| !filter.is_empty());
 
         let license_parser = Some(LicenseParserOptions {
             mode: match self.license_strict {
@@ -174,10 +311,24 @@ impl Args {
 
         let describe = self.describe;
         let spec_version = self.spec_version;
-        let only_normal_deps = Some(self.no_build_deps);
+        let only_normal_deps = self.no_build_deps.then_some(true);
+        let merged = self.merged.then_some(true);
+        let dev_deps = match (self.dev_deps, self.no_dev_deps) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        };
 
         Ok(SbomConfig {
-            format: self.format,
+            formats: (!self.format.is_empty()).then(|| {
+                let mut formats = Vec::new();
+                for format in &self.format {
+                    if !formats.contains(format) {
+                        formats.push(*format);
+                    }
+                }
+                formats
+            }),
             included_dependencies,
             output_options,
             features,
@@ -186,14 +337,59 @@ impl Args {
             describe,
             spec_version,
             only_normal_deps,
+            properties: None,
+            merged,
+            dev_deps,
+            artifact_dir: self.artifact_dir.clone(),
+            license_texts: self.license_texts.then_some(true),
+            crates_io: self.crates_io.then_some(true),
+            crates_io_owners: self.crates_io_owners.then_some(true),
+            offline: self.offline.then_some(true),
+            hash_sources: self.hash_sources.then_some(true),
+            package_filter,
+            output_dir: self.output_dir.clone(),
+            reproducible: self.reproducible.then_some(true),
+            advisories: self.advisories.then_some(true),
+            advisory_db: self.advisory_db.clone(),
+            sign_key: self.sign_key.clone(),
+            attestation: self.attestation.then_some(true),
         })
     }
+
+    /// Returns one config per target platform given on the command line, which include the
+    /// target in the filename if there are several.
+    pub fn as_configs(&self) -> Result<Vec<SbomConfig>, ArgsError> {
+        let config = self.as_config()?;
+        if self.target.len() <= 1 {
+            return Ok(vec![config]);
+        }
+
+        // Only the platform suffix is set, a filename or pattern from the config files is kept
+        let output_options = OutputOptions {
+            platform_suffix: PlatformSuffix::Included,
+            ..config.output_options()
+        };
+        Ok(self
+            .target
+            .iter()
+            .map(|target| SbomConfig {
+                target: Some(match target.as_str() {
+                    "all" => Target::AllTargets,
+                    _ => Target::SingleTarget(target.clone()),
+                }),
+                output_options: Some(output_options.clone()),
+                ..config.clone()
+            })
+            .collect())
+    }
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum ArgsError {
     #[error("Invalid filename")]
     FilenameOverrideError(#[from] FilenameOverrideError),
+    #[error("Invalid output pattern")]
+    FilenameTemplateError(#[from] FilenameTemplateError),
 }
 
 #[cfg(test)]
@@ -205,6 +401,19 @@ pub fn parse_to_config(args: &[&str]) -> SbomConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_formats() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert_eq!(config.formats, None);
+        assert_eq!(config.formats(), [Format::Xml]);
+
+        let config = parse_to_config(&["cyclonedx", "--format=json"]);
+        assert_eq!(config.formats(), [Format::Json]);
+
+        let config = parse_to_config(&["cyclonedx", "--format=json,xml", "-f", "json"]);
+        assert_eq!(config.formats(), [Format::Json, Format::Xml]);
+    }
+
     #[test]
     fn parse_features() {
         let args = vec!["cyclonedx"];
@@ -233,6 +442,90 @@ mod tests {
         assert!(!contains_feature(&config, ""));
     }
 
+    #[test]
+    fn parse_spec_version() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert_eq!(config.spec_version, None);
+
+        let config = parse_to_config(&["cyclonedx", "--spec-version", "1.4"]);
+        assert_eq!(config.spec_version, Some(SpecVersion::V1_4));
+
+        let config = parse_to_config(&["cyclonedx", "--spec-version=1.5"]);
+        assert_eq!(config.spec_version, Some(SpecVersion::V1_5));
+
+        let error = Args::try_parse_from(["cyclonedx", "--spec-version", "1.6"]).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("Spec version `1.6` is not supported, expected 1.3, 1.4 or 1.5"));
+    }
+
+    #[test]
+    fn parse_targets() {
+        let args = Args::parse_from(["cyclonedx", "--target", "x86_64-unknown-linux-gnu"]);
+        let configs = args.as_configs().unwrap();
+        assert_eq!(configs.len(), 1);
+        assert_eq!(
+            configs[0].target,
+            Some(Target::SingleTarget("x86_64-unknown-linux-gnu".to_string()))
+        );
+        assert_eq!(configs[0].output_options, None);
+
+        let args = Args::parse_from([
+            "cyclonedx",
+            "--target=x86_64-pc-windows-msvc",
+            "--target=all",
+            "--override-filename=bom",
+        ]);
+        let configs = args.as_configs().unwrap();
+        assert_eq!(
+            configs
+                .iter()
+                .map(|config| config.target.clone().unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Target::SingleTarget("x86_64-pc-windows-msvc".to_string()),
+                Target::AllTargets
+            ]
+        );
+        for config in configs {
+            let output_options = config.output_options.unwrap();
+            assert_eq!(output_options.platform_suffix, PlatformSuffix::Included);
+            assert!(matches!(
+                output_options.filename,
+                FilenamePattern::Custom(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_dev_deps() {
+        assert_eq!(parse_to_config(&["cyclonedx"]).dev_deps, None);
+        assert_eq!(
+            parse_to_config(&["cyclonedx", "--dev-deps"]).dev_deps,
+            Some(true)
+        );
+        assert_eq!(
+            parse_to_config(&["cyclonedx", "--no-dev-deps"]).dev_deps,
+            Some(false)
+        );
+        assert!(Args::try_parse_from(["cyclonedx", "--dev-deps", "--no-dev-deps"]).is_err());
+    }
+
+    #[test]
+    fn parse_merged() {
+        let config = parse_to_config(&["cyclonedx", "--workspace", "--merged"]);
+        assert_eq!(config.merged, Some(true));
+
+        assert!(Args::try_parse_from(["cyclonedx", "--merged"]).is_err());
+        assert!(Args::try_parse_from([
+            "cyclonedx",
+            "--workspace",
+            "--merged",
+            "--describe=binaries"
+        ])
+        .is_err());
+    }
+
     fn contains_feature(config: &SbomConfig, feature: &str) -> bool {
         config
             .features
@@ -1,6 +1,7 @@
 use cyclonedx_bom::models::bom::SpecVersion;
 use serde::Deserialize;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -25,7 +26,7 @@ use crate::format::Format;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct SbomConfig {
-    pub format: Option<Format>,
+    pub formats: Option<Vec<Format>>,
     pub included_dependencies: Option<IncludedDependencies>,
     pub output_options: Option<OutputOptions>,
     pub features: Option<Features>,
@@ -34,6 +35,22 @@ pub struct SbomConfig {
     pub describe: Option<Describe>,
     pub spec_version: Option<SpecVersion>,
     pub only_normal_deps: Option<bool>,
+    pub properties: Option<BTreeMap<String, String>>,
+    pub merged: Option<bool>,
+    pub dev_deps: Option<bool>,
+    pub artifact_dir: Option<PathBuf>,
+    pub license_texts: Option<bool>,
+    pub crates_io: Option<bool>,
+    pub crates_io_owners: Option<bool>,
+    pub offline: Option<bool>,
+    pub hash_sources: Option<bool>,
+    pub package_filter: Option<PackageFilter>,
+    pub output_dir: Option<PathBuf>,
+    pub reproducible: Option<bool>,
+    pub advisories: Option<bool>,
+    pub advisory_db: Option<PathBuf>,
+    pub sign_key: Option<PathBuf>,
+    pub attestation: Option<bool>,
 }
 
 impl SbomConfig {
@@ -43,11 +60,12 @@ impl SbomConfig {
 
     pub fn merge(&self, other: &SbomConfig) -> SbomConfig {
         SbomConfig {
-            format: other.format.or(self.format),
+            formats: other.formats.clone().or_else(|| self.formats.clone()),
             included_dependencies: other.included_dependencies.or(self.included_dependencies),
             output_options: other
                 .output_options
                 .clone()
+                .map(|other| self.output_options.clone().unwrap_or_default().merge(other))
                 .or_else(|| self.output_options.clone()),
             features: other.features.clone().or_else(|| self.features.clone()),
             target: other.target.clone().or_else(|| self.target.clone()),
@@ -59,11 +77,50 @@ impl SbomConfig {
             describe: other.describe.or(self.describe),
             spec_version: other.spec_version.or(self.spec_version),
             only_normal_deps: other.only_normal_deps.or(self.only_normal_deps),
+            properties: other.properties.clone().or_else(|| self.properties.clone()),
+            merged: other.merged.or(self.merged),
+            dev_deps: other.dev_deps.or(self.dev_deps),
+            artifact_dir: other
+                .artifact_dir
+                .clone()
+                .or_else(|| self.artifact_dir.clone()),
+            license_texts: other.license_texts.or(self.license_texts),
+            crates_io: other.crates_io.or(self.crates_io),
+            crates_io_owners: other.crates_io_owners.or(self.crates_io_owners),
+            offline: other.offline.or(self.offline),
+            hash_sources: other.hash_sources.or(self.hash_sources),
+            package_filter: other
+                .package_filter
+                .clone()
+                .map(|other| self.package_filter.clone().unwrap_or_default().merge(other))
+                .or_else(|| self.package_filter.clone()),
+            output_dir: other.output_dir.clone().or_else(|| self.output_dir.clone()),
+            reproducible: other.reproducible.or(self.reproducible),
+            advisories: other.advisories.or(self.advisories),
+            advisory_db: other
+                .advisory_db
+                .clone()
+                .or_else(|| self.advisory_db.clone()),
+            sign_key: other.sign_key.clone().or_else(|| self.sign_key.clone()),
+            attestation: other.attestation.or(self.attestation),
+        }
+    }
+
+    /// Reads the `[package.metadata.cyclonedx]` table of a package, as found in the `metadata`
+    /// field of `cargo metadata` output. Returns an empty config if the table is missing.
+    pub fn from_package_metadata(metadata: &serde_json::Value) -> Result<Self, ConfigError> {
+        match metadata.get("cyclonedx") {
+            Some(table) => ConfigTable::deserialize(table)?.into_config(),
+            None => Ok(Self::empty_config()),
         }
     }
 
-    pub fn format(&self) -> Format {
-        self.format.unwrap_or_default()
+    /// The formats to write, XML if none are given
+    pub fn formats(&self) -> Vec<Format> {
+        self.formats
+            .clone()
+            .filter(|formats| !formats.is_empty())
+            .unwrap_or_else(|| vec![Format::default()])
     }
 
     pub fn included_dependencies(&self) -> IncludedDependencies {
@@ -79,6 +136,192 @@ impl SbomConfig {
     }
 }
 
+/// The name of the config file that is read from the workspace root
+pub const CONFIG_FILE_NAME: &str = "cyclonedx.toml";
+
+/// The options of a `cyclonedx.toml` file in the workspace root.
+///
+/// The top-level options apply to every workspace member, and the `[members.<name>]` tables
+/// override them for single members.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ConfigFile {
+    pub config: SbomConfig,
+    pub members: BTreeMap<String, SbomConfig>,
+}
+
+impl ConfigFile {
+    /// Reads the config file, returning an empty config if it does not exist.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(ConfigError::Read(error)),
+        }
+    }
+
+    /// Returns the config of a workspace member, with its overrides applied.
+    pub fn member_config(&self, name: &str) -> SbomConfig {
+        match self.members.get(name) {
+            Some(member) => self.config.merge(member),
+            None => self.config.clone(),
+        }
+    }
+}
+
+impl FromStr for ConfigFile {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut table: toml::Table = s.parse()?;
+        let members = match table.remove("members") {
+            Some(members) => members
+                .try_into::<BTreeMap<String, ConfigTable>>()?
+                .into_iter()
+                .map(|(name, member)| Ok((name, member.into_config()?)))
+                .collect::<Result<_, ConfigError>>()?,
+            None => BTreeMap::new(),
+        };
+
+        Ok(Self {
+            config: toml::Value::Table(table)
+                .try_into::<ConfigTable>()?
+                .into_config()?,
+            members,
+        })
+    }
+}
+
+/// The generation options of a `[package.metadata.cyclonedx]` table, or of a `cyclonedx.toml`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct ConfigTable {
+    format: Option<Formats>,
+    spec_version: Option<String>,
+    included_dependencies: Option<String>,
+    no_build_deps: Option<bool>,
+    dev_deps: Option<bool>,
+    override_filename: Option<String>,
+    output_pattern: Option<String>,
+    #[serde(default)]
+    properties: BTreeMap<String, String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    #[serde(default)]
+    exclude_tree: Vec<String>,
+    #[serde(default)]
+    only: Vec<String>,
+}
+
+/// Either a single format, `format = "json"`, or a list of formats, `format = ["json", "xml"]`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Formats {
+    Single(Format),
+    List(Vec<Format>),
+}
+
+impl ConfigTable {
+    fn into_config(self) -> Result<SbomConfig, ConfigError> {
+        let formats = match self.format {
+            Some(Formats::Single(format)) => Some(vec![format]),
+            Some(Formats::List(list)) if list.is_empty() => {
+                return Err(ConfigError::InvalidValue {
+                    key: "format",
+                    message: "Expected at least one format".to_owned(),
+                })
+            }
+            Some(Formats::List(list)) => {
+                let mut formats = Vec::new();
+                for format in list {
+                    if !formats.contains(&format) {
+                        formats.push(format);
+                    }
+                }
+                Some(formats)
+            }
+            None => None,
+        };
+        let included_dependencies = self
+            .included_dependencies
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|message| ConfigError::InvalidValue {
+                key: "included-dependencies",
+                message,
+            })?;
+        let spec_version = self
+            .spec_version
+            .map(|value| parse_spec_version(&value))
+            .transpose()
+            .map_err(|message| ConfigError::InvalidValue {
+                key: "spec-version",
+                message,
+            })?;
+        let output_options = self
+            .override_filename
+            .map(FilenameOverride::new)
+            .transpose()
+            .map_err(|error| ConfigError::InvalidValue {
+                key: "override-filename",
+                message: error.to_string(),
+            })?
+            .map(FilenamePattern::Custom);
+        let output_pattern = self
+            .output_pattern
+            .map(FilenameTemplate::new)
+            .transpose()
+            .map_err(|error| ConfigError::InvalidValue {
+                key: "output-pattern",
+                message: error.to_string(),
+            })?
+            .map(FilenamePattern::Template);
+        if output_options.is_some() && output_pattern.is_some() {
+            return Err(ConfigError::InvalidValue {
+                key: "output-pattern",
+                message: "cannot be combined with `override-filename`".to_owned(),
+            });
+        }
+        let output_options = output_options
+            .or(output_pattern)
+            .map(|filename| OutputOptions {
+                filename,
+                platform_suffix: PlatformSuffix::default(),
+            });
+        let package_filter = PackageFilter {
+            exclude: self.exclude,
+            exclude_tree: self.exclude_tree,
+            only: self.only,
+        };
+
+        Ok(SbomConfig {
+            formats,
+            included_dependencies,
+            output_options,
+            spec_version,
+            only_normal_deps: self.no_build_deps,
+            dev_deps: self.dev_deps,
+            properties: (!self.properties.is_empty()).then_some(self.properties),
+            package_filter: Some(package_filter).filter(|filter| !filter.is_empty()),
+            ..SbomConfig::empty_config()
+        })
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Could not read the config file")]
+    Read(#[source] std::io::Error),
+
+    #[error("Could not parse the config file")]
+    ConfigFile(#[from] toml::de::Error),
+
+    #[error("Could not read the [package.metadata.cyclonedx] table")]
+    PackageMetadata(#[from] serde_json::Error),
+
+    #[error("Invalid value for `{key}`: {message}")]
+    InvalidValue { key: &'static str, message: String },
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum IncludedDependencies {
     TopLevelDependencies,
@@ -98,12 +341,35 @@ impl FromStr for IncludedDependencies {
     }
 }
 
+/// Parses a `spec-version`, naming the supported versions if the library cannot write it
+pub fn parse_spec_version(s: &str) -> Result<SpecVersion, String> {
+    s.parse()
+        .map_err(|_| format!("Spec version `{s}` is not supported, expected 1.3, 1.4 or 1.5"))
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct OutputOptions {
     pub filename: FilenamePattern,
     pub platform_suffix: PlatformSuffix,
 }
 
+impl OutputOptions {
+    /// The default filename and platform suffix cannot be configured, so they do not replace
+    /// the ones configured in `self`
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            filename: match other.filename {
+                FilenamePattern::CrateName => self.filename,
+                filename => filename,
+            },
+            platform_suffix: match other.platform_suffix {
+                PlatformSuffix::NotIncluded => self.platform_suffix,
+                platform_suffix => platform_suffix,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Features {
     pub all_features: bool,
@@ -131,6 +397,7 @@ impl Target {
 pub enum FilenamePattern {
     CrateName,
     Custom(FilenameOverride),
+    Template(FilenameTemplate),
 }
 
 impl Default for FilenamePattern {
@@ -193,6 +460,86 @@ pub enum FilenameOverrideError {
     TheOne(String),
 }
 
+/// A filename with placeholders, e.g. `{name}-{version}.cdx.{ext}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilenameTemplate(String);
+
+impl FilenameTemplate {
+    /// The name of the package, or of the Cargo target with `--describe`
+    pub const NAME: &'static str = "name";
+    pub const VERSION: &'static str = "version";
+    /// The kinds of the Cargo target with `--describe`, e.g. `bin`, and empty otherwise
+    pub const KIND: &'static str = "kind";
+    /// The target platform, or `all`
+    pub const TARGET: &'static str = "target";
+    /// The file extension of the format, e.g. `json`
+    pub const EXT: &'static str = "ext";
+
+    const PLACEHOLDERS: [&'static str; 5] = [
+        Self::NAME,
+        Self::VERSION,
+        Self::KIND,
+        Self::TARGET,
+        Self::EXT,
+    ];
+
+    pub fn new(template: impl Into<String>) -> Result<Self, FilenameTemplateError> {
+        let template = Self(template.into());
+        if template.0.contains(std::path::MAIN_SEPARATOR) {
+            return Err(FilenameTemplateError::PathSeparator(
+                std::path::MAIN_SEPARATOR.to_string(),
+            ));
+        }
+        // Rendering fails on unknown or unclosed placeholders
+        template.try_render(|placeholder| {
+            Self::PLACEHOLDERS
+                .contains(&placeholder)
+                .then(String::new)
+                .ok_or_else(|| FilenameTemplateError::UnknownPlaceholder(placeholder.to_owned()))
+        })?;
+        Ok(template)
+    }
+
+    /// Returns `true` if the template uses the placeholder
+    pub fn contains(&self, placeholder: &str) -> bool {
+        self.0.contains(&format!("{{{}}}", placeholder))
+    }
+
+    /// Replaces every placeholder with its value
+    pub fn render(&self, value: impl Fn(&str) -> String) -> String {
+        self.try_render(|placeholder| Ok(value(placeholder)))
+            .expect("the template was validated")
+    }
+
+    fn try_render(
+        &self,
+        value: impl Fn(&str) -> Result<String, FilenameTemplateError>,
+    ) -> Result<String, FilenameTemplateError> {
+        let mut result = String::new();
+        let mut rest = self.0.as_str();
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            let end = rest[start..]
+                .find('}')
+                .ok_or(FilenameTemplateError::UnclosedPlaceholder)?;
+            result.push_str(&value(&rest[start + 1..start + end])?);
+            rest = &rest[start + end + 1..];
+        }
+        result.push_str(rest);
+        Ok(result)
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum FilenameTemplateError {
+    #[error("Illegal characters in output pattern: {0}")]
+    PathSeparator(String),
+    #[error("Unknown placeholder `{{{0}}}` in output pattern, expected one of {{name}}, {{version}}, {{kind}}, {{target}} or {{ext}}")]
+    UnknownPlaceholder(String),
+    #[error("Unclosed placeholder in output pattern")]
+    UnclosedPlaceholder,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub enum PlatformSuffix {
     Included,
@@ -233,6 +580,75 @@ pub enum ParseMode {
     Lax,
 }
 
+/// Selects the packages listed in the SBOM by their names, which can contain `*` and `?` wildcards
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageFilter {
+    /// Leave out these packages, their dependencies depend on their dependents instead
+    pub exclude: Vec<String>,
+    /// Leave out these packages and the dependencies only they pull in
+    pub exclude_tree: Vec<String>,
+    /// Only list these packages, if any are given
+    pub only: Vec<String>,
+}
+
+impl PackageFilter {
+    pub fn merge(mut self, other: Self) -> Self {
+        self.exclude.extend(other.exclude);
+        self.exclude_tree.extend(other.exclude_tree);
+        self.only.extend(other.only);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.exclude.is_empty() && self.exclude_tree.is_empty() && self.only.is_empty()
+    }
+
+    /// Returns `true` if the package and its dependencies only it pulls in are left out
+    pub fn excludes_tree(&self, name: &str) -> bool {
+        self.exclude_tree
+            .iter()
+            .any(|glob| glob_matches(glob, name))
+    }
+
+    /// Returns `true` if the package is left out, but not its dependencies
+    pub fn excludes(&self, name: &str) -> bool {
+        self.exclude.iter().any(|glob| glob_matches(glob, name))
+            || (!self.only.is_empty() && !self.only.iter().any(|glob| glob_matches(glob, name)))
+    }
+}
+
+/// Matches a name against a pattern where `*` stands for any sequence of characters
+/// and `?` for any single character
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `*` in the pattern and of the name when it was reached
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // Let the `*` match one more character
+                Some((star, star_n)) => {
+                    backtrack = Some((star, star_n + 1));
+                    p = star + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 /// What does the SBOM describe?
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
 pub enum Describe {
@@ -249,6 +665,64 @@ pub enum Describe {
 mod test {
     use super::*;
 
+    #[test]
+    fn it_should_render_filename_templates() {
+        let template = FilenameTemplate::new("{name}-{version}.cdx.{ext}").unwrap();
+        assert!(template.contains(FilenameTemplate::VERSION));
+        assert!(!template.contains(FilenameTemplate::TARGET));
+        assert_eq!(
+            template.render(|placeholder| placeholder.to_uppercase()),
+            "NAME-VERSION.cdx.EXT"
+        );
+
+        assert_eq!(
+            FilenameTemplate::new("{name}-{arch}.json"),
+            Err(FilenameTemplateError::UnknownPlaceholder("arch".to_owned()))
+        );
+        assert_eq!(
+            FilenameTemplate::new("{name.json"),
+            Err(FilenameTemplateError::UnclosedPlaceholder)
+        );
+        assert!(matches!(
+            FilenameTemplate::new(format!("dir{}{{name}}", std::path::MAIN_SEPARATOR)),
+            Err(FilenameTemplateError::PathSeparator(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_match_globs() {
+        assert!(glob_matches("serde", "serde"));
+        assert!(!glob_matches("serde", "serde_json"));
+        assert!(glob_matches("serde*", "serde_json"));
+        assert!(glob_matches("*-sys", "openssl-sys"));
+        assert!(!glob_matches("*-sys", "openssl-src"));
+        assert!(glob_matches("windows_*_msvc", "windows_x86_64_msvc"));
+        assert!(glob_matches("tokio-?", "tokio-a"));
+        assert!(!glob_matches("tokio-?", "tokio-ab"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("a*b*c", "aXbYbZc"));
+    }
+
+    #[test]
+    fn it_should_filter_packages() {
+        let filter = PackageFilter {
+            exclude: vec!["windows*".to_owned()],
+            exclude_tree: vec!["criterion".to_owned()],
+            only: Vec::new(),
+        };
+        assert!(filter.excludes("windows-sys"));
+        assert!(!filter.excludes("serde"));
+        assert!(filter.excludes_tree("criterion"));
+        assert!(!filter.excludes_tree("windows-sys"));
+
+        let filter = filter.merge(PackageFilter {
+            only: vec!["serde*".to_owned()],
+            ..Default::default()
+        });
+        assert!(filter.excludes("tokio"));
+        assert!(!filter.excludes("serde_json"));
+    }
+
     #[test]
     fn it_should_error_for_a_filename_with_a_path_separator() {
         let filename = format!("directory{}filename", std::path::MAIN_SEPARATOR);
@@ -273,6 +747,194 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn it_should_read_package_metadata() {
+        let metadata = serde_json::json!({
+            "cyclonedx": {
+                "format": "json",
+                "spec-version": "1.5",
+                "included-dependencies": "top-level",
+                "no-build-deps": true,
+                "dev-deps": false,
+                "override-filename": "sbom",
+                "properties": { "org:team": "platform" },
+            },
+            "docs": { "rs": { "all-features": true } },
+        });
+
+        let config = SbomConfig::from_package_metadata(&metadata).unwrap();
+
+        assert_eq!(
+            config,
+            SbomConfig {
+                formats: Some(vec![Format::Json]),
+                included_dependencies: Some(IncludedDependencies::TopLevelDependencies),
+                output_options: Some(OutputOptions {
+                    filename: FilenamePattern::Custom(FilenameOverride("sbom".to_string())),
+                    platform_suffix: PlatformSuffix::NotIncluded,
+                }),
+                spec_version: Some(SpecVersion::V1_5),
+                only_normal_deps: Some(true),
+                dev_deps: Some(false),
+                properties: Some([("org:team".to_string(), "platform".to_string())].into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_ignore_missing_package_metadata() {
+        let config = SbomConfig::from_package_metadata(&serde_json::Value::Null).unwrap();
+
+        assert_eq!(config, SbomConfig::empty_config());
+    }
+
+    #[test]
+    fn it_should_reject_invalid_package_metadata() {
+        let metadata = serde_json::json!({ "cyclonedx": { "spec-version": "1.6" } });
+        let error = SbomConfig::from_package_metadata(&metadata).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid value for `spec-version`: Spec version `1.6` is not supported, expected 1.3, 1.4 or 1.5"
+        );
+
+        let metadata = serde_json::json!({ "cyclonedx": { "formt": "json" } });
+        let error = SbomConfig::from_package_metadata(&metadata).unwrap_err();
+        assert!(matches!(error, ConfigError::PackageMetadata(_)));
+    }
+
+    #[test]
+    fn it_should_parse_a_config_file() {
+        let config_file: ConfigFile = r#"
+            format = "json"
+            spec-version = "1.4"
+            exclude = ["windows_*"]
+
+            [members.cli]
+            spec-version = "1.5"
+            override-filename = "cli-sbom"
+            exclude-tree = ["criterion"]
+            "#
+        .parse()
+        .unwrap();
+
+        let workspace_config = SbomConfig {
+            formats: Some(vec![Format::Json]),
+            spec_version: Some(SpecVersion::V1_4),
+            package_filter: Some(PackageFilter {
+                exclude: vec!["windows_*".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(config_file.member_config("lib"), workspace_config);
+        assert_eq!(
+            config_file.member_config("cli"),
+            SbomConfig {
+                spec_version: Some(SpecVersion::V1_5),
+                output_options: Some(OutputOptions {
+                    filename: FilenamePattern::Custom(FilenameOverride("cli-sbom".to_string())),
+                    platform_suffix: PlatformSuffix::NotIncluded,
+                }),
+                // Filters of the workspace and the member are combined
+                package_filter: Some(PackageFilter {
+                    exclude: vec!["windows_*".to_string()],
+                    exclude_tree: vec!["criterion".to_string()],
+                    only: Vec::new(),
+                }),
+                ..workspace_config
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_parse_a_list_of_formats() {
+        let config_file: ConfigFile = r#"
+            format = ["json", "xml", "json"]
+
+            [members.cli]
+            format = ["xml"]
+            "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            config_file.member_config("lib").formats,
+            Some(vec![Format::Json, Format::Xml])
+        );
+        assert_eq!(
+            config_file.member_config("cli").formats,
+            Some(vec![Format::Xml])
+        );
+
+        let metadata = serde_json::json!({ "cyclonedx": { "format": ["xml", "json"] } });
+        assert_eq!(
+            SbomConfig::from_package_metadata(&metadata)
+                .unwrap()
+                .formats,
+            Some(vec![Format::Xml, Format::Json])
+        );
+
+        let error = "format = []\n".parse::<ConfigFile>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid value for `format`: Expected at least one format"
+        );
+        let error = "format = [\"json\", \"yaml\"]\n"
+            .parse::<ConfigFile>()
+            .unwrap_err();
+        assert!(matches!(error, ConfigError::ConfigFile(_)));
+    }
+
+    #[test]
+    fn it_should_reject_an_invalid_config_file() {
+        let error = "[members.cli]\nformat = \"yaml\"\n"
+            .parse::<ConfigFile>()
+            .unwrap_err();
+        assert!(matches!(error, ConfigError::ConfigFile(_)));
+
+        let error = "included-dependencies = \"some\"\n"
+            .parse::<ConfigFile>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid value for `included-dependencies`: Expected all or top-level, got `some`"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_the_configured_filename_when_adding_the_platform_suffix() {
+        let config_file = SbomConfig {
+            output_options: Some(OutputOptions {
+                filename: FilenamePattern::Template(
+                    FilenameTemplate::new("{name}-{version}.cdx.{ext}").unwrap(),
+                ),
+                platform_suffix: PlatformSuffix::NotIncluded,
+            }),
+            ..Default::default()
+        };
+        // As set by the command line for several `--target`s
+        let cli = SbomConfig {
+            output_options: Some(OutputOptions {
+                filename: FilenamePattern::CrateName,
+                platform_suffix: PlatformSuffix::Included,
+            }),
+            ..Default::default()
+        };
+
+        let config = config_file.merge(&cli);
+
+        assert_eq!(
+            config.output_options,
+            Some(OutputOptions {
+                filename: FilenamePattern::Template(
+                    FilenameTemplate::new("{name}-{version}.cdx.{ext}").unwrap(),
+                ),
+                platform_suffix: PlatformSuffix::Included,
+            })
+        );
+    }
+
     #[test]
     fn it_should_merge_license_names() {
         let config_1 = SbomConfig {
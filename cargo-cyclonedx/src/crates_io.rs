@@ -0,0 +1,382 @@
+//! Enrichment of components with the metadata that crates.io publishes about a crate,
+//! which is not part of `cargo metadata`: who owns the crate and when a version was published.
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use cargo_metadata::{Package, PackageId};
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use cyclonedx_bom::models::property::{Properties, Property};
+use serde::Deserialize;
+
+pub const CRATES_IO_API: &str = "https://crates.io/api/v1";
+
+/// The crawler policy of crates.io allows at most one request per second,
+/// see <https://crates.io/data-access#api>
+pub const REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+pub const PUBLISHED_PROPERTY: &str = "cargo-cyclonedx:crates-io:published";
+pub const OWNER_PROPERTY: &str = "cargo-cyclonedx:crates-io:owner";
+pub const KEYWORD_PROPERTY: &str = "cargo-cyclonedx:crates-io:keyword";
+
+/// Metadata about a crate version published on crates.io
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CrateInfo {
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    /// When the version was published, as an RFC 3339 timestamp
+    pub published: Option<String>,
+    pub owners: Vec<String>,
+    pub keywords: Vec<String>,
+}
+
+#[derive(Clone, Deserialize)]
+struct CrateResponse {
+    #[serde(rename = "crate")]
+    krate: CrateData,
+    #[serde(default)]
+    versions: Vec<VersionData>,
+}
+
+#[derive(Clone, Deserialize)]
+struct CrateData {
+    description: Option<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
+}
+
+#[derive(Clone, Deserialize)]
+struct VersionData {
+    num: String,
+    created_at: String,
+}
+
+#[derive(Deserialize)]
+struct OwnersResponse {
+    users: Vec<OwnerData>,
+}
+
+#[derive(Deserialize)]
+struct OwnerData {
+    login: String,
+}
+
+impl CrateInfo {
+    /// Combines the responses of the `/crates/{name}` and, if the owners were looked up,
+    /// `/crates/{name}/owners` endpoints
+    pub fn from_responses(
+        name: &str,
+        version: &str,
+        krate: &str,
+        owners: Option<&str>,
+    ) -> Result<Self, serde_json::Error> {
+        let krate: CrateResponse = serde_json::from_str(krate)?;
+        let owners = match owners {
+            Some(owners) => Self::owner_logins(serde_json::from_str(owners)?),
+            None => Vec::new(),
+        };
+        Ok(Self::new(name, version, &krate, &owners))
+    }
+
+    fn new(name: &str, version: &str, krate: &CrateResponse, owners: &[String]) -> Self {
+        Self {
+            name: name.to_owned(),
+            version: version.to_owned(),
+            description: krate.krate.description.clone(),
+            published: krate
+                .versions
+                .iter()
+                .find(|v| v.num == version)
+                .map(|v| v.created_at.clone()),
+            owners: owners.to_vec(),
+            keywords: krate.krate.keywords.clone(),
+        }
+    }
+
+    fn owner_logins(owners: OwnersResponse) -> Vec<String> {
+        owners.users.into_iter().map(|user| user.login).collect()
+    }
+
+    /// Adds the metadata to a component. The description from `Cargo.toml` takes precedence.
+    pub fn apply(&self, component: &mut Component) {
+        if component.description.is_none() {
+            component.description = self.description.as_deref().map(NormalizedString::new);
+        }
+
+        let mut properties = Vec::new();
+        if let Some(published) = &self.published {
+            properties.push(Property::new(PUBLISHED_PROPERTY, published));
+        }
+        for owner in &self.owners {
+            properties.push(Property::new(OWNER_PROPERTY, owner));
+        }
+        for keyword in &self.keywords {
+            properties.push(Property::new(KEYWORD_PROPERTY, keyword));
+        }
+        if !properties.is_empty() {
+            component
+                .properties
+                .get_or_insert_with(|| Properties(Vec::new()))
+                .0
+                .extend(properties);
+        }
+
+        let url = format!("https://crates.io/crates/{}/{}", self.name, self.version);
+        match Uri::try_from(url.clone()) {
+            Ok(uri) => component
+                .external_references
+                .get_or_insert_with(|| ExternalReferences(Vec::new()))
+                .0
+                .push(ExternalReference::new(
+                    ExternalReferenceType::Distribution,
+                    uri,
+                )),
+            Err(e) => log::warn!("Invalid crates.io URI ({}): {}", url, e),
+        }
+    }
+}
+
+/// What crates.io returned about a crate, which covers all its versions
+#[derive(Default)]
+struct CachedCrate {
+    krate: Option<CrateResponse>,
+    /// `None` until the owners are looked up
+    owners: Option<Vec<String>>,
+}
+
+/// Queries the crates.io API, remembering the results for crates shared by several SBOMs or
+/// used in several versions. Requests are sent at most once per [`REQUEST_INTERVAL`].
+pub struct CratesIoClient {
+    agent: ureq::Agent,
+    offline: bool,
+    last_request: Option<Instant>,
+    cache: HashMap<String, CachedCrate>,
+}
+
+impl CratesIoClient {
+    /// Creates a client that never touches the network if `offline` is set
+    /// or if Cargo's `CARGO_NET_OFFLINE` environment variable is `true`
+    pub fn new(offline: bool) -> Self {
+        let offline =
+            offline || std::env::var("CARGO_NET_OFFLINE").is_ok_and(|value| value == "true");
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(USER_AGENT)
+            .build();
+        Self {
+            agent,
+            offline,
+            last_request: None,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Looks up the crates.io metadata of all packages published on crates.io, with their
+    /// owners if `owners` is set, which takes another request per crate.
+    /// Packages that cannot be looked up are left out. If crates.io cannot be reached,
+    /// all further lookups are skipped.
+    pub fn crate_infos<'a>(
+        &mut self,
+        packages: impl IntoIterator<Item = &'a Package>,
+        owners: bool,
+    ) -> HashMap<PackageId, CrateInfo> {
+        if self.offline {
+            log::info!("Running offline, skipping the crates.io metadata");
+            return HashMap::new();
+        }
+
+        let mut infos = HashMap::new();
+        for package in packages {
+            if !package.source.as_ref().is_some_and(|s| s.is_crates_io()) {
+                continue;
+            }
+            let name = &package.name;
+            if !self.cache.contains_key(name) {
+                let krate = self
+                    .get::<CrateResponse>(&format!("{}/crates/{}", CRATES_IO_API, name))
+                    .map(Some)
+                    .or_else(|error| self.handle_error(name, error));
+                let Ok(krate) = krate else {
+                    break;
+                };
+                self.cache.insert(
+                    name.clone(),
+                    CachedCrate {
+                        krate,
+                        owners: None,
+                    },
+                );
+            }
+            if owners && self.cache[name].krate.is_some() && self.cache[name].owners.is_none() {
+                let owners = self
+                    .get::<OwnersResponse>(&format!("{}/crates/{}/owners", CRATES_IO_API, name))
+                    .map(|owners| Some(CrateInfo::owner_logins(owners)))
+                    .or_else(|error| self.handle_error(name, error));
+                let Ok(owners) = owners else {
+                    break;
+                };
+                self.cache.get_mut(name).unwrap().owners = Some(owners.unwrap_or_default());
+            }
+
+            let cached = &self.cache[name];
+            if let Some(krate) = &cached.krate {
+                let owners = match (owners, &cached.owners) {
+                    (true, Some(owners)) => owners.as_slice(),
+                    _ => &[],
+                };
+                let version = package.version.to_string();
+                infos.insert(
+                    package.id.clone(),
+                    CrateInfo::new(name, &version, krate, owners),
+                );
+            }
+        }
+        infos
+    }
+
+    /// Sends a request to the API, waiting until [`REQUEST_INTERVAL`] has passed since the last
+    fn get<T: serde::de::DeserializeOwned>(&mut self, url: &str) -> Result<T, Box<ureq::Error>> {
+        if let Some(last_request) = self.last_request {
+            std::thread::sleep(REQUEST_INTERVAL.saturating_sub(last_request.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+
+        log::debug!("Requesting {}", url);
+        self.agent
+            .get(url)
+            .call()?
+            .into_json()
+            .map_err(|e| Box::new(e.into()))
+    }
+
+    /// Skips a crate that cannot be looked up, or returns an error to skip all further lookups
+    /// if crates.io cannot be reached
+    fn handle_error<T>(&mut self, name: &str, error: Box<ureq::Error>) -> Result<Option<T>, ()> {
+        if matches!(*error, ureq::Error::Transport(_)) {
+            log::warn!(
+                "Failed to reach crates.io, skipping the crates.io metadata: {}",
+                error
+            );
+            self.offline = true;
+            Err(())
+        } else {
+            log::warn!("Failed to look up package {} on crates.io: {}", name, error);
+            Ok(None)
+        }
+    }
+}
+
+/// Identifies the tool to crates.io as its crawler policy requires, with where to reach its
+/// maintainers
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_REPOSITORY"),
+    "; contact: ",
+    env!("CARGO_PKG_REPOSITORY"),
+    "/issues)"
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cyclonedx_bom::models::component::Classification;
+
+    const CRATE_RESPONSE: &str = r#"{
+        "crate": {
+            "id": "serde",
+            "name": "serde",
+            "description": "A generic serialization/deserialization framework",
+            "keywords": ["serde", "serialization", "no_std"]
+        },
+        "versions": [
+            { "num": "1.0.194", "created_at": "2024-01-02T19:28:38.036838+00:00" },
+            { "num": "1.0.193", "created_at": "2023-11-20T02:15:18.466431+00:00" }
+        ]
+    }"#;
+
+    const OWNERS_RESPONSE: &str = r#"{
+        "users": [
+            { "id": 3618, "login": "dtolnay", "kind": "user" },
+            { "id": 1234, "login": "github:serde-rs:publish", "kind": "team" }
+        ]
+    }"#;
+
+    #[test]
+    fn it_should_parse_crates_io_responses() {
+        let info =
+            CrateInfo::from_responses("serde", "1.0.193", CRATE_RESPONSE, Some(OWNERS_RESPONSE))
+                .unwrap();
+
+        assert_eq!(
+            info.description.as_deref(),
+            Some("A generic serialization/deserialization framework")
+        );
+        assert_eq!(
+            info.published.as_deref(),
+            Some("2023-11-20T02:15:18.466431+00:00")
+        );
+        assert_eq!(info.owners, ["dtolnay", "github:serde-rs:publish"]);
+        assert_eq!(info.keywords, ["serde", "serialization", "no_std"]);
+    }
+
+    #[test]
+    fn it_should_leave_out_owners_that_were_not_looked_up() {
+        let info = CrateInfo::from_responses("serde", "1.0.194", CRATE_RESPONSE, None).unwrap();
+
+        assert_eq!(
+            info.published.as_deref(),
+            Some("2024-01-02T19:28:38.036838+00:00")
+        );
+        assert!(info.owners.is_empty());
+    }
+
+    #[test]
+    fn it_should_enrich_components() {
+        let info =
+            CrateInfo::from_responses("serde", "1.0.193", CRATE_RESPONSE, Some(OWNERS_RESPONSE))
+                .unwrap();
+        let mut component = Component::new(Classification::Library, "serde", "1.0.193", None);
+        component.description = Some(NormalizedString::new("From Cargo.toml"));
+
+        info.apply(&mut component);
+
+        assert_eq!(
+            component.description,
+            Some(NormalizedString::new("From Cargo.toml"))
+        );
+        let properties = component.properties.unwrap();
+        assert_eq!(
+            properties.get(PUBLISHED_PROPERTY),
+            Some("2023-11-20T02:15:18.466431+00:00")
+        );
+        assert_eq!(
+            properties
+                .0
+                .iter()
+                .filter(|p| p.name == OWNER_PROPERTY)
+                .count(),
+            2
+        );
+        assert_eq!(
+            properties
+                .0
+                .iter()
+                .filter(|p| p.name == KEYWORD_PROPERTY)
+                .count(),
+            3
+        );
+        let references = component.external_references.unwrap();
+        assert_eq!(
+            references.0[0].url.to_string(),
+            "https://crates.io/crates/serde/1.0.193"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Comparison of a generated SBOM with a baseline, e.g. the SBOM of the target branch of a PR.
+use std::fmt::Write;
+use std::path::Path;
+
+use cyclonedx_bom::errors::{JsonReadError, JsonWriteError, XmlReadError};
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::models::component::Component;
+use cyclonedx_bom::operations::diff::BomDiff;
+use thiserror::Error;
+
+use crate::GeneratedSbom;
+
+/// Compares the baseline with the SBOM as it would be written, so that fields which the
+/// spec version cannot express do not show up as changes
+pub fn diff_with_baseline(baseline: &Bom, sbom: &GeneratedSbom) -> Result<BomDiff, DiffError> {
+    let spec_version = sbom.sbom_config.spec_version.unwrap_or_default();
+    let mut output = Vec::new();
+    sbom.bom
+        .clone()
+        .output_as_json(&mut output, spec_version)
+        .map_err(DiffError::JsonWriteError)?;
+    let written = Bom::parse_from_json_slice(&output).map_err(DiffError::JsonReadError)?;
+    Ok(baseline.diff(&written))
+}
+
+/// Reads a baseline SBOM, in XML if the file name ends in `.xml` or else in JSON
+pub fn read_baseline(path: &Path) -> Result<Bom, DiffError> {
+    if !path.extension().is_some_and(|ext| ext == "xml") {
+        return Bom::parse_from_json_file(path).map_err(DiffError::JsonReadError);
+    }
+
+    let input = std::fs::read(path)?;
+    // The XML namespace differs between spec versions, so only the right one succeeds
+    let mut error = None;
+    for version in [SpecVersion::V1_5, SpecVersion::V1_4, SpecVersion::V1_3] {
+        match Bom::parse_from_xml_with_version(input.as_slice(), version) {
+            Ok(bom) => return Ok(bom),
+            Err(e) => error = error.or(Some(e)),
+        }
+    }
+    Err(DiffError::XmlReadError(error.unwrap()))
+}
+
+/// Lists the added, removed and changed crates, one per line. The Cargo targets of a crate
+/// change along with it and are not listed separately. Changes to the dependency graph are
+/// left out, as the `bom-ref`s of path dependencies contain absolute paths.
+pub fn report(diff: &BomDiff) -> String {
+    let mut lines = Vec::new();
+    for component in &diff.added {
+        lines.push(format!("+ {}", describe(component)));
+    }
+    for component in &diff.removed {
+        lines.push(format!("- {}", describe(component)));
+    }
+    for change in &diff.changed {
+        let mut fields = Vec::new();
+        if change.licenses.is_some() {
+            fields.push("licenses");
+        }
+        if change.hashes.is_some() {
+            fields.push("hashes");
+        }
+        let mut line = match &change.version {
+            Some(version) => format!(
+                "~ {} {} -> {}",
+                change.new.name,
+                version.old.as_deref().unwrap_or("(no version)"),
+                version.new.as_deref().unwrap_or("(no version)")
+            ),
+            None => format!("~ {}", describe(&change.new)),
+        };
+        if !fields.is_empty() {
+            write!(line, " ({} changed)", fields.join(" and ")).unwrap();
+        }
+        lines.push(line);
+    }
+
+    let mut report = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        if !lines[..index].contains(line) {
+            writeln!(report, "{line}").unwrap();
+        }
+    }
+    report
+}
+
+/// Returns `true` if components were added, removed or changed
+pub fn has_changes(diff: &BomDiff) -> bool {
+    !(diff.added.is_empty() && diff.removed.is_empty() && diff.changed.is_empty())
+}
+
+fn describe(component: &Component) -> String {
+    match &component.version {
+        Some(version) => format!("{} {}", component.name, version),
+        None => component.name.to_string(),
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DiffError {
+    #[error("I/O error")]
+    IoError(#[from] std::io::Error),
+
+    #[error("Error reading JSON file")]
+    JsonReadError(#[source] JsonReadError),
+
+    #[error("Error reading XML file")]
+    XmlReadError(#[source] XmlReadError),
+
+    #[error("Error writing JSON file")]
+    JsonWriteError(#[source] JsonWriteError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cyclonedx_bom::models::component::Classification;
+
+    fn bom(components: &[(&str, &str)]) -> Bom {
+        components
+            .iter()
+            .fold(Bom::builder(), |builder, (name, version)| {
+                builder.component(Component::new(Classification::Library, name, version, None))
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_report_added_removed_and_upgraded_crates() {
+        let old = bom(&[("serde", "1.0.0"), ("log", "0.4.0")]);
+        let new = bom(&[("serde", "1.0.1"), ("anyhow", "1.0.0")]);
+
+        let diff = old.diff(&new);
+
+        assert!(has_changes(&diff));
+        assert_eq!(
+            report(&diff),
+            "+ anyhow 1.0.0\n- log 0.4.0\n~ serde 1.0.0 -> 1.0.1\n"
+        );
+    }
+
+    #[test]
+    fn it_should_report_no_changes_for_identical_boms() {
+        let old = bom(&[("serde", "1.0.0")]);
+
+        let diff = old.diff(&old.clone());
+
+        assert!(!has_changes(&diff));
+        assert_eq!(report(&diff), "");
+    }
+}
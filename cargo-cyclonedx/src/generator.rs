@@ -1,4 +1,7 @@
+use crate::advisories::{dedupe_vulnerabilities, AdvisoryDatabase, AdvisoryError};
+use crate::attestation::{self, AttestationError};
 use crate::config::Describe;
+use crate::crates_io::{CrateInfo, CratesIoClient};
 use std::cmp::min;
 use std::collections::HashSet;
 /*
@@ -19,11 +22,12 @@ use std::collections::HashSet;
  * SPDX-License-Identifier: Apache-2.0
  */
 use crate::config::FilenamePattern;
+use crate::config::FilenameTemplate;
 use crate::config::PlatformSuffix;
-use crate::config::SbomConfig;
-use crate::config::{IncludedDependencies, ParseMode};
+use crate::config::{ConfigError, ConfigFile, SbomConfig, CONFIG_FILE_NAME};
+use crate::config::{IncludedDependencies, PackageFilter, ParseMode, Target};
 use crate::format::Format;
-use crate::purl::get_purl;
+use crate::purl::{get_purl, is_vendored, registry_url, GitSource};
 
 use cargo_metadata;
 use cargo_metadata::DependencyKind;
@@ -35,23 +39,30 @@ use cargo_metadata::PackageId;
 
 use cargo_lock::package::Checksum;
 use cargo_lock::Lockfile;
-use cargo_metadata::camino::Utf8PathBuf;
+use cargo_metadata::camino::{Utf8Path, Utf8PathBuf};
+use cyclonedx_bom::external_models::date_time::DateTime;
 use cyclonedx_bom::external_models::normalized_string::NormalizedString;
 use cyclonedx_bom::external_models::spdx::SpdxExpression;
 use cyclonedx_bom::external_models::uri::Uri;
 use cyclonedx_bom::models::attached_text::AttachedText;
-use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion, UrnUuid};
 use cyclonedx_bom::models::component::{Classification, Component, Components, Scope};
 use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
 use cyclonedx_bom::models::external_reference::{
-    ExternalReference, ExternalReferenceType, ExternalReferences,
+    BomLink, ExternalReference, ExternalReferenceType, ExternalReferences,
 };
+use cyclonedx_bom::models::hash::{HashAlgorithm, Hashes};
 use cyclonedx_bom::models::license::{License, LicenseChoice, Licenses};
 use cyclonedx_bom::models::metadata::Metadata;
 use cyclonedx_bom::models::metadata::MetadataError;
 use cyclonedx_bom::models::organization::OrganizationalContact;
-use cyclonedx_bom::models::tool::{Tool, Tools};
-use cyclonedx_bom::validation::Validate;
+use cyclonedx_bom::models::property::{Properties, Property};
+use cyclonedx_bom::models::tool::Tool;
+use cyclonedx_bom::operations::identity::IdentityPolicy;
+use cyclonedx_bom::operations::merge::MergePolicy;
+use cyclonedx_bom::operations::signature::{SignatureError, SigningKey};
+use cyclonedx_bom::operations::sort::SortPolicy;
+use cyclonedx_bom::validation::{Validate, Violation};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
@@ -65,10 +76,26 @@ use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use thiserror::Error;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Characters of a `bom-ref` that are percent-encoded in the fragment of a BOM-Link,
+/// everything but the unreserved characters of RFC 3986
+const BOM_LINK_FRAGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
 use validator::validate_email;
 
 // Maps from PackageId to Package for efficiency - faster lookups than in a Vec
 type PackageMap = BTreeMap<PackageId, Package>;
+/// Name of the property recording the kind of dependency a component was pulled in as
+pub const DEPENDENCY_KIND_PROPERTY: &str = "cargo-cyclonedx:dependency-kind";
+/// Name of the property telling local packages apart from those downloaded from a registry or
+/// git: `first-party` for path dependencies and `vendored` for packages copied by `cargo vendor`
+pub const SOURCE_KIND_PROPERTY: &str = "cargo-cyclonedx:source-kind";
+
 type ResolveMap = BTreeMap<PackageId, Node>;
 type DependencyKindMap = BTreeMap<PackageId, DependencyKind>;
 
@@ -104,6 +131,7 @@ pub struct SbomGenerator {
     config: SbomConfig,
     workspace_root: Utf8PathBuf,
     crate_hashes: HashMap<cargo_metadata::PackageId, Checksum>,
+    crate_infos: HashMap<cargo_metadata::PackageId, CrateInfo>,
 }
 
 /// Contains a map from `bom_ref` of a subcomponent to the kinds of Cargo targets it has,
@@ -125,18 +153,47 @@ impl SbomGenerator {
         let packages = index_packages(meta.packages);
         let resolve = index_resolve(meta.resolve.unwrap().nodes);
 
+        let config_file_path = meta.workspace_root.join(CONFIG_FILE_NAME);
+        let config_file = ConfigFile::load(config_file_path.as_std_path()).map_err(|error| {
+            GeneratorError::ConfigFileError {
+                config_filepath: config_file_path.to_string(),
+                error,
+            }
+        })?;
+
+        let cache_dirs = registry_cache_dirs();
+        let mut crates_io = CratesIoClient::new(config.offline == Some(true));
+        // Cargo.lock always lives in the workspace root
+        let lockfile = std::fs::read(meta.workspace_root.join("Cargo.lock")).ok();
+        let mut advisory_db = None;
+
         let mut result = Vec::with_capacity(members.len());
         for member in members.iter() {
             log::trace!("Processing the package {}", member);
 
+            // Options given on the command line take precedence over the package metadata,
+            // which takes precedence over the config file
+            let package_config = SbomConfig::from_package_metadata(&packages[member].metadata)
+                .map_err(|error| GeneratorError::PackageMetadataError {
+                    config_filepath: packages[member].manifest_path.to_string(),
+                    error,
+                })?;
+            let config = &config_file
+                .member_config(&packages[member].name)
+                .merge(&package_config)
+                .merge(config);
+
             let dep_kinds = index_dep_kinds(member, &resolve);
 
-            let (dependencies, pruned_resolve) =
+            let (mut dependencies, mut pruned_resolve) =
                 if config.included_dependencies() == IncludedDependencies::AllDependencies {
                     all_dependencies(member, &packages, &resolve, config)
                 } else {
                     top_level_dependencies(member, &packages, &resolve, config)
                 };
+            if let Some(filter) = &config.package_filter {
+                filter_packages(member, &mut dependencies, &mut pruned_resolve, filter);
+            }
 
             let manifest_path = packages[member].manifest_path.clone().into_std_path_buf();
 
@@ -154,14 +211,36 @@ impl SbomGenerator {
                     Hashes will not be included in the SBOM."
                 ),
             }
+            add_cached_crate_hashes(&dependencies, &mut crate_hashes, &cache_dirs);
+            if config.hash_sources == Some(true) {
+                add_source_dir_hashes(&dependencies, &mut crate_hashes);
+            }
+
+            let crate_infos = if config.crates_io == Some(true) {
+                crates_io.crate_infos(dependencies.values(), config.crates_io_owners == Some(true))
+            } else {
+                HashMap::new()
+            };
 
             let generator = SbomGenerator {
                 config: config.clone(),
                 workspace_root: meta.workspace_root.to_owned(),
                 crate_hashes,
+                crate_infos,
             };
-            let (bom, target_kinds) =
+            let (mut bom, target_kinds) =
                 generator.create_bom(member, &dependencies, &pruned_resolve, &dep_kinds)?;
+            if config.advisories == Some(true) {
+                if advisory_db.is_none() {
+                    advisory_db = Some(open_advisory_db(config)?);
+                }
+                bom.vulnerabilities = advisory_db
+                    .as_ref()
+                    .and_then(|db| db.vulnerabilities(dependencies.values()));
+            }
+            if config.reproducible == Some(true) {
+                make_reproducible(&mut bom, lockfile.as_deref(), config);
+            }
 
             let generated = GeneratedSbom {
                 bom,
@@ -174,6 +253,15 @@ impl SbomGenerator {
             result.push(generated);
         }
 
+        if config.merged == Some(true) {
+            let config = config_file.config.merge(config);
+            let mut merged = merge_workspace(result, &meta.workspace_root, config)?;
+            if merged.sbom_config.reproducible == Some(true) {
+                make_reproducible(&mut merged.bom, lockfile.as_deref(), &merged.sbom_config);
+            }
+            return Ok(vec![merged]);
+        }
+
         Ok(result)
     }
 
@@ -229,13 +317,31 @@ impl SbomGenerator {
         );
 
         component.purl = purl;
-        component.scope = match dep_kinds
+        let dep_kind = dep_kinds
             .get(&package.id)
-            .unwrap_or(&DependencyKind::Normal)
-        {
+            .unwrap_or(&DependencyKind::Normal);
+        component.scope = match dep_kind {
             DependencyKind::Normal => Some(Scope::Required),
             _ => Some(Scope::Excluded),
         };
+        // Tell build and development dependencies apart, both are excluded
+        let dep_kind_value = match dep_kind {
+            DependencyKind::Build => Some("build"),
+            DependencyKind::Development => Some("dev"),
+            _ => None,
+        };
+        let mut properties = Vec::new();
+        if let Some(value) = dep_kind_value {
+            properties.push(Property::new(DEPENDENCY_KIND_PROPERTY, value));
+        }
+        if package.source.is_none() {
+            properties.push(Property::new(SOURCE_KIND_PROPERTY, "first-party"));
+        } else if is_vendored(package) {
+            properties.push(Property::new(SOURCE_KIND_PROPERTY, "vendored"));
+        }
+        if !properties.is_empty() {
+            component.properties = Some(Properties(properties));
+        }
         component.external_references = Self::get_external_references(package);
         component.licenses = self.get_licenses(package);
         component.hashes = self.get_hashes(package);
@@ -244,6 +350,12 @@ impl SbomGenerator {
             .description
             .as_ref()
             .map(|s| NormalizedString::new(s));
+        if !package.authors.is_empty() {
+            component.author = Some(NormalizedString::new(&package.authors.join(", ")));
+        }
+        if let Some(crate_info) = self.crate_infos.get(&package.id) {
+            crate_info.apply(&mut component);
+        }
 
         component
     }
@@ -315,6 +427,10 @@ impl SbomGenerator {
                 );
             }
 
+            if let Some(artifact_dir) = &self.config.artifact_dir {
+                subcomponent.hashes = artifact_hashes(artifact_dir, tgt);
+            }
+
             subcomponents.push(subcomponent);
         }
         top_component.components = Some(Components(subcomponents));
@@ -395,6 +511,51 @@ impl SbomGenerator {
             }
         }
 
+        if let Some(registry) = package.source.as_ref().and_then(registry_url) {
+            match Uri::try_from(registry.to_owned()) {
+                Ok(uri) => {
+                    let mut reference =
+                        ExternalReference::new(ExternalReferenceType::Distribution, uri);
+                    reference.comment = Some("Cargo registry index".to_owned());
+                    references.push(reference)
+                }
+                Err(e) => log::warn!(
+                    "Package {} has an invalid registry URI ({}): {} ",
+                    package.name,
+                    registry,
+                    e
+                ),
+            }
+        }
+
+        // The commit that Cargo.lock pins a git dependency to
+        if let Some(git) = package.source.as_ref().and_then(GitSource::parse) {
+            let comment = git
+                .commit
+                .map(|commit| format!("Resolved to commit {}", commit));
+            let existing = references.iter_mut().find(|r| {
+                r.external_reference_type == ExternalReferenceType::Vcs
+                    && r.url.to_string().trim_end_matches(".git")
+                        == git.url.trim_end_matches(".git")
+            });
+            match existing {
+                Some(reference) => reference.comment = comment,
+                None => match Uri::try_from(git.url.to_owned()) {
+                    Ok(uri) => {
+                        let mut reference = ExternalReference::new(ExternalReferenceType::Vcs, uri);
+                        reference.comment = comment;
+                        references.push(reference)
+                    }
+                    Err(e) => log::warn!(
+                        "Package {} has an invalid git source URI ({}): {} ",
+                        package.name,
+                        git.url,
+                        e
+                    ),
+                },
+            }
+        }
+
         if !references.is_empty() {
             return Some(ExternalReferences(references));
         }
@@ -426,7 +587,17 @@ impl SbomGenerator {
             };
 
             match result {
-                Ok(expression) => licenses.push(LicenseChoice::Expression(expression)),
+                Ok(expression) => {
+                    if expression.to_string() != *license {
+                        log::warn!(
+                            "Package {} has a legacy license expression ({}), using {}",
+                            package.name,
+                            license,
+                            expression,
+                        );
+                    }
+                    licenses.push(LicenseChoice::Expression(expression))
+                }
                 Err(err) => {
                     let level = match &self.config.license_parser {
                         Some(opts) if opts.accept_named.contains(license) => Level::Info,
@@ -466,6 +637,11 @@ impl SbomGenerator {
             }
         }
 
+        if self.config.license_texts == Some(true) {
+            licenses.extend(Self::find_license_texts(package));
+        }
+        let licenses = expand_license_expression(&package.name, licenses);
+
         if licenses.is_empty() {
             log::trace!(
                 "Package {} has no licenses or license file specified",
@@ -477,6 +653,78 @@ impl SbomGenerator {
         Some(Licenses(licenses))
     }
 
+    /// Embeds the license files found next to the `Cargo.toml` of a package, such as `LICENSE`,
+    /// `LICENSE-MIT` or `COPYING`. A file is attributed to a license of the package's license
+    /// expression if its name suffix matches the license identifier.
+    fn find_license_texts(package: &Package) -> Vec<LicenseChoice> {
+        let Some(package_dir) = package.manifest_path.parent() else {
+            return Vec::new();
+        };
+        let entries = match std::fs::read_dir(package_dir) {
+            Ok(entries) => entries,
+            Err(error) => {
+                log::warn!(
+                    "Failed to read the source directory of package {}: {}",
+                    package.name,
+                    error
+                );
+                return Vec::new();
+            }
+        };
+        let license_file = package.license_file();
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file())
+            .filter(|path| license_file.as_ref().map(|f| f.as_std_path()) != Some(path.as_path()))
+            .filter(|path| license_file_suffix(path).is_some())
+            .collect();
+        files.sort();
+
+        let ids: Vec<&str> = package
+            .license
+            .as_deref()
+            .and_then(|license| spdx::Expression::parse_mode(license, spdx::ParseMode::LAX).ok())
+            .map(|expression| {
+                expression
+                    .requirements()
+                    .filter_map(|req| req.req.license.id())
+                    .map(|id| id.name)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        files
+            .into_iter()
+            .filter_map(|path| {
+                let content = match std::fs::read(&path) {
+                    Ok(content) => content,
+                    Err(error) => {
+                        log::warn!(
+                            "Failed to read license file '{}': {}",
+                            path.display(),
+                            error
+                        );
+                        return None;
+                    }
+                };
+                let suffix = license_file_suffix(&path)?;
+                let id = match suffix.as_str() {
+                    "" => (ids.len() == 1).then(|| ids[0]),
+                    suffix => ids
+                        .iter()
+                        .find(|id| id.to_lowercase().starts_with(suffix))
+                        .copied(),
+                };
+                let mut license = match id {
+                    Some(id) => License::license_id(id),
+                    None => License::named_license(&path.file_name()?.to_string_lossy()),
+                };
+                license.text = Some(AttachedText::new(None, content));
+                Some(LicenseChoice::License(license))
+            })
+            .collect()
+    }
+
     fn get_hashes(&self, package: &Package) -> Option<cyclonedx_bom::models::hash::Hashes> {
         match self.crate_hashes.get(&package.id) {
             Some(hash) => Some(cyclonedx_bom::models::hash::Hashes(vec![to_bom_hash(hash)])),
@@ -512,9 +760,18 @@ impl SbomGenerator {
 
         metadata.component = Some(component);
 
+        if let Some(properties) = &self.config.properties {
+            metadata.properties = Some(Properties(
+                properties
+                    .iter()
+                    .map(|(name, value)| Property::new(name, value))
+                    .collect(),
+            ));
+        }
+
         let tool = Tool::new("CycloneDX", "cargo-cyclonedx", env!("CARGO_PKG_VERSION"));
 
-        metadata.tools = Some(Tools::List(vec![tool]));
+        metadata.register_tool(tool, self.config.spec_version.unwrap_or_default());
 
         Ok((metadata, target_kinds))
     }
@@ -650,6 +907,20 @@ pub enum GeneratorError {
         error: anyhow::Error,
     },
 
+    #[error("Invalid [package.metadata.cyclonedx] table in {config_filepath}")]
+    PackageMetadataError {
+        config_filepath: String,
+        #[source]
+        error: ConfigError,
+    },
+
+    #[error("Invalid config file: {config_filepath}")]
+    ConfigFileError {
+        config_filepath: String,
+        #[source]
+        error: ConfigError,
+    },
+
     #[error("Error retrieving package information: {package_id}")]
     PackageError {
         package_id: cargo_metadata::PackageId,
@@ -662,6 +933,110 @@ pub enum GeneratorError {
 
     #[error("Could not parse author string: {}", .0)]
     AuthorParseError(String),
+
+    #[error("Could not load the advisory database")]
+    AdvisoryError(#[from] AdvisoryError),
+
+    #[error("The SBOM has no serial number for the VEX document to link to")]
+    VexWithoutSerialNumber,
+}
+
+/// Opens the advisory database given in the config, or else the one used by `cargo audit`
+fn open_advisory_db(config: &SbomConfig) -> Result<AdvisoryDatabase, AdvisoryError> {
+    if config.spec_version.unwrap_or_default() < SpecVersion::V1_4 {
+        log::warn!(
+            "Vulnerabilities are only written with spec version 1.4 or later, \
+            use `--spec-version 1.4` or `--spec-version 1.5`"
+        );
+    }
+    let path = config
+        .advisory_db
+        .clone()
+        .or_else(AdvisoryDatabase::default_path)
+        .ok_or(AdvisoryError::NoDefaultPath)?;
+    AdvisoryDatabase::open_or_fetch(&path, config.offline == Some(true))
+}
+
+/// Combines the SBOMs of all workspace members into one. A component describing the workspace
+/// becomes the metadata component, and depends on every member.
+fn merge_workspace(
+    sboms: Vec<GeneratedSbom>,
+    workspace_root: &Utf8Path,
+    config: SbomConfig,
+) -> Result<GeneratedSbom, GeneratorError> {
+    let name = workspace_root.file_name().unwrap_or("workspace").to_owned();
+    let members: Vec<&Component> = sboms
+        .iter()
+        .filter_map(|sbom| sbom.bom.metadata.as_ref()?.component.as_ref())
+        .collect();
+    let member_refs: Vec<String> = members
+        .iter()
+        .filter_map(|member| member.bom_ref.clone())
+        .collect();
+    // The workspace has no version of its own, use the one shared by all members if there is one
+    let version = match members.split_first() {
+        Some((first, rest)) if rest.iter().all(|member| member.version == first.version) => first
+            .version
+            .as_ref()
+            .map_or("0.0.0".to_owned(), |version| version.to_string()),
+        _ => "0.0.0".to_owned(),
+    };
+    let root_ref = format!("path+file://{}", workspace_root);
+
+    let mut metadata = Metadata::new()?;
+    metadata.component = Some(Component::new(
+        Classification::Application,
+        &name,
+        &version,
+        Some(root_ref.clone()),
+    ));
+    if let Some(properties) = &config.properties {
+        metadata.properties = Some(Properties(
+            properties
+                .iter()
+                .map(|(name, value)| Property::new(name, value))
+                .collect(),
+        ));
+    }
+    let tool = Tool::new("CycloneDX", "cargo-cyclonedx", env!("CARGO_PKG_VERSION"));
+    metadata.register_tool(tool, config.spec_version.unwrap_or_default());
+    let root = Bom {
+        metadata: Some(metadata),
+        ..Bom::default()
+    };
+
+    // Members and shared dependencies appear in several SBOMs, with package URLs relative to
+    // different members, so they are matched by name and version instead
+    let policy = MergePolicy {
+        identity: IdentityPolicy::Coordinates,
+        ..MergePolicy::default()
+    };
+    let mut bom = Bom::merge(
+        std::iter::once(root).chain(sboms.into_iter().map(|sbom| sbom.bom)),
+        policy,
+    );
+    // Members share dependencies, and with them their vulnerabilities
+    if let Some(vulnerabilities) = &mut bom.vulnerabilities {
+        dedupe_vulnerabilities(&mut vulnerabilities.0);
+    }
+    bom.dependencies
+        .get_or_insert_with(|| Dependencies(Vec::new()))
+        .0
+        .insert(
+            0,
+            Dependency {
+                dependency_ref: root_ref,
+                dependencies: member_refs,
+            },
+        );
+
+    Ok(GeneratedSbom {
+        bom,
+        manifest_path: workspace_root.join("Cargo.toml").into_std_path_buf(),
+        package_name: name,
+        sbom_config: config,
+        target_kinds: TargetKinds(HashMap::new()),
+    })
 }
 
 /// Generates the `Dependencies` field in the final SBOM
@@ -761,23 +1136,90 @@ fn add_filtered_dependencies(node: &Node, config: &SbomConfig) -> Node {
     node
 }
 
-/// Filters out dependencies only used for development, and not affecting the final binary.
-/// These are specified under `[dev-dependencies]` in Cargo.toml.
+/// Filters out dependencies only used for development, and not affecting the final binary,
+/// unless they were asked for. These are specified under `[dev-dependencies]` in Cargo.toml.
 fn filtered_dependencies<'a>(
     input: &'a [NodeDep],
     config: &'a SbomConfig,
 ) -> impl Iterator<Item = &'a NodeDep> {
     input.iter().filter(|p| {
-        p.dep_kinds.iter().any(|dep| {
-            if let Some(true) = config.only_normal_deps {
-                dep.kind == DependencyKind::Normal
-            } else {
-                dep.kind != DependencyKind::Development
-            }
+        p.dep_kinds.iter().any(|dep| match dep.kind {
+            DependencyKind::Normal => true,
+            DependencyKind::Development => config.dev_deps == Some(true),
+            _ => config.only_normal_deps != Some(true),
         })
     })
 }
 
+/// Leaves out the packages rejected by the filter, keeping the dependency graph connected.
+/// Subtrees are pruned first, then the dependencies of the remaining excluded packages
+/// become direct dependencies of their dependents. The root package is always kept.
+fn filter_packages(
+    root: &PackageId,
+    packages: &mut PackageMap,
+    resolve: &mut ResolveMap,
+    filter: &PackageFilter,
+) {
+    let name = |id: &PackageId, packages: &PackageMap| packages[id].name.clone();
+
+    let pruned: HashSet<PackageId> = packages
+        .keys()
+        .filter(|id| *id != root && filter.excludes_tree(&name(id, packages)))
+        .cloned()
+        .collect();
+    if !pruned.is_empty() {
+        let mut reachable = HashSet::from([root.clone()]);
+        let mut queue = vec![root];
+        while let Some(id) = queue.pop() {
+            for dep in &resolve[id].dependencies {
+                if !pruned.contains(dep) && reachable.insert(dep.clone()) {
+                    queue.push(dep);
+                }
+            }
+        }
+        resolve.retain(|id, _| reachable.contains(id));
+        packages.retain(|id, _| reachable.contains(id));
+    }
+
+    let excluded: HashSet<PackageId> = packages
+        .keys()
+        .filter(|id| *id != root && filter.excludes(&name(id, packages)))
+        .cloned()
+        .collect();
+    let spliced: Vec<(PackageId, Vec<PackageId>)> = resolve
+        .values()
+        .filter(|node| !excluded.contains(&node.id))
+        .map(|node| {
+            // Replace excluded dependencies by their dependencies, transitively
+            let mut dependencies = Vec::new();
+            let mut visited = HashSet::new();
+            let mut queue: Vec<&PackageId> = node.dependencies.iter().rev().collect();
+            while let Some(dep) = queue.pop() {
+                if !resolve.contains_key(dep) || !visited.insert(dep) {
+                    continue;
+                }
+                if excluded.contains(dep) {
+                    queue.extend(resolve[dep].dependencies.iter().rev());
+                } else {
+                    dependencies.push(dep.clone());
+                }
+            }
+            (node.id.clone(), dependencies)
+        })
+        .collect();
+    for (id, dependencies) in spliced {
+        let node = resolve.get_mut(&id).unwrap();
+        node.deps.retain(|dep| dependencies.contains(&dep.pkg));
+        node.dependencies = dependencies;
+    }
+
+    for id in &excluded {
+        log::debug!("Leaving out package {}", id);
+    }
+    resolve.retain(|id, _| !excluded.contains(id));
+    packages.retain(|id, _| !excluded.contains(id));
+}
+
 /// Contains a generated SBOM and context used in its generation
 ///
 /// * `bom` - Generated SBOM
@@ -795,12 +1237,24 @@ pub struct GeneratedSbom {
 }
 
 impl GeneratedSbom {
-    /// Writes SBOM to either a JSON or XML file in the same folder as `Cargo.toml` manifest
+    /// Writes SBOM to JSON and/or XML files in the same folder as `Cargo.toml` manifest
     pub fn write_to_files(self) -> Result<(), SbomWriterError> {
+        if self.sbom_config.attestation == Some(true)
+            && !self.sbom_config.formats().contains(&Format::Json)
+        {
+            log::warn!("Attestations are only written for JSON SBOMs, no attestation is written");
+        }
+        for format in self.sbom_config.formats() {
+            self.write_to_files_in(format)?;
+        }
+        Ok(())
+    }
+
+    fn write_to_files_in(&self, format: Format) -> Result<(), SbomWriterError> {
         match self.sbom_config.describe.unwrap_or_default() {
             Describe::Crate => {
-                let path = self.manifest_path.with_file_name(self.filename(None, &[]));
-                Self::write_to_file(self.bom, &path, &self.sbom_config)
+                let path = self.output_path(self.filename(None, &[], format))?;
+                Self::write_to_file(self.bom.clone(), &path, &self.sbom_config, format)
             }
             pattern @ (Describe::Binaries | Describe::AllCargoTargets) => {
                 for (sbom, target_kind) in
@@ -808,35 +1262,189 @@ impl GeneratedSbom {
                 {
                     let meta = sbom.metadata.as_ref().unwrap();
                     let name = meta.component.as_ref().unwrap().name.as_ref();
-                    let path = self
-                        .manifest_path
-                        .with_file_name(self.filename(Some(name), &target_kind));
-                    Self::write_to_file(sbom, &path, &self.sbom_config)?;
+                    let path = self.output_path(self.filename(Some(name), &target_kind, format))?;
+                    Self::write_to_file(sbom, &path, &self.sbom_config, format)?;
                 }
                 Ok(())
             }
         }
     }
 
-    fn write_to_file(bom: Bom, path: &Path, config: &SbomConfig) -> Result<(), SbomWriterError> {
-        // If running in debug mode, validate that the SBOM is self-consistent and well-formed
-        if cfg!(debug_assertions) {
-            let result = bom.validate();
-            if result.has_errors() {
-                panic!(
-                    "The generated SBOM failed validation: {:?}",
-                    result.errors()
-                );
+    /// Places the file in the output directory if there is one, or else next to `Cargo.toml`
+    fn output_path(&self, filename: String) -> Result<PathBuf, SbomWriterError> {
+        match &self.sbom_config.output_dir {
+            Some(dir) => {
+                std::fs::create_dir_all(dir)?;
+                Ok(dir.join(filename))
+            }
+            None => Ok(self.manifest_path.with_file_name(filename)),
+        }
+    }
+
+    fn write_to_file(
+        bom: Bom,
+        path: &Path,
+        config: &SbomConfig,
+        format: Format,
+    ) -> Result<(), SbomWriterError> {
+        // Validate and sign first so that an invalid SBOM does not leave a file behind
+        let bom = Self::prepare(bom, config, format)?;
+        log::info!("Outputting {}", path.display());
+        if config.attestation == Some(true) && format == Format::Json {
+            // The attestation covers the exact bytes of the file
+            let mut output = Vec::new();
+            Self::write_bom(bom, &mut output, config, format)?;
+            std::fs::write(path, &output)?;
+            return Self::write_attestation(path, &output, config);
+        }
+        let file = File::create(path)?;
+        Self::write_bom(bom, file, config, format)
+    }
+
+    /// Writes an in-toto attestation of a JSON SBOM file next to it, replacing `.json` with
+    /// `.intoto.jsonl`. The DSSE envelope is signed if a signing key is configured.
+    fn write_attestation(
+        path: &Path,
+        sbom: &[u8],
+        config: &SbomConfig,
+    ) -> Result<(), SbomWriterError> {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let key = config
+            .sign_key
+            .as_deref()
+            .map(Self::read_signing_key)
+            .transpose()?;
+        let envelope = attestation::statement(&name, sbom)
+            .and_then(|statement| attestation::envelope(&statement, key.as_ref()))
+            .map_err(SbomWriterError::AttestationError)?;
+
+        let stem = name.strip_suffix(".json").unwrap_or(&name);
+        let path = path.with_file_name(format!("{stem}.intoto.jsonl"));
+        log::info!("Outputting {}", path.display());
+        std::fs::write(path, format!("{envelope}\n"))?;
+        Ok(())
+    }
+
+    /// Writes the SBOM of the crate to a writer such as stdout, for when a single SBOM is
+    /// generated in a single format and `--describe` is not used
+    pub fn write_to(self, writer: impl Write) -> Result<(), SbomWriterError> {
+        debug_assert_eq!(
+            self.sbom_config.describe.unwrap_or_default(),
+            Describe::Crate
+        );
+        let format = self.sbom_config.formats()[0];
+        let bom = Self::prepare(self.bom, &self.sbom_config, format)?;
+        Self::write_bom(bom, writer, &self.sbom_config, format)
+    }
+
+    /// Moves the vulnerabilities into a separate VEX document, whose vulnerabilities refer to the
+    /// components of this SBOM with BOM-Links. The document identifies the product with the
+    /// metadata component of this SBOM, and has no components of its own.
+    pub fn split_vex(&mut self) -> Result<Bom, GeneratorError> {
+        let serial_number = self
+            .bom
+            .serial_number
+            .clone()
+            .ok_or(GeneratorError::VexWithoutSerialNumber)?;
+        let link = BomLink::new(&serial_number, self.bom.version);
+
+        let mut vulnerabilities = self.bom.vulnerabilities.take();
+        for target in vulnerabilities
+            .iter_mut()
+            .flat_map(|v| v.0.iter_mut())
+            .flat_map(|vulnerability| vulnerability.vulnerability_targets.iter_mut())
+            .flat_map(|targets| targets.0.iter_mut())
+        {
+            target.bom_ref = format!(
+                "{}#{}",
+                link,
+                utf8_percent_encode(&target.bom_ref, BOM_LINK_FRAGMENT)
+            );
+        }
+
+        let metadata = self.bom.metadata.as_ref().map(|metadata| Metadata {
+            timestamp: metadata.timestamp.clone(),
+            tools: metadata.tools.clone(),
+            component: metadata.component.clone().map(|component| Component {
+                components: None,
+                ..component
+            }),
+            ..Metadata::default()
+        });
+        let serial_number = if self.sbom_config.reproducible == Some(true) {
+            reproducible_serial_number(&[serial_number.0.as_bytes(), b"vex"])
+        } else {
+            Some(UrnUuid::generate())
+        };
+        Ok(Bom {
+            serial_number,
+            metadata,
+            vulnerabilities,
+            ..Bom::default()
+        })
+    }
+
+    /// Writes a VEX document created with [`GeneratedSbom::split_vex`], in XML if the file name
+    /// ends in `.xml` or else in JSON. It is always written with spec version 1.5, the first
+    /// version with BOM-Links. It is signed with the key of this SBOM, if there is one.
+    pub fn write_vex(&self, vex: Bom, path: &Path) -> Result<(), SbomWriterError> {
+        let config = SbomConfig {
+            spec_version: Some(SpecVersion::V1_5),
+            sign_key: self.sbom_config.sign_key.clone(),
+            ..SbomConfig::empty_config()
+        };
+        let format = if path.extension().is_some_and(|ext| ext == "xml") {
+            Format::Xml
+        } else {
+            Format::Json
+        };
+        Self::write_to_file(vex, path, &config, format)
+    }
+
+    /// Validates the SBOM, and signs it if a signing key is configured
+    fn prepare(mut bom: Bom, config: &SbomConfig, format: Format) -> Result<Bom, SbomWriterError> {
+        Self::validate(&bom, config)?;
+        if let Some(path) = &config.sign_key {
+            // The signature is created over the JSON representation for spec version 1.5
+            if format != Format::Json || config.spec_version != Some(SpecVersion::V1_5) {
+                return Err(SbomWriterError::UnsupportedSigningFormat);
             }
+            let key = Self::read_signing_key(path)?;
+            bom.sign(&key).map_err(SbomWriterError::SignatureError)?;
         }
+        Ok(bom)
+    }
 
+    fn read_signing_key(path: &Path) -> Result<SigningKey, SbomWriterError> {
+        let pem = std::fs::read_to_string(path)
+            .map_err(|e| SbomWriterError::ReadSigningKey(path.to_path_buf(), e))?;
+        SigningKey::from_pem(&pem).map_err(SbomWriterError::SignatureError)
+    }
+
+    /// Checks that the SBOM is self-consistent and well-formed for the spec version it is
+    /// written in, so that invalid output is reported here rather than by its consumers
+    fn validate(bom: &Bom, config: &SbomConfig) -> Result<(), SbomWriterError> {
+        let violations = bom
+            .validate_version(config.spec_version.unwrap_or_default())
+            .violations();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(SbomWriterError::InvalidSbom(violations))
+        }
+    }
+
+    fn write_bom(
+        bom: Bom,
+        writer: impl Write,
+        config: &SbomConfig,
+        format: Format,
+    ) -> Result<(), SbomWriterError> {
         use cyclonedx_bom::models::bom::SpecVersion::*;
         let spec_version = config.spec_version.unwrap_or(V1_3);
 
-        log::info!("Outputting {}", path.display());
-        let file = File::create(path)?;
-        let mut writer = BufWriter::new(file);
-        match config.format() {
+        let mut writer = BufWriter::new(writer);
+        match format {
             Format::Json => {
                 bom.output_as_json(&mut writer, spec_version)
                     .map_err(SbomWriterError::JsonWriteError)?;
@@ -892,12 +1500,23 @@ impl GeneratedSbom {
                 toplevel_component.name = component.name.clone();
                 toplevel_component.component_type = component.component_type.clone();
                 toplevel_component.purl.clone_from(&component.purl);
+                if component.hashes.is_some() {
+                    toplevel_component.hashes.clone_from(&component.hashes);
+                }
+                // Build dependencies only run during the build and are not linked into
+                // the artifact, so they are not part of its SBOM
+                new_bom.retain_components(|component| component.scope != Some(Scope::Excluded));
 
                 (new_bom, target_kind.clone())
             })
     }
 
-    fn filename(&self, binary_name: Option<&str>, target_kind: &[String]) -> String {
+    fn filename(
+        &self,
+        binary_name: Option<&str>,
+        target_kind: &[String],
+        format: Format,
+    ) -> String {
         let output_options = self.sbom_config.output_options();
         let describe = self.sbom_config.describe.unwrap_or_default();
 
@@ -915,6 +1534,15 @@ impl GeneratedSbom {
                 prefix = name_override.to_string();
                 extension = ""; // do not append the extension to allow writing to literally "bom.xml" as per spec
             }
+            FilenamePattern::Template(template) => {
+                return self.render_filename(
+                    &template,
+                    &prefix,
+                    target_kind,
+                    output_options.platform_suffix,
+                    format,
+                )
+            }
         }
 
         let target_kind_suffix = if !target_kind.is_empty() {
@@ -938,13 +1566,60 @@ impl GeneratedSbom {
 
         format!(
             "{}{}{}{}.{}",
-            prefix,
-            target_kind_suffix,
-            platform_suffix,
-            extension,
-            self.sbom_config.format()
+            prefix, target_kind_suffix, platform_suffix, extension, format
         )
     }
+
+    /// Fills in the placeholders of an output pattern. The kinds of the Cargo target and the
+    /// target platform are appended to the name if they are needed to tell the files apart,
+    /// but the pattern does not use them.
+    fn render_filename(
+        &self,
+        template: &FilenameTemplate,
+        name: &str,
+        target_kind: &[String],
+        platform_suffix: PlatformSuffix,
+        format: Format,
+    ) -> String {
+        let kind = target_kind.join("-");
+        let target = self
+            .sbom_config
+            .target
+            .as_ref()
+            .map_or("all", |target| target.as_str());
+
+        let mut name = name.to_owned();
+        if !kind.is_empty() && !template.contains(FilenameTemplate::KIND) {
+            name = format!("{}_{}", name, kind);
+        }
+        if platform_suffix == PlatformSuffix::Included
+            && !template.contains(FilenameTemplate::TARGET)
+        {
+            name = format!("{}_{}", name, target);
+        }
+        let version = self
+            .bom
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+            .and_then(|component| component.version.as_ref())
+            .map(|version| version.to_string())
+            .unwrap_or_default();
+
+        let mut filename = template.render(|placeholder| match placeholder {
+            FilenameTemplate::NAME => name.clone(),
+            FilenameTemplate::VERSION => version.clone(),
+            FilenameTemplate::KIND => kind.clone(),
+            FilenameTemplate::TARGET => target.to_owned(),
+            FilenameTemplate::EXT => format.to_string(),
+            _ => String::new(),
+        });
+        // Without the extension, the files of several formats would overwrite each other
+        if self.sbom_config.formats().len() > 1 && !template.contains(FilenameTemplate::EXT) {
+            filename = format!("{}.{}", filename, format);
+        }
+        filename
+    }
 }
 
 /// Locates the corresponding `Cargo.lock` file given the location of `Cargo.toml`.
@@ -978,6 +1653,346 @@ fn package_hashes(lockfile: &Lockfile) -> HashMap<cargo_metadata::PackageId, Che
     result
 }
 
+/// Returns the lowercase part of a license file name that names the license, e.g. `mit` for
+/// `LICENSE-MIT.txt`, or `None` if the file is not a license file. License files are named
+/// `LICENSE`, `LICENSE-*`, `COPYING`, `COPYING-*`, `COPYING.LESSER` or `COPYING.LIB`, with a
+/// text or Markdown extension or none.
+fn license_file_suffix(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?.to_lowercase();
+    let name = [".txt", ".md", ".markdown"]
+        .iter()
+        .find_map(|extension| name.strip_suffix(extension))
+        .unwrap_or(&name);
+    let (prefix, rest) = ["license", "licence", "copying"]
+        .iter()
+        .find_map(|prefix| Some((*prefix, name.strip_prefix(prefix)?)))?;
+    match rest {
+        "" => Some(String::new()),
+        ".lesser" | ".lib" if prefix == "copying" => Some(rest[1..].to_owned()),
+        // Allow versions such as `LICENSE-APACHE-2.0`, but not other extensions
+        _ if rest.len() > 1
+            && rest.starts_with('-')
+            && Path::new(rest).extension().map_or(true, |extension| {
+                extension
+                    .to_string_lossy()
+                    .chars()
+                    .all(|c| c.is_ascii_digit())
+            }) =>
+        {
+            Some(rest[1..].to_owned())
+        }
+        _ => None,
+    }
+}
+
+/// The licenses of a component are either a single license expression or a list of licenses,
+/// with a text or not. If license texts were found for a package with a license expression, the
+/// expression is replaced by one license per SPDX identifier and each text is attached to its
+/// license. The texts are left out if the expression cannot be split up that way.
+fn expand_license_expression(package: &str, licenses: Vec<LicenseChoice>) -> Vec<LicenseChoice> {
+    let (expressions, texts): (Vec<_>, Vec<_>) = licenses
+        .into_iter()
+        .partition(|license| matches!(license, LicenseChoice::Expression(_)));
+    let [LicenseChoice::Expression(expression)] = expressions.as_slice() else {
+        return expressions.into_iter().chain(texts).collect();
+    };
+    if texts.is_empty() {
+        return expressions;
+    }
+
+    let Some(ids) = license_ids(&expression.to_string()) else {
+        log::warn!(
+            "Package {} has license files, but its license expression ({}) cannot be split into licenses to attach them to. Leaving out the license texts",
+            package,
+            expression
+        );
+        return expressions;
+    };
+    let mut licenses: Vec<License> = ids.iter().map(|id| License::license_id(id)).collect();
+    for text in texts {
+        let LicenseChoice::License(text) = text else {
+            continue;
+        };
+        match licenses.iter_mut().find(|license| {
+            license.license_identifier == text.license_identifier && license.text.is_none()
+        }) {
+            Some(license) => license.text = text.text,
+            None => licenses.push(text),
+        }
+    }
+    licenses.into_iter().map(LicenseChoice::License).collect()
+}
+
+/// Returns the SPDX identifiers of the licenses in an expression, or `None` if the expression
+/// has parts that are not plain SPDX identifiers, like exceptions or `+`
+fn license_ids(expression: &str) -> Option<Vec<&'static str>> {
+    let expression = spdx::Expression::parse_mode(expression, spdx::ParseMode::LAX).ok()?;
+    let mut ids = Vec::new();
+    for requirement in expression.requirements() {
+        let spdx::LicenseItem::Spdx {
+            id,
+            or_later: false,
+        } = requirement.req.license
+        else {
+            return None;
+        };
+        if requirement.req.exception.is_some() {
+            return None;
+        }
+        if !ids.contains(&id.name) {
+            ids.push(id.name);
+        }
+    }
+    Some(ids)
+}
+
+/// Computes the SHA-256 and SHA-512 hashes of the file built from a Cargo target, if it is
+/// in the build output directory.
+fn artifact_hashes(artifact_dir: &Path, target: &cargo_metadata::Target) -> Option<Hashes> {
+    // Libraries are named after the crate, which has underscores instead of dashes
+    let lib_name = target.name.replace('-', "_");
+    let file_names: Vec<String> = target
+        .kind
+        .iter()
+        .flat_map(|kind| match kind.as_str() {
+            "bin" => vec![target.name.clone(), format!("{}.exe", target.name)],
+            "lib" | "rlib" => vec![format!("lib{lib_name}.rlib")],
+            "cdylib" | "dylib" | "proc-macro" => vec![
+                format!("lib{lib_name}.so"),
+                format!("lib{lib_name}.dylib"),
+                format!("{lib_name}.dll"),
+            ],
+            "staticlib" => vec![format!("lib{lib_name}.a"), format!("{lib_name}.lib")],
+            _ => Vec::new(),
+        })
+        .collect();
+    let path = file_names
+        .iter()
+        .map(|file_name| artifact_dir.join(file_name))
+        .find(|path| path.is_file())?;
+
+    match Hashes::from_file(&path, &[HashAlgorithm::SHA_256, HashAlgorithm::SHA_512]) {
+        Ok(hashes) => Some(hashes),
+        Err(err) => {
+            log::warn!("Failed to hash {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+/// Returns the directories in the local Cargo cache that hold the `.crate` archives
+/// downloaded from each registry.
+fn registry_cache_dirs() -> Vec<PathBuf> {
+    let home = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    let cargo_home = std::env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os(home).map(|home| PathBuf::from(home).join(".cargo")));
+    let Some(cargo_home) = cargo_home else {
+        return Vec::new();
+    };
+
+    match std::fs::read_dir(cargo_home.join("registry").join("cache")) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_dir())
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Hashes the `.crate` archives of registry packages whose checksum is not in `Cargo.lock`,
+/// e.g. because the lockfile is missing or cargo is older than 1.77, if they are in the
+/// local Cargo cache.
+fn add_cached_crate_hashes(
+    packages: &PackageMap,
+    crate_hashes: &mut HashMap<PackageId, Checksum>,
+    cache_dirs: &[PathBuf],
+) {
+    let archives: Vec<(&PackageId, PathBuf)> = packages
+        .values()
+        .filter(|package| !crate_hashes.contains_key(&package.id))
+        .filter(|package| {
+            package.source.as_ref().is_some_and(|source| {
+                source.repr.starts_with("registry+") || source.repr.starts_with("sparse+")
+            })
+        })
+        .filter_map(|package| {
+            let file_name = format!("{}-{}.crate", package.name, package.version);
+            cache_dirs
+                .iter()
+                .map(|dir| dir.join(&file_name))
+                .find(|path| path.is_file())
+                .map(|path| (&package.id, path))
+        })
+        .collect();
+    if archives.is_empty() {
+        return;
+    }
+
+    let paths: Vec<&PathBuf> = archives.iter().map(|(_, path)| path).collect();
+    let hashes = Hashes::from_files(&paths, &[HashAlgorithm::SHA_256]);
+    for ((package_id, path), hashes) in archives.iter().zip(hashes) {
+        let checksum = hashes.map_err(|err| err.to_string()).and_then(|hashes| {
+            hashes.0[0]
+                .content
+                .0
+                .parse()
+                .map_err(|err| format!("{err}"))
+        });
+        match checksum {
+            Ok(checksum) => {
+                crate_hashes.insert((*package_id).clone(), checksum);
+            }
+            Err(err) => log::warn!("Failed to hash {}: {err}", path.display()),
+        }
+    }
+}
+
+/// Hashes the source directories of path dependencies and vendored packages that have no
+/// checksum. The hash is the SHA-256 of a listing of the SHA-256 hashes and paths of all files,
+/// in the format of `sha256sum`, leaving out hidden files and `target` directories.
+fn add_source_dir_hashes(packages: &PackageMap, crate_hashes: &mut HashMap<PackageId, Checksum>) {
+    for package in packages.values() {
+        if crate_hashes.contains_key(&package.id)
+            || !(package.source.is_none() || is_vendored(package))
+        {
+            continue;
+        }
+        let Some(package_dir) = package.manifest_path.parent() else {
+            continue;
+        };
+        let checksum = source_dir_hash(package_dir.as_std_path()).and_then(|hash| {
+            hash.parse().map_err(|err| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, format!("{err}"))
+            })
+        });
+        match checksum {
+            Ok(checksum) => {
+                crate_hashes.insert(package.id.clone(), checksum);
+            }
+            Err(err) => log::warn!("Failed to hash {}: {err}", package_dir),
+        }
+    }
+}
+
+fn source_dir_hash(dir: &Path) -> Result<String, std::io::Error> {
+    fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), std::io::Error> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if name.starts_with('.') {
+                continue;
+            }
+            if path.is_dir() {
+                if name != "target" {
+                    collect_files(&path, files)?;
+                }
+            } else {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    collect_files(dir, &mut files)?;
+    files.sort();
+
+    let mut listing = String::new();
+    for (path, hashes) in files
+        .iter()
+        .zip(Hashes::from_files(&files, &[HashAlgorithm::SHA_256]))
+    {
+        let hashes = hashes
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, format!("{err}")))?;
+        let relative = path.strip_prefix(dir).unwrap_or(path);
+        let relative: Vec<_> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect();
+        listing.push_str(&format!(
+            "{}  {}\n",
+            hashes.0[0].content.0,
+            relative.join("/")
+        ));
+    }
+
+    let hashes = Hashes::from_reader(listing.as_bytes(), &[HashAlgorithm::SHA_256])
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, format!("{err}")))?;
+    Ok(hashes.0[0].content.0.clone())
+}
+
+/// Makes the SBOM depend only on its inputs. The timestamp is taken from `SOURCE_DATE_EPOCH`
+/// or left out, the serial number is derived from `Cargo.lock`, the described component and
+/// the target, and components, dependencies and properties are sorted.
+fn make_reproducible(bom: &mut Bom, lockfile: Option<&[u8]>, config: &SbomConfig) {
+    let component = bom
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.component.as_ref());
+    let name = component.map(|c| c.name.to_string()).unwrap_or_default();
+    let version = component
+        .and_then(|c| c.version.as_ref())
+        .map(|v| v.to_string())
+        .unwrap_or_default();
+    let target = config.target.as_ref().map_or("all", Target::as_str);
+
+    bom.serial_number = lockfile.and_then(|lockfile| {
+        reproducible_serial_number(&[
+            lockfile,
+            name.as_bytes(),
+            version.as_bytes(),
+            target.as_bytes(),
+        ])
+    });
+    if let Some(metadata) = &mut bom.metadata {
+        metadata.timestamp = source_date_epoch();
+    }
+    bom.sort(SortPolicy::default());
+}
+
+/// Reads the timestamp from `SOURCE_DATE_EPOCH`, see <https://reproducible-builds.org/specs/source-date-epoch/>
+fn source_date_epoch() -> Option<DateTime> {
+    let value = std::env::var("SOURCE_DATE_EPOCH").ok()?;
+    let timestamp = value
+        .trim()
+        .parse::<i64>()
+        .map_err(|err| err.to_string())
+        .and_then(|seconds| DateTime::from_unix_timestamp(seconds).map_err(|err| err.to_string()));
+    match timestamp {
+        Ok(timestamp) => Some(timestamp),
+        Err(err) => {
+            log::warn!("Ignoring invalid SOURCE_DATE_EPOCH {:?}: {}", value, err);
+            None
+        }
+    }
+}
+
+/// Derives a UUID from the SHA-256 hash of the inputs. It is marked as a version 8 UUID,
+/// which RFC 9562 reserves for custom schemes.
+fn reproducible_serial_number(inputs: &[&[u8]]) -> Option<UrnUuid> {
+    let mut data = Vec::new();
+    for input in inputs {
+        data.extend_from_slice(input);
+        data.push(0);
+    }
+    let hashes = Hashes::from_reader(data.as_slice(), &[HashAlgorithm::SHA_256]).ok()?;
+    let mut hex: Vec<u8> = hashes.0[0].content.0.as_bytes()[..32].to_vec();
+    hex[12] = b'8';
+    // The two most significant bits of the variant are `10`
+    hex[16] = b"89ab"[(hex[16] as char).to_digit(16)? as usize & 3];
+    let hex = String::from_utf8(hex).ok()?;
+    UrnUuid::new(format!(
+        "urn:uuid:{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+    .ok()
+}
+
 /// Returns a Cargo unique identifier for a package.
 /// See `cargo help pkgid` for more info.
 fn pkgid(pkg: &cargo_lock::Package) -> String {
@@ -1016,6 +2031,23 @@ pub enum SbomWriterError {
 
     #[error("Error serializing to XML")]
     SerializeXmlError(#[source] std::io::Error),
+
+    #[error("The generated SBOM is invalid:{}", .0.iter().map(|v| format!("\n  {v}")).collect::<String>())]
+    InvalidSbom(Vec<Violation>),
+
+    #[error(
+        "Signing requires JSON output with spec version 1.5, as XML signatures are not supported"
+    )]
+    UnsupportedSigningFormat,
+
+    #[error("Could not read the signing key {}", .0.display())]
+    ReadSigningKey(PathBuf, #[source] std::io::Error),
+
+    #[error("Could not sign the SBOM")]
+    SignatureError(#[source] SignatureError),
+
+    #[error("Could not write the attestation")]
+    AttestationError(#[source] AttestationError),
 }
 
 impl From<std::io::Error> for SbomWriterError {
@@ -1027,6 +2059,376 @@ impl From<std::io::Error> for SbomWriterError {
 #[cfg(test)]
 mod test {
     use super::*;
+    use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+    use cyclonedx_bom::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
+
+    #[test]
+    fn it_should_describe_the_toplevel_crate() {
+        let package: Package =
+            serde_json::from_str(include_str!("../tests/fixtures/root_package.json")).unwrap();
+        let generator = SbomGenerator {
+            config: SbomConfig::default(),
+            workspace_root: package.manifest_path.parent().unwrap().to_owned(),
+            crate_hashes: HashMap::new(),
+            crate_infos: HashMap::new(),
+        };
+
+        let (metadata, _) = generator.create_metadata(&package).unwrap();
+
+        assert_eq!(
+            metadata.authors,
+            Some(vec![OrganizationalContact::new(
+                "Steve Springett",
+                Some("steve.springett@owasp.org")
+            )])
+        );
+        let component = metadata.component.unwrap();
+        assert_eq!(component.component_type, Classification::Application);
+        assert_eq!(
+            component.author,
+            Some(NormalizedString::new(
+                "Steve Springett <steve.springett@owasp.org>"
+            ))
+        );
+        assert_eq!(
+            component.description,
+            Some(NormalizedString::new(
+                "CycloneDX Software Bill of Materials (SBOM) for Rust Crates"
+            ))
+        );
+        assert_eq!(
+            component.licenses,
+            Some(Licenses(vec![LicenseChoice::Expression(
+                SpdxExpression::parse_lax("Apache-2.0".to_string()).unwrap()
+            )]))
+        );
+        let references: Vec<_> = component
+            .external_references
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|reference| (reference.external_reference_type, reference.url.to_string()))
+            .collect();
+        assert_eq!(
+            references,
+            vec![
+                (
+                    ExternalReferenceType::Website,
+                    "https://cyclonedx.org/".to_string()
+                ),
+                (
+                    ExternalReferenceType::Vcs,
+                    "https://github.com/CycloneDX/cyclonedx-rust-cargo".to_string()
+                ),
+            ]
+        );
+        assert!(component
+            .purl
+            .unwrap()
+            .to_string()
+            .starts_with("pkg:cargo/cargo-cyclonedx@"));
+    }
+
+    #[test]
+    fn it_should_reference_the_commit_of_git_dependencies() {
+        let package: Package =
+            serde_json::from_str(include_str!("../tests/fixtures/git_package.json")).unwrap();
+
+        let references = SbomGenerator::get_external_references(&package).unwrap();
+
+        let vcs: Vec<_> = references
+            .0
+            .iter()
+            .filter(|r| r.external_reference_type == ExternalReferenceType::Vcs)
+            .collect();
+        assert_eq!(vcs.len(), 1);
+        assert_eq!(
+            vcs[0].url.to_string(),
+            "https://github.com/rust-secure-code/cargo-auditable"
+        );
+        assert_eq!(
+            vcs[0].comment.as_deref(),
+            Some("Resolved to commit da85607fb1a09435d77288ccf05a92b2e8ec3f71")
+        );
+    }
+
+    #[test]
+    fn it_should_reference_alternative_registries() {
+        let mut package: Package =
+            serde_json::from_str(include_str!("../tests/fixtures/crates_io_package.json")).unwrap();
+        assert!(SbomGenerator::get_external_references(&package)
+            .unwrap()
+            .0
+            .iter()
+            .all(|r| r.external_reference_type != ExternalReferenceType::Distribution));
+
+        package.source = Some(cargo_metadata::Source {
+            repr: "sparse+https://my-intranet.example/index/".to_owned(),
+        });
+        let references = SbomGenerator::get_external_references(&package).unwrap();
+        let registry = references
+            .0
+            .iter()
+            .find(|r| r.external_reference_type == ExternalReferenceType::Distribution)
+            .unwrap();
+        assert_eq!(
+            registry.url.to_string(),
+            "sparse+https://my-intranet.example/index/"
+        );
+    }
+
+    #[test]
+    fn it_should_hash_cached_crate_archives() {
+        let package: Package =
+            serde_json::from_str(include_str!("../tests/fixtures/crates_io_package.json")).unwrap();
+        let cache = assert_fs::TempDir::new().unwrap();
+        let registry = cache.path().join("index.crates.io-6f17d22bba15001f");
+        std::fs::create_dir(&registry).unwrap();
+        std::fs::write(registry.join("aho-corasick-1.1.2.crate"), "hello world").unwrap();
+        let packages = PackageMap::from([(package.id.clone(), package.clone())]);
+
+        let mut crate_hashes = HashMap::new();
+        add_cached_crate_hashes(&packages, &mut crate_hashes, &[registry]);
+
+        assert_eq!(
+            to_bom_hash(&crate_hashes[&package.id]).content.0,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+    }
+
+    #[test]
+    fn it_should_hash_source_directories() {
+        let mut package: Package =
+            serde_json::from_str(include_str!("../tests/fixtures/workspace_package.json")).unwrap();
+        let source = assert_fs::TempDir::new().unwrap();
+        std::fs::create_dir_all(source.path().join("src")).unwrap();
+        std::fs::create_dir_all(source.path().join("target/debug")).unwrap();
+        std::fs::write(source.path().join("Cargo.toml"), "[package]").unwrap();
+        std::fs::write(source.path().join("src/lib.rs"), "").unwrap();
+        std::fs::write(source.path().join("target/debug/libfoo.rlib"), "").unwrap();
+        std::fs::write(source.path().join(".gitignore"), "/target").unwrap();
+        package.manifest_path =
+            Utf8PathBuf::from_path_buf(source.path().join("Cargo.toml")).unwrap();
+        let packages = PackageMap::from([(package.id.clone(), package.clone())]);
+
+        let mut crate_hashes = HashMap::new();
+        add_source_dir_hashes(&packages, &mut crate_hashes);
+
+        // The SHA-256 of the `sha256sum` listing of Cargo.toml and src/lib.rs
+        let expected = "c697755e5e942ce02a5ce64dd9a72d04eab1a15fdf834f99d2ddf32699a43176";
+        assert_eq!(to_bom_hash(&crate_hashes[&package.id]).content.0, expected);
+
+        // Build outputs and hidden files do not change the hash
+        std::fs::write(source.path().join("target/debug/libfoo.rlib"), "changed").unwrap();
+        std::fs::write(source.path().join(".gitignore"), "changed").unwrap();
+        assert_eq!(source_dir_hash(source.path()).unwrap(), expected);
+        std::fs::write(source.path().join("src/lib.rs"), "changed").unwrap();
+        assert_ne!(source_dir_hash(source.path()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_derive_serial_numbers_from_the_inputs() {
+        let serial = reproducible_serial_number(&[b"lockfile", b"pkg"]).unwrap();
+
+        assert_eq!(
+            serial,
+            reproducible_serial_number(&[b"lockfile", b"pkg"]).unwrap()
+        );
+        assert_ne!(
+            serial,
+            reproducible_serial_number(&[b"lockfile", b"other"]).unwrap()
+        );
+        // Inputs are delimited, so moving bytes between them changes the serial number
+        assert_ne!(
+            serial,
+            reproducible_serial_number(&[b"lockfil", b"epkg"]).unwrap()
+        );
+        assert_eq!(&serial.0[23..24], "8");
+        assert!(matches!(&serial.0[28..29], "8" | "9" | "a" | "b"));
+    }
+
+    #[test]
+    fn it_should_refuse_to_write_invalid_sboms() {
+        let bom = Bom {
+            dependencies: Some(Dependencies(vec![Dependency {
+                dependency_ref: "missing".to_string(),
+                dependencies: Vec::new(),
+            }])),
+            ..Bom::default()
+        };
+        let output = assert_fs::TempDir::new().unwrap();
+        let sbom = GeneratedSbom {
+            bom,
+            manifest_path: output.path().join("Cargo.toml"),
+            package_name: "pkg".to_string(),
+            sbom_config: SbomConfig {
+                formats: Some(vec![Format::Json]),
+                ..SbomConfig::empty_config()
+            },
+            target_kinds: TargetKinds(HashMap::new()),
+        };
+
+        let error = sbom.write_to_files().unwrap_err();
+
+        assert!(
+            matches!(&error, SbomWriterError::InvalidSbom(violations) if violations.len() == 1)
+        );
+        assert!(error
+            .to_string()
+            .contains("\n  dependency_ref: Dependency ref 'missing' does not exist in the BOM"));
+        assert!(!output.path().join("bom.json").exists());
+    }
+
+    #[test]
+    fn it_should_split_vulnerabilities_into_a_vex_document() {
+        let package: Package =
+            serde_json::from_str(include_str!("../tests/fixtures/crates_io_package.json")).unwrap();
+        let mut metadata = Metadata::new().unwrap();
+        metadata.component = Some(Component::new(
+            Classification::Application,
+            "pkg",
+            "0.0.0",
+            Some("pkg".to_string()),
+        ));
+        let serial_number =
+            UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap();
+        let mut vulnerability = Vulnerability::new(Some("RUSTSEC-0000-0001".to_string()));
+        vulnerability.vulnerability_targets =
+            Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                package.id.to_string(),
+            )]));
+        let mut sbom = GeneratedSbom {
+            bom: Bom {
+                serial_number: Some(serial_number),
+                metadata: Some(metadata),
+                vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+                ..Bom::default()
+            },
+            manifest_path: PathBuf::from("Cargo.toml"),
+            package_name: "pkg".to_string(),
+            sbom_config: SbomConfig::empty_config(),
+            target_kinds: TargetKinds(HashMap::new()),
+        };
+
+        let vex = sbom.split_vex().unwrap();
+
+        assert_eq!(sbom.bom.vulnerabilities, None);
+        let targets = vex.vulnerabilities.as_ref().unwrap().0[0]
+            .vulnerability_targets
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            targets.0[0].bom_ref,
+            "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1#aho-corasick%201.1.2%20%28registry%2Bhttps%3A%2F%2Fgithub.com%2Frust-lang%2Fcrates.io-index%29"
+        );
+        assert_ne!(vex.serial_number, sbom.bom.serial_number);
+        assert_eq!(vex.components, None);
+        assert!(vex.validate_version(SpecVersion::V1_5).passed());
+    }
+
+    #[test]
+    fn it_should_hash_built_artifacts() {
+        let package: Package =
+            serde_json::from_str(include_str!("../tests/fixtures/root_package.json")).unwrap();
+        let artifact_dir = assert_fs::TempDir::new().unwrap();
+        std::fs::write(artifact_dir.path().join("cargo-cyclonedx"), "hello world").unwrap();
+        let generator = SbomGenerator {
+            config: SbomConfig {
+                artifact_dir: Some(artifact_dir.path().to_owned()),
+                ..SbomConfig::default()
+            },
+            workspace_root: package.manifest_path.parent().unwrap().to_owned(),
+            crate_hashes: HashMap::new(),
+            crate_infos: HashMap::new(),
+        };
+
+        let (component, _) = generator.create_toplevel_component(&package);
+
+        let targets = component.components.unwrap().0;
+        let binary = targets
+            .iter()
+            .find(|target| target.component_type == Classification::Application)
+            .unwrap();
+        let hashes = binary.hashes.as_ref().unwrap();
+        assert_eq!(hashes.0[0].alg, HashAlgorithm::SHA_256);
+        assert_eq!(
+            hashes.0[0].content.0,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+        assert_eq!(hashes.0[1].alg, HashAlgorithm::SHA_512);
+        // The library was not built
+        let library = targets
+            .iter()
+            .find(|target| target.component_type == Classification::Library)
+            .unwrap();
+        assert_eq!(library.hashes, None);
+    }
+
+    #[test]
+    fn it_should_recognize_license_file_names() {
+        let suffix = |name: &str| license_file_suffix(Path::new(name));
+
+        assert_eq!(suffix("LICENSE").as_deref(), Some(""));
+        assert_eq!(suffix("LICENSE.md").as_deref(), Some(""));
+        assert_eq!(suffix("licence.txt").as_deref(), Some(""));
+        assert_eq!(suffix("LICENSE-MIT").as_deref(), Some("mit"));
+        assert_eq!(suffix("LICENSE-MIT.txt").as_deref(), Some("mit"));
+        assert_eq!(suffix("LICENSE-APACHE-2.0").as_deref(), Some("apache-2.0"));
+        assert_eq!(suffix("COPYING").as_deref(), Some(""));
+        assert_eq!(suffix("COPYING.LESSER").as_deref(), Some("lesser"));
+
+        assert_eq!(suffix("license.rs"), None);
+        assert_eq!(suffix("licenses.txt"), None);
+        assert_eq!(suffix("copying_util.rs"), None);
+        assert_eq!(suffix("LICENSE-MIT.rs"), None);
+        assert_eq!(suffix("license_checker.py"), None);
+    }
+
+    #[test]
+    fn it_should_attach_license_texts_to_the_licenses_of_the_expression() {
+        let expression = || {
+            LicenseChoice::Expression(
+                SpdxExpression::try_from("MIT OR Apache-2.0".to_string()).unwrap(),
+            )
+        };
+        let with_text = |mut license: License, text: &str| {
+            license.text = Some(AttachedText::new(None, text));
+            license
+        };
+        let licenses = vec![
+            expression(),
+            LicenseChoice::License(with_text(License::license_id("MIT"), "MIT License")),
+            LicenseChoice::License(with_text(License::named_license("COPYRIGHT"), "Copyright")),
+        ];
+
+        assert_eq!(
+            expand_license_expression("pkg", licenses),
+            vec![
+                LicenseChoice::License(with_text(License::license_id("MIT"), "MIT License")),
+                LicenseChoice::License(License::license_id("Apache-2.0")),
+                LicenseChoice::License(with_text(License::named_license("COPYRIGHT"), "Copyright")),
+            ]
+        );
+
+        // Without texts the expression is kept as is
+        assert_eq!(
+            expand_license_expression("pkg", vec![expression()]),
+            vec![expression()]
+        );
+
+        // Exceptions cannot be expressed as a list of licenses
+        let expression = LicenseChoice::Expression(
+            SpdxExpression::try_from("Apache-2.0 WITH LLVM-exception".to_string()).unwrap(),
+        );
+        let licenses = vec![
+            expression.clone(),
+            LicenseChoice::License(with_text(
+                License::license_id("Apache-2.0"),
+                "Apache License",
+            )),
+        ];
+        assert_eq!(expand_license_expression("pkg", licenses), vec![expression]);
+    }
 
     #[test]
     fn it_should_parse_author_and_email() {
@@ -16,7 +16,11 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+pub mod advisories;
+pub mod attestation;
 pub mod config;
+pub mod crates_io;
+pub mod diff;
 pub mod format;
 pub mod generator;
 pub mod platform;
@@ -47,9 +47,11 @@
 */
 use cargo_cyclonedx::{
     config::{SbomConfig, Target},
+    diff::{diff_with_baseline, has_changes, read_baseline, report},
     generator::SbomGenerator,
     GeneratedSbom,
 };
+use cyclonedx_bom::operations::diff::BomDiff;
 
 use std::{
     io::{self},
@@ -58,7 +60,7 @@ use std::{
 
 use cargo_metadata::{self, CargoOpt, Metadata};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
 use env_logger::Builder;
 use log::LevelFilter;
@@ -67,17 +69,20 @@ mod cli;
 use cli::{Args, Opts};
 
 fn generate_sboms(args: &Args) -> Result<Vec<GeneratedSbom>> {
-    let cli_config = args.as_config()?;
     let manifest_path = locate_manifest(args)?;
     log::debug!("Found the Cargo.toml file at {}", manifest_path.display());
 
-    log::trace!("Running `cargo metadata` started");
-    let metadata = get_metadata(args, &manifest_path, &cli_config)?;
-    log::trace!("Running `cargo metadata` finished");
+    let mut boms = Vec::new();
+    // Dependencies are resolved separately for every target platform
+    for cli_config in args.as_configs()? {
+        log::trace!("Running `cargo metadata` started");
+        let metadata = get_metadata(args, &manifest_path, &cli_config)?;
+        log::trace!("Running `cargo metadata` finished");
 
-    log::trace!("SBOM generation started");
-    let boms = SbomGenerator::create_sboms(metadata, &cli_config)?;
-    log::trace!("SBOM generation finished");
+        log::trace!("SBOM generation started");
+        boms.extend(SbomGenerator::create_sboms(metadata, &cli_config)?);
+        log::trace!("SBOM generation finished");
+    }
 
     Ok(boms)
 }
@@ -86,17 +91,96 @@ fn main() -> anyhow::Result<()> {
     let Opts::Bom(args) = Opts::parse();
     setup_logging(&args)?;
 
-    let boms = generate_sboms(&args)?;
+    let mut boms = generate_sboms(&args)?;
+
+    if let Some(path) = &args.vex {
+        ensure_single_sbom(&boms, "`--vex` links to")?;
+        let vex = boms[0].split_vex()?;
+        boms[0].write_vex(vex, path)?;
+    }
+
+    let diff = match &args.diff {
+        Some(baseline) => Some(diff_sbom(&boms, baseline)?),
+        None => None,
+    };
 
     log::trace!("SBOM output started");
-    for bom in boms {
-        bom.write_to_files()?;
+    match &args.output {
+        Some(path) => write_single_sbom(boms, path)?,
+        None => {
+            for bom in boms {
+                bom.write_to_files()?;
+            }
+        }
     }
     log::trace!("SBOM output finished");
 
+    if let (Some(baseline), Some(diff)) = (&args.diff, diff) {
+        let sbom_on_stdout = args.output.as_deref() == Some(Path::new("-"));
+        report_diff(&diff, baseline, sbom_on_stdout)?;
+    }
+
+    Ok(())
+}
+
+/// Fails unless there is a single SBOM, for options that work on a single SBOM. `usage`
+/// describes the option in the error message, e.g. "`--output` writes"
+fn ensure_single_sbom(boms: &[GeneratedSbom], usage: &str) -> anyhow::Result<()> {
+    if boms.len() != 1 {
+        anyhow::bail!(
+            "{} a single SBOM, but {} were generated. \
+            Select a package with `--manifest-path` or use `--workspace --merged`",
+            usage,
+            boms.len()
+        );
+    }
     Ok(())
 }
 
+/// Returns the only SBOM, see [`ensure_single_sbom`]
+fn single_sbom(mut boms: Vec<GeneratedSbom>, usage: &str) -> anyhow::Result<GeneratedSbom> {
+    ensure_single_sbom(&boms, usage)?;
+    Ok(boms.pop().unwrap())
+}
+
+/// Writes the only SBOM to a file, or to stdout if the path is `-`
+fn write_single_sbom(boms: Vec<GeneratedSbom>, path: &Path) -> anyhow::Result<()> {
+    let bom = single_sbom(boms, "`--output` writes")?;
+    if bom.sbom_config.formats().len() > 1 {
+        anyhow::bail!("`--output` writes a single SBOM, but several formats were requested");
+    }
+    if path.as_os_str() == "-" {
+        bom.write_to(io::stdout().lock())?;
+    } else {
+        log::info!("Outputting {}", path.display());
+        bom.write_to(std::fs::File::create(path)?)?;
+    }
+    Ok(())
+}
+
+/// Compares the only SBOM with the baseline
+fn diff_sbom(boms: &[GeneratedSbom], baseline: &Path) -> anyhow::Result<BomDiff> {
+    ensure_single_sbom(boms, "`--diff` compares")?;
+    let baseline_bom = read_baseline(baseline)
+        .with_context(|| format!("Could not read the baseline {}", baseline.display()))?;
+    Ok(diff_with_baseline(&baseline_bom, &boms[0])?)
+}
+
+/// Prints the crates that were added, removed or changed since the baseline, and fails if
+/// there are any. The changes go to stderr if the SBOM was written to stdout
+fn report_diff(diff: &BomDiff, baseline: &Path, sbom_on_stdout: bool) -> anyhow::Result<()> {
+    if !has_changes(diff) {
+        log::info!("No changes since {}", baseline.display());
+        return Ok(());
+    }
+    if sbom_on_stdout {
+        eprint!("{}", report(diff));
+    } else {
+        print!("{}", report(diff));
+    }
+    anyhow::bail!("The SBOM differs from {}", baseline.display());
+}
+
 fn setup_logging(args: &Args) -> anyhow::Result<()> {
     let mut builder = Builder::new();
 
@@ -164,9 +248,14 @@ fn get_metadata(
         cmd.verbose(true);
     }
 
+    let mut other_options = Vec::new();
     if let Some(Target::SingleTarget(target)) = config.target.as_ref() {
-        cmd.other_options(vec!["--filter-platform".to_owned(), target.to_owned()]);
+        other_options.extend(["--filter-platform".to_owned(), target.to_owned()]);
+    }
+    if config.offline == Some(true) {
+        other_options.push("--offline".to_owned());
     }
+    cmd.other_options(other_options);
 
     Ok(cmd.exec()?)
 }
@@ -227,5 +316,204 @@ mod tests {
         assert!(components.0.iter().all(|c| c.name
             != NormalizedString::new("runtime_dep_of_build_dep")
             || c.scope == Some(Scope::Excluded)));
+
+        // Build dependencies are recorded as such, and keep their place in the graph
+        let build_dep = components
+            .0
+            .iter()
+            .find(|c| c.name == NormalizedString::new("build_dep"))
+            .unwrap();
+        assert_eq!(
+            build_dep
+                .properties
+                .as_ref()
+                .and_then(|p| p.get(cargo_cyclonedx::generator::DEPENDENCY_KIND_PROPERTY)),
+            Some("build")
+        );
+        let build_dep_ref = build_dep.bom_ref.as_ref().unwrap();
+        let dependencies = &sboms[0].bom.dependencies.as_ref().unwrap().0;
+        assert!(dependencies
+            .iter()
+            .any(|d| d.dependencies.contains(build_dep_ref)));
+        let build_dep_dependencies = &dependencies
+            .iter()
+            .find(|d| &d.dependency_ref == build_dep_ref)
+            .unwrap()
+            .dependencies;
+        assert!(build_dep_dependencies
+            .iter()
+            .any(|d| d.contains("runtime_dep_of_build_dep")));
+    }
+
+    #[test]
+    fn parse_toml_merged() {
+        use crate::cli;
+        use crate::generate_sboms;
+        use clap::Parser;
+        use cyclonedx_bom::validation::Validate;
+        use std::path::PathBuf;
+
+        let mut test_cargo_toml = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        test_cargo_toml.push("tests/fixtures/build_then_runtime_dep/Cargo.toml");
+
+        let path_arg = &format!("--manifest-path={}", test_cargo_toml.display());
+        let args = ["cyclonedx", path_arg, "--workspace", "--merged"];
+        let args_parsed = cli::Args::parse_from(args.iter());
+
+        let sboms = generate_sboms(&args_parsed).unwrap();
+        assert_eq!(sboms.len(), 1);
+
+        let bom = &sboms[0].bom;
+        assert!(!bom.validate().has_errors());
+
+        let root = bom.metadata.as_ref().unwrap().component.as_ref().unwrap();
+        assert_eq!(root.name, NormalizedString::new("build_then_runtime_dep"));
+
+        // Every member is a top-level component exactly once, and a dependency of the workspace
+        let components = &bom.components.as_ref().unwrap().0;
+        let members = [
+            "top_level_crate",
+            "build_dep",
+            "runtime_dep_of_build_dep",
+            "runtime_dep_of_runtime_dep",
+        ];
+        for member in members {
+            assert_eq!(
+                components
+                    .iter()
+                    .filter(|c| c.name == NormalizedString::new(member))
+                    .count(),
+                1,
+                "{member}"
+            );
+        }
+        let root_dependency = &bom.dependencies.as_ref().unwrap().0[0];
+        assert_eq!(
+            root_dependency.dependency_ref,
+            *root.bom_ref.as_ref().unwrap()
+        );
+        assert_eq!(root_dependency.dependencies.len(), members.len());
+    }
+
+    #[test]
+    fn parse_toml_dependency_graph() {
+        use crate::cli;
+        use crate::generate_sboms;
+        use clap::Parser;
+        use std::collections::HashSet;
+        use std::path::PathBuf;
+
+        let mut test_cargo_toml = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        test_cargo_toml.push("tests/fixtures/build_then_runtime_dep/Cargo.toml");
+
+        let path_arg = &format!("--manifest-path={}", test_cargo_toml.display());
+        let args = ["cyclonedx", path_arg];
+        let args_parsed = cli::Args::parse_from(args.iter());
+
+        for sbom in generate_sboms(&args_parsed).unwrap() {
+            let bom = &sbom.bom;
+            let root = bom.metadata.as_ref().unwrap().component.as_ref().unwrap();
+            let bom_refs: HashSet<&str> = std::iter::once(root)
+                .chain(bom.components.as_ref().unwrap().0.iter())
+                .filter_map(|c| c.bom_ref.as_deref())
+                .collect();
+            let dependencies = &bom.dependencies.as_ref().unwrap().0;
+
+            // One entry per package, whose edges only point to packages of the BOM
+            let dependency_refs: HashSet<&str> = dependencies
+                .iter()
+                .map(|d| d.dependency_ref.as_str())
+                .collect();
+            assert_eq!(dependency_refs, bom_refs);
+            assert!(dependencies
+                .iter()
+                .flat_map(|d| d.dependencies.iter())
+                .all(|bom_ref| bom_refs.contains(bom_ref.as_str())));
+
+            // Every package is reachable from the crate the SBOM describes
+            let graph = bom.dependency_graph();
+            let reachable: HashSet<&str> = graph.reachable_from_root().into_iter().collect();
+            assert_eq!(reachable, bom_refs, "{}", sbom.package_name);
+        }
+    }
+
+    /// Returns the components and the dependencies of the root of the SBOM of `top_level_crate`
+    fn filtered_top_level_crate(filter_args: &[&str]) -> (Vec<String>, Vec<String>) {
+        use crate::cli;
+        use crate::generate_sboms;
+        use clap::Parser;
+        use std::path::PathBuf;
+
+        let mut test_cargo_toml = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        test_cargo_toml.push("tests/fixtures/build_then_runtime_dep/Cargo.toml");
+
+        let path_arg = &format!("--manifest-path={}", test_cargo_toml.display());
+        let mut args = vec!["cyclonedx", path_arg];
+        args.extend(filter_args);
+        let args_parsed = cli::Args::parse_from(args.iter());
+
+        let sboms = generate_sboms(&args_parsed).unwrap();
+        let bom = &sboms
+            .iter()
+            .find(|sbom| sbom.package_name == "top_level_crate")
+            .unwrap()
+            .bom;
+
+        let components: Vec<_> = bom.components.as_ref().unwrap().0.iter().collect();
+        let root = bom.metadata.as_ref().unwrap().component.as_ref().unwrap();
+        let root_dependencies = bom
+            .dependencies
+            .as_ref()
+            .unwrap()
+            .0
+            .iter()
+            .find(|d| d.dependency_ref == *root.bom_ref.as_ref().unwrap())
+            .unwrap()
+            .dependencies
+            .iter()
+            .map(|bom_ref| {
+                let component = components
+                    .iter()
+                    .find(|c| c.bom_ref.as_ref() == Some(bom_ref))
+                    .unwrap();
+                component.name.to_string()
+            })
+            .collect();
+
+        // Every package is still reachable from the crate the SBOM describes
+        let graph = bom.dependency_graph();
+        assert_eq!(graph.reachable_from_root().len(), components.len() + 1);
+
+        let components = components.iter().map(|c| c.name.to_string()).collect();
+        (components, root_dependencies)
+    }
+
+    #[test]
+    fn parse_toml_exclude() {
+        let (components, root_dependencies) = filtered_top_level_crate(&["--exclude=build_dep"]);
+
+        assert_eq!(
+            components,
+            ["runtime_dep_of_build_dep", "runtime_dep_of_runtime_dep"]
+        );
+        // The dependencies of the excluded package are attached to its dependents
+        assert_eq!(root_dependencies, ["runtime_dep_of_build_dep"]);
+    }
+
+    #[test]
+    fn parse_toml_exclude_tree() {
+        let (components, root_dependencies) = filtered_top_level_crate(&["--exclude-tree=build_*"]);
+
+        assert!(components.is_empty());
+        assert!(root_dependencies.is_empty());
+    }
+
+    #[test]
+    fn parse_toml_only() {
+        let (components, root_dependencies) =
+            filtered_top_level_crate(&["--only=runtime_dep*", "--exclude=*of_runtime*"]);
+
+        assert_eq!(components, ["runtime_dep_of_build_dep"]);
+        assert_eq!(root_dependencies, ["runtime_dep_of_build_dep"]);
     }
 }
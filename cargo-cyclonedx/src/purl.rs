@@ -14,16 +14,22 @@ pub fn get_purl(
     let mut builder = PurlBuilder::new(PackageType::Cargo, &package.name)
         .with_version(package.version.to_string());
 
-    if let Some(source) = &package.source {
+    if let Some(source) = package.source.as_ref().filter(|_| !is_vendored(package)) {
         if !source.is_crates_io() {
             match source.repr.split_once('+') {
                 // qualifier names are taken from the spec, which defines these two for all PURL types:
                 // https://github.com/package-url/purl-spec/blob/master/PURL-SPECIFICATION.rst#known-qualifiers-keyvalue-pairs
                 Some(("git", _git_path)) => {
-                    builder = builder.with_qualifier("vcs_url", source_to_vcs_url(source))?
+                    let git = GitSource::parse(source).unwrap();
+                    builder = builder.with_qualifier("vcs_url", git.vcs_url())?;
+                    if let Some(commit) = git.commit {
+                        builder = builder.with_qualifier("commit", commit)?;
+                    }
                 }
-                Some(("registry", registry_url)) => {
-                    builder = builder.with_qualifier("repository_url", registry_url)?
+                Some(("registry" | "sparse", _)) => {
+                    if let Some(registry_url) = registry_url(source) {
+                        builder = builder.with_qualifier("repository_url", registry_url)?
+                    }
                 }
                 Some((source, _path)) => log::warn!("Unknown source kind {}", source),
                 None => {
@@ -33,6 +39,7 @@ pub fn get_purl(
         }
     } else {
         // source is None for packages from the local filesystem.
+        // Vendored packages are built from a local copy too, so they are treated the same way.
         // The manifest path ends with a `Cargo.toml`, so the package directory is its parent
         let mut package_dir = package.manifest_path.parent().unwrap().to_owned();
         // If the package is within the workspace, encode the relative path instead of the absolute one
@@ -64,11 +71,59 @@ pub fn get_purl(
     Ok(CdxPurl::from_str(&purl.to_string()).unwrap())
 }
 
-/// Converts the `cargo metadata`'s `source` field to a valid PURL `vcs_url`.
-/// Assumes that the source kind is `git`, panics if it isn't.
-fn source_to_vcs_url(source: &cargo_metadata::Source) -> String {
-    assert!(source.repr.starts_with("git+"));
-    source.repr.replace('#', "@")
+/// Returns the index URL of a registry other than crates.io, or `None` for other sources.
+/// Sparse registries keep the `sparse+` prefix that Cargo uses to tell them apart from git
+/// registries, e.g. `sparse+https://my-intranet:8080/index/`.
+pub fn registry_url(source: &cargo_metadata::Source) -> Option<&str> {
+    if source.is_crates_io() {
+        return None;
+    }
+    match source.repr.split_once('+') {
+        Some(("registry", url)) => Some(url),
+        Some(("sparse", _)) => Some(&source.repr),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the package was copied into a local directory by `cargo vendor`,
+/// which keeps reporting the original source of the package
+pub fn is_vendored(package: &Package) -> bool {
+    package.source.is_some()
+        && package
+            .manifest_path
+            .parent()
+            .is_some_and(|dir| dir.join(".cargo-checksum.json").is_file())
+}
+
+/// The git repository and commit of a package, as pinned in `Cargo.lock`, e.g.
+/// `git+https://github.com/rust-lang/cargo.git?branch=master#0227f048`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GitSource<'a> {
+    /// The repository URL, without the branch, tag or rev the dependency was declared with
+    pub url: &'a str,
+    /// The commit the dependency was resolved to
+    pub commit: Option<&'a str>,
+}
+
+impl<'a> GitSource<'a> {
+    /// Returns `None` if the source kind is not `git`
+    pub fn parse(source: &'a cargo_metadata::Source) -> Option<Self> {
+        let source = source.repr.strip_prefix("git+")?;
+        let (source, commit) = match source.split_once('#') {
+            Some((source, commit)) => (source, Some(commit)),
+            None => (source, None),
+        };
+        let url = source.split_once('?').map_or(source, |(url, _query)| url);
+        Some(Self { url, commit })
+    }
+
+    /// Formats the source as a PURL `vcs_url`, which follows the SPDX `downloadLocation` syntax
+    pub fn vcs_url(&self) -> String {
+        match self.commit {
+            Some(commit) => format!("git+{}@{}", self.url, commit),
+            None => format!("git+{}", self.url),
+        }
+    }
 }
 
 /// Converts a relative path to PURL subpath
@@ -121,14 +176,89 @@ mod tests {
         let parsed_purl = Purl::from_str(purl.as_ref()).unwrap();
         assert_eq!(parsed_purl.name(), "auditable-extract");
         assert_eq!(parsed_purl.version(), Some("0.3.2"));
-        assert_eq!(parsed_purl.qualifiers().len(), 1);
-        let (qualifier, value) = parsed_purl.qualifiers().iter().next().unwrap();
-        assert_eq!(qualifier.as_str(), "vcs_url");
-        assert_eq!(value, "git+https://github.com/rust-secure-code/cargo-auditable.git@da85607fb1a09435d77288ccf05a92b2e8ec3f71");
+        assert_eq!(parsed_purl.qualifiers().len(), 2);
+        assert_eq!(
+            parsed_purl.qualifiers().get("vcs_url"),
+            Some("git+https://github.com/rust-secure-code/cargo-auditable.git@da85607fb1a09435d77288ccf05a92b2e8ec3f71")
+        );
+        assert_eq!(
+            parsed_purl.qualifiers().get("commit"),
+            Some("da85607fb1a09435d77288ccf05a92b2e8ec3f71")
+        );
         assert!(parsed_purl.subpath().is_none());
         assert!(parsed_purl.namespace().is_none());
     }
 
+    #[test]
+    fn git_source_with_branch() {
+        let source = cargo_metadata::Source {
+            repr: "git+https://github.com/rust-lang/cargo.git?branch=master#0227f048fcb7c798026ede6cc20c92befc84c3a4".to_owned(),
+        };
+        let git = GitSource::parse(&source).unwrap();
+        assert_eq!(git.url, "https://github.com/rust-lang/cargo.git");
+        assert_eq!(git.commit, Some("0227f048fcb7c798026ede6cc20c92befc84c3a4"));
+        assert_eq!(
+            git.vcs_url(),
+            "git+https://github.com/rust-lang/cargo.git@0227f048fcb7c798026ede6cc20c92befc84c3a4"
+        );
+    }
+
+    #[test]
+    fn alternative_registry_purl() {
+        let mut package: Package = serde_json::from_str(CRATES_IO_PACKAGE_JSON).unwrap();
+        for (source, repository_url) in [
+            (
+                "registry+https://my-intranet.example/git/index",
+                "https://my-intranet.example/git/index",
+            ),
+            (
+                "sparse+https://my-intranet.example/index/",
+                "sparse+https://my-intranet.example/index/",
+            ),
+        ] {
+            package.source = Some(cargo_metadata::Source {
+                repr: source.to_owned(),
+            });
+            let purl = get_purl(&package, &package, Utf8Path::new("/foo/bar"), None).unwrap();
+            let parsed_purl = Purl::from_str(purl.as_ref()).unwrap();
+            assert_eq!(parsed_purl.qualifiers().len(), 1);
+            assert_eq!(
+                parsed_purl.qualifiers().get("repository_url"),
+                Some(repository_url)
+            );
+        }
+    }
+
+    #[test]
+    fn vendored_purl() {
+        let mut crates_io_package: Package = serde_json::from_str(CRATES_IO_PACKAGE_JSON).unwrap();
+        let mut root_package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        let workspace = assert_fs::TempDir::new().unwrap();
+        let workspace_root = Utf8Path::from_path(workspace.path()).unwrap();
+        let vendor_dir = workspace_root.join("vendor/aho-corasick");
+        std::fs::create_dir_all(&vendor_dir).unwrap();
+        std::fs::write(vendor_dir.join(".cargo-checksum.json"), "{}").unwrap();
+        crates_io_package.manifest_path = vendor_dir.join("Cargo.toml");
+        root_package.manifest_path = workspace_root.join("Cargo.toml");
+
+        assert!(is_vendored(&crates_io_package));
+        let purl = get_purl(&crates_io_package, &root_package, workspace_root, None).unwrap();
+        let parsed_purl = Purl::from_str(purl.as_ref()).unwrap();
+        assert_eq!(parsed_purl.name(), "aho-corasick");
+        assert_eq!(parsed_purl.version(), Some("1.1.2"));
+        let decoded_path = percent_decode(
+            parsed_purl
+                .qualifiers()
+                .get("download_url")
+                .unwrap()
+                .as_bytes(),
+        )
+        .decode_utf8()
+        .unwrap()
+        .into_owned();
+        assert_eq!(decoded_path, "file://vendor/aho-corasick");
+    }
+
     #[test]
     fn toplevel_package_purl() {
         let root_package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
@@ -133,6 +133,858 @@ fn find_content_in_stderr() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn read_options_from_package_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [package.metadata.cyclonedx]
+        format = "json"
+        override-filename = "sbom"
+        properties = { "org:team" = "platform" }
+        "#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("sbom.json")
+        .assert(predicate::str::contains(r#""name": "org:team""#));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("xml");
+    cmd.assert().success().stdout("");
+
+    tmp_dir.child("sbom.xml").assert(predicate::str::contains(
+        r#"<property name="org:team">platform</property>"#,
+    ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn read_options_from_config_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("cyclonedx.toml").write_str(
+        r#"
+        format = "json"
+
+        [members.pkg]
+        override-filename = "sbom"
+        "#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("sbom.json")
+        .assert(predicate::str::contains(r#""vendor": "CycloneDX"#));
+
+    tmp_dir
+        .child("cyclonedx.toml")
+        .write_str("format = \"yaml\"\n")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path()).arg("cyclonedx");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid config file"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn omit_build_dependencies_from_target_boms() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [build-dependencies]
+        codegen = { path = "codegen" }
+        "#,
+    )?;
+    tmp_dir.child("codegen/src/lib.rs").touch()?;
+    tmp_dir
+        .child("codegen/Cargo.toml")
+        .write_str(r#"package = { name = "codegen", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--describe=binaries");
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("pkg_bin.cdx.json")
+        .assert(predicate::str::contains("codegen").not());
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json");
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("pkg.cdx.json")
+        .assert(predicate::str::contains(r#""name": "codegen""#));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn select_features() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [dependencies]
+        extra = { path = "extra", optional = true }
+
+        [features]
+        default = ["extra"]
+        "#,
+    )?;
+    tmp_dir.child("extra/src/lib.rs").touch()?;
+    tmp_dir
+        .child("extra/Cargo.toml")
+        .write_str(r#"package = { name = "extra", version = "0.0.0" }"#)?;
+
+    let bom = tmp_dir.child("pkg.cdx.json");
+    let has_extra = predicate::str::contains(r#""name": "extra""#);
+
+    for (args, included) in [
+        (&[][..], true),
+        (&["--no-default-features"][..], false),
+        (&["--no-default-features", "--features=extra"][..], true),
+        (&["--no-default-features", "--all-features"][..], true),
+    ] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.current_dir(tmp_dir.path())
+            .arg("cyclonedx")
+            .arg("--format=json")
+            .args(args);
+        cmd.assert().success().stdout("");
+
+        if included {
+            bom.assert(has_extra.clone());
+        } else {
+            bom.assert(has_extra.clone().not());
+        }
+    }
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn resolve_dependencies_per_target() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [target.'cfg(windows)'.dependencies]
+        windows-only = { path = "windows-only" }
+        "#,
+    )?;
+    tmp_dir.child("windows-only/src/lib.rs").touch()?;
+    tmp_dir
+        .child("windows-only/Cargo.toml")
+        .write_str(r#"package = { name = "windows-only", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--target=x86_64-pc-windows-msvc")
+        .arg("--target=x86_64-unknown-linux-gnu");
+    cmd.assert().success().stdout("");
+
+    let has_windows_only = predicate::str::contains(r#""name": "windows-only""#);
+    tmp_dir
+        .child("pkg_x86_64-pc-windows-msvc.cdx.json")
+        .assert(has_windows_only.clone());
+    tmp_dir
+        .child("pkg_x86_64-unknown-linux-gnu.cdx.json")
+        .assert(has_windows_only.not());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn include_dev_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [dev-dependencies]
+        test-helper = { path = "test-helper" }
+        "#,
+    )?;
+    tmp_dir.child("test-helper/src/lib.rs").touch()?;
+    tmp_dir
+        .child("test-helper/Cargo.toml")
+        .write_str(r#"package = { name = "test-helper", version = "0.0.0" }"#)?;
+
+    let bom = tmp_dir.child("pkg.cdx.json");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json");
+    cmd.assert().success().stdout("");
+
+    bom.assert(predicate::str::contains("test-helper").not());
+
+    cmd.arg("--dev-deps");
+    cmd.assert().success().stdout("");
+
+    bom.assert(predicate::str::contains(r#""name": "test-helper""#));
+    bom.assert(predicate::str::contains(r#""scope": "excluded""#));
+    bom.assert(predicate::str::contains(
+        r#""name": "cargo-cyclonedx:dependency-kind""#,
+    ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn normalize_legacy_licenses() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+        license = "MIT/Apache-2.0"
+        "#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json");
+
+    // Reported at the default log level
+    cmd.assert().success().stderr(predicate::str::contains(
+        "Package pkg has a legacy license expression (MIT/Apache-2.0), using MIT OR Apache-2.0",
+    ));
+
+    tmp_dir
+        .child("pkg.cdx.json")
+        .assert(predicate::str::contains(
+            r#""expression": "MIT OR Apache-2.0""#,
+        ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn embed_license_texts() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [dependencies]
+        dep = { path = "dep" }
+        "#,
+    )?;
+    tmp_dir.child("dep/src/lib.rs").touch()?;
+    tmp_dir.child("dep/Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "dep"
+        version = "0.1.0"
+        license = "MIT OR Apache-2.0"
+        "#,
+    )?;
+    tmp_dir.child("dep/LICENSE-MIT").write_str("MIT License")?;
+    tmp_dir
+        .child("dep/LICENSE-APACHE")
+        .write_str("Apache License")?;
+    tmp_dir.child("dep/COPYRIGHT").write_str("Copyright")?;
+    // Not license files
+    tmp_dir.child("dep/licenses.txt").write_str("A list")?;
+    tmp_dir.child("dep/license.rs").write_str("fn main() {}")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--spec-version=1.5")
+        .arg("--license-texts");
+
+    cmd.assert().success();
+
+    let sbom: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        tmp_dir.child("pkg.cdx.json").path(),
+    )?)?;
+    test_utils::validate_json_with_schema(&sbom, cyclonedx_bom::models::bom::SpecVersion::V1_5)
+        .unwrap_or_else(|errors| panic!("The SBOM does not match the schema: {errors:?}"));
+
+    let dep = sbom["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|component| component["name"] == "dep")
+        .unwrap();
+    // The expression is split up to attach the texts to its licenses
+    let licenses = dep["licenses"].as_array().unwrap();
+    assert_eq!(licenses.len(), 2);
+    assert_eq!(licenses[0]["license"]["id"], "MIT");
+    assert_eq!(licenses[0]["license"]["text"]["encoding"], "base64");
+    assert_eq!(licenses[1]["license"]["id"], "Apache-2.0");
+    assert_eq!(licenses[1]["license"]["text"]["encoding"], "base64");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn skip_crates_io_metadata_offline() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--crates-io")
+        .arg("--offline")
+        .arg("--verbose");
+
+    cmd.assert().success().stderr(predicate::str::contains(
+        "Running offline, skipping the crates.io metadata",
+    ));
+
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::path::exists());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn mark_and_hash_path_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [dependencies]
+        dep = { path = "dep" }
+        "#,
+    )?;
+    tmp_dir.child("dep/src/lib.rs").touch()?;
+    tmp_dir
+        .child("dep/Cargo.toml")
+        .write_str(r#"package = { name = "dep", version = "0.1.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--hash-sources");
+
+    cmd.assert().success();
+
+    let sbom: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        tmp_dir.child("pkg.cdx.json").path(),
+    )?)?;
+    let dep = sbom["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|component| component["name"] == "dep")
+        .unwrap();
+    assert_eq!(dep["properties"][0]["name"], "cargo-cyclonedx:source-kind");
+    assert_eq!(dep["properties"][0]["value"], "first-party");
+    assert_eq!(dep["hashes"][0]["alg"], "SHA-256");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn output_pattern_and_directory() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--output-pattern={name}-{version}.cdx.{ext}")
+        .arg("--output-dir=sboms");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("sboms/pkg-0.0.0.cdx.json")
+        .assert(predicate::str::contains(r#""vendor": "CycloneDX"#));
+
+    // The kind of the Cargo target tells apart the SBOMs of targets with the same name
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--describe=all-cargo-targets")
+        .arg("--output-pattern={name}.{ext}");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("pkg_bin.xml")
+        .assert(predicate::path::exists());
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--output-pattern={name}-{arch}.{ext}");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown placeholder `{arch}`"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_to_stdout() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--output=-")
+        .arg("--verbose");
+
+    let output = cmd.assert().success().get_output().clone();
+    let sbom: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(sbom["metadata"]["component"]["name"], "pkg");
+    assert!(String::from_utf8(output.stderr)?.contains("INFO"));
+
+    tmp_dir
+        .child("pkg.cdx.json")
+        .assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_to_stdout_requires_a_single_sbom() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [workspace]
+        members = ["member"]
+        "#,
+    )?;
+    tmp_dir.child("member/src/lib.rs").touch()?;
+    tmp_dir
+        .child("member/Cargo.toml")
+        .write_str(r#"package = { name = "member", version = "0.1.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--output=-");
+
+    cmd.assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "`--output` writes a single SBOM, but 2 were generated",
+        ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_several_formats() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json,xml");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("pkg.cdx.json")
+        .assert(predicate::str::contains(r#""vendor": "CycloneDX"#));
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::str::contains("<vendor>CycloneDX</vendor>"));
+
+    // Patterns without the extension are told apart by appending it
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json,xml")
+        .arg("--output-pattern=sbom-{version}");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("sbom-0.0.0.json")
+        .assert(predicate::path::exists());
+    tmp_dir
+        .child("sbom-0.0.0.xml")
+        .assert(predicate::path::exists());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn advisory_db_is_not_fetched_offline() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--advisories")
+        .arg("--advisory-db=advisory-db")
+        .arg("--offline");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("and it cannot be fetched offline"));
+    tmp_dir
+        .child("advisory-db")
+        .assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn sign_the_sbom() -> Result<(), Box<dyn std::error::Error>> {
+    use cyclonedx_bom::operations::signature::{
+        verify_json_signatures, PublicKey, SigningKey, VerificationStatus,
+    };
+
+    let tmp_dir = make_temp_rust_project()?;
+    let key_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/ed25519_signing_key.pem"
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--spec-version=1.5")
+        .arg(format!("--sign-key={key_path}"));
+
+    cmd.assert().success().stdout("");
+
+    let SigningKey::Ed25519(key) = SigningKey::from_pem(&std::fs::read_to_string(key_path)?)?
+    else {
+        panic!("expected an Ed25519 key");
+    };
+    let sbom: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        tmp_dir.child("pkg.cdx.json").path(),
+    )?)?;
+    let verifications = verify_json_signatures(&sbom, &[PublicKey::Ed25519(key.verifying_key())]);
+    assert_eq!(verifications.len(), 1);
+    assert_eq!(verifications[0].status, VerificationStatus::Verified);
+
+    // XML signatures are not supported
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=xml")
+        .arg("--spec-version=1.5")
+        .arg(format!("--sign-key={key_path}"));
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Signing requires JSON output with spec version 1.5",
+    ));
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_attestation() -> Result<(), Box<dyn std::error::Error>> {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use cyclonedx_bom::models::hash::{HashAlgorithm, Hashes};
+
+    let tmp_dir = make_temp_rust_project()?;
+    let key_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/ed25519_signing_key.pem"
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--spec-version=1.5")
+        .arg("--attestation")
+        .arg(format!("--sign-key={key_path}"));
+
+    cmd.assert().success().stdout("");
+
+    let sbom = std::fs::read(tmp_dir.child("pkg.cdx.json").path())?;
+    let attestation = std::fs::read_to_string(tmp_dir.child("pkg.cdx.intoto.jsonl").path())?;
+    assert_eq!(attestation.lines().count(), 1);
+    let envelope: serde_json::Value = serde_json::from_str(&attestation)?;
+    assert_eq!(envelope["payloadType"], "application/vnd.in-toto+json");
+    assert_eq!(envelope["signatures"].as_array().unwrap().len(), 1);
+
+    let payload = STANDARD.decode(envelope["payload"].as_str().unwrap())?;
+    let statement: serde_json::Value = serde_json::from_slice(&payload)?;
+    assert_eq!(statement["_type"], "https://in-toto.io/Statement/v1");
+    assert_eq!(statement["subject"][0]["name"], "pkg.cdx.json");
+    assert_eq!(
+        statement["subject"][0]["digest"]["sha256"],
+        Hashes::from_reader(sbom.as_slice(), &[HashAlgorithm::SHA_256])?.0[0]
+            .content
+            .0
+    );
+    assert_eq!(statement["predicateType"], "https://cyclonedx.org/bom");
+    assert_eq!(
+        statement["predicate"],
+        serde_json::from_slice::<serde_json::Value>(&sbom)?
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn write_separate_vex_document() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    let advisory_db = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/advisory-db");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--spec-version=1.5")
+        .arg("--advisories")
+        .arg(format!("--advisory-db={advisory_db}"))
+        .arg("--offline")
+        .arg("--vex=pkg.vdr.json");
+
+    cmd.assert().success().stdout("");
+
+    let sbom: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        tmp_dir.child("pkg.cdx.json").path(),
+    )?)?;
+    let vex: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(
+        tmp_dir.child("pkg.vdr.json").path(),
+    )?)?;
+    assert_eq!(vex["specVersion"], "1.5");
+    assert_ne!(vex["serialNumber"], sbom["serialNumber"]);
+    assert_eq!(vex["metadata"]["component"]["name"], "pkg");
+    assert!(vex.get("components").is_none());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn diff_against_baseline() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--output=baseline.cdx.json");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--diff=baseline.cdx.json");
+    cmd.assert().success().stdout("");
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::path::exists());
+
+    tmp_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "pkg", version = "0.1.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--diff=baseline.cdx.json");
+    cmd.assert()
+        .failure()
+        .stdout("~ pkg 0.0.0 -> 0.1.0\n")
+        .stderr(predicate::str::contains(
+            "The SBOM differs from baseline.cdx.json",
+        ));
+    tmp_dir
+        .child("pkg.cdx.xml")
+        .assert(predicate::str::contains("<version>0.1.0</version>"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn diff_with_output_file() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--output=baseline.cdx.json");
+    cmd.assert().success();
+
+    tmp_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "pkg", version = "0.1.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--output=bom.json")
+        .arg("--diff=baseline.cdx.json");
+    cmd.assert()
+        .failure()
+        .stdout("~ pkg 0.0.0 -> 0.1.0\n")
+        .stderr(predicate::str::contains(
+            "The SBOM differs from baseline.cdx.json",
+        ));
+    let sbom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    assert_eq!(sbom["metadata"]["component"]["version"], "0.1.0");
+    tmp_dir
+        .child("pkg.cdx.json")
+        .assert(predicate::path::missing());
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--output=-")
+        .arg("--diff=baseline.cdx.json");
+    let output = cmd.assert().failure().get_output().clone();
+    let sbom: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(sbom["metadata"]["component"]["version"], "0.1.0");
+    assert!(String::from_utf8(output.stderr)?.contains("~ pkg 0.0.0 -> 0.1.0\n"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn reproducible_output() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let generate = || -> Result<String, Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+        cmd.current_dir(tmp_dir.path())
+            .arg("cyclonedx")
+            .arg("--format=json")
+            .arg("--reproducible")
+            .env("SOURCE_DATE_EPOCH", "1700000000");
+        cmd.assert().success().stdout("");
+        Ok(std::fs::read_to_string(
+            tmp_dir.child("pkg.cdx.json").path(),
+        )?)
+    };
+
+    let first = generate()?;
+    assert_eq!(first, generate()?);
+
+    let bom: serde_json::Value = serde_json::from_str(&first)?;
+    assert_eq!(
+        bom["metadata"]["timestamp"],
+        "2023-11-14T22:13:20.000000000Z"
+    );
+    assert!(bom["serialNumber"]
+        .as_str()
+        .is_some_and(|serial| serial.starts_with("urn:uuid:")));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 fn make_temp_rust_project() -> Result<assert_fs::TempDir, assert_fs::fixture::FixtureError> {
     let tmp_dir = assert_fs::TempDir::new()?;
     tmp_dir.child("src/main.rs").touch()?;
@@ -0,0 +1,123 @@
+/* SPDX-License-Identifier: Apache-2.0 */
+
+#ifndef CYCLONEDX_BOM_H
+#define CYCLONEDX_BOM_H
+
+/* Generated by cbindgen from cyclonedx-bom-ffi, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Serialization format of a BOM document.
+typedef enum CdxFormat {
+  CDX_FORMAT_JSON = 0,
+  CDX_FORMAT_XML = 1,
+} CdxFormat;
+
+// Version of the CycloneDX specification.
+typedef enum CdxSpecVersion {
+  CDX_SPEC_VERSION_V1_3 = 0,
+  CDX_SPEC_VERSION_V1_4 = 1,
+  CDX_SPEC_VERSION_V1_5 = 2,
+} CdxSpecVersion;
+
+// Result of a call into the library.
+typedef enum CdxStatus {
+  // The call succeeded.
+  CDX_STATUS_OK = 0,
+  // A required pointer argument was null.
+  CDX_STATUS_NULL_POINTER = 1,
+  // The input could not be parsed as a BOM.
+  CDX_STATUS_PARSE = 2,
+  // The BOM could not be serialized.
+  CDX_STATUS_SERIALIZE = 3,
+  // The BOM failed validation.
+  CDX_STATUS_INVALID = 4,
+  // The library panicked, this is a bug.
+  CDX_STATUS_PANIC = 5,
+} CdxStatus;
+
+// An opaque handle to a parsed BOM.
+typedef struct CdxBom CdxBom;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns the message of the last failed call on this thread, or null if the last call
+// succeeded.
+//
+// The string is owned by the library and stays valid until the next call on this thread.
+const char *cdx_last_error(void);
+
+// Parses `len` bytes at `data` as a BOM and stores a new handle in `out`.
+//
+// JSON documents are parsed according to their `specVersion` field and `version` is ignored,
+// XML documents are parsed as the given `version`.
+//
+// # Safety
+//
+// `data` must point to `len` readable bytes and `out` must be a valid pointer to write to.
+enum CdxStatus cdx_bom_parse(const uint8_t *data,
+                             size_t len,
+                             enum CdxFormat format,
+                             enum CdxSpecVersion version,
+                             struct CdxBom **out);
+
+// Releases a BOM handle. Passing null is a no-op.
+//
+// # Safety
+//
+// `bom` must be null or a handle returned by this library that has not been freed yet.
+void cdx_bom_free(struct CdxBom *bom);
+
+// Validates the BOM against the given specification `version`.
+//
+// Returns [`CdxStatus::Invalid`] if validation fails, the validation errors are available
+// through [`cdx_last_error`].
+//
+// # Safety
+//
+// `bom` must be a valid handle returned by this library.
+enum CdxStatus cdx_bom_validate(const struct CdxBom *bom, enum CdxSpecVersion version);
+
+// Serializes the BOM as the given `format` and specification `version`.
+//
+// On success `out` holds a NUL-terminated string that must be released with
+// [`cdx_string_free`].
+//
+// # Safety
+//
+// `bom` must be a valid handle returned by this library and `out` must be a valid pointer to
+// write to.
+enum CdxStatus cdx_bom_serialize(const struct CdxBom *bom,
+                                 enum CdxFormat format,
+                                 enum CdxSpecVersion version,
+                                 char **out);
+
+// Releases a string returned by this library. Passing null is a no-op.
+//
+// # Safety
+//
+// `string` must be null or a string returned by [`cdx_bom_serialize`] that has not been freed
+// yet.
+void cdx_string_free(char *string);
+
+// Merges `count` BOMs into a new handle stored in `out`, using the default merge policy.
+//
+// The input handles are left untouched and must still be freed by the caller.
+//
+// # Safety
+//
+// `boms` must point to `count` valid handles returned by this library and `out` must be a
+// valid pointer to write to.
+enum CdxStatus cdx_bom_merge(const struct CdxBom *const *boms, size_t count, struct CdxBom **out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* CYCLONEDX_BOM_H */
@@ -0,0 +1,287 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! C bindings for the `cyclonedx-bom` library.
+//!
+//! A BOM is handed out as an opaque [`CdxBom`] pointer which must be released with
+//! [`cdx_bom_free`]. Every fallible function returns a [`CdxStatus`], and the message of the
+//! last failure on the calling thread can be read with [`cdx_last_error`].
+//!
+//! The header `include/cyclonedx_bom.h` is generated with `cbindgen` from this file.
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CString},
+    panic::{catch_unwind, UnwindSafe},
+    ptr, slice,
+};
+
+use cyclonedx_bom::{
+    models::bom::{Bom, SpecVersion},
+    operations::merge::MergePolicy,
+    validation::Validate,
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Result of a call into the library.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CdxStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// The input could not be parsed as a BOM.
+    Parse = 2,
+    /// The BOM could not be serialized.
+    Serialize = 3,
+    /// The BOM failed validation.
+    Invalid = 4,
+    /// The library panicked, this is a bug.
+    Panic = 5,
+}
+
+/// Serialization format of a BOM document.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CdxFormat {
+    Json = 0,
+    Xml = 1,
+}
+
+/// Version of the CycloneDX specification.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CdxSpecVersion {
+    V1_3 = 0,
+    V1_4 = 1,
+    V1_5 = 2,
+}
+
+impl From<CdxSpecVersion> for SpecVersion {
+    fn from(version: CdxSpecVersion) -> Self {
+        match version {
+            CdxSpecVersion::V1_3 => SpecVersion::V1_3,
+            CdxSpecVersion::V1_4 => SpecVersion::V1_4,
+            CdxSpecVersion::V1_5 => SpecVersion::V1_5,
+        }
+    }
+}
+
+/// An opaque handle to a parsed BOM.
+pub struct CdxBom(Bom);
+
+fn set_last_error(message: impl ToString) {
+    let message = CString::new(message.to_string().replace('\0', "\\0"))
+        .expect("interior NUL bytes were escaped");
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Runs `f`, recording its error message and turning panics into [`CdxStatus::Panic`].
+fn guard<F>(f: F) -> CdxStatus
+where
+    F: FnOnce() -> Result<(), (CdxStatus, String)> + UnwindSafe,
+{
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+    match catch_unwind(f) {
+        Ok(Ok(())) => CdxStatus::Ok,
+        Ok(Err((status, message))) => {
+            set_last_error(message);
+            status
+        }
+        Err(_) => {
+            set_last_error("panic in cyclonedx-bom");
+            CdxStatus::Panic
+        }
+    }
+}
+
+fn null_pointer(name: &str) -> (CdxStatus, String) {
+    (CdxStatus::NullPointer, format!("'{name}' must not be null"))
+}
+
+/// Returns the message of the last failed call on this thread, or null if the last call
+/// succeeded.
+///
+/// The string is owned by the library and stays valid until the next call on this thread.
+#[no_mangle]
+pub extern "C" fn cdx_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Parses `len` bytes at `data` as a BOM and stores a new handle in `out`.
+///
+/// JSON documents are parsed according to their `specVersion` field and `version` is ignored,
+/// XML documents are parsed as the given `version`.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes and `out` must be a valid pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_parse(
+    data: *const u8,
+    len: usize,
+    format: CdxFormat,
+    version: CdxSpecVersion,
+    out: *mut *mut CdxBom,
+) -> CdxStatus {
+    guard(|| {
+        if data.is_null() {
+            return Err(null_pointer("data"));
+        }
+        if out.is_null() {
+            return Err(null_pointer("out"));
+        }
+        let input = slice::from_raw_parts(data, len);
+        let bom = match format {
+            CdxFormat::Json => Bom::parse_from_json(input).map_err(|e| e.to_string()),
+            CdxFormat::Xml => {
+                Bom::parse_from_xml_with_version(input, version.into()).map_err(|e| e.to_string())
+            }
+        }
+        .map_err(|message| (CdxStatus::Parse, message))?;
+        *out = Box::into_raw(Box::new(CdxBom(bom)));
+        Ok(())
+    })
+}
+
+/// Releases a BOM handle. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `bom` must be null or a handle returned by this library that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_free(bom: *mut CdxBom) {
+    if !bom.is_null() {
+        drop(Box::from_raw(bom));
+    }
+}
+
+/// Validates the BOM against the given specification `version`.
+///
+/// Returns [`CdxStatus::Invalid`] if validation fails, the validation errors are available
+/// through [`cdx_last_error`].
+///
+/// # Safety
+///
+/// `bom` must be a valid handle returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_validate(
+    bom: *const CdxBom,
+    version: CdxSpecVersion,
+) -> CdxStatus {
+    guard(|| {
+        let bom = bom.as_ref().ok_or_else(|| null_pointer("bom"))?;
+        let result = bom.0.validate_version(version.into());
+        if result.passed() {
+            Ok(())
+        } else {
+            Err((CdxStatus::Invalid, format!("{result:?}")))
+        }
+    })
+}
+
+/// Serializes the BOM as the given `format` and specification `version`.
+///
+/// On success `out` holds a NUL-terminated string that must be released with
+/// [`cdx_string_free`].
+///
+/// # Safety
+///
+/// `bom` must be a valid handle returned by this library and `out` must be a valid pointer to
+/// write to.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_serialize(
+    bom: *const CdxBom,
+    format: CdxFormat,
+    version: CdxSpecVersion,
+    out: *mut *mut c_char,
+) -> CdxStatus {
+    guard(|| {
+        let bom = bom.as_ref().ok_or_else(|| null_pointer("bom"))?;
+        if out.is_null() {
+            return Err(null_pointer("out"));
+        }
+        let mut buffer = Vec::new();
+        let bom = bom.0.clone();
+        match format {
+            CdxFormat::Json => bom
+                .output_as_json(&mut buffer, version.into())
+                .map_err(|e| e.to_string()),
+            CdxFormat::Xml => bom
+                .output_as_xml(&mut buffer, version.into())
+                .map_err(|e| e.to_string()),
+        }
+        .map_err(|message| (CdxStatus::Serialize, message))?;
+        let output = CString::new(buffer).map_err(|e| (CdxStatus::Serialize, e.to_string()))?;
+        *out = output.into_raw();
+        Ok(())
+    })
+}
+
+/// Releases a string returned by this library. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by [`cdx_bom_serialize`] that has not been freed
+/// yet.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Merges `count` BOMs into a new handle stored in `out`, using the default merge policy.
+///
+/// The input handles are left untouched and must still be freed by the caller.
+///
+/// # Safety
+///
+/// `boms` must point to `count` valid handles returned by this library and `out` must be a
+/// valid pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn cdx_bom_merge(
+    boms: *const *const CdxBom,
+    count: usize,
+    out: *mut *mut CdxBom,
+) -> CdxStatus {
+    guard(|| {
+        if boms.is_null() {
+            return Err(null_pointer("boms"));
+        }
+        if out.is_null() {
+            return Err(null_pointer("out"));
+        }
+        let inputs = slice::from_raw_parts(boms, count)
+            .iter()
+            .map(|bom| bom.as_ref().map(|bom| bom.0.clone()))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| null_pointer("boms[i]"))?;
+        let merged = Bom::merge(inputs, MergePolicy::default());
+        *out = Box::into_raw(Box::new(CdxBom(merged)));
+        Ok(())
+    })
+}
@@ -0,0 +1,112 @@
+use std::{
+    ffi::{c_char, CStr},
+    ptr,
+};
+
+use cyclonedx_bom_ffi::*;
+
+const BOM: &str = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "version": 1,
+  "components": [
+    {
+      "type": "library",
+      "bom-ref": "pkg:cargo/serde@1.0.0",
+      "name": "serde",
+      "version": "1.0.0"
+    }
+  ]
+}"#;
+
+fn parse(input: &str, format: CdxFormat) -> Result<*mut CdxBom, CdxStatus> {
+    let mut bom = ptr::null_mut();
+    let status = unsafe {
+        cdx_bom_parse(
+            input.as_ptr(),
+            input.len(),
+            format,
+            CdxSpecVersion::V1_5,
+            &mut bom,
+        )
+    };
+    match status {
+        CdxStatus::Ok => Ok(bom),
+        status => Err(status),
+    }
+}
+
+fn serialize(bom: *const CdxBom, format: CdxFormat) -> String {
+    let mut output: *mut c_char = ptr::null_mut();
+    let status = unsafe { cdx_bom_serialize(bom, format, CdxSpecVersion::V1_5, &mut output) };
+    assert_eq!(status, CdxStatus::Ok);
+    let string = unsafe { CStr::from_ptr(output) }
+        .to_str()
+        .expect("output should be UTF-8")
+        .to_string();
+    unsafe { cdx_string_free(output) };
+    string
+}
+
+fn last_error() -> Option<String> {
+    let message = cdx_last_error();
+    (!message.is_null()).then(|| {
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned()
+    })
+}
+
+#[test]
+fn it_should_round_trip_between_formats() {
+    let bom = parse(BOM, CdxFormat::Json).expect("BOM should parse");
+    assert_eq!(
+        unsafe { cdx_bom_validate(bom, CdxSpecVersion::V1_5) },
+        CdxStatus::Ok
+    );
+
+    let xml = serialize(bom, CdxFormat::Xml);
+    assert!(xml.contains("<name>serde</name>"));
+    unsafe { cdx_bom_free(bom) };
+
+    let bom = parse(&xml, CdxFormat::Xml).expect("XML should parse");
+    assert!(serialize(bom, CdxFormat::Json).contains("\"name\": \"serde\""));
+    unsafe { cdx_bom_free(bom) };
+}
+
+#[test]
+fn it_should_merge_boms() {
+    let first = parse(BOM, CdxFormat::Json).unwrap();
+    let second = parse(BOM, CdxFormat::Json).unwrap();
+
+    let mut merged = ptr::null_mut();
+    let boms = [first as *const CdxBom, second as *const CdxBom];
+    let status = unsafe { cdx_bom_merge(boms.as_ptr(), boms.len(), &mut merged) };
+    assert_eq!(status, CdxStatus::Ok);
+    assert_eq!(
+        serialize(merged, CdxFormat::Json)
+            .matches("\"name\": \"serde\"")
+            .count(),
+        1
+    );
+
+    unsafe {
+        cdx_bom_free(merged);
+        cdx_bom_free(first);
+        cdx_bom_free(second);
+    }
+}
+
+#[test]
+fn it_should_report_errors() {
+    assert_eq!(parse("not a bom", CdxFormat::Json), Err(CdxStatus::Parse));
+    assert!(last_error().is_some());
+
+    let status = unsafe { cdx_bom_validate(ptr::null(), CdxSpecVersion::V1_5) };
+    assert_eq!(status, CdxStatus::NullPointer);
+    assert_eq!(last_error().as_deref(), Some("'bom' must not be null"));
+
+    let bom = parse(BOM, CdxFormat::Json).unwrap();
+    assert!(last_error().is_none());
+    unsafe { cdx_bom_free(bom) };
+}
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+/// Regenerates the C header and checks that the committed copy is up to date.
+///
+/// Run with `UPDATE_HEADER=1` to overwrite `include/cyclonedx_bom.h` instead.
+#[test]
+fn header_is_up_to_date() {
+    let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("cbindgen.toml should be valid");
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("header generation should succeed")
+        .write(&mut generated);
+    let generated = String::from_utf8(generated).expect("header should be UTF-8");
+
+    let header = crate_dir.join("include").join("cyclonedx_bom.h");
+    if std::env::var_os("UPDATE_HEADER").is_some() {
+        std::fs::write(&header, generated).expect("header should be writable");
+        return;
+    }
+    let committed = std::fs::read_to_string(&header).unwrap_or_default();
+    assert!(
+        committed == generated,
+        "{} is out of date, run `UPDATE_HEADER=1 cargo test -p cyclonedx-bom-ffi` to regenerate it",
+        header.display()
+    );
+}
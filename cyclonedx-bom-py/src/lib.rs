@@ -0,0 +1,134 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Python bindings for the `cyclonedx-bom` library.
+//!
+//! The extension module is built with [maturin](https://www.maturin.rs/) and imported as
+//! `cyclonedx_bom`.
+
+use std::str::FromStr;
+
+use cyclonedx_bom::{
+    models::bom::{Bom, SpecVersion},
+    validation::Validate,
+};
+use pyo3::{create_exception, exceptions::PyValueError, prelude::*};
+
+create_exception!(
+    cyclonedx_bom,
+    BomError,
+    PyValueError,
+    "Raised when a BOM cannot be parsed or serialized."
+);
+
+fn spec_version(version: &str) -> PyResult<SpecVersion> {
+    SpecVersion::from_str(version).map_err(|e| BomError::new_err(e.to_string()))
+}
+
+/// A CycloneDX Software Bill of Materials.
+#[pyclass(name = "Bom", module = "cyclonedx_bom")]
+#[derive(Clone)]
+struct PyBom(Bom);
+
+#[pymethods]
+impl PyBom {
+    /// Parses a JSON document, the specification version is read from its `specVersion` field.
+    #[staticmethod]
+    fn parse_json(data: &[u8]) -> PyResult<Self> {
+        Bom::parse_from_json(data)
+            .map(Self)
+            .map_err(|e| BomError::new_err(e.to_string()))
+    }
+
+    /// Parses an XML document conforming to the given specification version.
+    #[staticmethod]
+    #[pyo3(signature = (data, version = "1.5"))]
+    fn parse_xml(data: &[u8], version: &str) -> PyResult<Self> {
+        Bom::parse_from_xml_with_version(data, spec_version(version)?)
+            .map(Self)
+            .map_err(|e| BomError::new_err(e.to_string()))
+    }
+
+    /// Validates the BOM against the given specification version.
+    ///
+    /// Returns a list of `(path, message)` tuples, which is empty if the BOM is valid.
+    #[pyo3(signature = (version = "1.5"))]
+    fn validate(&self, version: &str) -> PyResult<Vec<(String, String)>> {
+        Ok(self
+            .0
+            .validate_version(spec_version(version)?)
+            .violations()
+            .into_iter()
+            .map(|violation| (violation.path, violation.message))
+            .collect())
+    }
+
+    /// Serializes the BOM as a JSON document of the given specification version.
+    #[pyo3(signature = (version = "1.5"))]
+    fn to_json(&self, version: &str) -> PyResult<String> {
+        let mut output = Vec::new();
+        self.0
+            .clone()
+            .output_as_json(&mut output, spec_version(version)?)
+            .map_err(|e| BomError::new_err(e.to_string()))?;
+        String::from_utf8(output).map_err(|e| BomError::new_err(e.to_string()))
+    }
+
+    /// Serializes the BOM as an XML document of the given specification version.
+    #[pyo3(signature = (version = "1.5"))]
+    fn to_xml(&self, version: &str) -> PyResult<String> {
+        let mut output = Vec::new();
+        self.0
+            .clone()
+            .output_as_xml(&mut output, spec_version(version)?)
+            .map_err(|e| BomError::new_err(e.to_string()))?;
+        String::from_utf8(output).map_err(|e| BomError::new_err(e.to_string()))
+    }
+
+    /// The version of the BOM document.
+    #[getter]
+    fn version(&self) -> u32 {
+        self.0.version
+    }
+
+    /// The serial number of the BOM, if any.
+    #[getter]
+    fn serial_number(&self) -> Option<String> {
+        self.0.serial_number.as_ref().map(ToString::to_string)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Bom(version={}, serial_number={:?})",
+            self.0.version,
+            self.serial_number()
+        )
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "cyclonedx_bom")]
+fn py_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyBom>()?;
+    m.add("BomError", m.py().get_type::<BomError>())?;
+    Ok(())
+}
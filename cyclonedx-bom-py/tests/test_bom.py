@@ -0,0 +1,45 @@
+import pytest
+
+import cyclonedx_bom
+
+BOM = b"""{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+  "version": 1,
+  "components": [
+    {
+      "type": "library",
+      "bom-ref": "pkg:cargo/serde@1.0.0",
+      "name": "serde",
+      "version": "1.0.0"
+    }
+  ]
+}"""
+
+
+def test_round_trip_between_formats():
+    bom = cyclonedx_bom.Bom.parse_json(BOM)
+    assert bom.version == 1
+    assert bom.serial_number == "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79"
+
+    xml = bom.to_xml("1.5")
+    assert "<name>serde</name>" in xml
+    assert cyclonedx_bom.Bom.parse_xml(xml.encode(), "1.5") == bom
+
+
+def test_validate():
+    bom = cyclonedx_bom.Bom.parse_json(BOM)
+    assert bom.validate("1.5") == []
+
+    invalid = cyclonedx_bom.Bom.parse_json(
+        BOM.replace(b'"version": "1.0.0"', b'"version": "1.0.0", "purl": "not a purl"')
+    )
+    assert [path for path, _ in invalid.validate()] == ["components[0].purl"]
+
+
+def test_errors():
+    with pytest.raises(cyclonedx_bom.BomError):
+        cyclonedx_bom.Bom.parse_json(b"not a bom")
+    with pytest.raises(ValueError, match="1.9"):
+        cyclonedx_bom.Bom.parse_json(BOM).to_json("1.9")
@@ -0,0 +1,49 @@
+//! Parses a large generated BOM, with `serde_json` and, if the `simd-json` feature is enabled,
+//! with `simd-json`: `cargo bench -p cyclonedx-bom --features simd-json`
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+use cyclonedx_bom::models::component::{Classification, Component};
+
+/// A document with as many components as the SBOM of a container image with a full distribution
+fn large_document() -> Vec<u8> {
+    let bom = (0..20_000)
+        .fold(Bom::builder(), |builder, index| {
+            let mut component = Component::new(
+                Classification::Library,
+                &format!("package-{index}"),
+                &format!("1.{}.{}", index / 100, index % 100),
+                Some(format!("pkg:cargo/package-{index}@1.0.0")),
+            );
+            component.description = Some(NormalizedString::new(&format!(
+                "Package number {index}, with a description that has \"quotes\""
+            )));
+            builder.component(component)
+        })
+        .build()
+        .expect("valid BOM");
+    let mut output = Vec::new();
+    bom.output_as_json(&mut output, SpecVersion::V1_5)
+        .expect("BOM should be written as JSON");
+    output
+}
+
+fn parse_json(c: &mut Criterion) {
+    let input = large_document();
+    let mut group = c.benchmark_group("parse_json");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.sample_size(20);
+
+    group.bench_function("serde_json", |b| {
+        b.iter(|| Bom::parse_from_json_slice(black_box(&input)).unwrap())
+    });
+    #[cfg(feature = "simd-json")]
+    group.bench_function("simd_json", |b| {
+        b.iter(|| Bom::parse_from_json(black_box(input.as_slice())).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, parse_json);
+criterion_main!(benches);
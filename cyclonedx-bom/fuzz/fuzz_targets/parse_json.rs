@@ -0,0 +1,20 @@
+#![no_main]
+
+use cyclonedx_bom::{models::bom::Bom, validation::Validate};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((version, input)) = data.split_first() else {
+        return;
+    };
+    let bom = match version % 4 {
+        0 => Bom::parse_from_json(input),
+        1 => Bom::parse_from_json_v1_3(input),
+        2 => Bom::parse_from_json_v1_4(input),
+        _ => Bom::parse_from_json_v1_5(input),
+    };
+    if let Ok(bom) = bom {
+        let _ = bom.validate();
+        let _ = bom.output_as_json_v1_5(&mut std::io::sink());
+    }
+});
@@ -0,0 +1,443 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! A product BOM distributed together with the VEX documents describing its vulnerabilities.
+//!
+//! VEX (Vulnerability Exploitability eXchange) documents are BOMs with a `vulnerabilities`
+//! section whose `affects` entries point into the product BOM by BOM-Link, e.g.
+//! `urn:cdx:<uuid>/<version>#<bom-ref>`. [`Bundle::link`] establishes these links,
+//! [`Bundle::validate_references`] checks them.
+
+use std::collections::HashSet;
+
+use crate::{
+    models::{
+        bom::{Bom, UrnUuid},
+        external_reference::{
+            BomLink, ExternalReference, ExternalReferenceType, ExternalReferences, Uri,
+        },
+    },
+    validation::Violation,
+};
+
+#[cfg(feature = "json")]
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "json")]
+use crate::{errors::JsonWriteError, models::bom::SpecVersion};
+
+#[cfg(feature = "json")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BundleError {
+    #[error("Failed to write bundle: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to serialize bundle: {0}")]
+    Json(#[from] JsonWriteError),
+}
+
+/// A product BOM and the VEX documents referring to it.
+/// ```
+/// use cyclonedx_bom::bundle::Bundle;
+/// use cyclonedx_bom::models::component::{Classification, Component};
+/// use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+/// use cyclonedx_bom::models::vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets};
+/// use cyclonedx_bom::prelude::*;
+///
+/// let product = Bom::builder()
+///     .component(Component::new(Classification::Library, "openssl", "3.0.0", Some("openssl".into())))
+///     .build()
+///     .expect("valid BOM");
+/// let mut vulnerability = Vulnerability::new(None);
+/// vulnerability.vulnerability_targets = Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+///     "openssl".to_string(),
+/// )]));
+/// let vex = Bom {
+///     vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+///     ..Bom::default()
+/// };
+///
+/// let mut bundle = Bundle::new(product).with_vex(vex);
+/// // The product has no serial number, the VEX document neither links to it nor uses a BOM-Link
+/// assert_eq!(bundle.validate_references().len(), 3);
+///
+/// bundle.link();
+/// assert!(bundle.validate_references().is_empty());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bundle {
+    pub product: Bom,
+    pub vex: Vec<Bom>,
+}
+
+impl Bundle {
+    pub fn new(product: Bom) -> Self {
+        Self {
+            product,
+            vex: Vec::new(),
+        }
+    }
+
+    pub fn with_vex(mut self, vex: Bom) -> Self {
+        self.vex.push(vex);
+        self
+    }
+
+    /// Cross-links the product BOM and the VEX documents.
+    ///
+    /// Documents without a serial number get a random one. Each VEX document gets an external
+    /// reference of type `bom` to the product, the product one of type
+    /// `exploitability-statement` to each VEX document. `affects` entries referring to an
+    /// element of the product by its plain `bom-ref` are turned into BOM-Links, unless the
+    /// VEX document contains an element with the same `bom-ref` itself.
+    pub fn link(&mut self) {
+        let product_link = document_link(&mut self.product);
+        let product_refs = bom_refs(&self.product);
+
+        let mut vex_links = Vec::new();
+        for vex in &mut self.vex {
+            let vex_refs: HashSet<String> = bom_refs(vex).into_iter().map(str::to_string).collect();
+            let affects = vex
+                .vulnerabilities
+                .iter_mut()
+                .flat_map(|vulnerabilities| vulnerabilities.0.iter_mut())
+                .flat_map(|vulnerability| vulnerability.vulnerability_targets.iter_mut())
+                .flat_map(|targets| targets.0.iter_mut());
+            for target in affects {
+                if !target.bom_ref.starts_with("urn:cdx:")
+                    && product_refs.contains(target.bom_ref.as_str())
+                    && !vex_refs.contains(target.bom_ref.as_str())
+                {
+                    target.bom_ref = format!("{product_link}#{}", target.bom_ref);
+                }
+            }
+
+            add_reference(vex, ExternalReferenceType::Bom, &product_link);
+            vex_links.push(document_link(vex));
+        }
+
+        for vex_link in vex_links {
+            add_reference(
+                &mut self.product,
+                ExternalReferenceType::ExploitabilityStatement,
+                &vex_link,
+            );
+        }
+    }
+
+    /// Checks that every VEX document links to the product and that every `affects` entry
+    /// refers to an existing element of the product or of the VEX document itself.
+    pub fn validate_references(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        let product_link = self
+            .product
+            .serial_number
+            .as_ref()
+            .map(|serial_number| BomLink::new(serial_number, self.product.version));
+        if product_link.is_none() {
+            violations.push(violation("product", "has no serial number"));
+        }
+        let product_refs = bom_refs(&self.product);
+
+        for (i, vex) in self.vex.iter().enumerate() {
+            let links_product = product_link.as_ref().is_some_and(|product_link| {
+                vex.external_references
+                    .iter()
+                    .flat_map(|references| references.0.iter())
+                    .any(|reference| {
+                        reference.external_reference_type == ExternalReferenceType::Bom
+                            && reference.url == Uri::BomLink(product_link.clone())
+                    })
+            });
+            if !links_product {
+                violations.push(violation(
+                    &format!("vex[{i}].externalReferences"),
+                    "does not link to the product BOM",
+                ));
+            }
+
+            let vex_refs = bom_refs(vex);
+            let vulnerabilities = vex.vulnerabilities.iter().flat_map(|v| v.0.iter());
+            for (j, vulnerability) in vulnerabilities.enumerate() {
+                let targets = vulnerability
+                    .vulnerability_targets
+                    .iter()
+                    .flat_map(|targets| targets.0.iter());
+                for (k, target) in targets.enumerate() {
+                    let path = format!("vex[{i}].vulnerabilities[{j}].affects[{k}].ref");
+                    let message = if target.bom_ref.starts_with("urn:cdx:") {
+                        let bom_link = BomLink(target.bom_ref.clone());
+                        let in_product = product_link.as_ref().is_some_and(|product_link| {
+                            bom_link.document().is_some()
+                                && bom_link.document() == product_link.document()
+                        });
+                        match bom_link.fragment() {
+                            _ if !in_product => Some("refers to a BOM outside of the bundle"),
+                            Some(bom_ref) if !product_refs.contains(bom_ref) => {
+                                Some("refers to an unknown element of the product BOM")
+                            }
+                            _ => None,
+                        }
+                    } else if vex_refs.contains(target.bom_ref.as_str()) {
+                        None
+                    } else if product_refs.contains(target.bom_ref.as_str()) {
+                        Some("refers to an element of the product BOM without a BOM-Link")
+                    } else {
+                        Some("refers to an unknown element")
+                    };
+                    if let Some(message) = message {
+                        violations.push(violation(&path, message));
+                    }
+                }
+            }
+        }
+        violations
+    }
+
+    /// Writes the product BOM to `<name>.cdx.json` and the VEX documents next to it, to
+    /// `<name>.vex.cdx.json` or, for more than one, `<name>.vex-<n>.cdx.json` starting at 1.
+    ///
+    /// Returns the paths of the written files, the product first.
+    #[cfg(feature = "json")]
+    pub fn write_json(
+        &self,
+        directory: impl AsRef<Path>,
+        name: &str,
+        version: SpecVersion,
+    ) -> Result<Vec<PathBuf>, BundleError> {
+        let directory = directory.as_ref();
+        let mut documents = vec![(format!("{name}.cdx.json"), &self.product)];
+        for (i, vex) in self.vex.iter().enumerate() {
+            let file_name = if self.vex.len() == 1 {
+                format!("{name}.vex.cdx.json")
+            } else {
+                format!("{name}.vex-{}.cdx.json", i + 1)
+            };
+            documents.push((file_name, vex));
+        }
+
+        let mut paths = Vec::new();
+        for (file_name, bom) in documents {
+            let mut output = Vec::new();
+            bom.clone().output_as_json(&mut output, version)?;
+            let path = directory.join(file_name);
+            std::fs::write(&path, output)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+}
+
+/// Returns the BOM-Link of a BOM, assigning a serial number if it has none.
+fn document_link(bom: &mut Bom) -> BomLink {
+    let serial_number = bom.serial_number.get_or_insert_with(UrnUuid::generate);
+    BomLink::new(serial_number, bom.version)
+}
+
+/// Returns the `bom-ref`s of all components and services, including nested ones.
+fn bom_refs(bom: &Bom) -> HashSet<&str> {
+    let mut refs = HashSet::new();
+    let mut components: Vec<_> = bom
+        .metadata
+        .iter()
+        .flat_map(|metadata| metadata.component.iter())
+        .chain(bom.components.iter().flat_map(|c| c.0.iter()))
+        .collect();
+    while let Some(component) = components.pop() {
+        refs.extend(component.bom_ref.as_deref());
+        components.extend(component.components.iter().flat_map(|c| c.0.iter()));
+    }
+    let mut services: Vec<_> = bom.services.iter().flat_map(|s| s.0.iter()).collect();
+    while let Some(service) = services.pop() {
+        refs.extend(service.bom_ref.as_deref());
+        services.extend(service.services.iter().flat_map(|s| s.0.iter()));
+    }
+    refs
+}
+
+/// Adds an external reference to a BOM-Link unless the BOM already has it.
+fn add_reference(bom: &mut Bom, reference_type: ExternalReferenceType, bom_link: &BomLink) {
+    let references = bom
+        .external_references
+        .get_or_insert_with(|| ExternalReferences(Vec::new()));
+    let url = Uri::BomLink(bom_link.clone());
+    if !references.0.iter().any(|reference| {
+        reference.external_reference_type == reference_type && reference.url == url
+    }) {
+        references
+            .0
+            .push(ExternalReference::new(reference_type, url));
+    }
+}
+
+fn violation(path: &str, message: &str) -> Violation {
+    Violation {
+        path: path.to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{
+        component::{Classification, Component, Components},
+        vulnerability::{Vulnerabilities, Vulnerability},
+        vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets},
+    };
+
+    fn component(bom_ref: &str) -> Component {
+        Component::new(
+            Classification::Library,
+            bom_ref,
+            "1.0.0",
+            Some(bom_ref.into()),
+        )
+    }
+
+    fn vex(affects: &[&str]) -> Bom {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.vulnerability_targets = Some(VulnerabilityTargets(
+            affects
+                .iter()
+                .map(|bom_ref| VulnerabilityTarget::new(bom_ref.to_string()))
+                .collect(),
+        ));
+        Bom {
+            vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+            ..Bom::default()
+        }
+    }
+
+    fn affects(bom: &Bom) -> Vec<&str> {
+        bom.vulnerabilities
+            .iter()
+            .flat_map(|v| v.0.iter())
+            .flat_map(|v| v.vulnerability_targets.iter().flat_map(|t| t.0.iter()))
+            .map(|target| target.bom_ref.as_str())
+            .collect()
+    }
+
+    fn bundle() -> Bundle {
+        let mut parent = component("parent");
+        parent.components = Some(Components(vec![component("child")]));
+        let product = Bom {
+            serial_number: None,
+            components: Some(Components(vec![parent])),
+            ..Bom::default()
+        };
+        let mut local = vex(&["child", "local", "unknown"]);
+        local.components = Some(Components(vec![component("local")]));
+        Bundle::new(product)
+            .with_vex(local)
+            .with_vex(vex(&["parent"]))
+    }
+
+    #[test]
+    fn it_should_cross_link_product_and_vex_documents() {
+        let mut bundle = bundle();
+        bundle.link();
+        bundle.link();
+
+        let product_link = BomLink::new(
+            bundle.product.serial_number.as_ref().unwrap(),
+            bundle.product.version,
+        );
+        assert_eq!(
+            affects(&bundle.vex[0]),
+            vec![format!("{product_link}#child").as_str(), "local", "unknown"]
+        );
+        assert_eq!(
+            bundle.vex[1].external_references,
+            Some(ExternalReferences(vec![ExternalReference::new(
+                ExternalReferenceType::Bom,
+                Uri::BomLink(product_link)
+            )]))
+        );
+        assert_eq!(
+            bundle.product.external_references.as_ref().unwrap().0.len(),
+            2
+        );
+    }
+
+    #[test]
+    fn it_should_report_invalid_references() {
+        let mut bundle = bundle();
+        bundle.link();
+        bundle.vex[1].vulnerabilities.as_mut().unwrap().0[0].vulnerability_targets =
+            Some(VulnerabilityTargets(vec![
+                VulnerabilityTarget::new(format!(
+                    "urn:cdx:{}/1#parent",
+                    &UrnUuid::generate().0[9..]
+                )),
+                VulnerabilityTarget::new(format!(
+                    "{}#missing",
+                    BomLink::new(bundle.product.serial_number.as_ref().unwrap(), 1)
+                )),
+            ]));
+
+        let violations: Vec<_> = bundle
+            .validate_references()
+            .into_iter()
+            .map(|violation| violation.to_string())
+            .collect();
+
+        assert_eq!(
+            violations,
+            vec![
+                "vex[0].vulnerabilities[0].affects[2].ref: refers to an unknown element",
+                "vex[1].vulnerabilities[0].affects[0].ref: refers to a BOM outside of the bundle",
+                "vex[1].vulnerabilities[0].affects[1].ref: refers to an unknown element of the product BOM",
+            ]
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_write_documents_side_by_side() {
+        let directory =
+            std::env::temp_dir().join(format!("cyclonedx-bom-bundle-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let mut bundle = bundle();
+        bundle.link();
+
+        let paths = bundle
+            .write_json(&directory, "product", SpecVersion::V1_5)
+            .unwrap();
+
+        let file_names: Vec<_> = paths
+            .iter()
+            .map(|path| path.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(
+            file_names,
+            vec![
+                "product.cdx.json",
+                "product.vex-1.cdx.json",
+                "product.vex-2.cdx.json"
+            ]
+        );
+        let vex = Bom::parse_from_json(std::fs::File::open(&paths[2]).unwrap()).unwrap();
+        assert_eq!(vex, bundle.vex[1]);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -18,13 +18,65 @@
 
 use std::convert::Infallible;
 
+#[cfg(feature = "xml")]
 use xml::name::OwnedName;
 
-use crate::models::bom::SpecVersion;
+use crate::{models::bom::SpecVersion, validation::Violation};
+
+/// The category of a read or write error, returned by the `kind` method of the error types.
+///
+/// This allows callers to handle a class of failures without matching on every variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Reading from or writing to the underlying stream failed.
+    Io,
+    /// The input is not well-formed JSON or XML.
+    Syntax,
+    /// The input is well-formed, but does not describe a valid CycloneDX document.
+    Semantic,
+    /// The BOM cannot be converted to or from the requested spec version.
+    Conversion,
+}
+
+#[cfg(feature = "json")]
+impl From<&serde_json::Error> for ErrorKind {
+    fn from(error: &serde_json::Error) -> Self {
+        match error.classify() {
+            serde_json::error::Category::Io => Self::Io,
+            serde_json::error::Category::Syntax | serde_json::error::Category::Eof => Self::Syntax,
+            serde_json::error::Category::Data => Self::Semantic,
+        }
+    }
+}
+
+#[cfg(feature = "simd-json")]
+impl From<&simd_json::Error> for ErrorKind {
+    fn from(error: &simd_json::Error) -> Self {
+        if error.is_io() {
+            Self::Io
+        } else if error.is_syntax() || error.is_eof() {
+            Self::Syntax
+        } else {
+            Self::Semantic
+        }
+    }
+}
+
+#[cfg(feature = "xml")]
+impl From<&xml::reader::Error> for ErrorKind {
+    fn from(error: &xml::reader::Error) -> Self {
+        match error.kind() {
+            xml::reader::ErrorKind::Io(_) => Self::Io,
+            _ => Self::Syntax,
+        }
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum BomError {
+    #[cfg(feature = "json")]
     #[error("Failed to serialize BOM to JSON: {0}")]
     JsonSerializationError(#[from] serde_json::Error),
 
@@ -38,6 +90,18 @@ pub enum BomError {
     UnsupportedSpecVersion(String),
 }
 
+impl BomError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(feature = "json")]
+            Self::JsonSerializationError(error) => error.into(),
+            Self::XmlSerializationError(_)
+            | Self::BomSerializationError(..)
+            | Self::UnsupportedSpecVersion(_) => ErrorKind::Conversion,
+        }
+    }
+}
+
 // This allows to use `TryFrom` when a type only implements `From` inside a
 // `TryFrom<Error = BomError>` implementation.
 impl From<Infallible> for BomError {
@@ -46,6 +110,7 @@ impl From<Infallible> for BomError {
     }
 }
 
+#[cfg(feature = "json")]
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum JsonWriteError {
@@ -61,6 +126,17 @@ pub enum JsonWriteError {
     },
 }
 
+#[cfg(feature = "json")]
+impl JsonWriteError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::JsonElementWriteError { error } => error.into(),
+            Self::BomError { error } => error.kind(),
+        }
+    }
+}
+
+#[cfg(feature = "xml")]
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum XmlWriteError {
@@ -77,6 +153,21 @@ pub enum XmlWriteError {
     },
 }
 
+#[cfg(feature = "xml")]
+impl XmlWriteError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::XmlElementWriteError {
+                error: xml::writer::Error::Io(_),
+                ..
+            } => ErrorKind::Io,
+            Self::XmlElementWriteError { .. } => ErrorKind::Syntax,
+            Self::BomError { error } => error.kind(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum JsonReadError {
@@ -90,8 +181,27 @@ pub enum JsonReadError {
         #[from]
         error: BomError,
     },
+    #[cfg(feature = "simd-json")]
+    #[error("Failed to deserialize JSON with simd-json: {error}")]
+    SimdJsonReadError {
+        #[from]
+        error: simd_json::Error,
+    },
 }
 
+#[cfg(feature = "json")]
+impl JsonReadError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::JsonElementReadError { error } => error.into(),
+            Self::BomError { error } => error.kind(),
+            #[cfg(feature = "simd-json")]
+            Self::SimdJsonReadError { error } => error.into(),
+        }
+    }
+}
+
+#[cfg(feature = "xml")]
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum XmlReadError {
@@ -127,9 +237,25 @@ pub enum XmlReadError {
         expected_namespace: String,
         actual_namespace: Option<String>,
     },
+
+    #[error("Element {element} is nested deeper than {max_depth} levels")]
+    NestingTooDeep { element: String, max_depth: usize },
 }
 
+#[cfg(feature = "xml")]
 impl XmlReadError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::ElementReadError { error, .. } => error.into(),
+            Self::NestingTooDeep { .. } => ErrorKind::Syntax,
+            Self::UnexpectedElementReadError { .. }
+            | Self::RequiredDataMissing { .. }
+            | Self::RequiredAttributeMissing { .. }
+            | Self::InvalidParseError { .. }
+            | Self::InvalidNamespaceError { .. } => ErrorKind::Semantic,
+        }
+    }
+
     pub fn required_data_missing(required_field: &str, element: &OwnedName) -> Self {
         Self::RequiredDataMissing {
             required_field: required_field.to_string(),
@@ -137,3 +263,78 @@ impl XmlReadError {
         }
     }
 }
+
+/// Returned when parsing a value that is not defined by the spec into an enum.
+#[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
+#[error("Unknown {kind} '{value}', expected one of: {}", .expected.join(", "))]
+pub struct ParseEnumError {
+    pub kind: &'static str,
+    pub value: String,
+    pub expected: Vec<String>,
+}
+
+/// Returned by the `build` method of a builder if the built value fails validation, listing
+/// all violations at once.
+#[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
+#[error("Invalid {model}: {}", .violations.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+pub struct BuildError {
+    pub model: &'static str,
+    pub violations: Vec<Violation>,
+}
+
+/// Returned when the properties of an [`Extension`](crate::models::extension::Extension) cannot
+/// be read.
+#[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExtensionError {
+    #[error("Required property {name} is missing")]
+    MissingProperty { name: String },
+    #[error("Invalid value '{value}' for property {name}")]
+    InvalidValue { name: String, value: String },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::bom::Bom;
+
+    #[test]
+    fn it_should_classify_read_errors() {
+        let syntax = Bom::parse_from_json("{".as_bytes()).unwrap_err();
+        assert_eq!(syntax.kind(), ErrorKind::Syntax);
+
+        let semantic = Bom::parse_from_json(r#"{"bomFormat": 1, "specVersion": "1.5"}"#.as_bytes())
+            .unwrap_err();
+        assert_eq!(semantic.kind(), ErrorKind::Semantic);
+
+        let conversion = Bom::parse_from_json(
+            r#"{"bomFormat": "CycloneDX", "specVersion": "0.1", "version": 1}"#.as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(conversion.kind(), ErrorKind::Conversion);
+
+        let namespace = Bom::parse_from_xml_v1_5(
+            r#"<?xml version="1.0"?><bom xmlns="http://example.com" version="1"/>"#.as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(namespace.kind(), ErrorKind::Semantic);
+    }
+
+    #[test]
+    fn it_should_keep_the_source_of_io_errors() {
+        struct FailingReader;
+
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "disconnected",
+                ))
+            }
+        }
+
+        let error = Bom::parse_from_json(FailingReader).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Io);
+        assert!(std::error::Error::source(&error).is_some());
+    }
+}
@@ -55,6 +55,32 @@ impl DateTime {
             .map_err(|_| DateTimeError::FailedCurrentTime)?;
         Ok(Self(now))
     }
+
+    /// Constructs a timestamp from the number of seconds since the Unix epoch, e.g. from the
+    /// [`SOURCE_DATE_EPOCH`](https://reproducible-builds.org/specs/source-date-epoch/)
+    /// environment variable of reproducible builds
+    pub fn from_unix_timestamp(timestamp: i64) -> Result<Self, DateTimeError> {
+        OffsetDateTime::from_unix_timestamp(timestamp)
+            .ok()
+            .and_then(|date_time| date_time.format(&Iso8601::DEFAULT).ok())
+            .map(Self)
+            .ok_or_else(|| {
+                DateTimeError::InvalidDateTime(format!("Invalid Unix timestamp: {}", timestamp))
+            })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DateTime {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Up to the end of year 9999, the largest year ISO 8601 covers without an explicit sign.
+        let timestamp = u.int_in_range(0..=253_402_300_799)?;
+        OffsetDateTime::from_unix_timestamp(timestamp)
+            .ok()
+            .and_then(|date_time| date_time.format(&Iso8601::DEFAULT).ok())
+            .map(Self)
+            .ok_or(arbitrary::Error::IncorrectFormat)
+    }
 }
 
 impl TryFrom<String> for DateTime {
@@ -84,6 +110,7 @@ impl AsRef<str> for DateTime {
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DateTimeError {
     #[error("Invalid DateTime: {}", .0)]
     InvalidDateTime(String),
@@ -106,6 +133,15 @@ mod test {
         assert!(validation_result.is_ok());
     }
 
+    #[test]
+    fn it_should_construct_datetimes_from_unix_timestamps() {
+        let date_time = DateTime::from_unix_timestamp(1_700_000_000).unwrap();
+
+        assert_eq!(date_time.to_string(), "2023-11-14T22:13:20.000000000Z");
+        assert!(validate_date_time(&date_time).is_ok());
+        assert!(DateTime::from_unix_timestamp(i64::MAX).is_err());
+    }
+
     #[test]
     fn invalid_datetimes_should_fail_validation() {
         let validation_result = validate_date_time(&DateTime("invalid date".to_string()));
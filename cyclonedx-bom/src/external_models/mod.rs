@@ -22,3 +22,41 @@ pub mod spdx;
 pub mod uri;
 
 pub(crate) use date_time::validate_date_time;
+
+/// The storage of [`NormalizedString`](normalized_string::NormalizedString) and
+/// [`SpdxIdentifier`](spdx::SpdxIdentifier), shared between equal values with the `interning`
+/// feature.
+#[cfg(not(feature = "interning"))]
+pub(crate) type Text = String;
+#[cfg(feature = "interning")]
+pub(crate) type Text = crate::interning::Interned;
+
+#[cfg(not(feature = "interning"))]
+pub(crate) fn text(value: String) -> Text {
+    value
+}
+
+#[cfg(feature = "interning")]
+pub(crate) fn text(value: String) -> Text {
+    Text::from(value)
+}
+
+#[cfg(not(feature = "interning"))]
+pub(crate) fn into_string(value: Text) -> String {
+    value
+}
+
+#[cfg(feature = "interning")]
+pub(crate) fn into_string(value: Text) -> String {
+    value.to_string()
+}
+
+/// Generates a short lowercase alphanumeric token, usable in URIs and package names.
+#[cfg(feature = "arbitrary")]
+pub(crate) fn arbitrary_token(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<String> {
+    const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+    let len = u.int_in_range(1..=16)?;
+    (0..len)
+        .map(|_| u.choose(CHARS).map(|c| *c as char))
+        .collect()
+}
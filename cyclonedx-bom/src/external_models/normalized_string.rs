@@ -16,6 +16,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::external_models::{into_string, text, Text};
 use crate::validation::ValidationError;
 use std::fmt::Display;
 use std::ops::Deref;
@@ -24,7 +25,7 @@ use std::ops::Deref;
 ///
 /// Defined via the [XML schema](https://www.w3.org/TR/xmlschema-2/#normalizedString)
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-pub struct NormalizedString(pub(crate) String);
+pub struct NormalizedString(pub(crate) Text);
 
 impl NormalizedString {
     /// Construct a `NormalizedString` by replacing all of the invalid characters with spaces
@@ -35,13 +36,23 @@ impl NormalizedString {
     /// assert_eq!(normalized_string.to_string(), "A string with invalid characters".to_string());
     /// ```
     pub fn new(value: &str) -> Self {
+        if !contains_invalid_characters(value) {
+            return NormalizedString(text(value.to_string()));
+        }
         let value = value.replace("\r\n", " ").replace(['\r', '\n', '\t'], " ");
-        NormalizedString(value)
+        NormalizedString(text(value))
     }
 
     /// Allow for the existence of invalid inputs from other data sources
     pub(crate) fn new_unchecked(value: String) -> Self {
-        NormalizedString(value)
+        NormalizedString(text(value))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NormalizedString {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
     }
 }
 
@@ -53,7 +64,7 @@ impl From<&str> for NormalizedString {
 
 impl From<NormalizedString> for String {
     fn from(value: NormalizedString) -> Self {
-        value.0
+        into_string(value.0)
     }
 }
 
@@ -87,11 +98,7 @@ impl Display for NormalizedString {
 pub fn validate_normalized_string(
     normalized_string: &NormalizedString,
 ) -> Result<(), ValidationError> {
-    if normalized_string.contains("\r\n")
-        || normalized_string.contains('\r')
-        || normalized_string.contains('\n')
-        || normalized_string.contains('\t')
-    {
+    if contains_invalid_characters(normalized_string) {
         return Err(ValidationError::new(
             "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n",
         ));
@@ -100,6 +107,14 @@ pub fn validate_normalized_string(
     Ok(())
 }
 
+/// Checks the bytes of `value` for carriage returns, line feeds and tabs. Being ASCII, they never
+/// occur inside the encoding of another character.
+fn contains_invalid_characters(value: &str) -> bool {
+    value
+        .bytes()
+        .any(|byte| matches!(byte, b'\r' | b'\n' | b'\t'))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -108,27 +123,27 @@ mod test {
     #[test]
     fn it_should_normalize_strings() {
         assert_eq!(
-            NormalizedString("no_whitespace".to_string()),
+            NormalizedString("no_whitespace".into()),
             NormalizedString::new("no_whitespace")
         );
         assert_eq!(
-            NormalizedString("spaces and tabs".to_string()),
+            NormalizedString("spaces and tabs".into()),
             NormalizedString::new("spaces and\ttabs")
         );
         assert_eq!(
-            NormalizedString("carriage returns and linefeeds".to_string()),
+            NormalizedString("carriage returns and linefeeds".into()),
             NormalizedString::new("carriage\r\nreturns\rand\nlinefeeds")
         );
     }
 
     #[test]
     fn it_should_pass_validation() {
-        assert!(validate_normalized_string(&NormalizedString("no_whitespace".to_string())).is_ok());
+        assert!(validate_normalized_string(&NormalizedString("no_whitespace".into())).is_ok());
     }
 
     #[test]
     fn it_should_fail_validation() {
-        let result = validate_normalized_string(&NormalizedString("spaces and\ttabs".to_string()));
+        let result = validate_normalized_string(&NormalizedString("spaces and\ttabs".into()));
 
         assert_eq!(
             result,
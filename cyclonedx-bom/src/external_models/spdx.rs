@@ -16,12 +16,17 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use std::convert::TryFrom;
+use std::{collections::HashMap, convert::TryFrom, sync::RwLock};
 
+use once_cell::sync::Lazy;
 use spdx::{Expression, ParseMode};
 use thiserror::Error;
 
-use crate::{models::bom::BomReference, validation::ValidationError};
+use crate::{
+    external_models::{text, Text},
+    models::bom::BomReference,
+    validation::ValidationError,
+};
 
 /// An identifier for a single, specific license
 ///
@@ -37,7 +42,7 @@ use crate::{models::bom::BomReference, validation::ValidationError};
 /// # Ok::<(), SpdxIdentifierError>(())
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct SpdxIdentifier(pub(crate) String);
+pub struct SpdxIdentifier(pub(crate) Text);
 
 impl SpdxIdentifier {
     /// Attempt to create an `SpdxIdentifier` using a best-effort translation of the license ID
@@ -52,7 +57,7 @@ impl SpdxIdentifier {
     /// ```
     pub fn imprecise(value: String) -> Result<Self, SpdxIdentifierError> {
         match spdx::imprecise_license_id(&value) {
-            Some(matched_license) => Ok(Self(matched_license.0.name.into())),
+            Some(matched_license) => Ok(Self(text(matched_license.0.name.to_string()))),
             None => Err(SpdxIdentifierError::InvalidImpreciseSpdxIdentifier(
                 format!("Not a valid identifier: {value}"),
             )),
@@ -65,7 +70,7 @@ impl TryFrom<String> for SpdxIdentifier {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         match spdx::license_id(&value) {
-            Some(_) => Ok(Self(value)),
+            Some(_) => Ok(Self(text(value))),
             None => Err(SpdxIdentifierError::InvalidSpdxIdentifier(format!(
                 "Not a valid identifier: {}",
                 value
@@ -80,14 +85,35 @@ impl std::fmt::Display for SpdxIdentifier {
     }
 }
 
+/// Picks one of the non-deprecated SPDX license identifiers.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_license_id(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<&'static str> {
+    use spdx::identifiers::{IS_DEPRECATED, LICENSES};
+
+    let ids: Vec<_> = LICENSES
+        .iter()
+        .filter(|(_, _, flags)| flags & IS_DEPRECATED == 0)
+        .map(|(id, _, _)| *id)
+        .collect();
+    u.choose(&ids).copied()
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SpdxIdentifier {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        arbitrary_license_id(u).map(|id| Self(text(id.to_string())))
+    }
+}
+
 pub fn validate_spdx_identifier(identifier: &SpdxIdentifier) -> Result<(), ValidationError> {
-    match SpdxIdentifier::try_from(identifier.0.to_string()) {
-        Err(_error) => Err(ValidationError::new("SPDX identifier is not valid")),
-        _ => Ok(()),
+    match spdx::license_id(&identifier.0) {
+        None => Err(ValidationError::new("SPDX identifier is not valid")),
+        Some(_) => Ok(()),
     }
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SpdxIdentifierError {
     #[error("Invalid SPDX identifier: {}", .0)]
     InvalidSpdxIdentifier(String),
@@ -130,7 +156,8 @@ impl SpdxExpression {
     /// Some Rust repositories have a `license` field of `"MIT/Apache-2.0"`,
     /// which is interpreted as `"MIT OR Apache-2.0"`. In order to allow
     /// interoperability, `parse_lax` converts expression with the first form
-    /// into the second.
+    /// into the second. Other legacy forms accepted by the lax parser, such as lowercase
+    /// operators or imprecise license names, are rewritten to their canonical SPDX form.
     /// ```
     /// use cyclonedx_bom::prelude::*;
     /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
@@ -141,25 +168,51 @@ impl SpdxExpression {
     /// ```
     pub fn parse_lax(value: String) -> Result<Self, SpdxExpressionError> {
         match Expression::parse_mode(&value, ParseMode::LAX) {
-            Ok(_) => Self::new(&value).convert_lax(),
+            Ok(expression) => Self::try_from(canonical_expression(&expression))
+                .map_err(|e| SpdxExpressionError::InvalidLaxSpdxExpression(format!("{}", e))),
             Err(e) => Err(SpdxExpressionError::InvalidLaxSpdxExpression(format!(
                 "{}",
                 e.reason
             ))),
         }
     }
+}
 
-    fn convert_lax(self) -> Result<Self, SpdxExpressionError> {
-        let converted = self.expression.replace('/', " OR ");
-
-        match Self::try_from(converted) {
-            Ok(converted) => Ok(converted),
-            Err(e) => Err(SpdxExpressionError::InvalidLaxSpdxExpression(format!(
-                "{}",
-                e
-            ))),
+/// Writes a parsed expression with canonical license identifiers and operators, adding
+/// parentheses only where an `OR` is an operand of an `AND`.
+fn canonical_expression(expression: &Expression) -> String {
+    use spdx::expression::{ExprNode, Operator};
+
+    // The nodes are in postfix order, each entry holds a subexpression and its operator
+    let mut stack: Vec<(String, Option<Operator>)> = Vec::new();
+    for node in expression.iter() {
+        match node {
+            ExprNode::Req(req) => stack.push((req.req.to_string(), None)),
+            ExprNode::Op(op) => {
+                let (Some(rhs), Some(lhs)) = (stack.pop(), stack.pop()) else {
+                    break;
+                };
+                let operand = |(text, inner): (String, Option<Operator>)| {
+                    if *op == Operator::And && inner == Some(Operator::Or) {
+                        format!("({text})")
+                    } else {
+                        text
+                    }
+                };
+                let keyword = match op {
+                    Operator::And => "AND",
+                    Operator::Or => "OR",
+                };
+                stack.push((
+                    format!("{} {keyword} {}", operand(lhs), operand(rhs)),
+                    Some(*op),
+                ));
+            }
         }
     }
+    stack
+        .pop()
+        .map_or_else(|| expression.to_string(), |(text, _)| text)
 }
 
 impl TryFrom<String> for SpdxExpression {
@@ -185,14 +238,60 @@ impl std::fmt::Display for SpdxExpression {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SpdxExpression {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut expression = arbitrary_license_id(u)?.to_string();
+        for _ in 0..u.int_in_range(0..=2)? {
+            let operator = u.choose(&["AND", "OR"])?;
+            expression = format!("{expression} {operator} {}", arbitrary_license_id(u)?);
+        }
+        Ok(Self {
+            bom_ref: u.arbitrary()?,
+            expression,
+        })
+    }
+}
+
 pub fn validate_spdx_expression(expression: &SpdxExpression) -> Result<(), ValidationError> {
-    if Expression::parse(&expression.expression).is_err() {
+    if !is_valid_expression(&expression.expression) {
         return Err(ValidationError::new("SPDX expression is not valid"));
     }
     Ok(())
 }
 
+/// The number of expressions whose validity is remembered before the cache starts over.
+const EXPRESSION_CACHE_CAPACITY: usize = 1024;
+
+/// Whether the SPDX expressions validated so far are valid, shared by all validations.
+///
+/// Parsing the expressions dominates the validation of licenses, while most BOMs only use a
+/// handful of distinct expressions across all of their components.
+static EXPRESSION_CACHE: Lazy<RwLock<HashMap<String, bool>>> = Lazy::new(Default::default);
+
+fn is_valid_expression(expression: &str) -> bool {
+    let cached = EXPRESSION_CACHE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(expression)
+        .copied();
+    if let Some(valid) = cached {
+        return valid;
+    }
+
+    let valid = Expression::parse(expression).is_ok();
+    let mut cache = EXPRESSION_CACHE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if cache.len() >= EXPRESSION_CACHE_CAPACITY {
+        cache.clear();
+    }
+    cache.insert(expression.to_string(), valid);
+    valid
+}
+
 #[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SpdxExpressionError {
     #[error("Invalid SPDX expression: {}", .0)]
     InvalidSpdxExpression(String),
@@ -211,7 +310,7 @@ mod test {
         let actual =
             SpdxIdentifier::try_from("MIT".to_string()).expect("Failed to parse as an identifier");
 
-        assert_eq!(actual, SpdxIdentifier("MIT".to_string()));
+        assert_eq!(actual, SpdxIdentifier("MIT".into()));
     }
 
     #[test]
@@ -232,7 +331,7 @@ mod test {
         let actual =
             SpdxIdentifier::imprecise("mit".to_string()).expect("Failed to parse as an identifier");
 
-        assert_eq!(actual, SpdxIdentifier("MIT".to_string()));
+        assert_eq!(actual, SpdxIdentifier("MIT".into()));
     }
 
     #[test]
@@ -250,7 +349,7 @@ mod test {
 
     #[test]
     fn valid_spdx_identifiers_should_pass_validation() {
-        let validation_result = validate_spdx_identifier(&SpdxIdentifier("MIT".to_string()));
+        let validation_result = validate_spdx_identifier(&SpdxIdentifier("MIT".into()));
 
         assert!(validation_result.is_ok());
     }
@@ -258,7 +357,7 @@ mod test {
     #[test]
     fn invalid_spdx_identifiers_should_fail_validation() {
         let validation_result =
-            validate_spdx_identifier(&SpdxIdentifier("MIT OR Apache-2.0".to_string()));
+            validate_spdx_identifier(&SpdxIdentifier("MIT OR Apache-2.0".into()));
 
         assert_eq!(
             validation_result,
@@ -280,6 +379,36 @@ mod test {
         assert_eq!(actual, SpdxExpression::new("MIT OR Apache-2.0"));
     }
 
+    #[test]
+    fn it_should_canonicalize_legacy_license_strings() {
+        for (legacy, canonical) in [
+            ("MIT / Apache-2.0", "MIT OR Apache-2.0"),
+            ("MIT or Apache-2.0", "MIT OR Apache-2.0"),
+            ("Apache 2.0", "Apache-2.0"),
+            (
+                "MIT/Apache-2.0 AND BSD-3-Clause",
+                "MIT OR Apache-2.0 AND BSD-3-Clause",
+            ),
+            (
+                "(MIT OR Apache-2.0) AND Unicode-DFS-2016",
+                "(MIT OR Apache-2.0) AND Unicode-DFS-2016",
+            ),
+            (
+                "MIT AND BSD-2-Clause OR Zlib",
+                "MIT AND BSD-2-Clause OR Zlib",
+            ),
+            (
+                "Apache-2.0 WITH LLVM-exception",
+                "Apache-2.0 WITH LLVM-exception",
+            ),
+            ("GPL-2.0+", "GPL-2.0-or-later"),
+        ] {
+            let actual = SpdxExpression::parse_lax(legacy.to_string())
+                .unwrap_or_else(|e| panic!("Failed to parse {legacy}: {e}"));
+            assert_eq!(actual, SpdxExpression::new(canonical), "{legacy}");
+        }
+    }
+
     #[test]
     fn it_should_fail_to_convert_an_invalid_spdx_expression() {
         let actual = SpdxExpression::try_from("not a real license".to_string())
@@ -307,4 +436,18 @@ mod test {
             Err("SPDX expression is not valid".into()),
         );
     }
+
+    #[test]
+    fn repeated_spdx_expressions_should_be_validated_from_the_cache() {
+        let expression = SpdxExpression::new("GPL-2.0-only WITH Classpath-exception-2.0");
+        let invalid = SpdxExpression::new("GPL-2.0-only WITH");
+
+        for _ in 0..2 {
+            assert!(validate_spdx_expression(&expression).is_ok());
+            assert!(validate_spdx_expression(&invalid).is_err());
+        }
+        let cache = EXPRESSION_CACHE.read().unwrap();
+        assert_eq!(cache.get(&expression.expression), Some(&true));
+        assert_eq!(cache.get(&invalid.expression), Some(&false));
+    }
 }
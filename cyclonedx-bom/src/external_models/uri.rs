@@ -45,6 +45,17 @@ impl Purl {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Purl {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let package_type = u.choose(&["cargo", "generic", "maven", "npm", "pypi"])?;
+        let name = super::arbitrary_token(u)?;
+        let version: (u8, u8, u8) = u.arbitrary()?;
+        let version = format!("{}.{}.{}", version.0, version.1, version.2);
+        Self::new(package_type, &name, &version).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 impl std::fmt::Display for Purl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.0)
@@ -65,7 +76,7 @@ impl AsRef<str> for Purl {
 }
 
 pub fn validate_uri(uri: &Uri) -> Result<(), ValidationError> {
-    if Url::parse(uri.0.as_str()).is_err() {
+    if !is_valid_uri(&uri.0) {
         return Err(ValidationError::new("Uri does not conform to RFC 3986"));
     }
     Ok(())
@@ -84,15 +95,25 @@ impl Uri {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Uri {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let host = super::arbitrary_token(u)?;
+        let path = super::arbitrary_token(u)?;
+        Ok(Self(format!("https://{host}.example.com/{path}")))
+    }
+}
+
 impl TryFrom<String> for Uri {
     type Error = UriError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match Url::parse(value.as_str()) {
-            Ok(_) => Ok(Uri(value)),
-            Err(_) => Err(UriError::InvalidUri(
+        if is_valid_uri(&value) {
+            Ok(Uri(value))
+        } else {
+            Err(UriError::InvalidUri(
                 "Uri does not conform to RFC 3986".to_string(),
-            )),
+            ))
         }
     }
 }
@@ -109,7 +130,57 @@ impl std::fmt::Display for Uri {
     }
 }
 
+fn is_valid_uri(value: &str) -> bool {
+    is_plain_http_url(value.as_bytes()) || Url::parse(value).is_ok()
+}
+
+/// Checks the bytes of the most common kind of URI, an `http` or `https` URL with a host name and
+/// without user info or port, without parsing it. Returns `false` for all other URIs, which need
+/// to be parsed to tell whether they are valid.
+fn is_plain_http_url(value: &[u8]) -> bool {
+    let Some(rest) = value
+        .strip_prefix(b"https://")
+        .or_else(|| value.strip_prefix(b"http://"))
+    else {
+        return false;
+    };
+    let host_length = rest
+        .iter()
+        .position(|byte| matches!(byte, b'/' | b'?' | b'#'))
+        .unwrap_or(rest.len());
+    let (host, rest) = rest.split_at(host_length);
+    if host.is_empty()
+        || !host
+            .iter()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.'))
+    {
+        return false;
+    }
+
+    // The path, query and fragment share the same characters, apart from the `#` which starts
+    // the fragment
+    let mut in_fragment = false;
+    let mut bytes = rest.iter();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'#' if !in_fragment => in_fragment = true,
+            b'%' => {
+                let is_hex = |byte: Option<&u8>| byte.is_some_and(u8::is_ascii_hexdigit);
+                if !(is_hex(bytes.next()) && is_hex(bytes.next())) {
+                    return false;
+                }
+            }
+            byte if byte.is_ascii_alphanumeric() => {}
+            b'-' | b'.' | b'_' | b'~' | b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+'
+            | b',' | b';' | b'=' | b':' | b'@' | b'/' | b'?' => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
 #[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum UriError {
     #[error("Invalid URI: {}", .0)]
     InvalidUri(String),
@@ -158,4 +229,36 @@ mod test {
             Err("Uri does not conform to RFC 3986".into()),
         );
     }
+
+    #[test]
+    fn plain_http_urls_should_agree_with_the_parser() {
+        let urls = [
+            "https://example.com",
+            "http://example.com/",
+            "https://github.com/CycloneDX/cyclonedx-rust-cargo.git",
+            "https://example.com/path?query=1&other=a%20b#fragment?/:@",
+            "https://example.com/a#b#c",
+            "https://example.com/%2",
+            "https://example.com/%zz",
+            "https://example.com/with space",
+            "https://example.com/ünicode",
+            "https://example.com/[brackets]",
+            "https://example.com/\\backslash",
+            "https://user@example.com/",
+            "https://example.com:8080/",
+            "https:///path",
+            "https://ex_ample.com/",
+            "ftp://example.com/",
+        ];
+        for url in urls {
+            if super::is_plain_http_url(url.as_bytes()) {
+                assert!(fluent_uri::Uri::parse(url).is_ok(), "{url}");
+            }
+            assert_eq!(
+                super::is_valid_uri(url),
+                fluent_uri::Uri::parse(url).is_ok(),
+                "{url}"
+            );
+        }
+    }
 }
@@ -0,0 +1,388 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! A BOM that reuses the JSON of its unchanged components when it is written again.
+//!
+//! Writing a large BOM after changing a few of its components, e.g. in a service that keeps a
+//! BOM up to date, serializes all of its components again. [`IncrementalBom`] keeps the
+//! serialized JSON of each top-level component and service and only serializes those that were
+//! added or modified through it since the last write.
+
+use std::io::Write;
+
+use crate::{
+    errors::JsonWriteError,
+    models::{
+        bom::{Bom, SpecVersion},
+        component::{Component, Components},
+        service::{Service, Services},
+    },
+    specs::direct,
+};
+
+/// A [`Bom`] that caches the JSON of its top-level components and services.
+///
+/// Components and services changed through [`component_mut`](Self::component_mut),
+/// [`service_mut`](Self::service_mut) and the other methods are serialized again on the next
+/// write, the cached JSON of all others is reused. The remaining fields, e.g. the metadata and
+/// dependencies, are serialized on every write. Changing the BOM through
+/// [`bom_mut`](Self::bom_mut) or writing another spec version discards the cache.
+///
+/// ```
+/// use cyclonedx_bom::incremental::IncrementalBom;
+/// use cyclonedx_bom::models::component::{Classification, Component};
+/// use cyclonedx_bom::prelude::*;
+///
+/// let bom = Bom::builder()
+///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+///     .build()
+///     .expect("valid BOM");
+/// let mut incremental = IncrementalBom::new(bom);
+///
+/// let mut output = Vec::new();
+/// incremental.output_as_json(&mut output, SpecVersion::V1_5).expect("serializable BOM");
+///
+/// incremental.set_version(2);
+/// incremental.push_component(Component::new(Classification::Library, "regex", "1.9.0", None));
+/// output.clear();
+/// incremental.output_as_json(&mut output, SpecVersion::V1_5).expect("serializable BOM");
+///
+/// let bom = Bom::parse_from_json(&output[..]).expect("valid JSON");
+/// assert_eq!(bom.version, 2);
+/// assert_eq!(bom.components.unwrap().0.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IncrementalBom {
+    bom: Bom,
+    /// The spec version of the cached JSON, `None` if the cache is discarded.
+    version: Option<SpecVersion>,
+    components: Vec<Option<Box<[u8]>>>,
+    services: Vec<Option<Box<[u8]>>>,
+}
+
+impl IncrementalBom {
+    pub fn new(bom: Bom) -> Self {
+        Self {
+            bom,
+            version: None,
+            components: Vec::new(),
+            services: Vec::new(),
+        }
+    }
+
+    /// The wrapped BOM.
+    pub fn bom(&self) -> &Bom {
+        &self.bom
+    }
+
+    /// Returns the BOM, dropping the cached JSON.
+    pub fn into_inner(self) -> Bom {
+        self.bom
+    }
+
+    /// Returns the BOM for arbitrary changes, which discards the cached JSON.
+    pub fn bom_mut(&mut self) -> &mut Bom {
+        self.version = None;
+        &mut self.bom
+    }
+
+    /// Sets the `version` of the BOM.
+    pub fn set_version(&mut self, version: u32) {
+        self.bom.version = version;
+    }
+
+    /// Returns the top-level component at `index` for modification.
+    pub fn component_mut(&mut self, index: usize) -> Option<&mut Component> {
+        let component = self.bom.components.as_mut()?.0.get_mut(index)?;
+        if let Some(cached) = self.components.get_mut(index) {
+            *cached = None;
+        }
+        Some(component)
+    }
+
+    /// Adds a top-level component.
+    pub fn push_component(&mut self, component: Component) {
+        let components = self
+            .bom
+            .components
+            .get_or_insert_with(|| Components(Vec::new()));
+        if self.components.len() == components.0.len() {
+            self.components.push(None);
+        }
+        components.0.push(component);
+    }
+
+    /// Removes the top-level component at `index`.
+    pub fn remove_component(&mut self, index: usize) -> Option<Component> {
+        let components = self.bom.components.as_mut()?;
+        if index >= components.0.len() {
+            return None;
+        }
+        if index < self.components.len() {
+            self.components.remove(index);
+        }
+        Some(components.0.remove(index))
+    }
+
+    /// Returns the top-level service at `index` for modification.
+    pub fn service_mut(&mut self, index: usize) -> Option<&mut Service> {
+        let service = self.bom.services.as_mut()?.0.get_mut(index)?;
+        if let Some(cached) = self.services.get_mut(index) {
+            *cached = None;
+        }
+        Some(service)
+    }
+
+    /// Adds a top-level service.
+    pub fn push_service(&mut self, service: Service) {
+        let services = self
+            .bom
+            .services
+            .get_or_insert_with(|| Services(Vec::new()));
+        if self.services.len() == services.0.len() {
+            self.services.push(None);
+        }
+        services.0.push(service);
+    }
+
+    /// Removes the top-level service at `index`.
+    pub fn remove_service(&mut self, index: usize) -> Option<Service> {
+        let services = self.bom.services.as_mut()?;
+        if index >= services.0.len() {
+            return None;
+        }
+        if index < self.services.len() {
+            self.services.remove(index);
+        }
+        Some(services.0.remove(index))
+    }
+
+    /// Writes the BOM as a JSON document conforming to the given spec version.
+    ///
+    /// Unlike [`Bom::output_as_json`] the document is written without indentation, and its
+    /// `components` and `services` come last.
+    pub fn output_as_json<W: Write>(
+        &mut self,
+        writer: &mut W,
+        version: SpecVersion,
+    ) -> Result<(), JsonWriteError> {
+        if self.version != Some(version) {
+            self.components.clear();
+            self.services.clear();
+            self.version = Some(version);
+        }
+        let components = self.bom.components.as_ref().map_or(&[][..], |c| &c.0[..]);
+        let services = self.bom.services.as_ref().map_or(&[][..], |s| &s.0[..]);
+        self.components.resize(components.len(), None);
+        self.services.resize(services.len(), None);
+
+        cache(
+            components,
+            &mut self.components,
+            version,
+            direct::component_to_writer,
+        )?;
+        cache(
+            services,
+            &mut self.services,
+            version,
+            direct::service_to_writer,
+        )?;
+
+        let mut rest = Vec::new();
+        direct::to_writer(&mut rest, &clone_without_lists(&self.bom), version)?;
+        let rest = rest
+            .strip_suffix(b"}")
+            .expect("the BOM is serialized as an object");
+
+        let write =
+            |writer: &mut W, bytes: &[u8]| writer.write_all(bytes).map_err(serde_json::Error::io);
+        write(writer, rest)?;
+        if self.bom.components.is_some() {
+            write(writer, br#","components":["#)?;
+            write_list(writer, &self.components)?;
+        }
+        if self.bom.services.is_some() {
+            write(writer, br#","services":["#)?;
+            write_list(writer, &self.services)?;
+        }
+        write(writer, b"}")?;
+        Ok(())
+    }
+}
+
+impl From<Bom> for IncrementalBom {
+    fn from(bom: Bom) -> Self {
+        Self::new(bom)
+    }
+}
+
+impl From<IncrementalBom> for Bom {
+    fn from(incremental: IncrementalBom) -> Self {
+        incremental.into_inner()
+    }
+}
+
+/// Clones all fields but the components and services.
+fn clone_without_lists(bom: &Bom) -> Bom {
+    Bom {
+        version: bom.version,
+        serial_number: bom.serial_number.clone(),
+        metadata: bom.metadata.clone(),
+        components: None,
+        services: None,
+        external_references: bom.external_references.clone(),
+        dependencies: bom.dependencies.clone(),
+        compositions: bom.compositions.clone(),
+        properties: bom.properties.clone(),
+        vulnerabilities: bom.vulnerabilities.clone(),
+        signature: bom.signature.clone(),
+        annotations: bom.annotations.clone(),
+        formulation: bom.formulation.clone(),
+    }
+}
+
+/// Serializes the items whose JSON is not cached.
+fn cache<M>(
+    items: &[M],
+    cache: &mut [Option<Box<[u8]>>],
+    version: SpecVersion,
+    serialize: fn(&mut dyn Write, &M, SpecVersion) -> Result<(), JsonWriteError>,
+) -> Result<(), JsonWriteError> {
+    for (item, cached) in items.iter().zip(cache) {
+        if cached.is_none() {
+            let mut json = Vec::new();
+            serialize(&mut json, item, version)?;
+            *cached = Some(json.into());
+        }
+    }
+    Ok(())
+}
+
+/// Writes the cached JSON of a list and closes it.
+fn write_list(
+    writer: &mut dyn Write,
+    items: &[Option<Box<[u8]>>],
+) -> Result<(), serde_json::Error> {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            writer.write_all(b",").map_err(serde_json::Error::io)?;
+        }
+        let item = item
+            .as_deref()
+            .expect("all items are serialized before writing");
+        writer.write_all(item).map_err(serde_json::Error::io)?;
+    }
+    writer.write_all(b"]").map_err(serde_json::Error::io)
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::models::component::Classification;
+
+    fn component(name: &str) -> Component {
+        Component::new(Classification::Library, name, "1.0", Some(name.into()))
+    }
+
+    fn example() -> IncrementalBom {
+        let bom = Bom::builder()
+            .component(component("a"))
+            .component(component("b"))
+            .service(Service::new("service", Some("service".into())))
+            .build()
+            .unwrap();
+        IncrementalBom::new(bom)
+    }
+
+    fn write(incremental: &mut IncrementalBom, version: SpecVersion) -> Value {
+        let mut output = Vec::new();
+        incremental.output_as_json(&mut output, version).unwrap();
+
+        let mut expected = Vec::new();
+        incremental
+            .bom()
+            .clone()
+            .output_as_json(&mut expected, version)
+            .unwrap();
+        let output: Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(output, serde_json::from_slice::<Value>(&expected).unwrap());
+        output
+    }
+
+    #[test]
+    fn it_should_write_the_same_json_as_the_bom() {
+        let mut incremental = example();
+
+        write(&mut incremental, SpecVersion::V1_5);
+        write(&mut incremental, SpecVersion::V1_3);
+        incremental.bom_mut().components = None;
+        write(&mut incremental, SpecVersion::V1_4);
+    }
+
+    #[test]
+    fn it_should_reserialize_changed_items_only() {
+        let mut incremental = example();
+        write(&mut incremental, SpecVersion::V1_5);
+
+        incremental.set_version(2);
+        incremental.component_mut(1).unwrap().description = Some("changed".into());
+        incremental.push_component(component("c"));
+        incremental.service_mut(0).unwrap().description = Some("changed".into());
+        assert_eq!(
+            incremental
+                .components
+                .iter()
+                .map(Option::is_some)
+                .collect::<Vec<_>>(),
+            [true, false, false]
+        );
+        assert!(incremental.services[0].is_none());
+
+        let output = write(&mut incremental, SpecVersion::V1_5);
+        assert_eq!(output["version"], 2);
+        assert_eq!(output["components"][1]["description"], "changed");
+        assert_eq!(output["components"][2]["name"], "c");
+
+        assert_eq!(
+            incremental.remove_component(0).unwrap().name.to_string(),
+            "a"
+        );
+        assert_eq!(
+            incremental.remove_service(0).unwrap().name.to_string(),
+            "service"
+        );
+        assert_eq!(incremental.components.len(), 2);
+        write(&mut incremental, SpecVersion::V1_5);
+    }
+
+    #[test]
+    fn it_should_discard_the_cache_for_another_version() {
+        let mut incremental = example();
+        write(&mut incremental, SpecVersion::V1_5);
+
+        incremental.bom_mut();
+        assert_eq!(incremental.version, None);
+        write(&mut incremental, SpecVersion::V1_5);
+        assert_eq!(incremental.version, Some(SpecVersion::V1_5));
+        write(&mut incremental, SpecVersion::V1_4);
+        assert_eq!(incremental.version, Some(SpecVersion::V1_4));
+    }
+}
@@ -0,0 +1,521 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! A BOM with hash indices over its components and dependencies.
+//!
+//! Looking up a component by `bom-ref` or package URL in a [`Bom`] scans all of its components.
+//! [`IndexedBom`] builds the indices once and keeps them up to date as the BOM is modified
+//! through it, so that repeated lookups take constant time.
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use crate::models::{
+    bom::Bom,
+    component::{Component, Components},
+    dependency::{Dependencies, Dependency},
+};
+
+/// Where a component tree starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Root {
+    Metadata,
+    Components,
+}
+
+/// The position of a component, the indices lead through the nested `components`.
+///
+/// Paths are ordered like the components appear in the document.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct ComponentPath {
+    root: Root,
+    indices: Vec<usize>,
+}
+
+impl ComponentPath {
+    fn starts_with(&self, prefix: &ComponentPath) -> bool {
+        self.root == prefix.root && self.indices.starts_with(&prefix.indices)
+    }
+}
+
+/// A [`Bom`] with indices of its components by `bom-ref` and package URL and of its
+/// dependencies by `ref`.
+///
+/// Components include the metadata component and nested components. If several components
+/// share a `bom-ref`, lookups return the first one in document order.
+///
+/// ```
+/// use cyclonedx_bom::indexed::IndexedBom;
+/// use cyclonedx_bom::models::component::{Classification, Component};
+/// use cyclonedx_bom::prelude::*;
+///
+/// let bom = Bom::builder()
+///     .component(Component::new(Classification::Library, "serde", "1.0.0", Some("serde".into())))
+///     .build()
+///     .expect("valid BOM");
+/// let mut indexed = IndexedBom::new(bom);
+///
+/// indexed
+///     .component_mut("serde")
+///     .expect("indexed component")
+///     .bom_ref = Some("serde@1.0.0".to_string());
+///
+/// assert!(indexed.component("serde").is_none());
+/// assert_eq!(indexed.component("serde@1.0.0").unwrap().name.to_string(), "serde");
+/// ```
+#[derive(Clone, Debug)]
+pub struct IndexedBom {
+    bom: Bom,
+    by_bom_ref: HashMap<String, Vec<ComponentPath>>,
+    by_purl: HashMap<String, Vec<ComponentPath>>,
+    dependencies: HashMap<String, usize>,
+}
+
+impl IndexedBom {
+    /// Indexes the components and dependencies of `bom`.
+    pub fn new(bom: Bom) -> Self {
+        let mut indexed = Self {
+            bom,
+            by_bom_ref: HashMap::new(),
+            by_purl: HashMap::new(),
+            dependencies: HashMap::new(),
+        };
+        indexed.reindex();
+        indexed
+    }
+
+    /// The indexed BOM.
+    pub fn bom(&self) -> &Bom {
+        &self.bom
+    }
+
+    /// Returns the BOM, dropping the indices.
+    pub fn into_inner(self) -> Bom {
+        self.bom
+    }
+
+    /// Returns the component with the given `bom-ref`.
+    pub fn component(&self, bom_ref: &str) -> Option<&Component> {
+        let path = self.by_bom_ref.get(bom_ref)?.first()?;
+        component(&self.bom, path)
+    }
+
+    /// Returns the components with the given package URL, in document order.
+    pub fn components_by_purl<'a>(
+        &'a self,
+        purl: &str,
+    ) -> impl Iterator<Item = &'a Component> + 'a {
+        self.by_purl
+            .get(purl)
+            .into_iter()
+            .flatten()
+            .filter_map(|path| component(&self.bom, path))
+    }
+
+    /// Returns the component with the given `bom-ref` for modification.
+    ///
+    /// The component and its subcomponents are indexed again when the returned guard is
+    /// dropped, so their `bom-ref`s and package URLs may be changed through it.
+    pub fn component_mut(&mut self, bom_ref: &str) -> Option<ComponentMut<'_>> {
+        let path = self.by_bom_ref.get(bom_ref)?.first()?.clone();
+        Some(ComponentMut {
+            indexed: self,
+            path,
+        })
+    }
+
+    /// Adds a top-level component and indexes it and its subcomponents.
+    pub fn push_component(&mut self, component: Component) {
+        let components = self
+            .bom
+            .components
+            .get_or_insert_with(|| Components(Vec::new()));
+        let path = ComponentPath {
+            root: Root::Components,
+            indices: vec![components.0.len()],
+        };
+        components.0.push(component);
+        self.index_subtree(path);
+    }
+
+    /// Removes the component with the given `bom-ref` together with its subcomponents.
+    ///
+    /// Removing a component moves the components after it, so the BOM is indexed again in
+    /// linear time.
+    pub fn remove_component(&mut self, bom_ref: &str) -> Option<Component> {
+        let path = self.by_bom_ref.get(bom_ref)?.first()?.clone();
+        let removed = match path.indices.split_last() {
+            None => self.bom.metadata.as_mut()?.component.take(),
+            Some((&last, parent)) => {
+                let siblings = match parent.is_empty() && path.root == Root::Components {
+                    true => self.bom.components.as_mut()?,
+                    false => component_mut(
+                        &mut self.bom,
+                        &ComponentPath {
+                            root: path.root,
+                            indices: parent.to_vec(),
+                        },
+                    )?
+                    .components
+                    .as_mut()?,
+                };
+                Some(siblings.0.remove(last))
+            }
+        };
+        self.reindex();
+        removed
+    }
+
+    /// Returns the `bom-ref`s the given `bom-ref` depends on.
+    pub fn dependencies_of(&self, bom_ref: &str) -> Option<&[String]> {
+        let index = *self.dependencies.get(bom_ref)?;
+        Some(&self.bom.dependencies.as_ref()?.0[index].dependencies)
+    }
+
+    /// Records that `from` depends on `to`, returns `false` if the dependency already existed.
+    pub fn add_dependency(&mut self, from: &str, to: &str) -> bool {
+        let dependencies = &mut self
+            .bom
+            .dependencies
+            .get_or_insert_with(|| Dependencies(Vec::new()))
+            .0;
+        match self.dependencies.get(from) {
+            Some(&index) => {
+                let dependency = &mut dependencies[index];
+                if dependency
+                    .dependencies
+                    .iter()
+                    .any(|existing| existing == to)
+                {
+                    return false;
+                }
+                dependency.dependencies.push(to.to_string());
+            }
+            None => {
+                self.dependencies
+                    .insert(from.to_string(), dependencies.len());
+                dependencies.push(Dependency {
+                    dependency_ref: from.to_string(),
+                    dependencies: vec![to.to_string()],
+                });
+            }
+        }
+        true
+    }
+
+    /// Rebuilds all indices.
+    fn reindex(&mut self) {
+        self.by_bom_ref.clear();
+        self.by_purl.clear();
+        self.dependencies.clear();
+
+        if self
+            .bom
+            .metadata
+            .as_ref()
+            .is_some_and(|metadata| metadata.component.is_some())
+        {
+            self.index_subtree(ComponentPath {
+                root: Root::Metadata,
+                indices: Vec::new(),
+            });
+        }
+        let count = self.bom.components.as_ref().map_or(0, |c| c.0.len());
+        for index in 0..count {
+            self.index_subtree(ComponentPath {
+                root: Root::Components,
+                indices: vec![index],
+            });
+        }
+
+        for (index, dependency) in self
+            .bom
+            .dependencies
+            .iter()
+            .flat_map(|d| d.0.iter())
+            .enumerate()
+        {
+            self.dependencies
+                .entry(dependency.dependency_ref.clone())
+                .or_insert(index);
+        }
+    }
+
+    /// Adds the component at `path` and its subcomponents to the indices.
+    fn index_subtree(&mut self, path: ComponentPath) {
+        let mut entries = Vec::new();
+        if let Some(component) = component(&self.bom, &path) {
+            collect_entries(component, path, &mut entries);
+        }
+        for (path, bom_ref, purl) in entries {
+            if let Some(bom_ref) = bom_ref {
+                insert_sorted(self.by_bom_ref.entry(bom_ref).or_default(), path.clone());
+            }
+            if let Some(purl) = purl {
+                insert_sorted(self.by_purl.entry(purl).or_default(), path);
+            }
+        }
+    }
+
+    /// Removes the component at `path` and its subcomponents from the indices.
+    fn unindex_subtree(&mut self, path: &ComponentPath) {
+        for index in [&mut self.by_bom_ref, &mut self.by_purl] {
+            index.retain(|_, paths| {
+                paths.retain(|existing| !existing.starts_with(path));
+                !paths.is_empty()
+            });
+        }
+    }
+}
+
+impl From<Bom> for IndexedBom {
+    fn from(bom: Bom) -> Self {
+        Self::new(bom)
+    }
+}
+
+impl From<IndexedBom> for Bom {
+    fn from(indexed: IndexedBom) -> Self {
+        indexed.into_inner()
+    }
+}
+
+/// A component of an [`IndexedBom`] borrowed for modification, see
+/// [`IndexedBom::component_mut`].
+pub struct ComponentMut<'a> {
+    indexed: &'a mut IndexedBom,
+    path: ComponentPath,
+}
+
+impl Deref for ComponentMut<'_> {
+    type Target = Component;
+
+    fn deref(&self) -> &Component {
+        component(&self.indexed.bom, &self.path).expect("indexed component exists")
+    }
+}
+
+impl DerefMut for ComponentMut<'_> {
+    fn deref_mut(&mut self) -> &mut Component {
+        component_mut(&mut self.indexed.bom, &self.path).expect("indexed component exists")
+    }
+}
+
+impl Drop for ComponentMut<'_> {
+    fn drop(&mut self) {
+        self.indexed.unindex_subtree(&self.path);
+        self.indexed.index_subtree(self.path.clone());
+    }
+}
+
+fn component<'a>(bom: &'a Bom, path: &ComponentPath) -> Option<&'a Component> {
+    let (mut component, rest) = match path.root {
+        Root::Metadata => (
+            bom.metadata.as_ref()?.component.as_ref()?,
+            &path.indices[..],
+        ),
+        Root::Components => {
+            let (first, rest) = path.indices.split_first()?;
+            (bom.components.as_ref()?.0.get(*first)?, rest)
+        }
+    };
+    for &index in rest {
+        component = component.components.as_ref()?.0.get(index)?;
+    }
+    Some(component)
+}
+
+fn component_mut<'a>(bom: &'a mut Bom, path: &ComponentPath) -> Option<&'a mut Component> {
+    let (mut component, rest) = match path.root {
+        Root::Metadata => (
+            bom.metadata.as_mut()?.component.as_mut()?,
+            &path.indices[..],
+        ),
+        Root::Components => {
+            let (first, rest) = path.indices.split_first()?;
+            (bom.components.as_mut()?.0.get_mut(*first)?, rest)
+        }
+    };
+    for &index in rest {
+        component = component.components.as_mut()?.0.get_mut(index)?;
+    }
+    Some(component)
+}
+
+type IndexEntry = (ComponentPath, Option<String>, Option<String>);
+
+fn collect_entries(component: &Component, path: ComponentPath, entries: &mut Vec<IndexEntry>) {
+    for (index, subcomponent) in component
+        .components
+        .iter()
+        .flat_map(|c| c.0.iter())
+        .enumerate()
+    {
+        let mut indices = path.indices.clone();
+        indices.push(index);
+        collect_entries(
+            subcomponent,
+            ComponentPath {
+                root: path.root,
+                indices,
+            },
+            entries,
+        );
+    }
+    entries.push((
+        path,
+        component.bom_ref.clone(),
+        component.purl.as_ref().map(|purl| purl.0.clone()),
+    ));
+}
+
+fn insert_sorted(paths: &mut Vec<ComponentPath>, path: ComponentPath) {
+    let position = paths.partition_point(|existing| *existing < path);
+    paths.insert(position, path);
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::uri::Purl,
+        models::{component::Classification, metadata::Metadata},
+    };
+
+    fn component(name: &str, purl: Option<&str>, children: Vec<Component>) -> Component {
+        let mut component = Component::new(Classification::Library, name, "1.0", Some(name.into()));
+        component.purl = purl.map(|purl| Purl(purl.to_string()));
+        if !children.is_empty() {
+            component.components = Some(Components(children));
+        }
+        component
+    }
+
+    fn example() -> IndexedBom {
+        let mut bom = Bom::builder()
+            .component(component(
+                "a",
+                Some("pkg:cargo/a@1.0"),
+                vec![component("a1", Some("pkg:cargo/shared@1.0"), vec![])],
+            ))
+            .component(component("b", Some("pkg:cargo/shared@1.0"), vec![]))
+            .build()
+            .unwrap();
+        bom.metadata = Some(Metadata {
+            component: Some(component("app", None, vec![])),
+            ..Metadata::default()
+        });
+        IndexedBom::new(bom)
+    }
+
+    fn names<'a>(components: impl Iterator<Item = &'a Component>) -> Vec<String> {
+        components.map(|c| c.name.to_string()).collect()
+    }
+
+    #[test]
+    fn it_should_look_up_components() {
+        let indexed = example();
+
+        assert_eq!(indexed.component("app").unwrap().name.to_string(), "app");
+        assert_eq!(indexed.component("a1").unwrap().name.to_string(), "a1");
+        assert!(indexed.component("missing").is_none());
+        assert_eq!(
+            names(indexed.components_by_purl("pkg:cargo/shared@1.0")),
+            ["a1", "b"]
+        );
+        assert_eq!(
+            names(indexed.components_by_purl("pkg:cargo/missing")),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn it_should_reindex_modified_components() {
+        let mut indexed = example();
+
+        {
+            let mut a = indexed.component_mut("a").unwrap();
+            a.purl = None;
+            a.components.as_mut().unwrap().0[0].bom_ref = Some("renamed".to_string());
+            a.components.as_mut().unwrap().0.push(component(
+                "a2",
+                Some("pkg:cargo/a2@1.0"),
+                vec![],
+            ));
+        }
+
+        assert_eq!(
+            names(indexed.components_by_purl("pkg:cargo/a@1.0")),
+            Vec::<String>::new()
+        );
+        assert!(indexed.component("a1").is_none());
+        assert_eq!(indexed.component("renamed").unwrap().name.to_string(), "a1");
+        assert_eq!(indexed.component("a2").unwrap().name.to_string(), "a2");
+        assert_eq!(
+            names(indexed.components_by_purl("pkg:cargo/shared@1.0")),
+            ["a1", "b"]
+        );
+    }
+
+    #[test]
+    fn it_should_push_and_remove_components() {
+        let mut indexed = example();
+
+        indexed.push_component(component("c", Some("pkg:cargo/shared@1.0"), vec![]));
+        assert_eq!(
+            names(indexed.components_by_purl("pkg:cargo/shared@1.0")),
+            ["a1", "b", "c"]
+        );
+
+        let removed = indexed.remove_component("a").unwrap();
+        assert_eq!(removed.name.to_string(), "a");
+        assert!(indexed.component("a1").is_none());
+        assert_eq!(indexed.component("b").unwrap().name.to_string(), "b");
+        assert_eq!(
+            names(indexed.components_by_purl("pkg:cargo/shared@1.0")),
+            ["b", "c"]
+        );
+
+        assert_eq!(
+            indexed.remove_component("app").unwrap().name.to_string(),
+            "app"
+        );
+        assert_eq!(indexed.bom().metadata.as_ref().unwrap().component, None);
+        assert_eq!(indexed.bom().components.as_ref().unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn it_should_index_dependencies() {
+        let mut indexed = example();
+
+        assert_eq!(indexed.dependencies_of("a"), None);
+        assert!(indexed.add_dependency("a", "b"));
+        assert!(indexed.add_dependency("a", "a1"));
+        assert!(!indexed.add_dependency("a", "b"));
+        assert_eq!(
+            indexed.dependencies_of("a").unwrap(),
+            ["b".to_string(), "a1".to_string()]
+        );
+
+        let bom = indexed.into_inner();
+        assert_eq!(bom.dependencies.unwrap().0.len(), 1);
+    }
+}
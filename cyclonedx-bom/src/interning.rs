@@ -0,0 +1,189 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Sharing the storage of repeated strings, enabled by the `interning` feature.
+//!
+//! Group names, supplier names and license identifiers repeat thousands of times in large BOMs.
+//! With this feature [`NormalizedString`](crate::external_models::normalized_string::NormalizedString)
+//! and [`SpdxIdentifier`](crate::external_models::spdx::SpdxIdentifier) store their value as an
+//! [`Interned`] string, so equal values point to the same allocation.
+//!
+//! The strings are kept in a process-wide pool. Strings that are no longer referenced by any
+//! model stay in the pool until [`purge`] is called.
+//!
+//! ```
+//! use cyclonedx_bom::prelude::*;
+//!
+//! let a = NormalizedString::new("Acme Inc.");
+//! let b = NormalizedString::new("Acme Inc.");
+//! assert_eq!(a.as_ptr(), b.as_ptr());
+//! ```
+
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    fmt,
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
+
+use once_cell::sync::Lazy;
+
+static POOL: Lazy<Mutex<HashSet<Arc<str>>>> = Lazy::new(Default::default);
+
+fn pool() -> std::sync::MutexGuard<'static, HashSet<Arc<str>>> {
+    POOL.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Removes the strings from the pool which are not used anymore and returns how many were removed.
+pub fn purge() -> usize {
+    let mut pool = pool();
+    let before = pool.len();
+    pool.retain(|string| Arc::strong_count(string) > 1);
+    before - pool.len()
+}
+
+/// Returns the number of distinct strings in the pool.
+pub fn pool_size() -> usize {
+    pool().len()
+}
+
+/// An immutable string whose storage is shared with all equal [`Interned`] strings.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interned(Arc<str>);
+
+impl Interned {
+    pub fn new(value: &str) -> Self {
+        let mut pool = pool();
+        match pool.get(value) {
+            Some(string) => Self(string.clone()),
+            None => {
+                let string: Arc<str> = Arc::from(value);
+                pool.insert(string.clone());
+                Self(string)
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for Interned {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Deref for Interned {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Interned {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Interned {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Interned {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for Interned {
+    fn from(value: String) -> Self {
+        Self::new(&value)
+    }
+}
+
+impl From<Interned> for String {
+    fn from(value: Interned) -> Self {
+        value.0.to_string()
+    }
+}
+
+impl PartialEq<str> for Interned {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Interned {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Interned {
+    fn eq(&self, other: &String) -> bool {
+        &*self.0 == other.as_str()
+    }
+}
+
+impl fmt::Debug for Interned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for Interned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_should_share_equal_strings() {
+        let a = Interned::new("it_should_share_equal_strings");
+        let b = Interned::from("it_should_share_equal_strings".to_string());
+
+        assert!(Arc::ptr_eq(&a.0, &b.0));
+        assert_eq!(a, "it_should_share_equal_strings");
+    }
+
+    #[test]
+    fn it_should_purge_unused_strings() {
+        let used = Interned::new("it_should_purge_unused_strings: used");
+        drop(Interned::new("it_should_purge_unused_strings: unused"));
+
+        purge();
+
+        let pool = pool();
+        assert!(pool.contains("it_should_purge_unused_strings: used"));
+        assert!(!pool.contains("it_should_purge_unused_strings: unused"));
+        drop(used);
+    }
+}
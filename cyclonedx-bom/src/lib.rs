@@ -114,6 +114,19 @@
 //! [`Validate`](crate::validation::Validate) trait to enable you to find invalid data in a parsed
 //! SBOM. An example of this can be seen in the "Read and validate an SBOM" code snippet.
 //!
+//! ### Memory use
+//!
+//! The models own their data, so a parsed [`Bom`](crate::models::bom::Bom) does not borrow from
+//! its input and can be kept after the input is gone. Every string of a document is a separate
+//! allocation, freed when the `Bom` is dropped.
+//!
+//! There is no mode that allocates the strings of a document in one arena instead. The strings
+//! would have to borrow from the arena, which means a lifetime on every model type, and `String`
+//! cannot be given an allocator on stable Rust. For short-lived workloads over large documents,
+//! the `interning` feature stores repeated strings once, and
+//! [`Bom::parse_metadata_only`](crate::models::bom::Bom::parse_metadata_only) reads the header of
+//! a document without its components.
+//!
 //! ### Prelude
 //!
 //! The library provides a prelude (similar to the [Rust Standard Library's prelude](https://doc.rust-lang.org/std/prelude/index.html)) to make it easier to use the code. The prelude contains commonly used types and traits. To use this in your library, include the following code snippet:
@@ -122,12 +135,24 @@
 //! use cyclonedx_bom::prelude::*;
 //! ```
 
+pub mod bundle;
 pub mod errors;
 pub mod external_models;
+#[cfg(feature = "json")]
+pub mod incremental;
+pub mod indexed;
+#[cfg(feature = "interning")]
+pub mod interning;
 pub mod models;
+pub mod operations;
+#[cfg(all(feature = "parallel", any(feature = "json", feature = "xml")))]
+mod parallel;
 pub mod prelude;
-pub mod validation;
-
+pub mod repository;
+#[cfg(any(feature = "json", feature = "xml"))]
 mod specs;
+#[cfg(any(feature = "json", feature = "xml"))]
 mod utilities;
+pub mod validation;
+#[cfg(feature = "xml")]
 mod xml;
@@ -26,7 +26,8 @@ use super::bom::SpecVersion;
 /// Represents an advisory, a notification of a threat to a component, service, or system.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_advisoryType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Advisory {
     pub title: Option<NormalizedString>,
     pub url: Uri,
@@ -57,9 +58,12 @@ impl Validate for Advisory {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Advisories(pub Vec<Advisory>);
 
+impl_collection!(Advisories, Advisory);
+
 impl Validate for Advisories {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -94,7 +98,7 @@ mod test {
     #[test]
     fn it_should_fail_validation() {
         let validation_result = Advisories(vec![Advisory {
-            title: Some(NormalizedString("invalid\ttitle".to_string())),
+            title: Some(NormalizedString("invalid\ttitle".into())),
             url: Uri("invalid url".to_string()),
         }])
         .validate();
@@ -30,9 +30,12 @@ use crate::{
 
 use super::bom::SpecVersion;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Annotations(pub Vec<Annotation>);
 
+impl_collection!(Annotations, Annotation);
+
 impl Validate for Annotations {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -43,7 +46,8 @@ impl Validate for Annotations {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Annotation {
     pub bom_ref: Option<String>,
     pub subjects: Vec<String>,
@@ -63,7 +67,8 @@ impl Validate for Annotation {
 }
 
 /// Represents an Annotator: organization, individual, component or service.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Annotator {
     Organization(OrganizationalEntity),
     Individual(OrganizationalContact),
@@ -26,6 +26,7 @@ use crate::{
 use super::bom::SpecVersion;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct AttachedText {
     pub content_type: Option<NormalizedString>,
     pub encoding: Option<Encoding>,
@@ -82,6 +83,7 @@ pub fn validate_encoding(encoding: &Encoding) -> Result<(), ValidationError> {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Encoding {
     Base64,
@@ -99,6 +101,8 @@ impl Encoding {
     }
 }
 
+impl_from_str!(Encoding, "encoding", [Encoding::Base64,]);
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -128,7 +132,7 @@ mod test {
     #[test]
     fn valid_attached_text_should_pass_validation() {
         let validation_result = AttachedText {
-            content_type: Some(NormalizedString("text/plain".to_string())),
+            content_type: Some(NormalizedString("text/plain".into())),
             encoding: Some(Encoding::Base64),
             content: "dGhpcyB0ZXh0IGlzIHBsYWlu".to_string(),
         }
@@ -140,7 +144,7 @@ mod test {
     #[test]
     fn invalid_attached_text_should_fail_validation() {
         let validation_result = AttachedText {
-            content_type: Some(NormalizedString("spaces and \ttabs".to_string())),
+            content_type: Some(NormalizedString("spaces and \ttabs".into())),
             encoding: Some(Encoding::Base64),
             content: "not base64 encoded".to_string(),
         }
@@ -162,7 +166,7 @@ mod test {
     #[test]
     fn an_unknown_encoding_should_fail_validation() {
         let validation_result = AttachedText {
-            content_type: Some(NormalizedString("text/plain".to_string())),
+            content_type: Some(NormalizedString("text/plain".into())),
             encoding: Some(Encoding::UnknownEncoding("unknown".to_string())),
             content: "not base64 encoded".to_string(),
         }
@@ -177,7 +181,7 @@ mod test {
     #[test]
     fn no_supplied_encoding_should_pass_validation() {
         let validation_result = AttachedText {
-            content_type: Some(NormalizedString("text/plain".to_string())),
+            content_type: Some(NormalizedString("text/plain".into())),
             encoding: None,
             content: "not base64 encoded".to_string(),
         }
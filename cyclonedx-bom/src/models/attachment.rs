@@ -24,6 +24,7 @@ use crate::{
 use super::bom::SpecVersion;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Attachment {
     pub content: String,
     pub content_type: Option<String>,
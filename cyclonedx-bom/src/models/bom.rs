@@ -17,35 +17,42 @@
  */
 
 use std::collections::HashSet;
+#[cfg(feature = "xml")]
 use std::convert::TryInto;
 use std::fmt;
 use std::str::FromStr;
 
-use once_cell::sync::Lazy;
-use regex::Regex;
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "json")]
 use serde_json::Value;
-use xml::{EmitterConfig, EventReader, EventWriter, ParserConfig};
+#[cfg(feature = "xml")]
+use xml::EventReader;
 
-use crate::errors::BomError;
-use crate::models::annotation::Annotations;
+use crate::errors::{BomError, BuildError};
+use crate::models::annotation::{Annotation, Annotations};
 use crate::models::component::{Component, Components};
-use crate::models::composition::Compositions;
-use crate::models::dependency::Dependencies;
-use crate::models::external_reference::ExternalReferences;
+use crate::models::composition::{Composition, Compositions};
+use crate::models::dependency::{Dependencies, Dependency};
+use crate::models::external_reference::{ExternalReference, ExternalReferences};
 use crate::models::formulation::Formula;
 use crate::models::metadata::Metadata;
-use crate::models::property::Properties;
+use crate::models::property::{Properties, Property};
 use crate::models::service::{Service, Services};
 use crate::models::signature::Signature;
 use crate::models::vulnerability::Vulnerabilities;
-use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
+use crate::validation::{
+    validate_built, Validate, ValidationContext, ValidationError, ValidationResult,
+};
+#[cfg(feature = "xml")]
 use crate::xml::{FromXmlDocument, ToXml};
 
 use super::vulnerability::Vulnerability;
 
 /// Represents the spec version of a BOM.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, PartialOrd, strum::Display)]
+#[derive(
+    Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, strum::Display,
+)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum SpecVersion {
     #[strum(to_string = "1.3")]
     #[serde(rename = "1.3")]
@@ -89,6 +96,7 @@ pub fn validate_bom_ref(
 
 /// A reference to a Bom element
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BomReference(pub String);
 
 impl BomReference {
@@ -100,7 +108,14 @@ impl BomReference {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+impl fmt::Display for BomReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Bom {
     pub version: u32,
     pub serial_number: Option<UrnUuid>,
@@ -122,15 +137,154 @@ pub struct Bom {
 }
 
 impl Bom {
+    /// Returns a [`BomBuilder`] to construct a `Bom` step by step.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::builder()
+    ///     .serial_number_random()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// assert_eq!(bom.version, 1);
+    /// assert!(bom.serial_number.is_some());
+    /// ```
+    pub fn builder() -> BomBuilder {
+        BomBuilder::new()
+    }
+
     /// General function to parse a JSON file, fetches the `specVersion` field first then applies the right conversion.
+    ///
+    /// The document is streamed from the reader in a single pass, see
+    /// [`parse_from_json_slice`](Self::parse_from_json_slice). With the `simd-json` feature the
+    /// input is read into memory first and parsed with `simd-json`.
+    #[cfg(feature = "json")]
     pub fn parse_from_json<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        #[cfg(feature = "simd-json")]
+        {
+            let mut reader = reader;
+            let mut input = Vec::new();
+            reader
+                .read_to_end(&mut input)
+                .map_err(serde_json::Error::io)?;
+            Self::parse_from_json_buffer(&mut input)
+        }
+
+        #[cfg(not(feature = "simd-json"))]
+        {
+            let mut deserializer = serde_json::Deserializer::from_reader(reader);
+            let bom = serde::Deserializer::deserialize_map(&mut deserializer, JsonBomVisitor)?;
+            deserializer.end()?;
+            Ok(bom?)
+        }
+    }
+
+    /// Parses a JSON document held in memory, fetches the `specVersion` field first then applies
+    /// the right conversion.
+    ///
+    /// The document is read in a single pass. The fields after the `specVersion` are
+    /// deserialized directly into the structs of that version, without an intermediate
+    /// [`Value`]. Only the fields before it, usually the `bomFormat`, are buffered.
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let input = br#"{"bomFormat": "CycloneDX", "specVersion": "1.4", "version": 3}"#;
+    /// let bom = Bom::parse_from_json_slice(input).expect("valid JSON");
+    /// assert_eq!(bom.version, 3);
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn parse_from_json_slice(input: &[u8]) -> Result<Self, crate::errors::JsonReadError> {
+        let mut deserializer = serde_json::Deserializer::from_slice(input);
+        let bom = serde::Deserializer::deserialize_map(&mut deserializer, JsonBomVisitor)?;
+        deserializer.end()?;
+        Ok(bom?)
+    }
+
+    /// Parses a JSON document in a buffer that may be modified, which `simd-json` uses to
+    /// unescape strings in place.
+    #[cfg(feature = "json")]
+    fn parse_from_json_buffer(input: &mut [u8]) -> Result<Self, crate::errors::JsonReadError> {
+        #[cfg(feature = "simd-json")]
+        {
+            let mut deserializer = simd_json::Deserializer::from_slice(input)?;
+            let bom = serde::Deserializer::deserialize_map(&mut deserializer, JsonBomVisitor)?;
+            Ok(bom?)
+        }
+
+        #[cfg(not(feature = "simd-json"))]
+        Self::parse_from_json_slice(input)
+    }
+
+    /// Parses the JSON document in the file at `path`, like [`parse_from_json`](Self::parse_from_json).
+    ///
+    /// The file is read into memory, and parsed with `simd-json` if the `simd-json` feature is
+    /// enabled.
+    #[cfg(feature = "json")]
+    pub fn parse_from_json_file(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let mut input = std::fs::read(path).map_err(serde_json::Error::io)?;
+        Self::parse_from_json_buffer(&mut input)
+    }
+
+    /// Parses the JSON document in the file at `path` through a read-only memory map, with
+    /// [`parse_from_json_slice`](Self::parse_from_json_slice).
+    ///
+    /// The operating system pages the document in as it is parsed, so large documents are not
+    /// copied into memory first. `simd-json` is not used, because it needs a writable input.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other process, until this
+    /// function returns. The mapped memory follows changes to the file, which is undefined
+    /// behaviour while it is borrowed, and reading pages past the end of a truncated file
+    /// raises `SIGBUS` on Unix.
+    #[cfg(feature = "mmap")]
+    pub unsafe fn parse_from_json_file_mapped(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let file = std::fs::File::open(path).map_err(serde_json::Error::io)?;
+        // SAFETY: the caller guarantees that the file is not modified while it is mapped.
+        let input = unsafe { memmap2::Mmap::map(&file) }.map_err(serde_json::Error::io)?;
+        Self::parse_from_json_slice(&input)
+    }
+
+    /// Parses only the header of a JSON document: its `version`, `serialNumber` and `metadata`.
+    ///
+    /// The other fields of the returned BOM are empty. Reading stops at the first field after
+    /// `metadata` that is not part of the header, so the components of large documents are
+    /// neither read nor deserialized. Fields before that, like the `bomFormat`, are skipped.
+    ///
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let input = br#"{
+    ///     "bomFormat": "CycloneDX",
+    ///     "specVersion": "1.5",
+    ///     "version": 2,
+    ///     "metadata": { "timestamp": "2024-01-02T03:04:05Z" },
+    ///     "components": [ this is not even read ]
+    /// }"#;
+    /// let bom = Bom::parse_metadata_only(&input[..]).expect("valid header");
+    /// assert_eq!(bom.version, 2);
+    /// assert!(bom.metadata.is_some());
+    /// assert!(bom.components.is_none());
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn parse_metadata_only<R: std::io::Read>(
         mut reader: R,
     ) -> Result<Self, crate::errors::JsonReadError> {
-        Self::parse_json_value(serde_json::from_reader(&mut reader)?)
+        read_json_metadata_only(&mut reader)
     }
 
     /// General function to parse a pre-parsed JSON file, fetches the `specVersion` field first,
     /// then applies the right conversion.
+    #[cfg(feature = "json")]
     pub fn parse_json_value(json: Value) -> Result<Self, crate::errors::JsonReadError> {
         if let Some(version) = json.get("specVersion") {
             let version = version
@@ -147,8 +301,39 @@ impl Bom {
         }
     }
 
+    /// Converts a JSON [`Value`] into a BOM, the counterpart of [`to_json_value`](Self::to_json_value).
+    ///
+    /// Like [`parse_json_value`](Self::parse_json_value) the conversion is chosen by the
+    /// `specVersion` field, so the value can be modified in between without serializing it.
+    ///
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::builder().build().expect("valid BOM");
+    /// let mut value = bom.to_json_value(SpecVersion::V1_5).expect("serializable BOM");
+    /// value["version"] = 2.into();
+    ///
+    /// let bom = Bom::from_json_value(value).expect("valid JSON");
+    /// assert_eq!(bom.version, 2);
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_json_value(value: Value) -> Result<Self, crate::errors::JsonReadError> {
+        Self::parse_json_value(value)
+    }
+
+    /// Converts the BOM into a JSON [`Value`] conforming to the specification version that you
+    /// provide, e.g. to post-process it before writing it.
+    #[cfg(feature = "json")]
+    pub fn to_json_value(
+        &self,
+        version: SpecVersion,
+    ) -> Result<Value, crate::errors::JsonWriteError> {
+        crate::specs::direct::to_value(self, version)
+    }
+
     /// Parse the input as a JSON document conforming to the version of the specification that you provide.
     /// Use [`parse_from_json`](Self::parse_from_json) if you want to support multiple versions instead.
+    #[cfg(feature = "json")]
     pub fn parse_from_json_with_version<R: std::io::Read>(
         reader: R,
         version: SpecVersion,
@@ -161,6 +346,7 @@ impl Bom {
     }
 
     /// Output as a JSON document conforming to the specification version that you provide.
+    #[cfg(feature = "json")]
     pub fn output_as_json<W: std::io::Write>(
         self,
         writer: &mut W,
@@ -174,6 +360,7 @@ impl Bom {
     }
 
     /// Parse the input as an XML document conforming to the version of the specification that you provide.
+    #[cfg(feature = "xml")]
     pub fn parse_from_xml_with_version<R: std::io::Read>(
         reader: R,
         version: SpecVersion,
@@ -185,7 +372,27 @@ impl Bom {
         }
     }
 
+    /// Parses only the header of an XML document conforming to the version of the specification
+    /// that you provide: the `version` and `serialNumber` attributes and the `metadata`.
+    ///
+    /// The other fields of the returned BOM are empty. Reading stops at the first element after
+    /// the metadata, which the schema requires to be the first element.
+    #[cfg(feature = "xml")]
+    pub fn parse_metadata_only_from_xml<R: std::io::Read>(
+        mut reader: R,
+        version: SpecVersion,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let reader: &mut dyn std::io::Read = &mut reader;
+        let mut event_reader = EventReader::new_with_config(reader, crate::xml::parser_config());
+        match version {
+            SpecVersion::V1_3 => crate::specs::v1_3::bom::read_xml_metadata_only(&mut event_reader),
+            SpecVersion::V1_4 => crate::specs::v1_4::bom::read_xml_metadata_only(&mut event_reader),
+            SpecVersion::V1_5 => crate::specs::v1_5::bom::read_xml_metadata_only(&mut event_reader),
+        }
+    }
+
     /// Output as an XML document conforming to the specification version that you provide.
+    #[cfg(feature = "xml")]
     pub fn output_as_xml<W: std::io::Write>(
         self,
         writer: &mut W,
@@ -199,137 +406,130 @@ impl Bom {
     }
 
     /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
+    #[cfg(feature = "json")]
     pub fn parse_from_json_v1_3<R: std::io::Read>(
         mut reader: R,
     ) -> Result<Self, crate::errors::JsonReadError> {
-        let bom: crate::specs::v1_3::bom::Bom = serde_json::from_reader(&mut reader)?;
+        let bom: crate::specs::v1_3::bom::Bom = read_json(&mut reader)?;
         Ok(bom.into())
     }
 
     /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
     /// from an existing [`Value`].
+    #[cfg(feature = "json")]
     pub fn parse_from_json_value_v1_3(value: Value) -> Result<Self, crate::errors::JsonReadError> {
         let bom: crate::specs::v1_3::bom::Bom = serde_json::from_value(value)?;
         Ok(bom.into())
     }
 
     /// Parse the input as an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/)
+    #[cfg(feature = "xml")]
     pub fn parse_from_xml_v1_3<R: std::io::Read>(
-        reader: R,
+        mut reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_3::bom::Bom::read_xml_document(&mut event_reader)?;
+        let bom: crate::specs::v1_3::bom::Bom = read_xml(&mut reader)?;
         Ok(bom.into())
     }
 
     /// Output as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
+    #[cfg(feature = "json")]
     pub fn output_as_json_v1_3<W: std::io::Write>(
         self,
         writer: &mut W,
     ) -> Result<(), crate::errors::JsonWriteError> {
-        let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
-        serde_json::to_writer_pretty(writer, &bom)?;
-        Ok(())
+        crate::specs::direct::to_writer_pretty(writer, &self, SpecVersion::V1_3)
     }
 
     /// Output as an XML document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/xml/)
+    #[cfg(feature = "xml")]
     pub fn output_as_xml_v1_3<W: std::io::Write>(
         self,
         writer: &mut W,
     ) -> Result<(), crate::errors::XmlWriteError> {
-        let config = EmitterConfig::default().perform_indent(true);
-        let mut event_writer = EventWriter::new_with_config(writer, config);
-
         let bom: crate::specs::v1_3::bom::Bom = self.try_into()?;
-        bom.write_xml_element(&mut event_writer)
+        write_xml(writer, &bom)
     }
 
     /// Parse the input as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
+    #[cfg(feature = "json")]
     pub fn parse_from_json_v1_4<R: std::io::Read>(
         mut reader: R,
     ) -> Result<Self, crate::errors::JsonReadError> {
-        let bom: crate::specs::v1_4::bom::Bom = serde_json::from_reader(&mut reader)?;
+        let bom: crate::specs::v1_4::bom::Bom = read_json(&mut reader)?;
         Ok(bom.into())
     }
 
     /// Parse the input as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
     /// from an existing [`Value`].
+    #[cfg(feature = "json")]
     pub fn parse_from_json_value_v1_4(value: Value) -> Result<Self, crate::errors::JsonReadError> {
         let bom: crate::specs::v1_4::bom::Bom = serde_json::from_value(value)?;
         Ok(bom.into())
     }
 
     /// Parse the input as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
+    #[cfg(feature = "xml")]
     pub fn parse_from_xml_v1_4<R: std::io::Read>(
-        reader: R,
+        mut reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_4::bom::Bom::read_xml_document(&mut event_reader)?;
+        let bom: crate::specs::v1_4::bom::Bom = read_xml(&mut reader)?;
         Ok(bom.into())
     }
 
     /// Output as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
+    #[cfg(feature = "json")]
     pub fn output_as_json_v1_4<W: std::io::Write>(
         self,
         writer: &mut W,
     ) -> Result<(), crate::errors::JsonWriteError> {
-        let bom: crate::specs::v1_4::bom::Bom = self.try_into()?;
-        serde_json::to_writer_pretty(writer, &bom)?;
-        Ok(())
+        crate::specs::direct::to_writer_pretty(writer, &self, SpecVersion::V1_4)
     }
 
     /// Output as an XML document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/)
+    #[cfg(feature = "xml")]
     pub fn output_as_xml_v1_4<W: std::io::Write>(
         self,
         writer: &mut W,
     ) -> Result<(), crate::errors::XmlWriteError> {
-        let config = EmitterConfig::default().perform_indent(true);
-        let mut event_writer = EventWriter::new_with_config(writer, config);
-
         let bom: crate::specs::v1_4::bom::Bom = self.try_into()?;
-        bom.write_xml_element(&mut event_writer)
+        write_xml(writer, &bom)
     }
 
     /// Parse the input as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    #[cfg(feature = "json")]
     pub fn parse_from_json_v1_5<R: std::io::Read>(
         mut reader: R,
     ) -> Result<Self, crate::errors::JsonReadError> {
-        let bom: crate::specs::v1_5::bom::Bom = serde_json::from_reader(&mut reader)?;
+        let bom: crate::specs::v1_5::bom::Bom = read_json(&mut reader)?;
         Ok(bom.into())
     }
 
     /// Parse the input as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    #[cfg(feature = "xml")]
     pub fn parse_from_xml_v1_5<R: std::io::Read>(
-        reader: R,
+        mut reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_5::bom::Bom::read_xml_document(&mut event_reader)?;
+        let bom: crate::specs::v1_5::bom::Bom = read_xml(&mut reader)?;
         Ok(bom.into())
     }
 
     /// Output as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    #[cfg(feature = "json")]
     pub fn output_as_json_v1_5<W: std::io::Write>(
         self,
         writer: &mut W,
     ) -> Result<(), crate::errors::JsonWriteError> {
-        let bom: crate::specs::v1_5::bom::Bom = self.try_into()?;
-        serde_json::to_writer_pretty(writer, &bom)?;
-        Ok(())
+        crate::specs::direct::to_writer_pretty(writer, &self, SpecVersion::V1_5)
     }
 
     /// Output as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    #[cfg(feature = "xml")]
     pub fn output_as_xml_v1_5<W: std::io::Write>(
         self,
         writer: &mut W,
     ) -> Result<(), crate::errors::XmlWriteError> {
-        let config = EmitterConfig::default().perform_indent(true);
-        let mut event_writer = EventWriter::new_with_config(writer, config);
-
         let bom: crate::specs::v1_5::bom::Bom = self.try_into()?;
-        bom.write_xml_element(&mut event_writer)
+        write_xml(writer, &bom)
     }
 }
 
@@ -354,6 +554,414 @@ impl Default for Bom {
     }
 }
 
+/// Deserializes a JSON document into the structs of its `specVersion` in a single pass.
+///
+/// The fields before the `specVersion` are buffered as [`Value`]s and replayed, followed by the
+/// remaining fields straight from the input. A missing or unsupported version is returned as a
+/// [`BomError`] after skipping the rest of the document.
+#[cfg(feature = "json")]
+struct JsonBomVisitor;
+
+#[cfg(feature = "json")]
+impl<'de> serde::de::Visitor<'de> for JsonBomVisitor {
+    type Value = Result<Bom, BomError>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a CycloneDX BOM")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(
+        self,
+        mut map: A,
+    ) -> Result<Result<Bom, BomError>, A::Error> {
+        use serde::de::{value::MapAccessDeserializer, IgnoredAny};
+
+        let mut buffered = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if key != "specVersion" {
+                buffered.push((key, map.next_value::<Value>()?));
+                continue;
+            }
+
+            let version = map.next_value::<String>()?;
+            let spec_version = match SpecVersion::from_str(&version) {
+                Ok(spec_version) => spec_version,
+                Err(error) => {
+                    while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+                    return Ok(Err(error));
+                }
+            };
+            buffered.push((key, Value::String(version)));
+            let fields = MapAccessDeserializer::new(BufferedMapAccess {
+                buffered: buffered.into_iter(),
+                value: None,
+                rest: map,
+            });
+            let bom = match spec_version {
+                SpecVersion::V1_3 => crate::specs::v1_3::bom::Bom::deserialize(fields)?.into(),
+                SpecVersion::V1_4 => crate::specs::v1_4::bom::Bom::deserialize(fields)?.into(),
+                SpecVersion::V1_5 => crate::specs::v1_5::bom::Bom::deserialize(fields)?.into(),
+            };
+            return Ok(Ok(bom));
+        }
+        Ok(Err(BomError::UnsupportedSpecVersion(
+            "No field 'specVersion' found".to_string(),
+        )))
+    }
+}
+
+/// The fields of a JSON object, with the ones already read from `rest` replayed first.
+#[cfg(feature = "json")]
+struct BufferedMapAccess<A> {
+    buffered: std::vec::IntoIter<(String, Value)>,
+    /// The value of the replayed key that was returned last
+    value: Option<Value>,
+    rest: A,
+}
+
+#[cfg(feature = "json")]
+impl<'de, A: serde::de::MapAccess<'de>> serde::de::MapAccess<'de> for BufferedMapAccess<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: serde::de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        use serde::de::IntoDeserializer;
+
+        match self.buffered.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => self.rest.next_key_seed(seed),
+        }
+    }
+
+    fn next_value_seed<V: serde::de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, A::Error> {
+        use serde::de::Error;
+
+        match self.value.take() {
+            Some(value) => seed.deserialize(value).map_err(A::Error::custom),
+            None => self.rest.next_value_seed(seed),
+        }
+    }
+}
+
+/// The header of a JSON document read by [`Bom::parse_metadata_only`].
+#[cfg(feature = "json")]
+#[derive(Default)]
+struct JsonMetadataOnly {
+    spec_version: Option<SpecVersion>,
+    version: Option<u32>,
+    serial_number: Option<String>,
+    metadata: Option<Metadata>,
+    /// The metadata of a document that has it before its `specVersion`.
+    unversioned_metadata: Option<Value>,
+    /// Set when the visitor stopped before the end of the document.
+    stopped: bool,
+}
+
+#[cfg(feature = "json")]
+impl<'de> serde::de::Visitor<'de> for &mut JsonMetadataOnly {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a CycloneDX BOM")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        use serde::de::Error;
+
+        let mut read_metadata = false;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "specVersion" => {
+                    let version = map.next_value::<String>()?;
+                    self.spec_version =
+                        Some(SpecVersion::from_str(&version).map_err(A::Error::custom)?);
+                }
+                "version" => self.version = Some(map.next_value()?),
+                "serialNumber" => self.serial_number = map.next_value()?,
+                "metadata" => {
+                    read_metadata = true;
+                    self.metadata = match self.spec_version {
+                        Some(SpecVersion::V1_3) => map
+                            .next_value::<Option<crate::specs::v1_3::metadata::Metadata>>()?
+                            .map(Into::into),
+                        Some(SpecVersion::V1_4) => map
+                            .next_value::<Option<crate::specs::v1_4::metadata::Metadata>>()?
+                            .map(Into::into),
+                        Some(SpecVersion::V1_5) => map
+                            .next_value::<Option<crate::specs::v1_5::metadata::Metadata>>()?
+                            .map(Into::into),
+                        None => {
+                            self.unversioned_metadata = map.next_value()?;
+                            None
+                        }
+                    };
+                }
+                _ if read_metadata => {
+                    self.stopped = true;
+                    return Err(A::Error::custom("stopped after the metadata"));
+                }
+                _ => {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "json")]
+fn read_json_metadata_only(
+    reader: &mut dyn std::io::Read,
+) -> Result<Bom, crate::errors::JsonReadError> {
+    let mut header = JsonMetadataOnly::default();
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    match serde::Deserializer::deserialize_map(&mut deserializer, &mut header) {
+        Ok(()) => deserializer.end()?,
+        Err(_) if header.stopped => {}
+        Err(error) => return Err(error.into()),
+    }
+
+    let spec_version = header.spec_version.ok_or_else(|| {
+        BomError::UnsupportedSpecVersion("No field 'specVersion' found".to_string())
+    })?;
+    let metadata = match header.unversioned_metadata {
+        Some(value) => match spec_version {
+            SpecVersion::V1_3 => {
+                serde_json::from_value::<Option<crate::specs::v1_3::metadata::Metadata>>(value)?
+                    .map(Into::into)
+            }
+            SpecVersion::V1_4 => {
+                serde_json::from_value::<Option<crate::specs::v1_4::metadata::Metadata>>(value)?
+                    .map(Into::into)
+            }
+            SpecVersion::V1_5 => {
+                serde_json::from_value::<Option<crate::specs::v1_5::metadata::Metadata>>(value)?
+                    .map(Into::into)
+            }
+        },
+        None => header.metadata,
+    };
+
+    Ok(Bom {
+        version: header
+            .version
+            .ok_or_else(|| <serde_json::Error as serde::de::Error>::missing_field("version"))?,
+        serial_number: header.serial_number.map(UrnUuid),
+        metadata,
+        ..Bom::default()
+    })
+}
+
+// The readers and writers below take trait objects, so that the deserializers and serializers
+// of each specification version are generated once instead of once per type of reader or writer.
+
+#[cfg(feature = "json")]
+fn read_json<T: serde::de::DeserializeOwned>(
+    reader: &mut dyn std::io::Read,
+) -> Result<T, serde_json::Error> {
+    serde_json::from_reader(reader)
+}
+
+#[cfg(feature = "xml")]
+fn read_xml<T: FromXmlDocument>(
+    reader: &mut dyn std::io::Read,
+) -> Result<T, crate::errors::XmlReadError> {
+    let mut event_reader = EventReader::new_with_config(reader, crate::xml::parser_config());
+    T::read_xml_document(&mut event_reader)
+}
+
+#[cfg(feature = "xml")]
+fn write_xml<T: ToXml>(
+    writer: &mut dyn std::io::Write,
+    bom: &T,
+) -> Result<(), crate::errors::XmlWriteError> {
+    crate::xml::write_xml_document(writer, bom, crate::xml::emitter_config())
+}
+
+/// Builder for a [`Bom`], created via [`Bom::builder`].
+///
+/// The builder starts with a `version` of `1` and no serial number. Setters taking a
+/// collection replace any previously set values, while the singular setters (e.g.
+/// [`BomBuilder::component`]) append a single entry.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BomBuilder {
+    bom: Bom,
+}
+
+impl BomBuilder {
+    pub fn new() -> Self {
+        Self {
+            bom: Bom {
+                serial_number: None,
+                ..Bom::default()
+            },
+        }
+    }
+
+    pub fn version(mut self, version: u32) -> Self {
+        self.bom.version = version;
+        self
+    }
+
+    pub fn serial_number(mut self, serial_number: UrnUuid) -> Self {
+        self.bom.serial_number = Some(serial_number);
+        self
+    }
+
+    /// Sets the serial number to a newly generated random UUID
+    pub fn serial_number_random(self) -> Self {
+        self.serial_number(UrnUuid::generate())
+    }
+
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.bom.metadata = Some(metadata);
+        self
+    }
+
+    pub fn components(mut self, components: impl IntoIterator<Item = Component>) -> Self {
+        self.bom.components = Some(Components(components.into_iter().collect()));
+        self
+    }
+
+    pub fn component(mut self, component: Component) -> Self {
+        self.bom
+            .components
+            .get_or_insert_with(|| Components(Vec::new()))
+            .0
+            .push(component);
+        self
+    }
+
+    pub fn services(mut self, services: impl IntoIterator<Item = Service>) -> Self {
+        self.bom.services = Some(Services(services.into_iter().collect()));
+        self
+    }
+
+    pub fn service(mut self, service: Service) -> Self {
+        self.bom
+            .services
+            .get_or_insert_with(|| Services(Vec::new()))
+            .0
+            .push(service);
+        self
+    }
+
+    pub fn external_references(
+        mut self,
+        external_references: impl IntoIterator<Item = ExternalReference>,
+    ) -> Self {
+        self.bom.external_references = Some(ExternalReferences(
+            external_references.into_iter().collect(),
+        ));
+        self
+    }
+
+    pub fn external_reference(mut self, external_reference: ExternalReference) -> Self {
+        self.bom
+            .external_references
+            .get_or_insert_with(|| ExternalReferences(Vec::new()))
+            .0
+            .push(external_reference);
+        self
+    }
+
+    pub fn dependencies(mut self, dependencies: impl IntoIterator<Item = Dependency>) -> Self {
+        self.bom.dependencies = Some(Dependencies(dependencies.into_iter().collect()));
+        self
+    }
+
+    pub fn dependency(mut self, dependency: Dependency) -> Self {
+        self.bom
+            .dependencies
+            .get_or_insert_with(|| Dependencies(Vec::new()))
+            .0
+            .push(dependency);
+        self
+    }
+
+    pub fn compositions(mut self, compositions: impl IntoIterator<Item = Composition>) -> Self {
+        self.bom.compositions = Some(Compositions(compositions.into_iter().collect()));
+        self
+    }
+
+    pub fn properties(mut self, properties: impl IntoIterator<Item = Property>) -> Self {
+        self.bom.properties = Some(Properties(properties.into_iter().collect()));
+        self
+    }
+
+    pub fn property(mut self, property: Property) -> Self {
+        self.bom
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .0
+            .push(property);
+        self
+    }
+
+    /// Added in version 1.4
+    pub fn vulnerabilities(
+        mut self,
+        vulnerabilities: impl IntoIterator<Item = Vulnerability>,
+    ) -> Self {
+        self.bom.vulnerabilities = Some(Vulnerabilities(vulnerabilities.into_iter().collect()));
+        self
+    }
+
+    /// Added in version 1.4
+    pub fn vulnerability(mut self, vulnerability: Vulnerability) -> Self {
+        self.bom
+            .vulnerabilities
+            .get_or_insert_with(|| Vulnerabilities(Vec::new()))
+            .0
+            .push(vulnerability);
+        self
+    }
+
+    /// Added in version 1.4
+    pub fn signature(mut self, signature: Signature) -> Self {
+        self.bom.signature = Some(signature);
+        self
+    }
+
+    /// Added in version 1.5
+    pub fn annotations(mut self, annotations: impl IntoIterator<Item = Annotation>) -> Self {
+        self.bom.annotations = Some(Annotations(annotations.into_iter().collect()));
+        self
+    }
+
+    /// Added in version 1.5
+    pub fn formulation(mut self, formulation: impl IntoIterator<Item = Formula>) -> Self {
+        self.bom.formulation = Some(formulation.into_iter().collect());
+        self
+    }
+
+    /// Builds the [`Bom`], validating it against the latest spec version.
+    ///
+    /// Returns a [`BuildError`] listing all violations if validation fails.
+    pub fn build(self) -> Result<Bom, BuildError> {
+        validate_built("BOM", self.bom)
+    }
+
+    /// Builds the [`Bom`] without validating it, e.g. to construct partial documents.
+    pub fn build_unchecked(self) -> Bom {
+        self.bom
+    }
+}
+
+impl Default for BomBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Validate for Bom {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         let mut context = ValidationContext::new();
@@ -562,7 +1170,7 @@ fn validate_vulnerabilities_bom_refs(
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct UrnUuid(pub String);
 
 impl UrnUuid {
@@ -592,6 +1200,13 @@ impl From<uuid::Uuid> for UrnUuid {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for UrnUuid {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from(uuid::Uuid::from_bytes(u.arbitrary()?)))
+    }
+}
+
 /// Validates a given [`UrnUuid`].
 pub fn validate_urn_uuid(urn_uuid: &UrnUuid) -> Result<(), ValidationError> {
     if !matches_urn_uuid_regex(&urn_uuid.0) {
@@ -600,17 +1215,28 @@ pub fn validate_urn_uuid(urn_uuid: &UrnUuid) -> Result<(), ValidationError> {
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+#[non_exhaustive]
 pub enum UrnUuidError {
+    #[error("Invalid URN UUID: {0}")]
     InvalidUrnUuid(String),
 }
 
+/// Checks that `value` is a UUID in its hyphenated, lowercase form, e.g.
+/// `3e671687-395b-41f5-a30f-a58921a69b79`.
+pub(crate) fn is_lowercase_uuid(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 36
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            8 | 13 | 18 | 23 => *b == b'-',
+            _ => matches!(b, b'0'..=b'9' | b'a'..=b'f'),
+        })
+}
+
 fn matches_urn_uuid_regex(value: &str) -> bool {
-    static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
-        Regex::new(r"^urn:uuid:[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$")
-            .expect("Failed to compile regex.")
-    });
-    UUID_REGEX.is_match(value)
+    value
+        .strip_prefix("urn:uuid:")
+        .is_some_and(is_lowercase_uuid)
 }
 
 #[cfg(test)]
@@ -628,7 +1254,7 @@ mod test {
             service::Service,
             vulnerability::Vulnerability,
         },
-        validation,
+        validation::{self, Violation},
     };
 
     use super::*;
@@ -647,6 +1273,162 @@ mod test {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn it_should_parse_json_with_fields_before_the_spec_version() {
+        let input = r#"{
+            "metadata": { "timestamp": "2024-01-02T03:04:05Z" },
+            "version": 2,
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "components": [{ "type": "library", "name": "serde", "version": "1.0.0" }]
+        }"#;
+
+        let bom = Bom::parse_from_json(input.as_bytes()).unwrap();
+
+        assert_eq!(bom.version, 2);
+        assert!(bom.metadata.unwrap().timestamp.is_some());
+        assert_eq!(bom.components.unwrap().0.len(), 1);
+        assert_eq!(
+            Bom::parse_from_json_slice(input.as_bytes())
+                .unwrap()
+                .version,
+            2
+        );
+    }
+
+    #[test]
+    fn it_should_reject_json_without_a_supported_spec_version() {
+        let missing = r#"{"bomFormat": "CycloneDX", "version": 1}"#;
+        let unsupported = r#"{"bomFormat": "CycloneDX", "specVersion": "1.1", "version": 1}"#;
+
+        for input in [missing, unsupported] {
+            let error = Bom::parse_from_json(input.as_bytes()).unwrap_err();
+            assert!(
+                matches!(error, crate::errors::JsonReadError::BomError { .. }),
+                "{error:?}"
+            );
+        }
+        assert!(Bom::parse_from_json(&br#"{"specVersion": "1.1"} trailing"#[..]).is_err());
+    }
+
+    #[test]
+    fn it_should_parse_json_files() {
+        let dir =
+            std::env::temp_dir().join(format!("cyclonedx-bom-json-file-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bom.cdx.json");
+        std::fs::write(
+            &path,
+            r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 7}"#,
+        )
+        .unwrap();
+
+        let bom = Bom::parse_from_json_file(&path).unwrap();
+        assert_eq!(bom.version, 7);
+        assert!(Bom::parse_from_json_file(dir.join("missing.cdx.json")).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn it_should_parse_json_files_through_a_memory_map() {
+        let dir = std::env::temp_dir().join(format!("cyclonedx-bom-mmap-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let full = dir.join("full.cdx.json");
+        let empty = dir.join("empty.cdx.json");
+        std::fs::write(
+            &full,
+            r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 7}"#,
+        )
+        .unwrap();
+        std::fs::write(&empty, b"").unwrap();
+
+        // SAFETY: the files are not modified while they are parsed.
+        unsafe {
+            assert_eq!(Bom::parse_from_json_file_mapped(&full).unwrap().version, 7);
+            assert!(Bom::parse_from_json_file_mapped(&empty).is_err());
+            assert!(Bom::parse_from_json_file_mapped(dir.join("missing.cdx.json")).is_err());
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn it_should_parse_the_metadata_only() {
+        let input = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "version": 3,
+            "metadata": {
+                "timestamp": "2024-01-02T03:04:05Z",
+                "component": { "type": "application", "name": "app" }
+            },
+            "components": "not read"
+        }"#;
+        let bom = Bom::parse_metadata_only(input.as_bytes()).unwrap();
+
+        assert_eq!(bom.version, 3);
+        assert_eq!(
+            bom.serial_number,
+            Some(UrnUuid(
+                "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()
+            ))
+        );
+        let metadata = bom.metadata.unwrap();
+        assert_eq!(
+            metadata.timestamp,
+            Some(DateTime("2024-01-02T03:04:05Z".to_string()))
+        );
+        assert_eq!(metadata.component.unwrap().name.to_string(), "app");
+        assert_eq!(bom.components, None);
+    }
+
+    #[test]
+    fn it_should_parse_the_metadata_only_in_any_order() {
+        let input = r#"{
+            "metadata": { "timestamp": "2024-01-02T03:04:05Z" },
+            "specVersion": "1.4",
+            "version": 1,
+            "components": []
+        }"#;
+        let bom = Bom::parse_metadata_only(input.as_bytes()).unwrap();
+        assert!(bom.metadata.is_some());
+        assert_eq!(bom.serial_number, None);
+
+        let input = r#"{"specVersion": "1.3", "version": 1, "components": []}"#;
+        let bom = Bom::parse_metadata_only(input.as_bytes()).unwrap();
+        assert_eq!(bom.metadata, None);
+
+        let input = r#"{"version": 1, "metadata": {}, "components": []}"#;
+        assert!(Bom::parse_metadata_only(input.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn it_should_parse_the_metadata_only_from_xml() {
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" serialNumber="urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79" version="2">
+  <metadata>
+    <timestamp>2024-01-02T03:04:05Z</timestamp>
+  </metadata>
+  <components>
+    <unclosed>
+"#;
+        let bom = Bom::parse_metadata_only_from_xml(input.as_bytes(), SpecVersion::V1_5).unwrap();
+
+        assert_eq!(bom.version, 2);
+        assert!(bom.serial_number.is_some());
+        assert_eq!(
+            bom.metadata.unwrap().timestamp,
+            Some(DateTime("2024-01-02T03:04:05Z".to_string()))
+        );
+
+        let input = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.4"><components/></bom>"#;
+        let bom = Bom::parse_metadata_only_from_xml(input.as_bytes(), SpecVersion::V1_4).unwrap();
+        assert_eq!(bom.metadata, None);
+    }
+
     #[test]
     fn it_should_validate_an_empty_bom_as_passed() {
         let bom = Bom {
@@ -815,7 +1597,7 @@ mod test {
             }])),
             properties: Some(Properties(vec![Property {
                 name: "name".to_string(),
-                value: NormalizedString("invalid\tvalue".to_string()),
+                value: NormalizedString("invalid\tvalue".into()),
             }])),
             vulnerabilities: Some(Vulnerabilities(vec![Vulnerability {
                 bom_ref: None,
@@ -1009,4 +1791,206 @@ mod test {
             Err("UrnUuid does not match regular expression".into()),
         );
     }
+
+    #[test]
+    fn it_should_convert_to_and_from_json_values() {
+        let bom = Bom::builder()
+            .serial_number_random()
+            .component(Component::new(
+                Classification::Library,
+                "serde",
+                "1.0.0",
+                None,
+            ))
+            .build()
+            .unwrap();
+
+        for version in [SpecVersion::V1_3, SpecVersion::V1_4, SpecVersion::V1_5] {
+            let value = bom.to_json_value(version).unwrap();
+            let mut output = Vec::new();
+            bom.clone().output_as_json(&mut output, version).unwrap();
+
+            assert_eq!(value, serde_json::from_slice::<Value>(&output).unwrap());
+            assert_eq!(value["specVersion"], version.to_string());
+            assert_eq!(Bom::from_json_value(value).unwrap(), bom);
+        }
+    }
+
+    #[test]
+    fn it_should_build_an_empty_bom_without_serial_number() {
+        let bom = Bom::builder().build().unwrap();
+
+        assert_eq!(
+            bom,
+            Bom {
+                serial_number: None,
+                ..Bom::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_reject_building_an_invalid_bom() {
+        let mut component = Component::new(Classification::Library, "a", "1.0", None);
+        component.name = NormalizedString::new_unchecked("a\tb".to_string());
+        let error = Bom::builder()
+            .component(component)
+            .dependency(Dependency {
+                dependency_ref: "missing".to_string(),
+                dependencies: vec![],
+            })
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            error.violations,
+            vec![
+                Violation {
+                    path: "components[0].name".to_string(),
+                    message: "NormalizedString contains invalid characters \\r \\n \\t or \\r\\n"
+                        .to_string(),
+                },
+                Violation {
+                    path: "dependency_ref".to_string(),
+                    message: "Dependency ref 'missing' does not exist in the BOM".to_string(),
+                },
+            ]
+        );
+        assert!(Bom::builder()
+            .dependency(Dependency {
+                dependency_ref: "missing".to_string(),
+                dependencies: vec![],
+            })
+            .build_unchecked()
+            .validate()
+            .has_errors());
+    }
+
+    #[test]
+    fn it_should_build_a_bom() {
+        let serial_number = UrnUuid::generate();
+        let bom = Bom::builder()
+            .version(2)
+            .serial_number(serial_number.clone())
+            .component(Component::new(
+                Classification::Library,
+                "a",
+                "1.0",
+                Some("a".to_string()),
+            ))
+            .component(Component::new(
+                Classification::Library,
+                "b",
+                "1.0",
+                Some("b".to_string()),
+            ))
+            .services(vec![Service::new("service", None)])
+            .dependency(Dependency {
+                dependency_ref: "a".to_string(),
+                dependencies: vec!["b".to_string()],
+            })
+            .property(Property::new("name", "value"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            bom,
+            Bom {
+                version: 2,
+                serial_number: Some(serial_number),
+                components: Some(Components(vec![
+                    Component::new(Classification::Library, "a", "1.0", Some("a".to_string())),
+                    Component::new(Classification::Library, "b", "1.0", Some("b".to_string())),
+                ])),
+                services: Some(Services(vec![Service::new("service", None)])),
+                dependencies: Some(Dependencies(vec![Dependency {
+                    dependency_ref: "a".to_string(),
+                    dependencies: vec!["b".to_string()],
+                }])),
+                properties: Some(Properties(vec![Property::new("name", "value")])),
+                ..Bom::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_replace_collections_in_builder() {
+        let bom = Bom::builder()
+            .component(Component::new(Classification::Library, "a", "1.0", None))
+            .components(vec![Component::new(
+                Classification::Library,
+                "b",
+                "1.0",
+                None,
+            )])
+            .serial_number_random()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            bom.components,
+            Some(Components(vec![Component::new(
+                Classification::Library,
+                "b",
+                "1.0",
+                None
+            )]))
+        );
+        assert!(bom.serial_number.is_some());
+    }
+
+    #[test]
+    fn it_should_store_boms_in_a_set() {
+        let bom = Bom::builder()
+            .component(Component::new(Classification::Library, "a", "1.0", None))
+            .build()
+            .unwrap();
+
+        let boms = std::collections::HashSet::from([bom.clone(), bom, Bom::default()]);
+
+        assert_eq!(boms.len(), 2);
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_write_xml_in_large_chunks() {
+        struct CountingWriter {
+            output: Vec<u8>,
+            writes: usize,
+        }
+
+        impl std::io::Write for CountingWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.writes += 1;
+                self.output.write(buf)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let components = (0..100)
+            .map(|index| Component::new(Classification::Library, &index.to_string(), "1.0", None))
+            .collect();
+        let bom = Bom {
+            components: Some(Components(components)),
+            ..Bom::default()
+        };
+        let mut expected = Vec::new();
+        bom.clone()
+            .output_as_xml_v1_5(&mut expected)
+            .expect("Failed to write XML");
+
+        let mut writer = CountingWriter {
+            output: Vec::new(),
+            writes: 0,
+        };
+        bom.output_as_xml_v1_5(&mut writer)
+            .expect("Failed to write XML");
+
+        assert_eq!(writer.output, expected);
+        assert!(expected.len() < 64 * 1024);
+        assert_eq!(writer.writes, 1);
+    }
 }
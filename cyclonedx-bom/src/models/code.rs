@@ -29,6 +29,7 @@ use crate::{
 use super::{attached_text::AttachedText, bom::SpecVersion};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Commit {
     pub uid: Option<NormalizedString>,
     pub url: Option<Uri>,
@@ -50,8 +51,11 @@ impl Validate for Commit {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Commits(pub Vec<Commit>);
 
+impl_collection!(Commits, Commit);
+
 impl Validate for Commits {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -61,6 +65,7 @@ impl Validate for Commits {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Diff {
     pub text: Option<AttachedText>,
     pub url: Option<Uri>,
@@ -76,6 +81,7 @@ impl Validate for Diff {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct IdentifiableAction {
     pub timestamp: Option<DateTime>,
     pub name: Option<NormalizedString>,
@@ -93,6 +99,7 @@ impl Validate for IdentifiableAction {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Issue {
     pub issue_type: IssueClassification,
     pub id: Option<NormalizedString>,
@@ -136,6 +143,7 @@ pub fn validate_issue_classification(
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "snake_case")]
 pub enum IssueClassification {
     Defect,
@@ -157,7 +165,18 @@ impl IssueClassification {
     }
 }
 
+impl_from_str!(
+    IssueClassification,
+    "issue classification",
+    [
+        IssueClassification::Defect,
+        IssueClassification::Enhancement,
+        IssueClassification::Security,
+    ]
+);
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Patch {
     pub patch_type: PatchClassification,
     pub diff: Option<Diff>,
@@ -181,8 +200,11 @@ impl Validate for Patch {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Patches(pub Vec<Patch>);
 
+impl_collection!(Patches, Patch);
+
 impl Validate for Patches {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -204,6 +226,7 @@ pub fn validate_patch_classification(
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum PatchClassification {
     Unofficial,
@@ -227,7 +250,19 @@ impl PatchClassification {
     }
 }
 
+impl_from_str!(
+    PatchClassification,
+    "patch classification",
+    [
+        PatchClassification::Unofficial,
+        PatchClassification::Monkey,
+        PatchClassification::Backport,
+        PatchClassification::CherryPick,
+    ]
+);
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Source {
     pub name: Option<NormalizedString>,
     pub url: Option<Uri>,
@@ -252,19 +287,19 @@ mod test {
     #[test]
     fn valid_commits_should_pass_validation() {
         let validation_result = Commits(vec![Commit {
-            uid: Some(NormalizedString("no_whitespace".to_string())),
+            uid: Some(NormalizedString("no_whitespace".into())),
             url: Some(Uri("https://www.example.com".to_string())),
             author: Some(IdentifiableAction {
                 timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
-                name: Some(NormalizedString("Name".to_string())),
-                email: Some(NormalizedString("email@example.com".to_string())),
+                name: Some(NormalizedString("Name".into())),
+                email: Some(NormalizedString("email@example.com".into())),
             }),
             committer: Some(IdentifiableAction {
                 timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
-                name: Some(NormalizedString("Name".to_string())),
-                email: Some(NormalizedString("email@example.com".to_string())),
+                name: Some(NormalizedString("Name".into())),
+                email: Some(NormalizedString("email@example.com".into())),
             }),
-            message: Some(NormalizedString("no_whitespace".to_string())),
+            message: Some(NormalizedString("no_whitespace".into())),
         }])
         .validate();
 
@@ -274,19 +309,19 @@ mod test {
     #[test]
     fn invalid_commits_should_fail_validation() {
         let validation_result = Commits(vec![Commit {
-            uid: Some(NormalizedString("spaces and\ttabs".to_string())),
+            uid: Some(NormalizedString("spaces and\ttabs".into())),
             url: Some(Uri("invalid uri".to_string())),
             author: Some(IdentifiableAction {
                 timestamp: Some(DateTime("Thursday".to_string())),
-                name: Some(NormalizedString("spaces and\ttabs".to_string())),
-                email: Some(NormalizedString("spaces and\ttabs".to_string())),
+                name: Some(NormalizedString("spaces and\ttabs".into())),
+                email: Some(NormalizedString("spaces and\ttabs".into())),
             }),
             committer: Some(IdentifiableAction {
                 timestamp: Some(DateTime("1970-01-01".to_string())),
-                name: Some(NormalizedString("spaces and\ttabs".to_string())),
-                email: Some(NormalizedString("spaces and\ttabs".to_string())),
+                name: Some(NormalizedString("spaces and\ttabs".into())),
+                email: Some(NormalizedString("spaces and\ttabs".into())),
             }),
-            message: Some(NormalizedString("spaces and\ttabs".to_string())),
+            message: Some(NormalizedString("spaces and\ttabs".into())),
         }])
         .validate();
 
@@ -339,11 +374,11 @@ mod test {
             }),
             resolves: Some(vec![Issue {
                 issue_type: IssueClassification::Defect,
-                id: Some(NormalizedString("issue_id".to_string())),
-                name: Some(NormalizedString("issue_name".to_string())),
-                description: Some(NormalizedString("issue_description".to_string())),
+                id: Some(NormalizedString("issue_id".into())),
+                name: Some(NormalizedString("issue_name".into())),
+                description: Some(NormalizedString("issue_description".into())),
                 source: Some(Source {
-                    name: Some(NormalizedString("source_name".to_string())),
+                    name: Some(NormalizedString("source_name".into())),
                     url: Some(Uri("https://example.com".to_string())),
                 }),
                 references: Some(vec![Uri("https://example.com".to_string())]),
@@ -360,7 +395,7 @@ mod test {
             patch_type: PatchClassification::UnknownPatchClassification("unknown".to_string()),
             diff: Some(Diff {
                 text: Some(AttachedText {
-                    content_type: Some(NormalizedString("spaces and \ttabs".to_string())),
+                    content_type: Some(NormalizedString("spaces and \ttabs".into())),
                     encoding: None,
                     content: "content".to_string(),
                 }),
@@ -368,11 +403,11 @@ mod test {
             }),
             resolves: Some(vec![Issue {
                 issue_type: IssueClassification::UnknownIssueClassification("unknown".to_string()),
-                id: Some(NormalizedString("spaces and \ttabs".to_string())),
-                name: Some(NormalizedString("spaces and \ttabs".to_string())),
-                description: Some(NormalizedString("spaces and \ttabs".to_string())),
+                id: Some(NormalizedString("spaces and \ttabs".into())),
+                name: Some(NormalizedString("spaces and \ttabs".into())),
+                description: Some(NormalizedString("spaces and \ttabs".into())),
                 source: Some(Source {
-                    name: Some(NormalizedString("spaces and \ttabs".to_string())),
+                    name: Some(NormalizedString("spaces and \ttabs".into())),
                     url: Some(Uri("invalid uri".to_string())),
                 }),
                 references: Some(vec![Uri("invalid uri".to_string())]),
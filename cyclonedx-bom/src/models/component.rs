@@ -33,11 +33,12 @@ use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
 use crate::validation::ValidationError;
 use crate::{
+    errors::BuildError,
     external_models::{
         normalized_string::NormalizedString,
         uri::{Purl, Uri as Url},
     },
-    validation::{Validate, ValidationContext, ValidationResult},
+    validation::{validate_built, Validate, ValidationContext, ValidationResult},
 };
 
 use super::bom::{validate_bom_ref, SpecVersion};
@@ -46,6 +47,7 @@ use super::modelcard::ModelCard;
 use super::signature::Signature;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Component {
     pub component_type: Classification,
     pub mime_type: Option<MimeType>,
@@ -63,22 +65,28 @@ pub struct Component {
     pub copyright: Option<NormalizedString>,
     pub cpe: Option<Cpe>,
     pub purl: Option<Purl>,
-    pub swid: Option<Swid>,
+    pub swid: Option<Box<Swid>>,
     pub modified: Option<bool>,
-    pub pedigree: Option<Pedigree>,
+    pub pedigree: Option<Box<Pedigree>>,
     pub external_references: Option<ExternalReferences>,
     pub properties: Option<Properties>,
     pub components: Option<Components>,
-    pub evidence: Option<ComponentEvidence>,
+    pub evidence: Option<Box<ComponentEvidence>>,
     /// Added in version 1.4
-    pub signature: Option<Signature>,
+    pub signature: Option<Box<Signature>>,
     /// Added in version 1.5
-    pub model_card: Option<ModelCard>,
+    pub model_card: Option<Box<ModelCard>>,
     /// Added in version 1.5
-    pub data: Option<ComponentData>,
+    pub data: Option<Box<ComponentData>>,
 }
 
 impl Component {
+    /// Constructs a `Component` with a type, name, version and optional BOM reference
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    ///
+    /// let component = Component::new(Classification::Library, "serde", "1.0.193", None);
+    /// ```
     pub fn new(
         component_type: Classification,
         name: &str,
@@ -114,6 +122,169 @@ impl Component {
             data: None,
         }
     }
+
+    /// Returns a [`ComponentBuilder`] for a component with the required type and name.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    ///
+    /// let component = Component::builder(Classification::Library, "serde")
+    ///     .version("1.0.193")
+    ///     .bom_ref("serde 1.0.193")
+    ///     .build()
+    ///     .expect("valid component");
+    /// ```
+    pub fn builder(component_type: Classification, name: &str) -> ComponentBuilder {
+        ComponentBuilder {
+            component: Self {
+                version: None,
+                ..Self::new(component_type, name, "", None)
+            },
+        }
+    }
+}
+
+/// Builder for a [`Component`], created via [`Component::builder`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ComponentBuilder {
+    component: Component,
+}
+
+impl ComponentBuilder {
+    pub fn bom_ref(mut self, bom_ref: impl ToString) -> Self {
+        self.component.bom_ref = Some(bom_ref.to_string());
+        self
+    }
+
+    pub fn mime_type(mut self, mime_type: MimeType) -> Self {
+        self.component.mime_type = Some(mime_type);
+        self
+    }
+
+    pub fn supplier(mut self, supplier: OrganizationalEntity) -> Self {
+        self.component.supplier = Some(supplier);
+        self
+    }
+
+    pub fn author(mut self, author: &str) -> Self {
+        self.component.author = Some(NormalizedString::new(author));
+        self
+    }
+
+    pub fn publisher(mut self, publisher: &str) -> Self {
+        self.component.publisher = Some(NormalizedString::new(publisher));
+        self
+    }
+
+    pub fn group(mut self, group: &str) -> Self {
+        self.component.group = Some(NormalizedString::new(group));
+        self
+    }
+
+    pub fn version(mut self, version: &str) -> Self {
+        self.component.version = Some(NormalizedString::new(version));
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.component.description = Some(NormalizedString::new(description));
+        self
+    }
+
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.component.scope = Some(scope);
+        self
+    }
+
+    pub fn hashes(mut self, hashes: Hashes) -> Self {
+        self.component.hashes = Some(hashes);
+        self
+    }
+
+    pub fn licenses(mut self, licenses: Licenses) -> Self {
+        self.component.licenses = Some(licenses);
+        self
+    }
+
+    pub fn copyright(mut self, copyright: &str) -> Self {
+        self.component.copyright = Some(NormalizedString::new(copyright));
+        self
+    }
+
+    pub fn cpe(mut self, cpe: Cpe) -> Self {
+        self.component.cpe = Some(cpe);
+        self
+    }
+
+    pub fn purl(mut self, purl: Purl) -> Self {
+        self.component.purl = Some(purl);
+        self
+    }
+
+    pub fn swid(mut self, swid: Swid) -> Self {
+        self.component.swid = Some(Box::new(swid));
+        self
+    }
+
+    pub fn modified(mut self, modified: bool) -> Self {
+        self.component.modified = Some(modified);
+        self
+    }
+
+    pub fn pedigree(mut self, pedigree: Pedigree) -> Self {
+        self.component.pedigree = Some(Box::new(pedigree));
+        self
+    }
+
+    pub fn external_references(mut self, external_references: ExternalReferences) -> Self {
+        self.component.external_references = Some(external_references);
+        self
+    }
+
+    pub fn properties(mut self, properties: Properties) -> Self {
+        self.component.properties = Some(properties);
+        self
+    }
+
+    pub fn components(mut self, components: impl IntoIterator<Item = Component>) -> Self {
+        self.component.components = Some(Components(components.into_iter().collect()));
+        self
+    }
+
+    pub fn evidence(mut self, evidence: ComponentEvidence) -> Self {
+        self.component.evidence = Some(Box::new(evidence));
+        self
+    }
+
+    /// Added in version 1.4
+    pub fn signature(mut self, signature: Signature) -> Self {
+        self.component.signature = Some(Box::new(signature));
+        self
+    }
+
+    /// Added in version 1.5
+    pub fn model_card(mut self, model_card: ModelCard) -> Self {
+        self.component.model_card = Some(Box::new(model_card));
+        self
+    }
+
+    /// Added in version 1.5
+    pub fn data(mut self, data: ComponentData) -> Self {
+        self.component.data = Some(Box::new(data));
+        self
+    }
+
+    /// Builds the [`Component`], validating it against the latest spec version.
+    ///
+    /// Returns a [`BuildError`] listing all violations if validation fails.
+    pub fn build(self) -> Result<Component, BuildError> {
+        validate_built("component", self.component)
+    }
+
+    /// Builds the [`Component`] without validating it, e.g. to construct partial documents.
+    pub fn build_unchecked(self) -> Component {
+        self.component
+    }
 }
 
 impl Validate for Component {
@@ -148,8 +319,8 @@ impl Validate for Component {
         );
         ctx.add_field_option("cpe", self.cpe.as_ref(), validate_cpe);
         ctx.add_field_option("purl", self.purl.as_ref(), validate_purl);
-        ctx.add_struct_option("swid", self.swid.as_ref(), version);
-        ctx.add_struct_option("pedigree", self.pedigree.as_ref(), version);
+        ctx.add_struct_option("swid", self.swid.as_deref(), version);
+        ctx.add_struct_option("pedigree", self.pedigree.as_deref(), version);
         ctx.add_struct_option(
             "external_references",
             self.external_references.as_ref(),
@@ -157,18 +328,21 @@ impl Validate for Component {
         );
         ctx.add_struct_option("properties", self.properties.as_ref(), version);
         ctx.add_struct_option("components", self.components.as_ref(), version);
-        ctx.add_struct_option("evidence", self.evidence.as_ref(), version);
+        ctx.add_struct_option("evidence", self.evidence.as_deref(), version);
         ctx.into()
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Components(pub Vec<Component>);
 
+impl_collection!(Components, Component);
+
 impl Validate for Components {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
-            .add_list("inner", &self.0, |component| {
+            .add_list_parallel("inner", &self.0, |component| {
                 component.validate_version(version)
             })
             .into()
@@ -193,6 +367,7 @@ pub fn validate_classification(
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 #[repr(u16)]
 pub enum Classification {
@@ -237,6 +412,25 @@ impl Classification {
     }
 }
 
+impl_from_str!(
+    Classification,
+    "classification",
+    [
+        Classification::Application,
+        Classification::Framework,
+        Classification::Library,
+        Classification::Container,
+        Classification::OperatingSystem,
+        Classification::Device,
+        Classification::Firmware,
+        Classification::File,
+        Classification::Platform,
+        Classification::DeviceDriver,
+        Classification::MachineLearningModel,
+        Classification::Data,
+    ]
+);
+
 pub fn validate_scope(scope: &Scope) -> Result<(), ValidationError> {
     if matches!(scope, Scope::UnknownScope(_)) {
         return Err(ValidationError::new("Unknown scope"));
@@ -245,6 +439,7 @@ pub fn validate_scope(scope: &Scope) -> Result<(), ValidationError> {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Scope {
     Required,
@@ -266,12 +461,26 @@ impl Scope {
     }
 }
 
+impl_from_str!(
+    Scope,
+    "scope",
+    [Scope::Required, Scope::Optional, Scope::Excluded,]
+);
+
 /// Checks if given [`MimeType`] is valid / supported.
 pub fn validate_mime_type(mime_type: &MimeType) -> Result<(), ValidationError> {
-    static UUID_REGEX: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"^[-+a-z0-9.]+/[-+a-z0-9.]+$").expect("Failed to compile regex."));
+    let is_mime_part = |part: &str| {
+        !part.is_empty()
+            && part
+                .bytes()
+                .all(|b| matches!(b, b'-' | b'+' | b'.' | b'a'..=b'z' | b'0'..=b'9'))
+    };
+    let matches = mime_type
+        .0
+        .split_once('/')
+        .is_some_and(|(kind, subtype)| is_mime_part(kind) && is_mime_part(subtype));
 
-    if !UUID_REGEX.is_match(&mime_type.0) {
+    if !matches {
         return Err(ValidationError::new(
             "MimeType does not match regular expression",
         ));
@@ -281,9 +490,17 @@ pub fn validate_mime_type(mime_type: &MimeType) -> Result<(), ValidationError> {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MimeType(pub String);
 
+impl std::fmt::Display for MimeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Swid {
     pub tag_id: String,
     pub name: String,
@@ -320,6 +537,7 @@ pub fn validate_cpe(cpe: &Cpe) -> Result<(), ValidationError> {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Cpe(pub(crate) String);
 
 impl Cpe {
@@ -359,6 +577,7 @@ impl std::fmt::Display for Cpe {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ComponentEvidence {
     pub licenses: Option<Licenses>,
     pub copyright: Option<CopyrightTexts>,
@@ -386,8 +605,11 @@ impl Validate for ComponentEvidence {
 /// https://cyclonedx.org/docs/1.5/json/#components_items_evidence_occurrences
 /// Added in version 1.5
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Occurrences(pub Vec<Occurrence>);
 
+impl_collection!(Occurrences, Occurrence);
+
 impl Validate for Occurrences {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -399,6 +621,7 @@ impl Validate for Occurrences {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Occurrence {
     pub bom_ref: Option<BomReference>,
     pub location: String,
@@ -424,6 +647,7 @@ impl Validate for Occurrence {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Callstack {
     pub frames: Frames,
 }
@@ -441,8 +665,11 @@ impl Validate for Callstack {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Frames(pub Vec<Frame>);
 
+impl_collection!(Frames, Frame);
+
 impl Validate for Frames {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -455,6 +682,7 @@ impl Validate for Frames {
 /// https://cyclonedx.org/docs/1.5/json/#components_items_evidence_callstack
 /// Added in version 1.5
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Frame {
     pub package: Option<NormalizedString>,
     pub module: NormalizedString,
@@ -497,6 +725,7 @@ pub fn validate_confidence(confidence: &ConfidenceScore) -> Result<(), Validatio
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConfidenceScore(pub OrderedFloat<f32>);
 
 impl ConfidenceScore {
@@ -517,6 +746,7 @@ pub fn validate_identity_field(field: &IdentityField) -> Result<(), ValidationEr
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 #[repr(u16)]
 pub enum IdentityField {
@@ -545,10 +775,25 @@ impl IdentityField {
     }
 }
 
+impl_from_str!(
+    IdentityField,
+    "identity field",
+    [
+        IdentityField::Group,
+        IdentityField::Name,
+        IdentityField::Version,
+        IdentityField::Purl,
+        IdentityField::Cpe,
+        IdentityField::Swid,
+        IdentityField::Hash,
+    ]
+);
+
 /// For more information see
 /// https://cyclonedx.org/docs/1.5/json/#components_items_evidence_identity
 /// Added in version 1.5
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Identity {
     pub field: IdentityField,
     /// Level between 0.0-1.0 (where 1.0 is highest confidence)
@@ -569,9 +814,13 @@ impl Validate for Identity {
 /// For more information see
 /// https://cyclonedx.org/docs/1.5/json/#components_items_evidence_identity_methods
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Methods(pub Vec<Method>);
 
+impl_collection!(Methods, Method);
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Method {
     pub technique: String,
     pub confidence: ConfidenceScore,
@@ -579,9 +828,13 @@ pub struct Method {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ToolsReferences(pub Vec<String>);
 
+impl_collection!(ToolsReferences, String);
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Pedigree {
     pub ancestors: Option<Components>,
     pub descendants: Option<Components>,
@@ -608,11 +861,21 @@ pub fn validate_copyright(_copyright: &Copyright) -> Result<(), ValidationError>
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Copyright(pub String);
 
+impl std::fmt::Display for Copyright {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CopyrightTexts(pub Vec<Copyright>);
 
+impl_collection!(CopyrightTexts, Copyright);
+
 impl Validate for CopyrightTexts {
     fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -681,7 +944,7 @@ mod test {
             copyright: Some(NormalizedString::new("copyright")),
             cpe: Some(Cpe("cpe:/a:example:mylibrary:1.0.0".to_string())),
             purl: Some(Purl("pkg:cargo/cyclonedx-bom@0.3.1".to_string())),
-            swid: Some(Swid {
+            swid: Some(Box::new(Swid {
                 tag_id: "tag ID".to_string(),
                 name: "name".to_string(),
                 version: Some("version".to_string()),
@@ -693,9 +956,9 @@ mod test {
                     content: "content".to_string(),
                 }),
                 url: Some(Url("https://example.com".to_string())),
-            }),
+            })),
             modified: Some(true),
-            pedigree: Some(Pedigree {
+            pedigree: Some(Box::new(Pedigree {
                 ancestors: Some(Components(vec![])),
                 descendants: Some(Components(vec![])),
                 variants: Some(Components(vec![])),
@@ -712,7 +975,7 @@ mod test {
                     resolves: None,
                 }])),
                 notes: Some("notes".to_string()),
-            }),
+            })),
             external_references: Some(ExternalReferences(vec![ExternalReference {
                 external_reference_type: ExternalReferenceType::Bom,
                 url: Uri::Url(Url("https://www.example.com".to_string())),
@@ -724,7 +987,7 @@ mod test {
                 value: NormalizedString::new("value"),
             }])),
             components: Some(Components(vec![])),
-            evidence: Some(ComponentEvidence {
+            evidence: Some(Box::new(ComponentEvidence {
                 licenses: Some(Licenses(vec![LicenseChoice::Expression(
                     SpdxExpression::new("MIT"),
                 )])),
@@ -752,9 +1015,9 @@ mod test {
                     }])),
                     tools: None,
                 }),
-            }),
-            signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
-            model_card: Some(ModelCard {
+            })),
+            signature: Some(Box::new(Signature::single(Algorithm::HS512, "abcdefgh"))),
+            model_card: Some(Box::new(ModelCard {
                 bom_ref: None,
                 model_parameters: Some(ModelParameters {
                     approach: Some(ModelParametersApproach {
@@ -824,10 +1087,10 @@ mod test {
                 considerations: Some(Considerations {}),
                 properties: Some(Properties(vec![Property {
                     name: "property".to_string(),
-                    value: NormalizedString("value".to_string()),
+                    value: NormalizedString("value".into()),
                 }])),
-            }),
-            data: Some(ComponentData {
+            })),
+            data: Some(Box::new(ComponentData {
                 bom_ref: None,
                 data_type: ComponentDataType::SourceCode,
                 name: Some("github".into()),
@@ -845,7 +1108,7 @@ mod test {
                 graphics: None,
                 description: None,
                 governance: None,
-            }),
+            })),
         }];
         let validation_result = Components(vec).validate();
 
@@ -860,16 +1123,16 @@ mod test {
             bom_ref: Some("bom ref".to_string()),
             supplier: Some(OrganizationalEntity {
                 bom_ref: Some(BomReference::new("Supplier 1")),
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 url: None,
                 contact: None,
             }),
-            author: Some(NormalizedString("invalid\tauthor".to_string())),
-            publisher: Some(NormalizedString("invalid\tpublisher".to_string())),
-            group: Some(NormalizedString("invalid\tgroup".to_string())),
-            name: NormalizedString("invalid\tname".to_string()),
-            version: Some(NormalizedString("invalid\tversion".to_string())),
-            description: Some(NormalizedString("invalid\tdescription".to_string())),
+            author: Some(NormalizedString("invalid\tauthor".into())),
+            publisher: Some(NormalizedString("invalid\tpublisher".into())),
+            group: Some(NormalizedString("invalid\tgroup".into())),
+            name: NormalizedString("invalid\tname".into()),
+            version: Some(NormalizedString("invalid\tversion".into())),
+            description: Some(NormalizedString("invalid\tdescription".into())),
             scope: Some(Scope::UnknownScope("unknown".to_string())),
             hashes: Some(Hashes(vec![Hash {
                 alg: HashAlgorithm::MD5,
@@ -878,29 +1141,29 @@ mod test {
             licenses: Some(Licenses(vec![LicenseChoice::Expression(
                 SpdxExpression::new("invalid license"),
             )])),
-            copyright: Some(NormalizedString("invalid\tcopyright".to_string())),
+            copyright: Some(NormalizedString("invalid\tcopyright".into())),
             cpe: Some(Cpe("invalid cpe".to_string())),
             purl: Some(Purl("invalid purl".to_string())),
-            swid: Some(Swid {
+            swid: Some(Box::new(Swid {
                 tag_id: "tag ID".to_string(),
                 name: "name".to_string(),
                 version: Some("version".to_string()),
                 tag_version: Some(1),
                 patch: Some(true),
                 text: Some(AttachedText {
-                    content_type: Some(NormalizedString("invalid\tcontent_type".to_string())),
+                    content_type: Some(NormalizedString("invalid\tcontent_type".into())),
                     encoding: None,
                     content: "content".to_string(),
                 }),
                 url: Some(Url("invalid url".to_string())),
-            }),
+            })),
             modified: Some(true),
-            pedigree: Some(Pedigree {
+            pedigree: Some(Box::new(Pedigree {
                 ancestors: Some(Components(vec![invalid_component()])),
                 descendants: Some(Components(vec![invalid_component()])),
                 variants: Some(Components(vec![invalid_component()])),
                 commits: Some(Commits(vec![Commit {
-                    uid: Some(NormalizedString("invalid\tuid".to_string())),
+                    uid: Some(NormalizedString("invalid\tuid".into())),
                     url: None,
                     author: None,
                     committer: None,
@@ -914,7 +1177,7 @@ mod test {
                     resolves: None,
                 }])),
                 notes: Some("notes".to_string()),
-            }),
+            })),
             external_references: Some(ExternalReferences(vec![ExternalReference {
                 external_reference_type: ExternalReferenceType::UnknownExternalReferenceType(
                     "unknown".to_string(),
@@ -925,10 +1188,10 @@ mod test {
             }])),
             properties: Some(Properties(vec![Property {
                 name: "name".to_string(),
-                value: NormalizedString("invalid\tvalue".to_string()),
+                value: NormalizedString("invalid\tvalue".into()),
             }])),
             components: Some(Components(vec![invalid_component()])),
-            evidence: Some(ComponentEvidence {
+            evidence: Some(Box::new(ComponentEvidence {
                 licenses: Some(Licenses(vec![LicenseChoice::Expression(
                     SpdxExpression::new("invalid license"),
                 )])),
@@ -936,8 +1199,8 @@ mod test {
                 occurrences: None,
                 callstack: None,
                 identity: None,
-            }),
-            signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
+            })),
+            signature: Some(Box::new(Signature::single(Algorithm::HS512, "abcdefgh"))),
             model_card: None,
             data: None,
         }])
@@ -1206,4 +1469,95 @@ mod test {
         )
         .is_err());
     }
+
+    #[test]
+    fn it_should_build_a_component() {
+        let component = Component::builder(Classification::Library, "serde")
+            .version("1.0.193")
+            .bom_ref("serde 1.0.193")
+            .scope(Scope::Required)
+            .purl(Purl::new("cargo", "serde", "1.0.193").unwrap())
+            .properties(Properties(vec![Property::new("name", "value")]))
+            .build()
+            .unwrap();
+
+        let mut expected = Component::new(
+            Classification::Library,
+            "serde",
+            "1.0.193",
+            Some("serde 1.0.193".to_string()),
+        );
+        expected.scope = Some(Scope::Required);
+        expected.purl = Some(Purl::new("cargo", "serde", "1.0.193").unwrap());
+        expected.properties = Some(Properties(vec![Property::new("name", "value")]));
+
+        assert_eq!(component, expected);
+    }
+
+    #[test]
+    fn it_should_build_a_component_without_version() {
+        let component = Component::builder(Classification::Application, "app")
+            .build()
+            .unwrap();
+
+        assert_eq!(component.name, NormalizedString::new("app"));
+        assert_eq!(component.version, None);
+    }
+
+    #[test]
+    fn it_should_display_spec_values() {
+        assert_eq!(
+            Classification::MachineLearningModel.to_string(),
+            "machine-learning-model"
+        );
+        assert_eq!(
+            Classification::new_unchecked("unknown").to_string(),
+            "unknown"
+        );
+        assert_eq!(Scope::Excluded.to_string(), "excluded");
+        assert_eq!(
+            crate::models::hash::HashAlgorithm::SHA3_256.to_string(),
+            "SHA3-256"
+        );
+        assert_eq!(MimeType("text/plain".to_string()).to_string(), "text/plain");
+    }
+
+    #[test]
+    fn it_should_parse_spec_values_case_insensitively() {
+        assert_eq!(
+            "Operating-System".parse::<Classification>(),
+            Ok(Classification::OperatingSystem)
+        );
+        assert_eq!("REQUIRED".parse::<Scope>(), Ok(Scope::Required));
+        assert_eq!(
+            "sha-256".parse::<crate::models::hash::HashAlgorithm>(),
+            Ok(crate::models::hash::HashAlgorithm::SHA_256)
+        );
+
+        let error = "optionally".parse::<Scope>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unknown scope 'optionally', expected one of: required, optional, excluded"
+        );
+    }
+
+    #[test]
+    fn it_should_iterate_and_index_components() {
+        let mut components: Components = ["a", "b"]
+            .into_iter()
+            .map(|name| Component::new(Classification::Library, name, "1.0", None))
+            .collect();
+        components.push(Component::new(Classification::Library, "c", "1.0", None));
+
+        for component in &mut components {
+            component.version = Some(NormalizedString::new("2.0"));
+        }
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[2].name, NormalizedString::new("c"));
+        assert!(components
+            .iter()
+            .all(|component| component.version == Some(NormalizedString::new("2.0"))));
+        assert_eq!(components.into_iter().count(), 3);
+    }
 }
@@ -30,6 +30,7 @@ use super::{
 
 /// Inline Component Data
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ComponentData {
     pub bom_ref: Option<BomReference>,
     /// 'type' field
@@ -63,6 +64,7 @@ fn validate_datatype(datatype: &ComponentDataType) -> Result<(), ValidationError
 
 /// Type of data
 #[derive(Clone, Debug, PartialEq, Eq, strum::Display, strum::EnumString, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum ComponentDataType {
     SourceCode,
@@ -82,6 +84,7 @@ impl From<String> for ComponentDataType {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DataContents {
     pub attachment: Option<Attachment>,
     pub url: Option<Uri>,
@@ -100,6 +103,7 @@ impl Validate for DataContents {
 
 /// bom-1.5.schema.json #definitions/graphicsCollection
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct GraphicsCollection {
     pub description: Option<String>,
     pub collection: Option<Vec<Graphic>>,
@@ -117,6 +121,7 @@ impl Validate for GraphicsCollection {
 
 /// bom-1.5.schema.json #definitions/graphic
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Graphic {
     pub name: Option<String>,
     pub image: Option<Attachment>,
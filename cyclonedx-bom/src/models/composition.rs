@@ -23,7 +23,8 @@ use super::{
     signature::Signature,
 };
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Composition {
     pub bom_ref: Option<BomReference>,
     pub aggregate: AggregateType,
@@ -33,6 +34,25 @@ pub struct Composition {
     pub signature: Option<Signature>,
 }
 
+impl Composition {
+    /// Constructs a `Composition` with an aggregate type and no references
+    /// ```
+    /// use cyclonedx_bom::models::composition::{AggregateType, Composition};
+    ///
+    /// let composition = Composition::new(AggregateType::Complete);
+    /// ```
+    pub fn new(aggregate: AggregateType) -> Self {
+        Self {
+            bom_ref: None,
+            aggregate,
+            assemblies: None,
+            dependencies: None,
+            vulnerabilities: None,
+            signature: None,
+        }
+    }
+}
+
 impl Validate for Composition {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -44,9 +64,12 @@ impl Validate for Composition {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Compositions(pub Vec<Composition>);
 
+impl_collection!(Compositions, Composition);
+
 impl Validate for Compositions {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -74,7 +97,8 @@ pub fn validate_aggregate_type(
     Ok(())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "snake_case")]
 #[repr(u16)]
 pub enum AggregateType {
@@ -103,7 +127,7 @@ impl AggregateType {
             "complete" => Self::Complete,
             "incomplete" => Self::Incomplete,
             "incomplete_first_party_only" => Self::IncompleteFirstPartyOnly,
-            "incomplete_first_party_propprietary_only" => Self::IncompleteFirstPartyProprietaryOnly,
+            "incomplete_first_party_proprietary_only" => Self::IncompleteFirstPartyProprietaryOnly,
             "incomplete_first_party_opensource_only" => Self::IncompleteFirstPartyOpensourceOnly,
             "incomplete_third_party_only" => Self::IncompleteThirdPartyOnly,
             "incomplete_third_party_proprietary_only" => Self::IncompleteThirdPartyProprietaryOnly,
@@ -115,6 +139,23 @@ impl AggregateType {
     }
 }
 
+impl_from_str!(
+    AggregateType,
+    "aggregate type",
+    [
+        AggregateType::Complete,
+        AggregateType::Incomplete,
+        AggregateType::IncompleteFirstPartyOnly,
+        AggregateType::IncompleteFirstPartyProprietaryOnly,
+        AggregateType::IncompleteFirstPartyOpensourceOnly,
+        AggregateType::IncompleteThirdPartyOnly,
+        AggregateType::IncompleteThirdPartyProprietaryOnly,
+        AggregateType::IncompleteThirdPartyOpensourceOnly,
+        AggregateType::Unknown,
+        AggregateType::NotSpecified,
+    ]
+);
+
 #[cfg(test)]
 mod test {
     use crate::{models::signature::Algorithm, validation};
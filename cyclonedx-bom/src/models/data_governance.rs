@@ -27,6 +27,7 @@ use super::{
 };
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DataGovernance {
     pub custodians: Option<Vec<DataGovernanceResponsibleParty>>,
     pub stewards: Option<Vec<DataGovernanceResponsibleParty>>,
@@ -50,6 +51,7 @@ impl Validate for DataGovernance {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum DataGovernanceResponsibleParty {
     Organization(OrganizationalEntity),
     Contact(OrganizationalContact),
@@ -16,11 +16,37 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Dependencies(pub Vec<Dependency>);
 
+impl_collection!(Dependencies, Dependency);
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Dependency {
     pub dependency_ref: String,
     pub dependencies: Vec<String>,
 }
+
+impl Dependency {
+    /// Constructs a `Dependency` of a `bom-ref` on the given `bom-ref`s
+    /// ```
+    /// use cyclonedx_bom::models::dependency::Dependency;
+    ///
+    /// let dependency = Dependency::new("app", ["serde", "regex"]);
+    /// ```
+    pub fn new<I, S>(dependency_ref: impl ToString, dependencies: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        Self {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies
+                .into_iter()
+                .map(|dependency| dependency.to_string())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,410 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Typed vendor extensions stored in the properties of a BOM, component or service.
+//!
+//! An [`Extension`] maps a Rust type to the properties in its namespace, e.g. `acme:build:*`.
+//! Since properties are part of every spec version and both formats, extensions survive
+//! serialization without changes to the schema. An [`ExtensionRegistry`] reads all registered
+//! extensions from a set of properties at once.
+//!
+//! ```
+//! use cyclonedx_bom::errors::ExtensionError;
+//! use cyclonedx_bom::models::extension::{Extension, ExtensionReader, ExtensionRegistry, ExtensionWriter};
+//! use cyclonedx_bom::models::property::Properties;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Build {
+//!     pipeline: String,
+//!     attempt: u64,
+//! }
+//!
+//! impl Extension for Build {
+//!     const NAMESPACE: &'static str = "acme:build";
+//!
+//!     fn write(&self, writer: &mut ExtensionWriter) {
+//!         writer.value("pipeline", &self.pipeline);
+//!         writer.value("attempt", &self.attempt);
+//!     }
+//!
+//!     fn read(reader: &ExtensionReader<'_>) -> Result<Self, ExtensionError> {
+//!         Ok(Self {
+//!             pipeline: reader.value("pipeline")?,
+//!             attempt: reader.value("attempt")?,
+//!         })
+//!     }
+//! }
+//!
+//! let build = Build { pipeline: "release".to_string(), attempt: 2 };
+//! let mut properties = Properties(vec![]);
+//! properties.set_extension(&build);
+//! assert_eq!(properties.get("acme:build:attempt"), Some("2"));
+//!
+//! let registry = ExtensionRegistry::new().register::<Build>();
+//! let extensions = registry.read(&properties).expect("valid extensions");
+//! assert_eq!(extensions.get::<Build>(), Some(&build));
+//! ```
+
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use crate::errors::ExtensionError;
+
+use super::property::{Properties, Property, PropertyValue};
+
+/// A type stored in the properties of a namespace.
+pub trait Extension: Sized + 'static {
+    /// The namespace of the properties, e.g. `acme:build` for `acme:build:*`.
+    const NAMESPACE: &'static str;
+
+    /// Writes the extension as properties in its namespace.
+    fn write(&self, writer: &mut ExtensionWriter);
+
+    /// Reads the extension from the properties in its namespace.
+    fn read(reader: &ExtensionReader<'_>) -> Result<Self, ExtensionError>;
+}
+
+/// Reads the values of the properties in the namespace of an [`Extension`].
+#[derive(Debug)]
+pub struct ExtensionReader<'a> {
+    namespace: &'static str,
+    properties: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> ExtensionReader<'a> {
+    fn new(namespace: &'static str, properties: &'a Properties) -> Self {
+        let properties = properties
+            .namespace(namespace)
+            .map(|property| {
+                (
+                    &property.name[namespace.len() + 1..],
+                    property.value.0.as_str(),
+                )
+            })
+            .collect();
+        Self {
+            namespace,
+            properties,
+        }
+    }
+
+    /// Returns the names of all properties in the namespace, without the namespace prefix.
+    pub fn names(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.properties.iter().map(|(name, _)| *name)
+    }
+
+    /// Returns the parsed value of a required property.
+    pub fn value<T: PropertyValue>(&self, name: &str) -> Result<T, ExtensionError> {
+        self.optional_value(name)?
+            .ok_or_else(|| ExtensionError::MissingProperty {
+                name: self.full_name(name),
+            })
+    }
+
+    /// Returns the parsed value of an optional property.
+    pub fn optional_value<T: PropertyValue>(
+        &self,
+        name: &str,
+    ) -> Result<Option<T>, ExtensionError> {
+        self.values(name).map(|values| values.into_iter().next())
+    }
+
+    /// Returns the parsed values of all properties with the given name.
+    pub fn values<T: PropertyValue>(&self, name: &str) -> Result<Vec<T>, ExtensionError> {
+        self.properties
+            .iter()
+            .filter(|(property, _)| *property == name)
+            .map(|(_, value)| {
+                T::from_property_value(value).ok_or_else(|| ExtensionError::InvalidValue {
+                    name: self.full_name(name),
+                    value: value.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    fn full_name(&self, name: &str) -> String {
+        format!("{}:{name}", self.namespace)
+    }
+}
+
+/// Collects the properties written by an [`Extension`].
+#[derive(Debug)]
+pub struct ExtensionWriter {
+    namespace: &'static str,
+    properties: Vec<Property>,
+}
+
+impl ExtensionWriter {
+    /// Adds a property, the name is prefixed with the namespace of the extension.
+    pub fn value<T: PropertyValue>(&mut self, name: &str, value: &T) {
+        self.properties.push(Property::new(
+            format!("{}:{name}", self.namespace),
+            &value.to_property_value(),
+        ));
+    }
+
+    /// Adds a property for every value.
+    pub fn values<'a, T: PropertyValue + 'a>(
+        &mut self,
+        name: &str,
+        values: impl IntoIterator<Item = &'a T>,
+    ) {
+        for value in values {
+            self.value(name, value);
+        }
+    }
+}
+
+impl Properties {
+    /// Reads an extension, returns `None` if there are no properties in its namespace.
+    pub fn get_extension<T: Extension>(&self) -> Option<Result<T, ExtensionError>> {
+        let reader = ExtensionReader::new(T::NAMESPACE, self);
+        (!reader.properties.is_empty()).then(|| T::read(&reader))
+    }
+
+    /// Writes an extension, replacing all existing properties in its namespace.
+    pub fn set_extension<T: Extension>(&mut self, extension: &T) {
+        self.remove_extension::<T>();
+        let mut writer = ExtensionWriter {
+            namespace: T::NAMESPACE,
+            properties: Vec::new(),
+        };
+        extension.write(&mut writer);
+        self.0.extend(writer.properties);
+    }
+
+    /// Removes all properties in the namespace of an extension.
+    pub fn remove_extension<T: Extension>(&mut self) {
+        let names = self
+            .namespace(T::NAMESPACE)
+            .map(|property| property.name.clone())
+            .collect::<Vec<_>>();
+        self.0.retain(|property| !names.contains(&property.name));
+    }
+}
+
+type ReadFn = fn(&Properties) -> Option<Result<Box<dyn Any>, ExtensionError>>;
+
+fn read_boxed<T: Extension>(
+    properties: &Properties,
+) -> Option<Result<Box<dyn Any>, ExtensionError>> {
+    properties
+        .get_extension::<T>()
+        .map(|result| result.map(|extension| Box::new(extension) as Box<dyn Any>))
+}
+
+/// The extensions known to an application, keyed by namespace.
+#[derive(Clone, Debug, Default)]
+pub struct ExtensionRegistry {
+    handlers: BTreeMap<&'static str, (TypeId, ReadFn)>,
+}
+
+impl ExtensionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an extension, replacing any extension previously registered for its namespace.
+    pub fn register<T: Extension>(mut self) -> Self {
+        self.handlers
+            .insert(T::NAMESPACE, (TypeId::of::<T>(), read_boxed::<T>));
+        self
+    }
+
+    /// Returns `true` if an extension is registered for the namespace.
+    pub fn is_registered(&self, namespace: &str) -> bool {
+        self.handlers.contains_key(namespace)
+    }
+
+    /// Reads all registered extensions present in the properties.
+    ///
+    /// Fails with the first error of an extension whose properties cannot be read.
+    pub fn read(&self, properties: &Properties) -> Result<Extensions, ExtensionError> {
+        let mut extensions = Extensions::default();
+        for (type_id, read) in self.handlers.values() {
+            if let Some(extension) = read(properties) {
+                extensions.values.insert(*type_id, extension?);
+            }
+        }
+        Ok(extensions)
+    }
+}
+
+/// The extensions read by an [`ExtensionRegistry`], looked up by type.
+#[derive(Default)]
+pub struct Extensions {
+    values: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl Extensions {
+    pub fn get<T: Extension>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Build {
+        pipeline: String,
+        attempt: u64,
+        runners: Vec<String>,
+    }
+
+    impl Extension for Build {
+        const NAMESPACE: &'static str = "acme:build";
+
+        fn write(&self, writer: &mut ExtensionWriter) {
+            writer.value("pipeline", &self.pipeline);
+            writer.value("attempt", &self.attempt);
+            writer.values("runner", &self.runners);
+        }
+
+        fn read(reader: &ExtensionReader<'_>) -> Result<Self, ExtensionError> {
+            Ok(Self {
+                pipeline: reader.value("pipeline")?,
+                attempt: reader.value("attempt")?,
+                runners: reader.values("runner")?,
+            })
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Reviewed(bool);
+
+    impl Extension for Reviewed {
+        const NAMESPACE: &'static str = "acme:review";
+
+        fn write(&self, writer: &mut ExtensionWriter) {
+            writer.value("reviewed", &self.0);
+        }
+
+        fn read(reader: &ExtensionReader<'_>) -> Result<Self, ExtensionError> {
+            reader.value("reviewed").map(Self)
+        }
+    }
+
+    fn build() -> Build {
+        Build {
+            pipeline: "release".to_string(),
+            attempt: 2,
+            runners: vec!["linux".to_string(), "macos".to_string()],
+        }
+    }
+
+    #[test]
+    fn it_should_write_and_read_an_extension() {
+        let mut properties = Properties(vec![
+            Property::new("acme:build:stale", "true"),
+            Property::new("other", "value"),
+        ]);
+        properties.set_extension(&build());
+
+        assert_eq!(
+            properties,
+            Properties(vec![
+                Property::new("other", "value"),
+                Property::new("acme:build:pipeline", "release"),
+                Property::new("acme:build:attempt", "2"),
+                Property::new("acme:build:runner", "linux"),
+                Property::new("acme:build:runner", "macos"),
+            ])
+        );
+        assert_eq!(properties.get_extension::<Build>(), Some(Ok(build())));
+        assert_eq!(properties.get_extension::<Reviewed>(), None);
+
+        properties.remove_extension::<Build>();
+        assert_eq!(
+            properties,
+            Properties(vec![Property::new("other", "value")])
+        );
+    }
+
+    #[test]
+    fn it_should_report_missing_and_invalid_values() {
+        let properties = Properties(vec![Property::new("acme:build:attempt", "many")]);
+        assert_eq!(
+            properties.get_extension::<Build>(),
+            Some(Err(ExtensionError::MissingProperty {
+                name: "acme:build:pipeline".to_string()
+            }))
+        );
+
+        let properties = Properties(vec![
+            Property::new("acme:build:pipeline", "release"),
+            Property::new("acme:build:attempt", "many"),
+        ]);
+        assert_eq!(
+            properties.get_extension::<Build>(),
+            Some(Err(ExtensionError::InvalidValue {
+                name: "acme:build:attempt".to_string(),
+                value: "many".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn it_should_read_registered_extensions() {
+        let registry = ExtensionRegistry::new()
+            .register::<Build>()
+            .register::<Reviewed>();
+        assert!(registry.is_registered("acme:review"));
+        assert!(!registry.is_registered("acme"));
+
+        let mut properties = Properties(vec![]);
+        properties.set_extension(&build());
+        let extensions = registry.read(&properties).unwrap();
+
+        assert_eq!(extensions.len(), 1);
+        assert_eq!(extensions.get::<Build>(), Some(&build()));
+        assert_eq!(extensions.get::<Reviewed>(), None);
+
+        properties.set_extension(&Reviewed(true));
+        let extensions = registry.read(&properties).unwrap();
+        assert_eq!(extensions.get::<Reviewed>(), Some(&Reviewed(true)));
+
+        properties.set("acme:review:reviewed", "maybe");
+        assert!(registry.read(&properties).is_err());
+    }
+}
@@ -16,19 +16,20 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use once_cell::sync::Lazy;
-use regex::Regex;
-
+use crate::errors::BuildError;
 use crate::external_models::uri::{validate_uri as validate_url, Uri as Url};
 use crate::models::hash::Hashes;
-use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
+use crate::validation::{
+    validate_built, Validate, ValidationContext, ValidationError, ValidationResult,
+};
 
-use super::bom::SpecVersion;
+use super::bom::{is_lowercase_uuid, SpecVersion, UrnUuid};
 
 /// Represents a way to document systems, sites, and information that may be relevant but which are not included with the BOM.
 ///
 /// Please see the [CycloneDX use case](https://cyclonedx.org/use-cases/#external-references) for more information and examples.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ExternalReference {
     pub external_reference_type: ExternalReferenceType,
     pub url: Uri,
@@ -53,6 +54,58 @@ impl ExternalReference {
             hashes: None,
         }
     }
+
+    /// Returns an [`ExternalReferenceBuilder`] for a reference with the required type and url
+    /// ```
+    /// use cyclonedx_bom::models::external_reference::{ExternalReference, ExternalReferenceType};
+    /// use cyclonedx_bom::external_models::uri::Uri;
+    ///
+    /// let external_reference = ExternalReference::builder(
+    ///     ExternalReferenceType::Vcs,
+    ///     Uri::new("https://github.com/CycloneDX/cyclonedx-rust-cargo"),
+    /// )
+    /// .comment("Source repository")
+    /// .build()
+    /// .expect("valid external reference");
+    /// ```
+    pub fn builder(
+        external_reference_type: ExternalReferenceType,
+        url: impl Into<Uri>,
+    ) -> ExternalReferenceBuilder {
+        ExternalReferenceBuilder {
+            external_reference: Self::new(external_reference_type, url),
+        }
+    }
+}
+
+/// Builder for an [`ExternalReference`], created via [`ExternalReference::builder`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExternalReferenceBuilder {
+    external_reference: ExternalReference,
+}
+
+impl ExternalReferenceBuilder {
+    pub fn comment(mut self, comment: impl ToString) -> Self {
+        self.external_reference.comment = Some(comment.to_string());
+        self
+    }
+
+    pub fn hashes(mut self, hashes: Hashes) -> Self {
+        self.external_reference.hashes = Some(hashes);
+        self
+    }
+
+    /// Builds the [`ExternalReference`], validating it against the latest spec version.
+    ///
+    /// Returns a [`BuildError`] listing all violations if validation fails.
+    pub fn build(self) -> Result<ExternalReference, BuildError> {
+        validate_built("external reference", self.external_reference)
+    }
+
+    /// Builds the [`ExternalReference`] without validating it, e.g. to construct partial documents.
+    pub fn build_unchecked(self) -> ExternalReference {
+        self.external_reference
+    }
 }
 
 impl Validate for ExternalReference {
@@ -72,8 +125,11 @@ impl Validate for ExternalReference {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ExternalReferences(pub Vec<ExternalReference>);
 
+impl_collection!(ExternalReferences, ExternalReference);
+
 impl Validate for ExternalReferences {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -98,6 +154,7 @@ pub fn validate_external_reference_type(
 
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_externalReferenceType).
 #[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum ExternalReferenceType {
     Vcs,
@@ -139,6 +196,7 @@ pub enum ExternalReferenceType {
     ComponentAnalysisReport,
     MaturityReport,
     CertificationReport,
+    #[strum(serialize = "codified-infrastructure")]
     CondifiedInfrastructure,
     QualityMetrics,
     Poam,
@@ -191,7 +249,54 @@ impl ExternalReferenceType {
     }
 }
 
+impl_from_str!(
+    ExternalReferenceType,
+    "external reference type",
+    [
+        ExternalReferenceType::Vcs,
+        ExternalReferenceType::IssueTracker,
+        ExternalReferenceType::Website,
+        ExternalReferenceType::Advisories,
+        ExternalReferenceType::Bom,
+        ExternalReferenceType::MailingList,
+        ExternalReferenceType::Social,
+        ExternalReferenceType::Chat,
+        ExternalReferenceType::Documentation,
+        ExternalReferenceType::Support,
+        ExternalReferenceType::Distribution,
+        ExternalReferenceType::DistributionIntake,
+        ExternalReferenceType::License,
+        ExternalReferenceType::BuildMeta,
+        ExternalReferenceType::BuildSystem,
+        ExternalReferenceType::ReleaseNotes,
+        ExternalReferenceType::SecurityContact,
+        ExternalReferenceType::ModelCard,
+        ExternalReferenceType::Log,
+        ExternalReferenceType::Configuration,
+        ExternalReferenceType::Evidence,
+        ExternalReferenceType::Formulation,
+        ExternalReferenceType::Attestation,
+        ExternalReferenceType::ThreatModel,
+        ExternalReferenceType::AdversaryModel,
+        ExternalReferenceType::RiskAssessment,
+        ExternalReferenceType::VulnerabilityAssertion,
+        ExternalReferenceType::ExploitabilityStatement,
+        ExternalReferenceType::PentestReport,
+        ExternalReferenceType::StaticAnalysisReport,
+        ExternalReferenceType::DynamicAnalysisReport,
+        ExternalReferenceType::RuntimeAnalysisReport,
+        ExternalReferenceType::ComponentAnalysisReport,
+        ExternalReferenceType::MaturityReport,
+        ExternalReferenceType::CertificationReport,
+        ExternalReferenceType::CondifiedInfrastructure,
+        ExternalReferenceType::QualityMetrics,
+        ExternalReferenceType::Poam,
+        ExternalReferenceType::Other,
+    ]
+);
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Uri {
     Url(Url),
     BomLink(BomLink),
@@ -228,22 +333,108 @@ impl std::fmt::Display for Uri {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BomLink(pub String);
 
+impl std::fmt::Display for BomLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl BomLink {
+    /// Constructs a `BomLink` to the given version of the BOM with the serial number
+    /// ```
+    /// use cyclonedx_bom::models::bom::UrnUuid;
+    /// use cyclonedx_bom::models::external_reference::BomLink;
+    ///
+    /// let serial_number = UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap();
+    /// let bom_link = BomLink::new(&serial_number, 2);
+    /// assert_eq!(bom_link.0, "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/2");
+    /// ```
+    pub fn new(serial_number: &UrnUuid, version: u32) -> Self {
+        let uuid = serial_number
+            .0
+            .strip_prefix("urn:uuid:")
+            .unwrap_or(&serial_number.0);
+        Self(format!("urn:cdx:{uuid}/{version}"))
+    }
+
+    /// Returns the serial number of the linked BOM, or `None` if the link is malformed.
+    pub fn serial_number(&self) -> Option<UrnUuid> {
+        let (uuid, _) = self.document()?.split_once('/')?;
+        UrnUuid::new(format!("urn:uuid:{uuid}")).ok()
+    }
+
+    /// Returns the version of the linked BOM, or `None` if the link is malformed.
+    /// ```
+    /// use cyclonedx_bom::models::external_reference::BomLink;
+    ///
+    /// let bom_link = BomLink("urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/2#pkg-a".to_string());
+    /// assert_eq!(bom_link.version(), Some(2));
+    /// assert_eq!(bom_link.fragment(), Some("pkg-a"));
+    /// assert_eq!(
+    ///     bom_link.serial_number().unwrap().to_string(),
+    ///     "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79"
+    /// );
+    /// ```
+    pub fn version(&self) -> Option<u32> {
+        let (_, version) = self.document()?.split_once('/')?;
+        version.parse().ok()
+    }
+
+    /// Returns the link to the BOM itself, without a reference to an element inside it.
+    pub fn document(&self) -> Option<&str> {
+        let link = self.0.strip_prefix("urn:cdx:")?;
+        Some(link.split_once('#').map_or(link, |(document, _)| document))
+    }
+
+    /// Returns the `bom-ref` of the linked element, or `None` if the link refers to the whole BOM.
+    pub fn fragment(&self) -> Option<&str> {
+        self.0
+            .strip_prefix("urn:cdx:")?
+            .split_once('#')
+            .map(|(_, fragment)| fragment)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for BomLink {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(&u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
 fn validate_bom_link(bom_link: &BomLink, version: SpecVersion) -> Result<(), ValidationError> {
     if version < SpecVersion::V1_5 {
         return Err("BOM-Link not supported before version 1.5".into());
     }
 
-    static BOM_LINK_REGEX: Lazy<Regex> = Lazy::new(|| {
-        Regex::new(r"^urn:cdx:[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}/[1-9][0-9]*(#.+)?$").unwrap()
-    });
-
-    if !BOM_LINK_REGEX.is_match(&bom_link.0) {
+    if !matches_bom_link(&bom_link.0) {
         return Err(ValidationError::new("Invalid BOM-Link"));
     }
 
     Ok(())
 }
 
+/// Matches `^urn:cdx:<uuid>/[1-9][0-9]*(#.+)?$`.
+fn matches_bom_link(value: &str) -> bool {
+    let Some((uuid, rest)) = value
+        .strip_prefix("urn:cdx:")
+        .and_then(|value| value.split_once('/'))
+    else {
+        return false;
+    };
+    let (version, fragment) = match rest.split_once('#') {
+        Some((version, fragment)) => (version, Some(fragment)),
+        None => (rest, None),
+    };
+
+    is_lowercase_uuid(uuid)
+        && version.starts_with(|c: char| matches!(c, '1'..='9'))
+        && version.bytes().all(|b| b.is_ascii_digit())
+        && fragment.map_or(true, |fragment| {
+            !fragment.is_empty() && !fragment.contains('\n')
+        })
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -295,6 +486,28 @@ mod test {
             .has_errors());
     }
 
+    #[test]
+    fn it_should_match_bom_links() {
+        for valid in [
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1",
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/10#component-a",
+        ] {
+            assert!(matches_bom_link(valid), "{valid}");
+        }
+        for invalid in [
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7",
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/",
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/0",
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1#",
+            "urn:cdx:f08a6ccd-4dce-4759-bd84-c626675d60a7/1a",
+            "urn:cdx:F08A6CCD-4DCE-4759-BD84-C626675D60A7/1",
+            "urn:cdx:f08a6ccd4dce4759bd84c626675d60a7/1",
+            "urn:uuid:f08a6ccd-4dce-4759-bd84-c626675d60a7/1",
+        ] {
+            assert!(!matches_bom_link(invalid), "{invalid}");
+        }
+    }
+
     #[test]
     fn it_should_pass_validation() {
         let validation_result = ExternalReferences(vec![
@@ -415,4 +628,15 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn it_should_roundtrip_external_reference_types() {
+        let reference_type = ExternalReferenceType::CondifiedInfrastructure;
+
+        assert_eq!(reference_type.to_string(), "codified-infrastructure");
+        assert_eq!(
+            "codified-infrastructure".parse::<ExternalReferenceType>(),
+            Ok(reference_type)
+        );
+    }
 }
@@ -9,7 +9,8 @@ use self::workflow::Workflow;
 
 use super::{bom::BomReference, component::Components, property::Properties, service::Services};
 
-#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Formula {
     pub bom_ref: Option<BomReference>,
     pub components: Option<Components>,
@@ -7,6 +7,7 @@ use crate::{
 use super::{resource_reference::ResourceReference, EnvironmentVar};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Input {
     pub required: RequiredInputField,
     pub source: Option<ResourceReference>,
@@ -39,6 +40,7 @@ impl Validate for Input {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum RequiredInputField {
     Resource(ResourceReference),
     Parameters(Vec<Parameter>),
@@ -47,6 +49,7 @@ pub enum RequiredInputField {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Parameter {
     pub name: Option<String>,
     pub value: Option<String>,
@@ -18,6 +18,7 @@ use self::{
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Workflow {
     pub bom_ref: BomReference,
     pub uid: String,
@@ -81,6 +82,7 @@ impl Validate for Workflow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Task {
     pub bom_ref: BomReference,
     pub uid: String,
@@ -136,6 +138,7 @@ impl Validate for Task {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum TaskType {
     Copy,
@@ -174,6 +177,25 @@ impl TaskType {
     }
 }
 
+impl_from_str!(
+    TaskType,
+    "task type",
+    [
+        TaskType::Copy,
+        TaskType::Clone,
+        TaskType::Lint,
+        TaskType::Scan,
+        TaskType::Merge,
+        TaskType::Build,
+        TaskType::Test,
+        TaskType::Deliver,
+        TaskType::Deploy,
+        TaskType::Release,
+        TaskType::Clean,
+        TaskType::Other,
+    ]
+);
+
 impl Validate for TaskType {
     fn validate_version(
         &self,
@@ -188,6 +210,7 @@ impl Validate for TaskType {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum EnvironmentVar {
     Property { name: String, value: String },
     Value(String),
@@ -7,6 +7,7 @@ use crate::{
 use super::{resource_reference::ResourceReference, EnvironmentVar};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Output {
     pub required: RequiredOutputField,
     pub r#type: Option<Type>,
@@ -41,6 +42,7 @@ impl Validate for Output {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum RequiredOutputField {
     Resource(ResourceReference),
     EnvironmentVars(Vec<EnvironmentVar>),
@@ -48,6 +50,7 @@ pub enum RequiredOutputField {
 }
 
 #[derive(Debug, Clone, strum::Display, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Type {
     Artifact,
@@ -75,6 +78,19 @@ impl Type {
     }
 }
 
+impl_from_str!(
+    Type,
+    "output type",
+    [
+        Type::Artifact,
+        Type::Attestation,
+        Type::Log,
+        Type::Evidence,
+        Type::Metrics,
+        Type::Other,
+    ]
+);
+
 impl Validate for Type {
     fn validate_version(
         &self,
@@ -3,6 +3,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ResourceReference {
     Ref(String),
     ExternalReference(ExternalReference),
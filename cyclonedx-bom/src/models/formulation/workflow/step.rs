@@ -1,6 +1,7 @@
 use crate::{models::property::Properties, prelude::Validate, validation::ValidationContext};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Step {
     pub commands: Option<Vec<Command>>,
     pub description: Option<String>,
@@ -23,6 +24,7 @@ impl Validate for Step {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Command {
     pub executed: Option<String>,
     pub properties: Option<Properties>,
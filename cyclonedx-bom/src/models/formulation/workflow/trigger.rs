@@ -12,6 +12,7 @@ use crate::{
 use super::{input::Input, output::Output, resource_reference::ResourceReference};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Trigger {
     pub bom_ref: BomReference,
     pub uid: String,
@@ -63,6 +64,7 @@ impl Validate for Trigger {
 }
 
 #[derive(Debug, Clone, strum::Display, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Type {
     Manual,
@@ -86,6 +88,12 @@ impl Type {
     }
 }
 
+impl_from_str!(
+    Type,
+    "trigger type",
+    [Type::Manual, Type::Api, Type::Webhook, Type::Scheduled,]
+);
+
 impl Validate for Type {
     fn validate_version(
         &self,
@@ -100,6 +108,7 @@ impl Validate for Type {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Event {
     pub uid: Option<String>,
     pub description: Option<String>,
@@ -130,6 +139,7 @@ impl Validate for Event {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Condition {
     pub description: Option<String>,
     pub expression: Option<String>,
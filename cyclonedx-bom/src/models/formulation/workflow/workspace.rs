@@ -7,6 +7,7 @@ use crate::{
 use super::resource_reference::ResourceReference;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Workspace {
     pub bom_ref: BomReference,
     pub uid: String,
@@ -40,6 +41,7 @@ impl Validate for Workspace {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum AccessMode {
     ReadOnly,
@@ -65,6 +67,18 @@ impl AccessMode {
     }
 }
 
+impl_from_str!(
+    AccessMode,
+    "access mode",
+    [
+        AccessMode::ReadOnly,
+        AccessMode::ReadWrite,
+        AccessMode::ReadWriteOnce,
+        AccessMode::WriteOnce,
+        AccessMode::WriteOnly,
+    ]
+);
+
 pub fn validate_access_mode(access_mode: &AccessMode) -> Result<(), ValidationError> {
     match access_mode {
         AccessMode::UnknownAccessMode(_) => Err(ValidationError::new("Unknown access mode")),
@@ -73,6 +87,7 @@ pub fn validate_access_mode(access_mode: &AccessMode) -> Result<(), ValidationEr
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Volume {
     pub uid: Option<String>,
     pub name: Option<String>,
@@ -93,6 +108,7 @@ impl Validate for Volume {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Mode {
     #[default]
@@ -113,6 +129,8 @@ impl Mode {
     }
 }
 
+impl_from_str!(Mode, "volume mode", [Mode::Filesystem, Mode::Block,]);
+
 pub fn validate_mode(mode: &Mode) -> Result<(), ValidationError> {
     match mode {
         Mode::UnknownMode(_) => Err(ValidationError::new("Unknown mode")),
@@ -27,11 +27,27 @@ use super::bom::SpecVersion;
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashType)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Hash {
     pub alg: HashAlgorithm,
     pub content: HashValue,
 }
 
+impl Hash {
+    /// Constructs a `Hash` from an algorithm and the hex encoded digest
+    /// ```
+    /// use cyclonedx_bom::models::hash::{Hash, HashAlgorithm};
+    ///
+    /// let hash = Hash::new(HashAlgorithm::SHA_256, "a3bf1f3d584747e2569483783ddee45b");
+    /// ```
+    pub fn new(alg: HashAlgorithm, content: &str) -> Self {
+        Self {
+            alg,
+            content: HashValue(content.to_string()),
+        }
+    }
+}
+
 impl Validate for Hash {
     fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -42,8 +58,11 @@ impl Validate for Hash {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Hashes(pub Vec<Hash>);
 
+impl_collection!(Hashes, Hash);
+
 impl Validate for Hashes {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -52,6 +71,163 @@ impl Validate for Hashes {
     }
 }
 
+#[cfg(feature = "hashing")]
+impl Hashes {
+    /// Computes the given digests of a byte stream, reading it only once.
+    ///
+    /// Supported algorithms are SHA-1, SHA-256, SHA-384, SHA-512 and BLAKE3.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::hash::{HashAlgorithm, Hashes};
+    ///
+    /// let hashes = Hashes::from_reader(
+    ///     "hello world".as_bytes(),
+    ///     &[HashAlgorithm::SHA1, HashAlgorithm::SHA_256],
+    /// )?;
+    /// assert_eq!(hashes.0[0].content.0, "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed");
+    /// # Ok::<(), cyclonedx_bom::models::hash::HashError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an algorithm is not supported or reading fails
+    pub fn from_reader<R: std::io::Read>(
+        mut reader: R,
+        algorithms: &[HashAlgorithm],
+    ) -> Result<Self, HashError> {
+        use sha1::Digest;
+
+        enum Hasher {
+            Sha1(sha1::Sha1),
+            Sha256(sha2::Sha256),
+            Sha384(sha2::Sha384),
+            Sha512(sha2::Sha512),
+            Blake3(Box<blake3::Hasher>),
+        }
+
+        let mut hashers = algorithms
+            .iter()
+            .map(|algorithm| {
+                let hasher = match algorithm {
+                    HashAlgorithm::SHA1 => Hasher::Sha1(sha1::Sha1::new()),
+                    HashAlgorithm::SHA_256 => Hasher::Sha256(sha2::Sha256::new()),
+                    HashAlgorithm::SHA_384 => Hasher::Sha384(sha2::Sha384::new()),
+                    HashAlgorithm::SHA_512 => Hasher::Sha512(sha2::Sha512::new()),
+                    HashAlgorithm::BLAKE3 => Hasher::Blake3(Box::default()),
+                    unsupported => {
+                        return Err(HashError::UnsupportedAlgorithm(unsupported.clone()))
+                    }
+                };
+                Ok((algorithm.clone(), hasher))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut buffer = vec![0; 64 * 1024];
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            for (_, hasher) in &mut hashers {
+                match hasher {
+                    Hasher::Sha1(hasher) => hasher.update(&buffer[..read]),
+                    Hasher::Sha256(hasher) => hasher.update(&buffer[..read]),
+                    Hasher::Sha384(hasher) => hasher.update(&buffer[..read]),
+                    Hasher::Sha512(hasher) => hasher.update(&buffer[..read]),
+                    Hasher::Blake3(hasher) => {
+                        hasher.update(&buffer[..read]);
+                    }
+                }
+            }
+        }
+
+        let hashes = hashers
+            .into_iter()
+            .map(|(alg, hasher)| {
+                let content = match hasher {
+                    Hasher::Sha1(hasher) => to_hex(&hasher.finalize()),
+                    Hasher::Sha256(hasher) => to_hex(&hasher.finalize()),
+                    Hasher::Sha384(hasher) => to_hex(&hasher.finalize()),
+                    Hasher::Sha512(hasher) => to_hex(&hasher.finalize()),
+                    Hasher::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+                };
+                Hash {
+                    alg,
+                    content: HashValue(content),
+                }
+            })
+            .collect();
+        Ok(Self(hashes))
+    }
+
+    /// Computes the given digests of a file, see [`Hashes::from_reader`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an algorithm is not supported or the file cannot be read
+    pub fn from_file(
+        path: impl AsRef<std::path::Path>,
+        algorithms: &[HashAlgorithm],
+    ) -> Result<Self, HashError> {
+        let file = std::fs::File::open(path)?;
+        Self::from_reader(file, algorithms)
+    }
+
+    /// Computes the given digests of many files, see [`Hashes::from_file`]. The results are in the
+    /// order of `paths`.
+    ///
+    /// With the `parallel` feature the files are hashed concurrently on the `rayon` thread pool,
+    /// so that hashing many files is not limited by reading them one after another.
+    pub fn from_files<P: AsRef<std::path::Path> + Sync>(
+        paths: &[P],
+        algorithms: &[HashAlgorithm],
+    ) -> Vec<Result<Self, HashError>> {
+        map_concurrently(paths, |path| Self::from_file(path, algorithms))
+    }
+}
+
+/// Maps the items on the `rayon` thread pool if the `parallel` feature is enabled, keeping their
+/// order.
+#[cfg(feature = "hashing")]
+pub(crate) fn map_concurrently<T: Sync, R: Send>(
+    items: &[T],
+    map: impl Fn(&T) -> R + Sync + Send,
+) -> Vec<R> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+
+        items.par_iter().map(map).collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    items.iter().map(map).collect()
+}
+
+#[cfg(feature = "hashing")]
+fn to_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+/// Returned by [`Hashes::from_reader`] and [`Hashes::from_file`]
+#[cfg(feature = "hashing")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum HashError {
+    #[error("Hash algorithm {0} is not supported")]
+    UnsupportedAlgorithm(HashAlgorithm),
+
+    #[error("Failed to read input: {0}")]
+    Io(#[from] std::io::Error),
+}
+
 pub fn validate_hash_algorithm(algorithm: &HashAlgorithm) -> Result<(), ValidationError> {
     if matches!(algorithm, HashAlgorithm::UnknownHashAlgorithm(_)) {
         return Err(ValidationError::new("Unknown HashAlgorithm"));
@@ -64,6 +240,7 @@ pub fn validate_hash_algorithm(algorithm: &HashAlgorithm) -> Result<(), Validati
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashAlg)
 #[allow(non_camel_case_types)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "SCREAMING-KEBAB-CASE")]
 pub enum HashAlgorithm {
     MD5,
@@ -106,6 +283,25 @@ impl HashAlgorithm {
     }
 }
 
+impl_from_str!(
+    HashAlgorithm,
+    "hash algorithm",
+    [
+        HashAlgorithm::MD5,
+        HashAlgorithm::SHA1,
+        HashAlgorithm::SHA_256,
+        HashAlgorithm::SHA_384,
+        HashAlgorithm::SHA_512,
+        HashAlgorithm::SHA3_256,
+        HashAlgorithm::SHA3_384,
+        HashAlgorithm::SHA3_512,
+        HashAlgorithm::BLAKE2b_256,
+        HashAlgorithm::BLAKE2b_384,
+        HashAlgorithm::BLAKE2b_512,
+        HashAlgorithm::BLAKE3,
+    ]
+);
+
 pub fn validate_hash_value(value: &HashValue) -> Result<(), ValidationError> {
     static HASH_VALUE_REGEX: Lazy<Regex> = Lazy::new(|| {
         Regex::new(
@@ -113,7 +309,10 @@ pub fn validate_hash_value(value: &HashValue) -> Result<(), ValidationError> {
         ).expect("Failed to compile regex.")
     });
 
-    if !HASH_VALUE_REGEX.is_match(&value.0) {
+    // Hash values are hex strings of one of the lengths in the regular expression in practice
+    let is_plain_hex = matches!(value.0.len(), 32 | 40 | 64 | 96 | 128)
+        && value.0.bytes().all(|byte| byte.is_ascii_hexdigit());
+    if !is_plain_hex && !HASH_VALUE_REGEX.is_match(&value.0) {
         return Err(ValidationError::new(
             "HashValue does not match regular expression",
         ));
@@ -124,8 +323,15 @@ pub fn validate_hash_value(value: &HashValue) -> Result<(), ValidationError> {
 
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_hashValue)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct HashValue(pub String);
 
+impl std::fmt::Display for HashValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::validation::{self};
@@ -166,4 +372,72 @@ mod test {
             )
         );
     }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn it_should_compute_hashes() {
+        let hashes = Hashes::from_reader(
+            "hello world".as_bytes(),
+            &[
+                HashAlgorithm::SHA1,
+                HashAlgorithm::SHA_256,
+                HashAlgorithm::SHA_512,
+                HashAlgorithm::BLAKE3,
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            hashes
+                .0
+                .iter()
+                .map(|hash| hash.content.0.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed",
+                "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+                "309ecc489c12d6eb4cc40f50c902f2b4d0ed77ee511a7c7a9bcd3ca86d4cd86f989dd35bc5ff499670da34255b45b0cfd830e81f605dcf7dc5542e93ae9cd76f",
+                "d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24",
+            ]
+        );
+        assert!(hashes.validate().passed());
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn it_should_reject_unsupported_algorithms() {
+        let result = Hashes::from_reader("".as_bytes(), &[HashAlgorithm::MD5]);
+
+        assert!(matches!(
+            result,
+            Err(HashError::UnsupportedAlgorithm(HashAlgorithm::MD5))
+        ));
+    }
+
+    #[cfg(feature = "hashing")]
+    #[test]
+    fn it_should_compute_hashes_of_many_files_in_order() {
+        let dir = std::env::temp_dir().join(format!("cyclonedx-bom-hashes-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let paths = (0..8)
+            .map(|index| {
+                let path = dir.join(format!("{index}.txt"));
+                std::fs::write(&path, index.to_string()).unwrap();
+                path
+            })
+            .chain([dir.join("missing.txt")])
+            .collect::<Vec<_>>();
+
+        let hashes = Hashes::from_files(&paths, &[HashAlgorithm::SHA_256]);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(hashes.len(), paths.len());
+        for (index, hashes) in hashes[..8].iter().enumerate() {
+            let expected =
+                Hashes::from_reader(index.to_string().as_bytes(), &[HashAlgorithm::SHA_256])
+                    .unwrap();
+            assert_eq!(hashes.as_ref().unwrap(), &expected);
+        }
+        assert!(matches!(hashes[8], Err(HashError::Io(_))));
+    }
 }
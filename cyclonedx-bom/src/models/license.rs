@@ -19,13 +19,13 @@
 use crate::external_models::normalized_string::validate_normalized_string;
 use crate::external_models::spdx::{validate_spdx_expression, validate_spdx_identifier};
 use crate::external_models::uri::validate_uri;
-use crate::external_models::validate_date_time;
 use crate::external_models::{
     date_time::DateTime,
     normalized_string::NormalizedString,
     spdx::{SpdxExpression, SpdxIdentifier},
     uri::Uri,
 };
+use crate::external_models::{text, validate_date_time};
 use crate::models::{
     attached_text::AttachedText,
     bom::{BomReference, SpecVersion},
@@ -40,6 +40,7 @@ use super::property::Properties;
 /// As defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_licenseChoiceType)
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum LicenseChoice {
     License(License),
     Expression(SpdxExpression),
@@ -82,12 +83,13 @@ impl Validate for LicenseChoice {
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_licenseType)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct License {
     pub bom_ref: Option<BomReference>,
     pub license_identifier: LicenseIdentifier,
     pub text: Option<AttachedText>,
     pub url: Option<Uri>,
-    pub licensing: Option<Licensing>,
+    pub licensing: Option<Box<Licensing>>,
     pub properties: Option<Properties>,
 }
 
@@ -116,7 +118,7 @@ impl License {
     /// let license = License::license_id("LGPL-3.0-or-later");
     /// ```
     pub fn license_id(license: &str) -> Self {
-        let identifier = SpdxIdentifier(license.to_string());
+        let identifier = SpdxIdentifier(text(license.to_string()));
         Self {
             bom_ref: None,
             license_identifier: LicenseIdentifier::SpdxId(identifier),
@@ -126,6 +128,36 @@ impl License {
             properties: None,
         }
     }
+
+    /// Returns the canonical text of the license from the SPDX license list, or `None` if the
+    /// license is not identified by a known SPDX license id.
+    /// ```
+    /// use cyclonedx_bom::models::license::License;
+    ///
+    /// let text = License::license_id("MIT").full_text().expect("SPDX license");
+    /// assert!(text.contains("Permission is hereby granted, free of charge"));
+    /// ```
+    #[cfg(feature = "license-text")]
+    pub fn full_text(&self) -> Option<&'static str> {
+        match &self.license_identifier {
+            LicenseIdentifier::SpdxId(id) => spdx::license_id(&id.0).map(|id| id.text()),
+            LicenseIdentifier::Name(_) => None,
+        }
+    }
+
+    /// Attaches the [`full_text`](Self::full_text) of the license as plain text, replacing any
+    /// existing text. Leaves the license unchanged if its text is not known.
+    #[cfg(feature = "license-text")]
+    pub fn with_full_text(mut self) -> Self {
+        if let Some(text) = self.full_text() {
+            self.text = Some(AttachedText {
+                content_type: Some(NormalizedString::new("text/plain")),
+                encoding: None,
+                content: text.to_string(),
+            });
+        }
+        self
+    }
 }
 
 impl Validate for License {
@@ -134,15 +166,18 @@ impl Validate for License {
             .add_struct("license_identifier", &self.license_identifier, version)
             .add_struct_option("text", self.text.as_ref(), version)
             .add_field_option("url", self.url.as_ref(), validate_uri)
-            .add_struct_option("licensing", self.licensing.as_ref(), version)
+            .add_struct_option("licensing", self.licensing.as_deref(), version)
             .add_struct_option("properties", self.properties.as_ref(), version)
             .into()
     }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Licenses(pub Vec<LicenseChoice>);
 
+impl_collection!(Licenses, LicenseChoice);
+
 impl Validate for Licenses {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         let mut context = ValidationContext::new();
@@ -172,6 +207,102 @@ impl Validate for Licenses {
     }
 }
 
+/// Identifies licenses from their raw text, e.g. the contents of a `LICENSE` file
+///
+/// Wraps an [`askalono`] store, which holds the license texts to compare against.
+#[cfg(feature = "license-detection")]
+pub struct LicenseDetector {
+    store: askalono::Store,
+    threshold: f32,
+}
+
+#[cfg(feature = "license-detection")]
+impl LicenseDetector {
+    /// Matches with a lower score are not reported by [`LicenseDetector::detect`]
+    pub const DEFAULT_THRESHOLD: f32 = 0.8;
+
+    /// Creates a detector from an existing store.
+    pub fn from_store(store: askalono::Store) -> Self {
+        Self {
+            store,
+            threshold: Self::DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Loads a store from a cache file as produced by `askalono`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache cannot be read or decoded
+    pub fn from_cache<R: std::io::Read>(reader: R) -> Result<Self, LicenseDetectionError> {
+        let store = askalono::Store::from_cache(reader)
+            .map_err(|error| LicenseDetectionError::Store(error.into()))?;
+        Ok(Self::from_store(store))
+    }
+
+    /// Loads a store from a directory of the SPDX license list data in JSON format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read
+    pub fn from_spdx_dir(path: impl AsRef<std::path::Path>) -> Result<Self, LicenseDetectionError> {
+        let mut store = askalono::Store::new();
+        store
+            .load_spdx(path.as_ref(), false)
+            .map_err(|error| LicenseDetectionError::Store(error.into()))?;
+        Ok(Self::from_store(store))
+    }
+
+    /// Sets the minimum confidence score between `0.0` and `1.0` a match needs to be reported.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns the best matching license for the given text, if it is confident enough.
+    ///
+    /// License names that are SPDX identifiers are returned as license ids, any other name as a
+    /// named license.
+    pub fn detect(&self, text: &str) -> Option<DetectedLicense> {
+        if self.store.is_empty() {
+            return None;
+        }
+
+        let matched = self.store.analyze(&askalono::TextData::new(text));
+        if matched.score < self.threshold {
+            return None;
+        }
+
+        let license = match spdx::license_id(matched.name) {
+            Some(id) => License::license_id(id.name),
+            None => License::named_license(matched.name),
+        };
+
+        Some(DetectedLicense {
+            license: LicenseChoice::License(license),
+            confidence: matched.score,
+        })
+    }
+}
+
+/// A license found by [`LicenseDetector::detect`]
+#[cfg(feature = "license-detection")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DetectedLicense {
+    pub license: LicenseChoice,
+    /// Similarity to the matched license text, between `0.0` and `1.0`
+    pub confidence: f32,
+}
+
+/// Returned when a [`LicenseDetector`] cannot be created
+#[cfg(feature = "license-detection")]
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum LicenseDetectionError {
+    #[error("Failed to load license store: {0}")]
+    Store(Box<dyn std::error::Error + Send + Sync>),
+}
+
 pub fn validate_license_identifier(identifier: &LicenseIdentifier) -> Result<(), ValidationError> {
     match identifier {
         LicenseIdentifier::Name(name) => validate_normalized_string(name),
@@ -180,6 +311,7 @@ pub fn validate_license_identifier(identifier: &LicenseIdentifier) -> Result<(),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum LicenseIdentifier {
     /// An SPDX license identifier from the list on the [SPDX website](https://spdx.org/licenses/).
     SpdxId(SpdxIdentifier),
@@ -187,6 +319,15 @@ pub enum LicenseIdentifier {
     Name(NormalizedString),
 }
 
+impl std::fmt::Display for LicenseIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LicenseIdentifier::SpdxId(id) => id.fmt(f),
+            LicenseIdentifier::Name(name) => name.fmt(f),
+        }
+    }
+}
+
 impl Validate for LicenseIdentifier {
     fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
         match self {
@@ -204,6 +345,7 @@ impl Validate for LicenseIdentifier {
 ///
 /// For more details see: https://cyclonedx.org/docs/1.5/json/#metadata_licenses_oneOf_i0_items_license_licensing
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Licensing {
     pub alt_ids: Option<Vec<NormalizedString>>,
     pub licensor: Option<LicenseContact>,
@@ -238,6 +380,7 @@ impl Validate for Licensing {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum LicenseContact {
     Organization(OrganizationalEntity),
     Contact(OrganizationalContact),
@@ -261,6 +404,7 @@ fn validate_license_type(license_type: &LicenseType) -> Result<(), ValidationErr
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 #[repr(u16)]
 pub enum LicenseType {
@@ -309,6 +453,29 @@ impl LicenseType {
     }
 }
 
+impl_from_str!(
+    LicenseType,
+    "license type",
+    [
+        LicenseType::Academic,
+        LicenseType::Appliance,
+        LicenseType::ClientAccess,
+        LicenseType::ConcurrentUser,
+        LicenseType::CorePoints,
+        LicenseType::CustomMetric,
+        LicenseType::Device,
+        LicenseType::Evaluation,
+        LicenseType::NamedUser,
+        LicenseType::NodeLocked,
+        LicenseType::Oem,
+        LicenseType::Perpetual,
+        LicenseType::ProcessorPoints,
+        LicenseType::Subscription,
+        LicenseType::User,
+        LicenseType::Other,
+    ]
+);
+
 #[cfg(test)]
 mod test {
     use crate::validation;
@@ -331,7 +498,7 @@ mod test {
         let validation_result = Licenses(vec![LicenseChoice::License(License {
             bom_ref: None,
             license_identifier: LicenseIdentifier::Name(NormalizedString(
-                "spaces and \ttabs".to_string(),
+                "spaces and \ttabs".into(),
             )),
             text: None,
             url: None,
@@ -410,7 +577,7 @@ mod test {
         let validation_result = Licenses(vec![
             LicenseChoice::License(License {
                 bom_ref: None,
-                license_identifier: LicenseIdentifier::Name(NormalizedString("MIT".to_string())),
+                license_identifier: LicenseIdentifier::Name(NormalizedString("MIT".into())),
                 text: None,
                 url: None,
                 licensing: None,
@@ -419,7 +586,7 @@ mod test {
             LicenseChoice::License(License {
                 bom_ref: None,
                 license_identifier: LicenseIdentifier::Name(NormalizedString(
-                    "spaces and \ttabs".to_string(),
+                    "spaces and \ttabs".into(),
                 )),
                 text: None,
                 url: None,
@@ -428,9 +595,7 @@ mod test {
             }),
             LicenseChoice::License(License {
                 bom_ref: None,
-                license_identifier: LicenseIdentifier::SpdxId(SpdxIdentifier(
-                    "Apache=2.0".to_string(),
-                )),
+                license_identifier: LicenseIdentifier::SpdxId(SpdxIdentifier("Apache=2.0".into())),
                 text: None,
                 url: None,
                 licensing: None,
@@ -526,4 +691,84 @@ mod test {
             validation::custom("licenses", ["More than one 'expression' entry found."])
         );
     }
+
+    #[cfg(feature = "license-text")]
+    #[test]
+    fn it_should_attach_the_full_text_of_spdx_licenses() {
+        let license = License::license_id("Apache-2.0").with_full_text();
+        let text = license.text.as_ref().unwrap();
+
+        assert_eq!(text.content_type, Some(NormalizedString::new("text/plain")));
+        assert_eq!(text.encoding, None);
+        assert!(text.content.contains("Apache License"));
+        assert!(license.validate().passed());
+
+        assert_eq!(License::license_id("Unknown-1.0").full_text(), None);
+        assert_eq!(
+            License::named_license("Apache-2.0").with_full_text(),
+            License::named_license("Apache-2.0")
+        );
+    }
+
+    #[cfg(feature = "license-detection")]
+    fn detector() -> LicenseDetector {
+        const MIT: &str =
+            "Permission is hereby granted, free of charge, to any person obtaining a copy \
+            of this software and associated documentation files (the \"Software\"), to deal in the \
+            Software without restriction, including without limitation the rights to use, copy, \
+            modify, merge, publish, distribute, sublicense, and/or sell copies of the Software.";
+        const OTHER: &str = "This is a completely different license that permits nothing at all \
+            and has no words in common with any well known open source license text.";
+
+        let mut store = askalono::Store::new();
+        store.add_license("MIT".to_string(), askalono::TextData::new(MIT));
+        store.add_license("Custom".to_string(), askalono::TextData::new(OTHER));
+        LicenseDetector::from_store(store)
+    }
+
+    #[cfg(feature = "license-detection")]
+    #[test]
+    fn it_should_detect_a_license_from_its_text() {
+        let detected = detector()
+            .detect(
+                "Permission is hereby granted, free of charge, to any person obtaining a copy \
+                of this software and associated documentation files (the \"Software\"), to deal in \
+                the Software without restriction, including without limitation the rights to use, \
+                copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the \
+                Software.",
+            )
+            .unwrap();
+
+        assert_eq!(
+            detected.license,
+            LicenseChoice::License(License::license_id("MIT"))
+        );
+        assert!(detected.confidence > 0.99);
+    }
+
+    #[cfg(feature = "license-detection")]
+    #[test]
+    fn it_should_return_names_that_are_not_spdx_ids_as_named_licenses() {
+        let detected = detector()
+            .detect(
+                "This is a completely different license that permits nothing at all \
+                and has no words in common with any well known open source license text.",
+            )
+            .unwrap();
+
+        assert_eq!(
+            detected.license,
+            LicenseChoice::License(License::named_license("Custom"))
+        );
+    }
+
+    #[cfg(feature = "license-detection")]
+    #[test]
+    fn it_should_not_detect_a_license_below_the_threshold() {
+        assert_eq!(detector().detect("All rights reserved."), None);
+        assert_eq!(
+            LicenseDetector::from_store(askalono::Store::new()).detect("anything"),
+            None
+        );
+    }
 }
@@ -18,16 +18,21 @@
 
 use crate::prelude::NormalizedString;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Lifecycles(pub Vec<Lifecycle>);
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+impl_collection!(Lifecycles, Lifecycle);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Lifecycle {
     Phase(Phase),
     Description(Description),
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Phase {
     Design,
     PreBuild,
@@ -71,8 +76,23 @@ impl Phase {
     }
 }
 
+impl_from_str!(
+    Phase,
+    "lifecycle phase",
+    [
+        Phase::Design,
+        Phase::PreBuild,
+        Phase::Build,
+        Phase::PostBuild,
+        Phase::Operations,
+        Phase::Discovery,
+        Phase::Decommission,
+    ]
+);
+
 /// A description of a `Lifecycle`.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Description {
     pub name: NormalizedString,
     pub description: Option<NormalizedString>,
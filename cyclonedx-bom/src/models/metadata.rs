@@ -18,22 +18,25 @@
 
 use thiserror::Error;
 
+use crate::errors::BuildError;
 use crate::external_models::date_time::{DateTime, DateTimeError};
 use crate::external_models::validate_date_time;
-use crate::models::component::Component;
+use crate::models::component::{Component, Components};
 use crate::models::license::Licenses;
 use crate::models::lifecycle::Lifecycles;
 use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::models::property::Properties;
-use crate::models::tool::Tools;
-use crate::validation::{Validate, ValidationContext, ValidationResult};
+use crate::models::service::Services;
+use crate::models::tool::{Tool, Tools};
+use crate::validation::{validate_built, Validate, ValidationContext, ValidationResult};
 
 use super::bom::SpecVersion;
 
 /// Represents additional information about a BOM
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_metadata)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Metadata {
     pub timestamp: Option<DateTime>,
     pub tools: Option<Tools>,
@@ -67,6 +70,176 @@ impl Metadata {
             Err(e) => Err(MetadataError::InvalidTimestamp(e)),
         }
     }
+
+    /// Adds a tool that was used to create the BOM, unless a tool with the same name and
+    /// version is already registered.
+    ///
+    /// The tools are stored in the form supported by the given spec version: the legacy list
+    /// of tools before 1.5, and a list of components since 1.5. Tools already stored in the
+    /// other form are converted, the vendor of a [`Tool`] becomes the supplier of a component
+    /// and vice versa.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::metadata::Metadata;
+    /// use cyclonedx_bom::models::tool::{Tool, Tools};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut metadata = Metadata::default();
+    /// metadata.register_tool(Tool::new("CycloneDX", "cargo-cyclonedx", "0.5.0"), SpecVersion::V1_5);
+    ///
+    /// assert!(matches!(metadata.tools, Some(Tools::Object { .. })));
+    /// ```
+    pub fn register_tool(&mut self, tool: Tool, spec_version: SpecVersion) {
+        let mut tools = match self.tools.take() {
+            Some(tools) => ToolEntries::from(tools),
+            None => ToolEntries::default(),
+        };
+        let exists = tools
+            .tools
+            .iter()
+            .any(|existing| existing.name == tool.name && existing.version == tool.version);
+        if !exists {
+            tools.tools.push(tool);
+        }
+        self.tools = Some(tools.into_tools(spec_version));
+    }
+
+    /// Registers this library as a tool, see [`Metadata::register_tool`].
+    pub fn register_cyclonedx_bom_tool(&mut self, spec_version: SpecVersion) {
+        self.register_tool(
+            Tool::new(
+                "CycloneDX",
+                env!("CARGO_PKG_NAME"),
+                env!("CARGO_PKG_VERSION"),
+            ),
+            spec_version,
+        );
+    }
+
+    /// Returns a [`MetadataBuilder`] without a timestamp
+    /// ```
+    /// use cyclonedx_bom::external_models::date_time::DateTime;
+    /// use cyclonedx_bom::models::metadata::Metadata;
+    ///
+    /// let metadata = Metadata::builder()
+    ///     .timestamp(DateTime::now().expect("valid timestamp"))
+    ///     .build()
+    ///     .expect("valid metadata");
+    /// ```
+    pub fn builder() -> MetadataBuilder {
+        MetadataBuilder {
+            metadata: Self::default(),
+        }
+    }
+}
+
+/// Builder for a [`Metadata`], created via [`Metadata::builder`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MetadataBuilder {
+    metadata: Metadata,
+}
+
+impl MetadataBuilder {
+    pub fn timestamp(mut self, timestamp: DateTime) -> Self {
+        self.metadata.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn tools(mut self, tools: Tools) -> Self {
+        self.metadata.tools = Some(tools);
+        self
+    }
+
+    pub fn authors(mut self, authors: impl IntoIterator<Item = OrganizationalContact>) -> Self {
+        self.metadata.authors = Some(authors.into_iter().collect());
+        self
+    }
+
+    pub fn component(mut self, component: Component) -> Self {
+        self.metadata.component = Some(component);
+        self
+    }
+
+    pub fn manufacture(mut self, manufacture: OrganizationalEntity) -> Self {
+        self.metadata.manufacture = Some(manufacture);
+        self
+    }
+
+    pub fn supplier(mut self, supplier: OrganizationalEntity) -> Self {
+        self.metadata.supplier = Some(supplier);
+        self
+    }
+
+    pub fn licenses(mut self, licenses: Licenses) -> Self {
+        self.metadata.licenses = Some(licenses);
+        self
+    }
+
+    pub fn properties(mut self, properties: Properties) -> Self {
+        self.metadata.properties = Some(properties);
+        self
+    }
+
+    /// Added in 1.5
+    pub fn lifecycles(mut self, lifecycles: Lifecycles) -> Self {
+        self.metadata.lifecycles = Some(lifecycles);
+        self
+    }
+
+    /// Builds the [`Metadata`], validating it against the latest spec version.
+    ///
+    /// Returns a [`BuildError`] listing all violations if validation fails.
+    pub fn build(self) -> Result<Metadata, BuildError> {
+        validate_built("metadata", self.metadata)
+    }
+
+    /// Builds the [`Metadata`] without validating it, e.g. to construct partial documents.
+    pub fn build_unchecked(self) -> Metadata {
+        self.metadata
+    }
+}
+
+/// The entries of [`Tools`] in a form independent of the spec version.
+#[derive(Default)]
+struct ToolEntries {
+    tools: Vec<Tool>,
+    services: Option<Services>,
+}
+
+impl From<Tools> for ToolEntries {
+    fn from(tools: Tools) -> Self {
+        match tools {
+            Tools::List(tools) => Self {
+                tools,
+                services: None,
+            },
+            Tools::Object {
+                services,
+                components,
+            } => Self {
+                tools: components
+                    .map(|components| components.0.into_iter().map(Tool::from).collect())
+                    .unwrap_or_default(),
+                services,
+            },
+        }
+    }
+}
+
+impl ToolEntries {
+    fn into_tools(self, spec_version: SpecVersion) -> Tools {
+        if spec_version <= SpecVersion::V1_4 {
+            let services = self.services.into_iter().flat_map(|services| services.0);
+            let tools = self.tools.into_iter().chain(services.map(Tool::from));
+            return Tools::List(tools.collect());
+        }
+        Tools::Object {
+            services: self.services,
+            components: Some(Components(
+                self.tools.into_iter().map(Component::from).collect(),
+            )),
+        }
+    }
 }
 
 impl Validate for Metadata {
@@ -93,6 +266,7 @@ impl Validate for Metadata {
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum MetadataError {
     #[error("Invalid timestamp")]
     InvalidTimestamp(#[from] DateTimeError),
@@ -192,7 +366,7 @@ mod test {
         let validation_result = Metadata {
             timestamp: Some(DateTime("invalid date".to_string())),
             tools: Some(Tools::List(vec![Tool {
-                vendor: Some(NormalizedString("invalid\tvendor".to_string())),
+                vendor: Some(NormalizedString("invalid\tvendor".into())),
                 name: None,
                 version: None,
                 hashes: None,
@@ -200,7 +374,7 @@ mod test {
             }])),
             authors: Some(vec![OrganizationalContact {
                 bom_ref: None,
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 email: None,
                 phone: None,
             }]),
@@ -234,13 +408,13 @@ mod test {
             }),
             manufacture: Some(OrganizationalEntity {
                 bom_ref: Some(BomReference::new("Manufacturer")),
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 url: None,
                 contact: None,
             }),
             supplier: Some(OrganizationalEntity {
                 bom_ref: Some(BomReference::new("Supplier")),
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 url: None,
                 contact: None,
             }),
@@ -249,11 +423,11 @@ mod test {
             )])),
             properties: Some(Properties(vec![Property {
                 name: "name".to_string(),
-                value: NormalizedString("invalid\tvalue".to_string()),
+                value: NormalizedString("invalid\tvalue".into()),
             }])),
             lifecycles: Some(Lifecycles(vec![Lifecycle::Description(Description {
                 name: "lifecycle".into(),
-                description: Some(NormalizedString("invalid\tvalue".to_string())),
+                description: Some(NormalizedString("invalid\tvalue".into())),
             })])),
         }
         .validate();
@@ -339,4 +513,78 @@ mod test {
             .into()
         );
     }
+
+    #[test]
+    fn it_should_build_metadata() {
+        let metadata = Metadata::builder()
+            .timestamp(DateTime("1969-06-28T01:20:00.00-04:00".to_string()))
+            .tools(Tools::List(vec![Tool::new("vendor", "name", "1.0")]))
+            .authors([OrganizationalContact::new("name", None)])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            metadata,
+            Metadata {
+                timestamp: Some(DateTime("1969-06-28T01:20:00.00-04:00".to_string())),
+                tools: Some(Tools::List(vec![Tool::new("vendor", "name", "1.0")])),
+                authors: Some(vec![OrganizationalContact::new("name", None)]),
+                ..Metadata::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_register_tools_as_list_before_1_5() {
+        let mut metadata = Metadata::default();
+
+        metadata.register_tool(Tool::new("vendor", "tool", "1.0"), SpecVersion::V1_4);
+        metadata.register_tool(Tool::new("vendor", "tool", "1.0"), SpecVersion::V1_4);
+
+        assert_eq!(
+            metadata.tools,
+            Some(Tools::List(vec![Tool::new("vendor", "tool", "1.0")]))
+        );
+    }
+
+    #[test]
+    fn it_should_convert_tools_to_components_for_1_5() {
+        let mut metadata = Metadata {
+            tools: Some(Tools::List(vec![Tool::new("vendor", "legacy", "1.0")])),
+            ..Metadata::default()
+        };
+
+        metadata.register_tool(Tool::new("vendor", "tool", "2.0"), SpecVersion::V1_5);
+
+        let Some(Tools::Object {
+            components: Some(components),
+            services: None,
+        }) = &metadata.tools
+        else {
+            panic!("expected tools object, got {:?}", metadata.tools);
+        };
+        assert_eq!(
+            components
+                .0
+                .iter()
+                .map(|component| component.name.to_string())
+                .collect::<Vec<_>>(),
+            vec!["legacy", "tool"]
+        );
+        assert_eq!(
+            components.0[0]
+                .supplier
+                .as_ref()
+                .and_then(|s| s.name.clone()),
+            Some(NormalizedString::new("vendor"))
+        );
+        assert!(metadata.validate_version(SpecVersion::V1_5).passed());
+
+        metadata.register_cyclonedx_bom_tool(SpecVersion::V1_4);
+        let Some(Tools::List(tools)) = &metadata.tools else {
+            panic!("expected tools list, got {:?}", metadata.tools);
+        };
+        assert_eq!(tools.len(), 3);
+        assert_eq!(tools[0], Tool::new("vendor", "legacy", "1.0"));
+    }
 }
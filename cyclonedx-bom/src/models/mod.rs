@@ -16,6 +16,116 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+/// Implements a case-insensitive `FromStr` for a spec enum, accepting the `Display` strings of
+/// the given variants and rejecting everything else with a [`ParseEnumError`](crate::errors::ParseEnumError).
+macro_rules! impl_from_str {
+    ($ty:ty, $kind:literal, [$($variant:expr),+ $(,)?]) => {
+        impl std::str::FromStr for $ty {
+            type Err = crate::errors::ParseEnumError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                let variants = [$($variant),+];
+                let expected = variants.iter().map(ToString::to_string).collect::<Vec<_>>();
+                match expected.iter().position(|name| name.eq_ignore_ascii_case(value)) {
+                    Some(index) => Ok(variants[index].clone()),
+                    None => Err(crate::errors::ParseEnumError {
+                        kind: $kind,
+                        value: value.to_string(),
+                        expected,
+                    }),
+                }
+            }
+        }
+    };
+}
+
+/// Implements the `Vec` accessors and the iterator and indexing traits for a collection newtype,
+/// so its items can be used without going through the inner `Vec`.
+macro_rules! impl_collection {
+    ($ty:ident, $item:ty) => {
+        impl $ty {
+            pub fn iter(&self) -> std::slice::Iter<'_, $item> {
+                self.0.iter()
+            }
+
+            pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, $item> {
+                self.0.iter_mut()
+            }
+
+            pub fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            pub fn push(&mut self, item: $item) {
+                self.0.push(item);
+            }
+        }
+
+        impl From<Vec<$item>> for $ty {
+            fn from(items: Vec<$item>) -> Self {
+                Self(items)
+            }
+        }
+
+        impl FromIterator<$item> for $ty {
+            fn from_iter<I: IntoIterator<Item = $item>>(iter: I) -> Self {
+                Self(iter.into_iter().collect())
+            }
+        }
+
+        impl Extend<$item> for $ty {
+            fn extend<I: IntoIterator<Item = $item>>(&mut self, iter: I) {
+                self.0.extend(iter);
+            }
+        }
+
+        impl IntoIterator for $ty {
+            type Item = $item;
+            type IntoIter = std::vec::IntoIter<$item>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $ty {
+            type Item = &'a $item;
+            type IntoIter = std::slice::Iter<'a, $item>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a mut $ty {
+            type Item = &'a mut $item;
+            type IntoIter = std::slice::IterMut<'a, $item>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter_mut()
+            }
+        }
+
+        impl std::ops::Index<usize> for $ty {
+            type Output = $item;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.0[index]
+            }
+        }
+
+        impl std::ops::IndexMut<usize> for $ty {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.0[index]
+            }
+        }
+    };
+}
+
 pub mod advisory;
 pub mod annotation;
 pub mod attached_text;
@@ -27,6 +137,7 @@ pub mod component_data;
 pub mod composition;
 pub mod data_governance;
 pub mod dependency;
+pub mod extension;
 pub mod external_reference;
 pub mod formulation;
 pub mod hash;
@@ -31,6 +31,7 @@ use super::{
 ///
 /// For more details see: https://cyclonedx.org/docs/1.5/json/#metadata_component_modelCard
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModelCard {
     pub bom_ref: Option<BomReference>,
     pub model_parameters: Option<ModelParameters>,
@@ -58,6 +59,7 @@ impl Validate for ModelCard {
 ///
 /// For more details see: https://cyclonedx.org/docs/1.5/json/#metadata_component_modelCard_modelParameters
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModelParameters {
     pub approach: Option<ModelParametersApproach>,
     pub task: Option<String>,
@@ -80,6 +82,7 @@ impl Validate for ModelParameters {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ModelParametersApproach {
     pub approach_type: Option<ApproachType>,
 }
@@ -109,6 +112,7 @@ pub fn validate_approach_type(approach_type: &ApproachType) -> Result<(), Valida
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ApproachType {
     Supervised,
     Unsupervised,
@@ -132,6 +136,18 @@ impl ApproachType {
     }
 }
 
+impl_from_str!(
+    ApproachType,
+    "approach type",
+    [
+        ApproachType::Supervised,
+        ApproachType::Unsupervised,
+        ApproachType::ReinforcementLearning,
+        ApproachType::SemiSupervised,
+        ApproachType::SelfSupervised,
+    ]
+);
+
 impl std::fmt::Display for ApproachType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
@@ -147,8 +163,11 @@ impl std::fmt::Display for ApproachType {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Datasets(pub Vec<Dataset>);
 
+impl_collection!(Datasets, Dataset);
+
 impl Validate for Datasets {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -161,6 +180,7 @@ impl Validate for Datasets {
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Dataset {
     Component(ComponentData),
     Reference(String),
@@ -176,8 +196,11 @@ impl Validate for Dataset {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Inputs(pub Vec<MLParameter>);
 
+impl_collection!(Inputs, MLParameter);
+
 impl Validate for Inputs {
     fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -187,8 +210,11 @@ impl Validate for Inputs {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Outputs(pub Vec<MLParameter>);
 
+impl_collection!(Outputs, MLParameter);
+
 impl Validate for Outputs {
     fn validate_version(&self, _version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -202,6 +228,7 @@ pub fn validate_mlparameter(_parameter: &MLParameter) -> Result<(), ValidationEr
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct MLParameter {
     pub format: Option<String>,
 }
@@ -215,6 +242,7 @@ impl MLParameter {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct QuantitativeAnalysis {
     pub performance_metrics: Option<PerformanceMetrics>,
     pub graphics: Option<GraphicsCollection>,
@@ -234,8 +262,11 @@ impl Validate for QuantitativeAnalysis {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PerformanceMetrics(pub Vec<PerformanceMetric>);
 
+impl_collection!(PerformanceMetrics, PerformanceMetric);
+
 impl Validate for PerformanceMetrics {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -245,6 +276,7 @@ impl Validate for PerformanceMetrics {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct PerformanceMetric {
     pub metric_type: Option<String>,
     pub value: Option<String>,
@@ -259,6 +291,7 @@ impl Validate for PerformanceMetric {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ConfidenceInterval {
     pub lower_bound: Option<String>,
     pub upper_bound: Option<String>,
@@ -266,6 +299,7 @@ pub struct ConfidenceInterval {
 
 /// TODO: implement struct
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Considerations {}
 
 impl Validate for Considerations {
@@ -17,11 +17,12 @@
  */
 
 use crate::{
+    errors::BuildError,
     external_models::{
         normalized_string::{validate_normalized_string, NormalizedString},
         uri::{validate_uri, Uri},
     },
-    validation::{Validate, ValidationContext, ValidationResult},
+    validation::{validate_built, Validate, ValidationContext, ValidationResult},
 };
 
 use super::bom::{validate_bom_ref, BomReference, SpecVersion};
@@ -30,6 +31,7 @@ use super::bom::{validate_bom_ref, BomReference, SpecVersion};
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_organizationalContact)
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct OrganizationalContact {
     pub bom_ref: Option<BomReference>,
     pub name: Option<NormalizedString>,
@@ -71,6 +73,7 @@ impl Validate for OrganizationalContact {
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_organizationalEntity)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct OrganizationalEntity {
     pub bom_ref: Option<BomReference>,
     pub name: Option<NormalizedString>,
@@ -87,6 +90,56 @@ impl OrganizationalEntity {
             contact: None,
         }
     }
+
+    /// Returns an [`OrganizationalEntityBuilder`] for an organization with the given name
+    /// ```
+    /// use cyclonedx_bom::models::organization::{OrganizationalContact, OrganizationalEntity};
+    ///
+    /// let organization = OrganizationalEntity::builder("Example Inc.")
+    ///     .contact([OrganizationalContact::new("Support", Some("support@example.com"))])
+    ///     .build()
+    ///     .expect("valid organization");
+    /// ```
+    pub fn builder(name: &str) -> OrganizationalEntityBuilder {
+        OrganizationalEntityBuilder {
+            organizational_entity: Self::new(name),
+        }
+    }
+}
+
+/// Builder for an [`OrganizationalEntity`], created via [`OrganizationalEntity::builder`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationalEntityBuilder {
+    organizational_entity: OrganizationalEntity,
+}
+
+impl OrganizationalEntityBuilder {
+    pub fn bom_ref(mut self, bom_ref: BomReference) -> Self {
+        self.organizational_entity.bom_ref = Some(bom_ref);
+        self
+    }
+
+    pub fn url(mut self, url: impl IntoIterator<Item = Uri>) -> Self {
+        self.organizational_entity.url = Some(url.into_iter().collect());
+        self
+    }
+
+    pub fn contact(mut self, contact: impl IntoIterator<Item = OrganizationalContact>) -> Self {
+        self.organizational_entity.contact = Some(contact.into_iter().collect());
+        self
+    }
+
+    /// Builds the [`OrganizationalEntity`], validating it against the latest spec version.
+    ///
+    /// Returns a [`BuildError`] listing all violations if validation fails.
+    pub fn build(self) -> Result<OrganizationalEntity, BuildError> {
+        validate_built("organizational entity", self.organizational_entity)
+    }
+
+    /// Builds the [`OrganizationalEntity`] without validating it, e.g. to construct partial documents.
+    pub fn build_unchecked(self) -> OrganizationalEntity {
+        self.organizational_entity
+    }
 }
 
 impl Validate for OrganizationalEntity {
@@ -16,8 +16,16 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::{borrow::Cow, marker::PhantomData};
+
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
 use crate::{
-    external_models::normalized_string::{validate_normalized_string, NormalizedString},
+    external_models::{
+        date_time::DateTime,
+        normalized_string::{validate_normalized_string, NormalizedString},
+        uri::Uri,
+    },
     validation::{Validate, ValidationContext, ValidationResult},
 };
 
@@ -29,8 +37,68 @@ use super::bom::SpecVersion;
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_propertyType). Please see the
 /// [CycloneDX use case](https://cyclonedx.org/use-cases/#properties--name-value-store) for more information and examples.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Properties(pub Vec<Property>);
 
+impl_collection!(Properties, Property);
+
+impl Properties {
+    /// Returns the value of the first property with the given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|property| property.name == name)
+            .map(|property| property.value.0.as_str())
+    }
+
+    /// Sets the value of a property, replacing all existing properties with the same name.
+    pub fn set(&mut self, name: impl ToString, value: &str) {
+        let name = name.to_string();
+        self.remove(&name);
+        self.0.push(Property::new(name, value));
+    }
+
+    /// Removes all properties with the given name.
+    pub fn remove(&mut self, name: &str) {
+        self.0.retain(|property| property.name != name);
+    }
+
+    /// Returns all properties in a namespace, e.g. `cdx:cargo` for `cdx:cargo:*`.
+    pub fn namespace<'a>(&'a self, namespace: &'a str) -> impl Iterator<Item = &'a Property> {
+        self.0.iter().filter(move |property| {
+            property
+                .name
+                .strip_prefix(namespace)
+                .is_some_and(|rest| rest.starts_with(':'))
+        })
+    }
+
+    /// Returns the parsed value of a taxonomy property.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::property::{taxonomy, Properties, Property};
+    ///
+    /// let properties = Properties(vec![Property::new("cdx:reproducible", "true")]);
+    /// assert_eq!(properties.get_value(&taxonomy::REPRODUCIBLE), Some(true));
+    /// ```
+    pub fn get_value<T: PropertyValue>(&self, key: &PropertyKey<T>) -> Option<T> {
+        self.get(key.name()).and_then(T::from_property_value)
+    }
+
+    /// Sets a taxonomy property, replacing all existing properties with the same name.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::property::{taxonomy, Properties};
+    ///
+    /// let mut properties = Properties(vec![]);
+    /// properties.set_value(&taxonomy::REPRODUCIBLE, false);
+    /// assert_eq!(properties.get("cdx:reproducible"), Some("false"));
+    /// ```
+    pub fn set_value<T: PropertyValue>(&mut self, key: &PropertyKey<T>, value: T) {
+        self.set(key.name(), &value.to_property_value());
+    }
+}
+
 impl Validate for Properties {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -45,6 +113,7 @@ impl Validate for Properties {
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_propertyType)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Property {
     pub name: String,
     pub value: NormalizedString,
@@ -63,6 +132,141 @@ impl Property {
             value: NormalizedString::new(value),
         }
     }
+
+    /// Constructs a `Property` with a typed value
+    /// ```
+    /// use cyclonedx_bom::models::property::Property;
+    ///
+    /// let property = Property::with_value("cdx:reproducible", true);
+    /// assert_eq!(property.value.to_string(), "true");
+    /// ```
+    pub fn with_value<T: PropertyValue>(name: impl ToString, value: T) -> Self {
+        Self::new(name, &value.to_property_value())
+    }
+
+    /// Parses the value, returns `None` if it is not a valid `T`.
+    /// ```
+    /// use cyclonedx_bom::models::property::Property;
+    ///
+    /// assert_eq!(Property::new("count", "42").parse_value::<u64>(), Some(42));
+    /// assert_eq!(Property::new("count", "many").parse_value::<u64>(), None);
+    /// ```
+    pub fn parse_value<T: PropertyValue>(&self) -> Option<T> {
+        T::from_property_value(&self.value)
+    }
+}
+
+/// The name of a property with a typed value, see [`taxonomy`] for the registered names.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PropertyKey<T> {
+    name: Cow<'static, str>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> PropertyKey<T> {
+    /// Constructs a key from its full name, e.g. `cdx:reproducible`.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Constructs a key in a namespace, the parts are joined with `:`.
+    /// ```
+    /// use cyclonedx_bom::models::property::PropertyKey;
+    ///
+    /// let key = PropertyKey::<String>::namespaced("cdx:cargo", "profile");
+    /// assert_eq!(key.name(), "cdx:cargo:profile");
+    /// ```
+    pub fn namespaced(namespace: &str, name: &str) -> Self {
+        Self {
+            name: Cow::Owned(format!("{namespace}:{name}")),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A value that can be stored in a property.
+pub trait PropertyValue: Sized {
+    fn to_property_value(&self) -> String;
+
+    /// Returns `None` if the value is not formatted as expected.
+    fn from_property_value(value: &str) -> Option<Self>;
+}
+
+impl PropertyValue for bool {
+    fn to_property_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_property_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
+impl PropertyValue for i64 {
+    fn to_property_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_property_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
+impl PropertyValue for u64 {
+    fn to_property_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_property_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
+/// Timestamps are formatted according to [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339).
+impl PropertyValue for DateTime {
+    fn to_property_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_property_value(value: &str) -> Option<Self> {
+        OffsetDateTime::parse(value, &Rfc3339).ok()?;
+        DateTime::try_from(value.to_string()).ok()
+    }
+}
+
+impl PropertyValue for Uri {
+    fn to_property_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_property_value(value: &str) -> Option<Self> {
+        Uri::try_from(value.to_string()).ok()
+    }
+}
+
+impl PropertyValue for String {
+    fn to_property_value(&self) -> String {
+        self.clone()
+    }
+
+    fn from_property_value(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+/// Names from the [CycloneDX property taxonomy](https://github.com/CycloneDX/cyclonedx-property-taxonomy).
+pub mod taxonomy {
+    use super::PropertyKey;
+
+    /// Whether a component or BOM was built reproducibly.
+    pub const REPRODUCIBLE: PropertyKey<bool> = PropertyKey::new("cdx:reproducible");
 }
 
 impl Validate for Property {
@@ -76,7 +280,7 @@ impl Validate for Property {
 #[cfg(test)]
 mod test {
     use crate::{
-        models::property::{Properties, Property},
+        models::property::{taxonomy, Properties, Property, PropertyKey},
         prelude::NormalizedString,
         validation,
     };
@@ -87,7 +291,7 @@ mod test {
     fn it_should_pass_validation() {
         let validation_result = Properties(vec![Property {
             name: "property name".to_string(),
-            value: NormalizedString("property value".to_string()),
+            value: NormalizedString("property value".into()),
         }])
         .validate();
 
@@ -98,7 +302,7 @@ mod test {
     fn it_should_fail_validation() {
         let validation_result = Properties(vec![Property {
             name: "property name".to_string(),
-            value: NormalizedString("spaces and \ttabs".to_string()),
+            value: NormalizedString("spaces and \ttabs".into()),
         }])
         .validate();
 
@@ -116,4 +320,59 @@ mod test {
             ),
         );
     }
+
+    #[test]
+    fn it_should_get_and_set_taxonomy_properties() {
+        let mut properties = Properties(vec![
+            Property::new("cdx:reproducible", "yes"),
+            Property::new("cdx:cargo:profile", "release"),
+            Property::new("cdx:cargoish", "ignored"),
+        ]);
+
+        assert_eq!(properties.get("cdx:reproducible"), Some("yes"));
+        assert_eq!(properties.get_value(&taxonomy::REPRODUCIBLE), None);
+
+        properties.set_value(&taxonomy::REPRODUCIBLE, true);
+        assert_eq!(properties.get_value(&taxonomy::REPRODUCIBLE), Some(true));
+        assert_eq!(properties.0.len(), 3);
+
+        let profile = PropertyKey::<String>::namespaced("cdx:cargo", "profile");
+        assert_eq!(properties.get_value(&profile), Some("release".to_string()));
+        assert_eq!(
+            properties
+                .namespace("cdx:cargo")
+                .map(|property| property.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["cdx:cargo:profile"]
+        );
+
+        properties.remove("cdx:cargo:profile");
+        assert_eq!(properties.get_value(&profile), None);
+    }
+
+    #[test]
+    fn it_should_parse_typed_property_values() {
+        use crate::external_models::{date_time::DateTime, uri::Uri};
+
+        assert_eq!(Property::new("n", "-3").parse_value::<i64>(), Some(-3));
+        assert_eq!(Property::new("n", "-3").parse_value::<u64>(), None);
+        assert_eq!(Property::new("b", "TRUE").parse_value::<bool>(), None);
+
+        let timestamp = Property::new("t", "2024-08-06T12:30:00Z");
+        assert_eq!(
+            timestamp.parse_value::<DateTime>().map(|t| t.to_string()),
+            Some("2024-08-06T12:30:00Z".to_string())
+        );
+        assert_eq!(
+            Property::new("t", "2024-08-06").parse_value::<DateTime>(),
+            None
+        );
+
+        let url = Uri("https://example.com/".to_string());
+        assert_eq!(
+            Property::with_value("u", url.clone()).parse_value::<Uri>(),
+            Some(url)
+        );
+        assert_eq!(Property::new("u", "not a url").parse_value::<Uri>(), None);
+    }
 }
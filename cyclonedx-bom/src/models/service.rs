@@ -16,6 +16,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::errors::BuildError;
 use crate::external_models::normalized_string::validate_normalized_string;
 use crate::external_models::uri::validate_uri as validate_url;
 use crate::external_models::{normalized_string::NormalizedString, uri::Uri};
@@ -23,7 +24,9 @@ use crate::models::external_reference::ExternalReferences;
 use crate::models::license::Licenses;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
-use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
+use crate::validation::{
+    validate_built, Validate, ValidationContext, ValidationError, ValidationResult,
+};
 
 use super::bom::SpecVersion;
 use super::data_governance::DataGovernance;
@@ -33,6 +36,7 @@ use super::signature::Signature;
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_service)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Service {
     pub bom_ref: Option<String>,
     pub provider: Option<OrganizationalEntity>,
@@ -63,7 +67,7 @@ impl Service {
     /// ```
     pub fn new(name: &str, bom_ref: Option<String>) -> Self {
         Self {
-            name: NormalizedString(name.to_string()),
+            name: NormalizedString::new_unchecked(name.to_string()),
             bom_ref,
             provider: None,
             group: None,
@@ -81,6 +85,115 @@ impl Service {
             trust_zone: None,
         }
     }
+
+    /// Returns a [`ServiceBuilder`] for a service with the required name.
+    /// ```
+    /// use cyclonedx_bom::models::service::Service;
+    ///
+    /// let service = Service::builder("service-x").version("1.0").build().expect("valid service");
+    /// ```
+    pub fn builder(name: &str) -> ServiceBuilder {
+        ServiceBuilder {
+            service: Self::new(name, None),
+        }
+    }
+}
+
+/// Builder for a [`Service`], created via [`Service::builder`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceBuilder {
+    service: Service,
+}
+
+impl ServiceBuilder {
+    pub fn bom_ref(mut self, bom_ref: impl ToString) -> Self {
+        self.service.bom_ref = Some(bom_ref.to_string());
+        self
+    }
+
+    pub fn provider(mut self, provider: OrganizationalEntity) -> Self {
+        self.service.provider = Some(provider);
+        self
+    }
+
+    pub fn group(mut self, group: &str) -> Self {
+        self.service.group = Some(NormalizedString::new(group));
+        self
+    }
+
+    pub fn version(mut self, version: &str) -> Self {
+        self.service.version = Some(NormalizedString::new(version));
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.service.description = Some(NormalizedString::new(description));
+        self
+    }
+
+    pub fn endpoints(mut self, endpoints: impl IntoIterator<Item = Uri>) -> Self {
+        self.service.endpoints = Some(endpoints.into_iter().collect());
+        self
+    }
+
+    pub fn authenticated(mut self, authenticated: bool) -> Self {
+        self.service.authenticated = Some(authenticated);
+        self
+    }
+
+    pub fn x_trust_boundary(mut self, x_trust_boundary: bool) -> Self {
+        self.service.x_trust_boundary = Some(x_trust_boundary);
+        self
+    }
+
+    pub fn data(mut self, data: Data) -> Self {
+        self.service.data = Some(data);
+        self
+    }
+
+    pub fn licenses(mut self, licenses: Licenses) -> Self {
+        self.service.licenses = Some(licenses);
+        self
+    }
+
+    pub fn external_references(mut self, external_references: ExternalReferences) -> Self {
+        self.service.external_references = Some(external_references);
+        self
+    }
+
+    pub fn properties(mut self, properties: Properties) -> Self {
+        self.service.properties = Some(properties);
+        self
+    }
+
+    pub fn services(mut self, services: impl IntoIterator<Item = Service>) -> Self {
+        self.service.services = Some(Services(services.into_iter().collect()));
+        self
+    }
+
+    /// Added in version 1.4
+    pub fn signature(mut self, signature: Signature) -> Self {
+        self.service.signature = Some(signature);
+        self
+    }
+
+    /// Added in version 1.5
+    pub fn trust_zone(mut self, trust_zone: &str) -> Self {
+        self.service.trust_zone = Some(NormalizedString::new(trust_zone));
+        self
+    }
+
+    /// Builds the [`Service`], validating it against the latest spec version.
+    ///
+    /// Returns a [`BuildError`] listing all violations if validation fails.
+    pub fn build(self) -> Result<Service, BuildError> {
+        validate_built("service", self.service)
+    }
+
+    /// Builds the [`Service`] without validating it, e.g. to construct partial documents.
+    pub fn build_unchecked(self) -> Service {
+        self.service
+    }
 }
 
 impl Validate for Service {
@@ -115,12 +228,15 @@ impl Validate for Service {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Services(pub Vec<Service>);
 
+impl_collection!(Services, Service);
+
 impl Validate for Services {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
-            .add_list("inner", &self.0, |service| {
+            .add_list_parallel("inner", &self.0, |service| {
                 service.validate_version(version)
             })
             .into()
@@ -128,6 +244,7 @@ impl Validate for Services {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Data {
     ServiceData(Vec<ServiceData>),
     Classification(Vec<DataClassification>),
@@ -147,6 +264,7 @@ impl Validate for Data {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct ServiceData {
     pub name: Option<NormalizedString>,
     pub description: Option<NormalizedString>,
@@ -184,6 +302,7 @@ pub fn validate_data_flow_type(data_flow_type: &DataFlowType) -> Result<(), Vali
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_dataClassificationType)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct DataClassification {
     pub flow: DataFlowType,
     pub classification: NormalizedString,
@@ -206,6 +325,7 @@ impl Validate for DataClassification {
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.3/xml/#type_dataFlowType)
 #[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum DataFlowType {
     Inbound,
@@ -229,6 +349,17 @@ impl DataFlowType {
     }
 }
 
+impl_from_str!(
+    DataFlowType,
+    "data flow type",
+    [
+        DataFlowType::Inbound,
+        DataFlowType::Outbound,
+        DataFlowType::BiDirectional,
+        DataFlowType::Unknown,
+    ]
+);
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -289,20 +420,20 @@ mod test {
             bom_ref: Some("bom ref".to_string()),
             provider: Some(OrganizationalEntity {
                 bom_ref: None,
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 url: None,
                 contact: None,
             }),
-            group: Some(NormalizedString("invalid\tgroup".to_string())),
-            name: NormalizedString("invalid\tname".to_string()),
-            version: Some(NormalizedString("invalid\tversion".to_string())),
-            description: Some(NormalizedString("invalid\tdescription".to_string())),
+            group: Some(NormalizedString("invalid\tgroup".into())),
+            name: NormalizedString("invalid\tname".into()),
+            version: Some(NormalizedString("invalid\tversion".into())),
+            description: Some(NormalizedString("invalid\tdescription".into())),
             endpoints: Some(vec![Uri("invalid url".to_string())]),
             authenticated: Some(true),
             x_trust_boundary: Some(true),
             data: Some(Data::Classification(vec![DataClassification {
                 flow: DataFlowType::UnknownDataFlow("unknown".to_string()),
-                classification: NormalizedString("invalid\tclassification".to_string()),
+                classification: NormalizedString("invalid\tclassification".into()),
             }])),
             licenses: Some(Licenses(vec![LicenseChoice::Expression(
                 SpdxExpression::new("invalid license"),
@@ -317,7 +448,7 @@ mod test {
             }])),
             properties: Some(Properties(vec![Property {
                 name: "name".to_string(),
-                value: NormalizedString("invalid\tvalue".to_string()),
+                value: NormalizedString("invalid\tvalue".into()),
             }])),
             services: Some(Services(vec![Service::new("invalid\tname", None)])),
             signature: Some(Signature::single(Algorithm::HS512, "abcdefgh")),
@@ -440,4 +571,22 @@ mod test {
             ].into()
         );
     }
+
+    #[test]
+    fn it_should_build_a_service() {
+        let service = Service::builder("service-x")
+            .bom_ref("service-x")
+            .version("1.0")
+            .authenticated(true)
+            .services([Service::new("subservice", None)])
+            .build()
+            .unwrap();
+
+        let mut expected = Service::new("service-x", Some("service-x".to_string()));
+        expected.version = Some(NormalizedString::new("1.0"));
+        expected.authenticated = Some(true);
+        expected.services = Some(Services(vec![Service::new("subservice", None)]));
+
+        assert_eq!(service, expected);
+    }
 }
@@ -23,6 +23,7 @@ use crate::{
 
 /// Enveloped signature in [JSON Signature Format (JSF)](https://cyberphone.github.io/doc/security/jsf.html)
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Signature {
     /// Multiple signatures
     Signers(Vec<Signer>),
@@ -51,6 +52,7 @@ impl Validate for Signature {
 
 /// For now the [`Signer`] struct only holds algorithm and value
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Signer {
     /// Signature algorithm.
     pub algorithm: Algorithm,
@@ -104,6 +106,7 @@ impl Signature {
 
 /// Supported signature algorithms.
 #[derive(Clone, Debug, PartialEq, Eq, strum::Display, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Algorithm {
     RS256,
     RS384,
@@ -151,3 +154,24 @@ impl Algorithm {
         }
     }
 }
+
+impl_from_str!(
+    Algorithm,
+    "signature algorithm",
+    [
+        Algorithm::RS256,
+        Algorithm::RS384,
+        Algorithm::RS512,
+        Algorithm::PS256,
+        Algorithm::PS384,
+        Algorithm::PS512,
+        Algorithm::ES256,
+        Algorithm::ES384,
+        Algorithm::ES512,
+        Algorithm::Ed25519,
+        Algorithm::Ed448,
+        Algorithm::HS256,
+        Algorithm::HS384,
+        Algorithm::HS512,
+    ]
+);
@@ -21,15 +21,17 @@ use crate::models::hash::Hashes;
 use crate::validation::{Validate, ValidationContext, ValidationResult};
 
 use super::bom::SpecVersion;
-use super::component::Components;
+use super::component::{Classification, Component, Components};
 use super::external_reference::ExternalReferences;
-use super::service::Services;
+use super::organization::OrganizationalEntity;
+use super::service::{Service, Services};
 
 /// Defines the creation tool(s)
 ///
 /// In version 1.5 the type of this property changed to
 /// https://cyclonedx.org/docs/1.5/json/#metadata_tools_oneOf_i0_services .
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Tools {
     /// Legacy https://cyclonedx.org/docs/1.4/json/#metadata_tools
     List(Vec<Tool>),
@@ -71,7 +73,8 @@ impl Validate for Tools {
 /// Represents the tool used to create the BOM
 ///
 /// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.3/xml/#type_toolType)
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Tool {
     pub vendor: Option<NormalizedString>,
     pub name: Option<NormalizedString>,
@@ -99,6 +102,50 @@ impl Tool {
     }
 }
 
+impl From<Component> for Tool {
+    /// Converts a 1.5 tool component into a legacy tool, the supplier becomes the vendor
+    fn from(component: Component) -> Self {
+        Self {
+            vendor: component.supplier.and_then(|supplier| supplier.name),
+            name: Some(component.name),
+            version: component.version,
+            hashes: component.hashes,
+            external_references: component.external_references,
+        }
+    }
+}
+
+impl From<Service> for Tool {
+    /// Converts a 1.5 tool service into a legacy tool, the provider becomes the vendor
+    fn from(service: Service) -> Self {
+        Self {
+            vendor: service.provider.and_then(|provider| provider.name),
+            name: Some(service.name),
+            version: service.version,
+            hashes: None,
+            external_references: service.external_references,
+        }
+    }
+}
+
+impl From<Tool> for Component {
+    /// Converts a legacy tool into a 1.5 tool component, the vendor becomes the supplier
+    fn from(tool: Tool) -> Self {
+        let mut component = Component::new(Classification::Application, "", "", None);
+        component.name = tool.name.unwrap_or_else(|| NormalizedString::new(""));
+        component.version = tool.version;
+        component.supplier = tool.vendor.map(|vendor| OrganizationalEntity {
+            bom_ref: None,
+            name: Some(vendor),
+            url: None,
+            contact: None,
+        });
+        component.hashes = tool.hashes;
+        component.external_references = tool.external_references;
+        component
+    }
+}
+
 impl Validate for Tool {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -135,7 +182,7 @@ mod test {
     #[test]
     fn it_should_pass_validation() {
         let validation_result = Tools::List(vec![Tool {
-            vendor: Some(NormalizedString("no_whitespace".to_string())),
+            vendor: Some(NormalizedString("no_whitespace".into())),
             name: None,
             version: None,
             hashes: None,
@@ -149,7 +196,7 @@ mod test {
     #[test]
     fn it_should_fail_validation() {
         let validation_result = Tools::List(vec![Tool {
-            vendor: Some(NormalizedString("spaces and\ttabs".to_string())),
+            vendor: Some(NormalizedString("spaces and\ttabs".into())),
             name: None,
             version: None,
             hashes: None,
@@ -176,14 +223,14 @@ mod test {
     fn it_should_merge_validations_correctly() {
         let validation_result = Tools::List(vec![
             Tool {
-                vendor: Some(NormalizedString("no_whitespace".to_string())),
+                vendor: Some(NormalizedString("no_whitespace".into())),
                 name: None,
                 version: None,
                 hashes: None,
                 external_references: None,
             },
             Tool {
-                vendor: Some(NormalizedString("spaces and\ttabs".to_string())),
+                vendor: Some(NormalizedString("spaces and\ttabs".into())),
                 name: None,
                 version: None,
                 hashes: None,
@@ -191,7 +238,7 @@ mod test {
             },
             Tool {
                 vendor: None,
-                name: Some(NormalizedString("spaces and\ttabs".to_string())),
+                name: Some(NormalizedString("spaces and\ttabs".into())),
                 version: None,
                 hashes: None,
                 external_references: None,
@@ -36,7 +36,8 @@ use super::bom::SpecVersion;
 /// Represents a vulnerability as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilitiesType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Vulnerability {
     pub bom_ref: Option<String>,
     pub id: Option<NormalizedString>,
@@ -141,9 +142,12 @@ impl Validate for Vulnerability {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Vulnerabilities(pub Vec<Vulnerability>);
 
+impl_collection!(Vulnerabilities, Vulnerability);
+
 impl Validate for Vulnerabilities {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -154,7 +158,8 @@ impl Validate for Vulnerabilities {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityProofOfConcept {
     pub reproduction_steps: Option<String>,
     pub environment: Option<String>,
@@ -265,13 +270,13 @@ mod test {
     fn invalid_vulnerabilities_should_fail_validation() {
         let validation_result = Vulnerabilities(vec![Vulnerability {
             bom_ref: Some("bom ref".to_string()),
-            id: Some(NormalizedString("invalid\tid".to_string())),
+            id: Some(NormalizedString("invalid\tid".into())),
             vulnerability_source: Some(VulnerabilitySource {
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 url: Some(Uri("invalid url".to_string())),
             }),
             vulnerability_references: Some(VulnerabilityReferences(vec![VulnerabilityReference {
-                id: NormalizedString("invalid\tid".to_string()),
+                id: NormalizedString("invalid\tid".into()),
                 vulnerability_source: VulnerabilitySource {
                     name: Some(NormalizedString::new("name")),
                     url: Some(Uri("https://example.com".to_string())),
@@ -285,7 +290,7 @@ mod test {
                 score: Score::from_f32(10.0),
                 severity: Some(Severity::UndefinedSeverity("undefined".to_string())),
                 score_method: Some(ScoreMethod::Unknown("other method".to_string())),
-                vector: Some(NormalizedString("invalid\tvector".to_string())),
+                vector: Some(NormalizedString("invalid\tvector".into())),
                 justification: Some("justification".to_string()),
             }])),
             cwes: Some(vec![1, 2, 3]),
@@ -303,7 +308,7 @@ mod test {
                 }]),
             }),
             advisories: Some(Advisories(vec![Advisory {
-                title: Some(NormalizedString("invalid\ttitle".to_string())),
+                title: Some(NormalizedString("invalid\ttitle".into())),
                 url: Uri("invalid url".to_string()),
             }])),
             created: Some(DateTime("Thursday".to_string())),
@@ -331,7 +336,7 @@ mod test {
             vulnerability_targets: None,
             properties: Some(Properties(vec![Property {
                 name: "name".to_string(),
-                value: NormalizedString("invalid\tvalue".to_string()),
+                value: NormalizedString("invalid\tvalue".into()),
             }])),
         }])
         .validate();
@@ -27,7 +27,8 @@ use super::bom::SpecVersion;
 /// Represents a vulnerability's analysis as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityAnalysis {
     pub state: Option<ImpactAnalysisState>,
     pub justification: Option<ImpactAnalysisJustification>,
@@ -102,7 +103,8 @@ pub fn validate_impact_analysis_state(state: &ImpactAnalysisState) -> Result<(),
 /// Specifies a vulnerability's state according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisStateType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "snake_case")]
 pub enum ImpactAnalysisState {
     Resolved,
@@ -130,6 +132,19 @@ impl ImpactAnalysisState {
     }
 }
 
+impl_from_str!(
+    ImpactAnalysisState,
+    "impact analysis state",
+    [
+        ImpactAnalysisState::Resolved,
+        ImpactAnalysisState::ResolvedWithPedigree,
+        ImpactAnalysisState::Exploitable,
+        ImpactAnalysisState::InTriage,
+        ImpactAnalysisState::FalsePositive,
+        ImpactAnalysisState::NotAffected,
+    ]
+);
+
 pub fn validate_impact_analysis_justification(
     justification: &ImpactAnalysisJustification,
 ) -> Result<(), ValidationError> {
@@ -145,7 +160,8 @@ pub fn validate_impact_analysis_justification(
 /// Justifies the vulnerability's state according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisJustificationType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "snake_case")]
 pub enum ImpactAnalysisJustification {
     CodeNotPresent,
@@ -179,6 +195,22 @@ impl ImpactAnalysisJustification {
     }
 }
 
+impl_from_str!(
+    ImpactAnalysisJustification,
+    "impact analysis justification",
+    [
+        ImpactAnalysisJustification::CodeNotPresent,
+        ImpactAnalysisJustification::CodeNotReachable,
+        ImpactAnalysisJustification::RequiresConfiguration,
+        ImpactAnalysisJustification::RequiresDependency,
+        ImpactAnalysisJustification::RequiresEnvironment,
+        ImpactAnalysisJustification::ProtectedByCompiler,
+        ImpactAnalysisJustification::ProtectedAtRuntime,
+        ImpactAnalysisJustification::ProtectedAtPerimeter,
+        ImpactAnalysisJustification::ProtectedByMitigatingControl,
+    ]
+);
+
 pub fn validate_impact_analysis_response(
     response: &ImpactAnalysisResponse,
 ) -> Result<(), ValidationError> {
@@ -191,7 +223,8 @@ pub fn validate_impact_analysis_response(
 /// Provides a response to the vulnerability according to impact analysis.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisResponsesType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "snake_case")]
 pub enum ImpactAnalysisResponse {
     CanNotFix,
@@ -217,6 +250,18 @@ impl ImpactAnalysisResponse {
     }
 }
 
+impl_from_str!(
+    ImpactAnalysisResponse,
+    "impact analysis response",
+    [
+        ImpactAnalysisResponse::CanNotFix,
+        ImpactAnalysisResponse::WillNotFix,
+        ImpactAnalysisResponse::Update,
+        ImpactAnalysisResponse::Rollback,
+        ImpactAnalysisResponse::WorkaroundAvailable,
+    ]
+);
+
 #[cfg(test)]
 mod test {
     use crate::validation;
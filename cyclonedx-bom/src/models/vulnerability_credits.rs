@@ -22,7 +22,8 @@ use crate::validation::{Validate, ValidationContext, ValidationResult};
 use super::bom::SpecVersion;
 
 /// Provides credits to organizations or individuals who contributed to a vulnerability.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityCredits {
     pub organizations: Option<Vec<OrganizationalEntity>>,
     pub individuals: Option<Vec<OrganizationalContact>>,
@@ -70,7 +71,7 @@ mod test {
             organizations: Some(vec![OrganizationalEntity::new("invalid\tname")]),
             individuals: Some(vec![OrganizationalContact {
                 bom_ref: None,
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 email: None,
                 phone: None,
             }]),
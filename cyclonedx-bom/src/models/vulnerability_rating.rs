@@ -27,7 +27,8 @@ use super::bom::SpecVersion;
 /// Represents a vulnerability's rating as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_ratingType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityRating {
     pub vulnerability_source: Option<VulnerabilitySource>,
     pub score: Option<Score>,
@@ -78,9 +79,12 @@ impl Validate for VulnerabilityRating {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityRatings(pub Vec<VulnerabilityRating>);
 
+impl_collection!(VulnerabilityRatings, VulnerabilityRating);
+
 impl Validate for VulnerabilityRatings {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -96,7 +100,8 @@ impl Validate for VulnerabilityRatings {
 /// convert a f32 into i32 because OWASP's scoring method uses up to three decimal places.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_ratingType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Score(OrderedFloat<f32>);
 
 impl Score {
@@ -125,6 +130,12 @@ impl From<Score> for f32 {
     }
 }
 
+impl std::fmt::Display for Score {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 pub fn validate_severity(severity: &Severity) -> Result<(), ValidationError> {
     if matches!(severity, Severity::UndefinedSeverity(_)) {
         return Err("Undefined severity".into());
@@ -135,7 +146,8 @@ pub fn validate_severity(severity: &Severity) -> Result<(), ValidationError> {
 /// Specifies a vulnerability's severity adopted by the analysis method.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_severityType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Severity {
     Critical,
@@ -165,6 +177,20 @@ impl Severity {
     }
 }
 
+impl_from_str!(
+    Severity,
+    "severity",
+    [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+        Severity::Info,
+        Severity::None,
+        Severity::Unknown,
+    ]
+);
+
 pub fn validate_score_method(
     method: &ScoreMethod,
     version: SpecVersion,
@@ -184,7 +210,8 @@ pub fn validate_score_method(
 /// Specifies the risk scoring method or standard used.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_scoreSourceType)
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(u16)]
 pub enum ScoreMethod {
     CVSSv2 = 1,
@@ -213,6 +240,19 @@ impl ScoreMethod {
     }
 }
 
+impl_from_str!(
+    ScoreMethod,
+    "score method",
+    [
+        ScoreMethod::CVSSv2,
+        ScoreMethod::CVSSv3,
+        ScoreMethod::CVSSv31,
+        ScoreMethod::CVSSv4,
+        ScoreMethod::OWASP,
+        ScoreMethod::SSVC,
+    ]
+);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -260,13 +300,13 @@ mod test {
     fn invalid_vulnerability_ratings_should_fail_validation() {
         let validation_result = VulnerabilityRatings(vec![VulnerabilityRating {
             vulnerability_source: Some(VulnerabilitySource {
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 url: Some(Uri("invalid url".to_string())),
             }),
             score: None,
             severity: Some(Severity::UndefinedSeverity("undefined".to_string())),
             score_method: None,
-            vector: Some(NormalizedString("invalid\tvector".to_string())),
+            vector: Some(NormalizedString("invalid\tvector".into())),
             justification: None,
         }])
         .validate();
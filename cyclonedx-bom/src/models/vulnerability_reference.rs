@@ -26,7 +26,8 @@ use super::bom::SpecVersion;
 /// to correlate vulnerabilities across multiple sources of vulnerability intelligence.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityReference {
     pub id: NormalizedString,
     pub vulnerability_source: VulnerabilitySource,
@@ -62,9 +63,12 @@ impl Validate for VulnerabilityReference {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityReferences(pub Vec<VulnerabilityReference>);
 
+impl_collection!(VulnerabilityReferences, VulnerabilityReference);
+
 impl Validate for VulnerabilityReferences {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -103,9 +107,9 @@ mod test {
     #[test]
     fn invalid_vulnerability_references_should_fail_validation() {
         let validation_result = VulnerabilityReferences(vec![VulnerabilityReference {
-            id: NormalizedString("invalid\tid".to_string()),
+            id: NormalizedString("invalid\tid".into()),
             vulnerability_source: VulnerabilitySource {
-                name: Some(NormalizedString("invalid\tname".to_string())),
+                name: Some(NormalizedString("invalid\tname".into())),
                 url: Some(Uri("invalid url".to_string())),
             },
         }])
@@ -26,7 +26,8 @@ use super::bom::SpecVersion;
 /// Defines a source related to the vulnerability, e.g. who published or calculated the severity or risk rating the vulnerability.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilitySourceType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilitySource {
     pub name: Option<NormalizedString>,
     pub url: Option<Uri>,
@@ -84,7 +85,7 @@ mod test {
     #[test]
     fn invalid_vulnerability_source_should_fail_validation() {
         let validation_result = VulnerabilitySource {
-            name: Some(NormalizedString("invalid\tname".to_string())),
+            name: Some(NormalizedString("invalid\tname".into())),
             url: Some(Uri("invalid url".to_string())),
         }
         .validate();
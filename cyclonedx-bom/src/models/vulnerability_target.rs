@@ -16,9 +16,6 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use once_cell::sync::Lazy;
-use regex::Regex;
-
 use crate::external_models::normalized_string::NormalizedString;
 use crate::validation::{Validate, ValidationContext, ValidationError, ValidationResult};
 
@@ -27,7 +24,8 @@ use super::bom::SpecVersion;
 /// Defines how a component or service is affected by a vulnerability as described in the [CycloneDX use cases](https://cyclonedx.org/use-cases/#vulnerability-exploitability)
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_vulnerabilityType)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityTarget {
     pub bom_ref: String,
     pub versions: Option<Versions>,
@@ -56,9 +54,12 @@ impl Validate for VulnerabilityTarget {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct VulnerabilityTargets(pub Vec<VulnerabilityTarget>);
 
+impl_collection!(VulnerabilityTargets, VulnerabilityTarget);
+
 impl Validate for VulnerabilityTargets {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -67,9 +68,12 @@ impl Validate for VulnerabilityTargets {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Versions(pub Vec<Version>);
 
+impl_collection!(Versions, Version);
+
 impl Validate for Versions {
     fn validate_version(&self, version: SpecVersion) -> ValidationResult {
         ValidationContext::new()
@@ -78,7 +82,8 @@ impl Validate for Versions {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Version {
     pub version_range: VersionRange,
     pub status: Status,
@@ -119,7 +124,8 @@ pub fn validate_version_range(range: &VersionRange) -> Result<(), ValidationErro
 ///
 /// Defined via the [PURL specification](https://github.com/package-url/purl-spec/blob/master/PURL-SPECIFICATION.rst)
 /// Spec for version ranges still work in progress [PURL version-range-spec](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum VersionRange {
     #[strum(default)]
     Version(NormalizedString),
@@ -140,10 +146,7 @@ impl VersionRange {
 }
 
 fn matches_purl_version_range_regex(value: &str) -> bool {
-    static PURL_VERSION_RANGE_REGEX: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"^vers:.*$").expect("Failed to compile regex."));
-
-    PURL_VERSION_RANGE_REGEX.is_match(value)
+    value.starts_with("vers:") && !value.contains('\n')
 }
 
 pub fn validate_status(status: &Status) -> Result<(), ValidationError> {
@@ -156,7 +159,8 @@ pub fn validate_status(status: &Status) -> Result<(), ValidationError> {
 /// Specifies if a vulnerability affects a component or service.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisAffectedStatusType)
-#[derive(Clone, Debug, PartialEq, Eq, strum::Display)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, strum::Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum(serialize_all = "kebab-case")]
 pub enum Status {
     Affected,
@@ -178,6 +182,12 @@ impl Status {
     }
 }
 
+impl_from_str!(
+    Status,
+    "status",
+    [Status::Affected, Status::Unaffected, Status::Unknown,]
+);
+
 #[cfg(test)]
 mod test {
     use crate::validation;
@@ -188,7 +198,7 @@ mod test {
     #[test]
     fn valid_version_range() {
         assert_eq!(
-            VersionRange::Version(NormalizedString("1.0".to_string())),
+            VersionRange::Version(NormalizedString("1.0".into())),
             Version::new("1.0", "unaffected").version_range,
         );
     }
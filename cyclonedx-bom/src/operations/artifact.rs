@@ -0,0 +1,390 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::models::{
+    bom::Bom,
+    component::Component,
+    hash::{map_concurrently, Hash, HashAlgorithm, HashError, Hashes},
+};
+
+/// The algorithms [`Hashes::from_reader`] can compute.
+const SUPPORTED_ALGORITHMS: [HashAlgorithm; 5] = [
+    HashAlgorithm::SHA1,
+    HashAlgorithm::SHA_256,
+    HashAlgorithm::SHA_384,
+    HashAlgorithm::SHA_512,
+    HashAlgorithm::BLAKE3,
+];
+
+/// Finds the local artifact described by a component, see [`Bom::verify_hashes`].
+pub trait ArtifactLocator {
+    fn locate(&mut self, component: &Component) -> Option<PathBuf>;
+}
+
+impl<F> ArtifactLocator for F
+where
+    F: FnMut(&Component) -> Option<PathBuf>,
+{
+    fn locate(&mut self, component: &Component) -> Option<PathBuf> {
+        self(component)
+    }
+}
+
+/// Maps the `bom-ref` of a component to its artifact.
+impl<P: AsRef<Path>> ArtifactLocator for HashMap<String, P> {
+    fn locate(&mut self, component: &Component) -> Option<PathBuf> {
+        component
+            .bom_ref
+            .as_ref()
+            .and_then(|bom_ref| self.get(bom_ref))
+            .map(|path| path.as_ref().to_path_buf())
+    }
+}
+
+/// The result of [`Bom::verify_hashes`] for a single component.
+#[derive(Debug)]
+pub struct ArtifactVerification {
+    pub bom_ref: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+    pub status: VerificationStatus,
+}
+
+#[derive(Debug)]
+pub enum VerificationStatus {
+    /// All supported hashes of the component match its artifact.
+    Verified { path: PathBuf },
+    /// At least one hash of the component does not match its artifact.
+    Mismatch {
+        path: PathBuf,
+        mismatches: Vec<HashMismatch>,
+    },
+    /// No artifact was located for the component.
+    MissingArtifact,
+    /// The component only has hashes of algorithms that cannot be computed.
+    UnsupportedAlgorithms,
+    /// The artifact could not be read.
+    Unreadable { path: PathBuf, error: HashError },
+}
+
+/// A hash that differs between the BOM and the artifact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashMismatch {
+    pub alg: HashAlgorithm,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// The per component results of [`Bom::verify_hashes`].
+#[derive(Debug, Default)]
+pub struct HashVerification {
+    pub components: Vec<ArtifactVerification>,
+}
+
+impl HashVerification {
+    /// Returns `true` if the artifacts of all components with hashes were found and matched.
+    pub fn is_verified(&self) -> bool {
+        self.components
+            .iter()
+            .all(|component| matches!(component.status, VerificationStatus::Verified { .. }))
+    }
+
+    /// Returns the components whose artifacts do not match their hashes.
+    pub fn mismatches(&self) -> impl Iterator<Item = &ArtifactVerification> {
+        self.components
+            .iter()
+            .filter(|component| matches!(component.status, VerificationStatus::Mismatch { .. }))
+    }
+}
+
+impl Bom {
+    /// Recomputes the hashes of the artifacts described by the components and reports
+    /// mismatches, to verify that the BOM describes the artifacts at hand.
+    ///
+    /// Every component with hashes is checked, including the metadata component and nested
+    /// components. The `locator` finds the artifact of a component, e.g. a map from `bom-ref` to
+    /// path or a closure. Hashes are compared case-insensitively, hashes of algorithms other
+    /// than SHA-1, SHA-256, SHA-384, SHA-512 and BLAKE3 are ignored.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    ///
+    /// use cyclonedx_bom::models::component::Component;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::parse_from_json(std::fs::File::open("bom.json")?)?;
+    /// let dist = Path::new("dist");
+    ///
+    /// let verification = bom.verify_hashes(|component: &Component| {
+    ///     Some(dist.join(component.name.to_string()))
+    /// });
+    /// for component in verification.mismatches() {
+    ///     println!("{} does not match its artifact", component.name);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn verify_hashes(&self, mut locator: impl ArtifactLocator) -> HashVerification {
+        let components = components_with_hashes(self);
+
+        // Artifacts are located one after another, as the locator may be stateful, and hashed
+        // concurrently with the `parallel` feature
+        let mut statuses = Vec::with_capacity(components.len());
+        let mut checks = Vec::new();
+        for component in &components {
+            match locate_artifact(component, &mut locator) {
+                Ok(check) => {
+                    checks.push(check);
+                    statuses.push(None);
+                }
+                Err(status) => statuses.push(Some(status)),
+            }
+        }
+        let mut checked = map_concurrently(&checks, ArtifactCheck::run).into_iter();
+
+        let components = components
+            .into_iter()
+            .zip(statuses)
+            .map(|(component, status)| ArtifactVerification {
+                bom_ref: component.bom_ref.clone(),
+                name: component.name.to_string(),
+                version: component.version.as_ref().map(ToString::to_string),
+                status: status.unwrap_or_else(|| checked.next().expect("every check has a result")),
+            })
+            .collect();
+        HashVerification { components }
+    }
+}
+
+fn components_with_hashes(bom: &Bom) -> Vec<&Component> {
+    fn collect<'a>(component: &'a Component, components: &mut Vec<&'a Component>) {
+        if component
+            .hashes
+            .as_ref()
+            .is_some_and(|hashes| !hashes.is_empty())
+        {
+            components.push(component);
+        }
+        for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+            collect(subcomponent, components);
+        }
+    }
+
+    let mut components = Vec::new();
+    if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+        collect(component, &mut components);
+    }
+    for component in bom.components.iter().flat_map(|c| c.0.iter()) {
+        collect(component, &mut components);
+    }
+    components
+}
+
+/// A located artifact whose hashes remain to be computed.
+struct ArtifactCheck<'a> {
+    path: PathBuf,
+    expected: Vec<&'a Hash>,
+    algorithms: Vec<HashAlgorithm>,
+}
+
+/// Locates the artifact of a component, or returns the status if there is nothing to hash.
+fn locate_artifact<'a>(
+    component: &'a Component,
+    locator: &mut impl ArtifactLocator,
+) -> Result<ArtifactCheck<'a>, VerificationStatus> {
+    let expected = component
+        .hashes
+        .iter()
+        .flat_map(|hashes| hashes.0.iter())
+        .filter(|hash| SUPPORTED_ALGORITHMS.contains(&hash.alg))
+        .collect::<Vec<_>>();
+    if expected.is_empty() {
+        return Err(VerificationStatus::UnsupportedAlgorithms);
+    }
+    let Some(path) = locator.locate(component) else {
+        return Err(VerificationStatus::MissingArtifact);
+    };
+
+    let mut algorithms = expected
+        .iter()
+        .map(|hash| hash.alg.clone())
+        .collect::<Vec<_>>();
+    algorithms.dedup();
+    Ok(ArtifactCheck {
+        path,
+        expected,
+        algorithms,
+    })
+}
+
+impl ArtifactCheck<'_> {
+    fn run(&self) -> VerificationStatus {
+        let path = self.path.clone();
+        let actual = match Hashes::from_file(&path, &self.algorithms) {
+            Ok(actual) => actual,
+            Err(error) => return VerificationStatus::Unreadable { path, error },
+        };
+
+        let mismatches = self
+            .expected
+            .iter()
+            .filter_map(|hash| {
+                let actual = actual.0.iter().find(|actual| actual.alg == hash.alg)?;
+                (!actual.content.0.eq_ignore_ascii_case(&hash.content.0)).then(|| HashMismatch {
+                    alg: hash.alg.clone(),
+                    expected: hash.content.0.clone(),
+                    actual: actual.content.0.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+        if mismatches.is_empty() {
+            VerificationStatus::Verified { path }
+        } else {
+            VerificationStatus::Mismatch { path, mismatches }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{
+        component::{Classification, Components},
+        hash::Hash,
+    };
+
+    const HELLO_SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+    fn component(name: &str, hashes: Vec<Hash>) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(name.to_string()),
+        );
+        component.hashes = Some(Hashes(hashes));
+        component
+    }
+
+    #[test]
+    fn it_should_verify_hashes_of_artifacts() {
+        let dir =
+            std::env::temp_dir().join(format!("cyclonedx-bom-artifact-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let artifact = dir.join("hello.txt");
+        std::fs::write(&artifact, "hello world").unwrap();
+
+        let mut parent = component(
+            "verified",
+            vec![Hash::new(
+                HashAlgorithm::SHA_256,
+                &HELLO_SHA256.to_uppercase(),
+            )],
+        );
+        parent.components = Some(Components(vec![component(
+            "mismatch",
+            vec![Hash::new(HashAlgorithm::SHA_256, &"0".repeat(64))],
+        )]));
+        let bom = Bom::builder()
+            .component(parent)
+            .component(component(
+                "md5-only",
+                vec![Hash::new(HashAlgorithm::MD5, &"0".repeat(32))],
+            ))
+            .component(component(
+                "missing",
+                vec![Hash::new(HashAlgorithm::SHA_256, HELLO_SHA256)],
+            ))
+            .component(Component::new(
+                Classification::Library,
+                "unhashed",
+                "1.0.0",
+                None,
+            ))
+            .build()
+            .unwrap();
+        let artifacts = HashMap::from([
+            ("verified".to_string(), &artifact),
+            ("mismatch".to_string(), &artifact),
+            ("md5-only".to_string(), &artifact),
+        ]);
+
+        let verification = bom.verify_hashes(artifacts);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(!verification.is_verified());
+        let statuses = verification
+            .components
+            .iter()
+            .map(|component| (component.name.as_str(), &component.status))
+            .collect::<Vec<_>>();
+        assert_eq!(statuses.len(), 4);
+        assert!(
+            matches!(statuses[0], ("verified", VerificationStatus::Verified { path }) if *path == artifact)
+        );
+        assert!(matches!(
+            statuses[2],
+            ("md5-only", VerificationStatus::UnsupportedAlgorithms)
+        ));
+        assert!(matches!(
+            statuses[3],
+            ("missing", VerificationStatus::MissingArtifact)
+        ));
+
+        let mismatches = verification.mismatches().collect::<Vec<_>>();
+        assert_eq!(mismatches.len(), 1);
+        let VerificationStatus::Mismatch { mismatches, .. } = &mismatches[0].status else {
+            unreachable!()
+        };
+        assert_eq!(
+            mismatches,
+            &vec![HashMismatch {
+                alg: HashAlgorithm::SHA_256,
+                expected: "0".repeat(64),
+                actual: HELLO_SHA256.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_report_unreadable_artifacts() {
+        let bom = Bom::builder()
+            .component(component(
+                "a",
+                vec![Hash::new(HashAlgorithm::SHA_256, HELLO_SHA256)],
+            ))
+            .build()
+            .unwrap();
+
+        let verification = bom.verify_hashes(|_: &Component| Some(PathBuf::from("does/not/exist")));
+
+        assert!(matches!(
+            verification.components[0].status,
+            VerificationStatus::Unreadable {
+                error: HashError::Io(_),
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,249 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::HashSet;
+
+use crate::models::{bom::Bom, component::Component, service::Service};
+
+/// How [`Bom::assign_bom_refs`] derives new `bom-ref`s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BomRefStrategy {
+    /// Use the package URL of a component, falling back to [`BomRefStrategy::ContentHash`]
+    /// for components without one and for services.
+    #[default]
+    Purl,
+    /// Use a hash of the type, group, name and version, which is stable across runs.
+    ContentHash,
+    /// Use a random UUID.
+    Uuid,
+}
+
+impl Bom {
+    /// Assigns a `bom-ref` to every component and service that lacks one, including the
+    /// metadata component and nested elements.
+    ///
+    /// Derived `bom-ref`s that collide with existing ones get a numeric suffix, so all
+    /// `bom-ref`s of the BOM stay unique. Existing `bom-ref`s are not changed.
+    ///
+    /// Returns the number of assigned `bom-ref`s.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::operations::bom_ref::BomRefStrategy;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut component = Component::new(Classification::Library, "serde", "1.0.0", None);
+    /// component.purl = Some(Purl::new("cargo", "serde", "1.0.0")?);
+    /// let mut bom = Bom::builder().component(component).build().expect("valid BOM");
+    ///
+    /// assert_eq!(bom.assign_bom_refs(BomRefStrategy::Purl), 1);
+    /// assert_eq!(
+    ///     bom.components.unwrap().0[0].bom_ref.as_deref(),
+    ///     Some("pkg:cargo/serde@1.0.0")
+    /// );
+    /// # Ok::<(), cyclonedx_bom::external_models::uri::UriError>(())
+    /// ```
+    pub fn assign_bom_refs(&mut self, strategy: BomRefStrategy) -> usize {
+        let mut existing = HashSet::new();
+        for component in components(self) {
+            collect_component_refs(component, &mut existing);
+        }
+        for service in self.services.iter().flat_map(|s| s.0.iter()) {
+            collect_service_refs(service, &mut existing);
+        }
+
+        let mut assigner = Assigner {
+            strategy,
+            existing,
+            assigned: 0,
+        };
+        if let Some(component) = self.metadata.as_mut().and_then(|m| m.component.as_mut()) {
+            assigner.component(component);
+        }
+        for component in self.components.iter_mut().flat_map(|c| c.0.iter_mut()) {
+            assigner.component(component);
+        }
+        for service in self.services.iter_mut().flat_map(|s| s.0.iter_mut()) {
+            assigner.service(service);
+        }
+        assigner.assigned
+    }
+}
+
+fn components(bom: &Bom) -> impl Iterator<Item = &Component> {
+    bom.metadata
+        .iter()
+        .filter_map(|metadata| metadata.component.as_ref())
+        .chain(bom.components.iter().flat_map(|c| c.0.iter()))
+}
+
+fn collect_component_refs(component: &Component, refs: &mut HashSet<String>) {
+    refs.extend(component.bom_ref.clone());
+    for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+        collect_component_refs(subcomponent, refs);
+    }
+}
+
+fn collect_service_refs(service: &Service, refs: &mut HashSet<String>) {
+    refs.extend(service.bom_ref.clone());
+    for subservice in service.services.iter().flat_map(|s| s.0.iter()) {
+        collect_service_refs(subservice, refs);
+    }
+}
+
+struct Assigner {
+    strategy: BomRefStrategy,
+    existing: HashSet<String>,
+    assigned: usize,
+}
+
+impl Assigner {
+    fn component(&mut self, component: &mut Component) {
+        if component.bom_ref.is_none() {
+            let derived = match (self.strategy, &component.purl) {
+                (BomRefStrategy::Purl, Some(purl)) => purl.to_string(),
+                (BomRefStrategy::Uuid, _) => uuid::Uuid::new_v4().to_string(),
+                _ => content_hash(&[
+                    "component",
+                    &component.component_type.to_string(),
+                    component.group.as_ref().map_or("", |group| &group.0),
+                    &component.name.0,
+                    component.version.as_ref().map_or("", |version| &version.0),
+                ]),
+            };
+            component.bom_ref = Some(self.unique(derived));
+        }
+        for subcomponent in component.components.iter_mut().flat_map(|c| c.0.iter_mut()) {
+            self.component(subcomponent);
+        }
+    }
+
+    fn service(&mut self, service: &mut Service) {
+        if service.bom_ref.is_none() {
+            let derived = match self.strategy {
+                BomRefStrategy::Uuid => uuid::Uuid::new_v4().to_string(),
+                _ => content_hash(&[
+                    "service",
+                    service.group.as_ref().map_or("", |group| &group.0),
+                    &service.name.0,
+                    service.version.as_ref().map_or("", |version| &version.0),
+                ]),
+            };
+            service.bom_ref = Some(self.unique(derived));
+        }
+        for subservice in service.services.iter_mut().flat_map(|s| s.0.iter_mut()) {
+            self.service(subservice);
+        }
+    }
+
+    fn unique(&mut self, derived: String) -> String {
+        let mut unique = derived.clone();
+        let mut suffix = 1;
+        while self.existing.contains(&unique) {
+            suffix += 1;
+            unique = format!("{derived}-{suffix}");
+        }
+        self.existing.insert(unique.clone());
+        self.assigned += 1;
+        unique
+    }
+}
+
+/// Hashes the given fields with 64 bit FNV-1a, which unlike the std hashers is guaranteed to
+/// produce the same value across platforms and Rust versions.
+fn content_hash(fields: &[&str]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for field in fields {
+        for byte in field.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::uri::Purl,
+        models::component::{Classification, Components},
+    };
+
+    #[test]
+    fn it_should_derive_stable_bom_refs_from_content() {
+        let mut parent = Component::new(Classification::Library, "parent", "1.0.0", None);
+        parent.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "child",
+            "1.0.0",
+            None,
+        )]));
+        let mut bom = Bom::builder()
+            .component(parent)
+            .service(Service::new("service", None))
+            .build()
+            .unwrap();
+        let mut other = bom.clone();
+
+        assert_eq!(bom.assign_bom_refs(BomRefStrategy::ContentHash), 3);
+        other.assign_bom_refs(BomRefStrategy::ContentHash);
+
+        assert_eq!(bom, other);
+        let parent = &bom.components.as_ref().unwrap().0[0];
+        let child = &parent.components.as_ref().unwrap().0[0];
+        assert_eq!(parent.bom_ref.as_ref().unwrap().len(), 16);
+        assert_ne!(parent.bom_ref, child.bom_ref);
+    }
+
+    #[test]
+    fn it_should_keep_bom_refs_unique() {
+        let mut existing = Component::new(
+            Classification::Library,
+            "existing",
+            "1.0.0",
+            Some("pkg:cargo/serde@1.0.0".to_string()),
+        );
+        existing.purl = Some(Purl::new("cargo", "existing", "1.0.0").unwrap());
+        let mut first = Component::new(Classification::Library, "serde", "1.0.0", None);
+        first.purl = Some(Purl::new("cargo", "serde", "1.0.0").unwrap());
+        let second = first.clone();
+        let mut bom = Bom::builder()
+            .components([existing, first, second])
+            .build()
+            .unwrap();
+
+        assert_eq!(bom.assign_bom_refs(BomRefStrategy::Purl), 2);
+
+        assert_eq!(
+            bom.components
+                .unwrap()
+                .0
+                .into_iter()
+                .map(|c| c.bom_ref.unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                "pkg:cargo/serde@1.0.0",
+                "pkg:cargo/serde@1.0.0-2",
+                "pkg:cargo/serde@1.0.0-3"
+            ]
+        );
+    }
+}
@@ -0,0 +1,275 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{HashMap, HashSet};
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::models::{bom::Bom, component::Component, dependency::Dependency};
+
+use super::{
+    identity::{IdentityKey, IdentityPolicy},
+    merge::merge_component,
+};
+
+impl Bom {
+    /// Merges components that are identical according to the [`IdentityPolicy`].
+    ///
+    /// Top-level and nested components are considered, the first occurrence in document order
+    /// is kept and the hashes, licenses, external references, properties and nested components
+    /// of its duplicates are merged into it. References to the `bom-ref` of a removed duplicate
+    /// are rewritten to the `bom-ref` of the surviving component.
+    ///
+    /// Returns the number of removed duplicates.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::operations::identity::IdentityPolicy;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut bom = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", Some("a".into())))
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", Some("b".into())))
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// assert_eq!(bom.dedupe_components(IdentityPolicy::Coordinates), 1);
+    /// assert_eq!(bom.components.unwrap().0.len(), 1);
+    /// ```
+    pub fn dedupe_components(&mut self, policy: IdentityPolicy) -> usize {
+        let mut seen = HashSet::new();
+        let mut duplicates = HashMap::<IdentityKey, Vec<Component>>::new();
+
+        if let Some(component) = self.metadata.as_mut().and_then(|m| m.component.as_mut()) {
+            if let Some(key) = policy.key(component) {
+                seen.insert(key);
+            }
+            if let Some(components) = &mut component.components {
+                remove_duplicates(&mut components.0, policy, &mut seen, &mut duplicates);
+            }
+        }
+        if let Some(components) = &mut self.components {
+            remove_duplicates(&mut components.0, policy, &mut seen, &mut duplicates);
+        }
+
+        let removed = duplicates.values().map(Vec::len).sum();
+        if removed == 0 {
+            return 0;
+        }
+
+        let mut renames = HashMap::new();
+        if let Some(component) = self.metadata.as_mut().and_then(|m| m.component.as_mut()) {
+            merge_duplicates(component, policy, &mut duplicates, &mut renames);
+        }
+        for component in self.components.iter_mut().flat_map(|c| c.0.iter_mut()) {
+            merge_duplicates(component, policy, &mut duplicates, &mut renames);
+        }
+        self.rename_references(&renames);
+
+        removed
+    }
+
+    /// Rewrites references in the dependencies, compositions and vulnerability targets.
+    ///
+    /// Dependency entries that end up with the same `bom-ref` are combined.
+    pub(crate) fn rename_references(&mut self, renames: &HashMap<String, String>) {
+        if renames.is_empty() {
+            return;
+        }
+        let rename = |bom_ref: &mut String| {
+            if let Some(renamed) = renames.get(bom_ref) {
+                *bom_ref = renamed.clone();
+            }
+        };
+
+        if let Some(dependencies) = &mut self.dependencies {
+            let mut combined = IndexMap::<String, IndexSet<String>>::new();
+            for mut dependency in dependencies.0.drain(..) {
+                rename(&mut dependency.dependency_ref);
+                let entry = combined.entry(dependency.dependency_ref).or_default();
+                for mut dependency in dependency.dependencies {
+                    rename(&mut dependency);
+                    entry.insert(dependency);
+                }
+            }
+            dependencies.0 = combined
+                .into_iter()
+                .map(|(dependency_ref, dependencies)| Dependency {
+                    dependency_ref,
+                    dependencies: dependencies.into_iter().collect(),
+                })
+                .collect();
+        }
+
+        for composition in self.compositions.iter_mut().flat_map(|c| c.0.iter_mut()) {
+            for refs in [&mut composition.assemblies, &mut composition.dependencies]
+                .into_iter()
+                .flatten()
+            {
+                for bom_ref in refs {
+                    rename(&mut bom_ref.0);
+                }
+            }
+        }
+
+        for target in self
+            .vulnerabilities
+            .iter_mut()
+            .flat_map(|v| v.0.iter_mut())
+            .filter_map(|vulnerability| vulnerability.vulnerability_targets.as_mut())
+            .flat_map(|targets| targets.0.iter_mut())
+        {
+            rename(&mut target.bom_ref);
+        }
+    }
+}
+
+/// Removes all components whose key was seen before, in pre-order.
+fn remove_duplicates(
+    components: &mut Vec<Component>,
+    policy: IdentityPolicy,
+    seen: &mut HashSet<IdentityKey>,
+    duplicates: &mut HashMap<IdentityKey, Vec<Component>>,
+) {
+    for mut component in std::mem::take(components) {
+        match policy.key(&component) {
+            Some(key) if seen.contains(&key) => {
+                duplicates.entry(key).or_default().push(component);
+            }
+            key => {
+                seen.extend(key);
+                if let Some(subcomponents) = &mut component.components {
+                    remove_duplicates(&mut subcomponents.0, policy, seen, duplicates);
+                }
+                components.push(component);
+            }
+        }
+    }
+}
+
+fn merge_duplicates(
+    component: &mut Component,
+    policy: IdentityPolicy,
+    duplicates: &mut HashMap<IdentityKey, Vec<Component>>,
+    renames: &mut HashMap<String, String>,
+) {
+    if let Some(removed) = policy
+        .key(component)
+        .and_then(|key| duplicates.remove(&key))
+    {
+        for duplicate in removed {
+            if let (Some(from), Some(to)) = (&duplicate.bom_ref, &component.bom_ref) {
+                if from != to {
+                    renames.insert(from.clone(), to.clone());
+                }
+            }
+            merge_component(component, duplicate);
+        }
+    }
+    for subcomponent in component.components.iter_mut().flat_map(|c| c.0.iter_mut()) {
+        merge_duplicates(subcomponent, policy, duplicates, renames);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::uri::Purl,
+        models::{
+            component::{Classification, Components},
+            dependency::Dependencies,
+            license::{LicenseChoice, Licenses},
+            property::{Properties, Property},
+        },
+    };
+
+    fn component(name: &str, bom_ref: &str) -> Component {
+        Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(bom_ref.to_string()),
+        )
+    }
+
+    fn dependency(from: &str, to: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: from.to_string(),
+            dependencies: to.iter().map(|to| to.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn it_should_merge_duplicates_and_rewrite_references() {
+        let mut first = component("serde", "serde-1");
+        first.licenses = Some(Licenses(vec![LicenseChoice::expression("MIT")]));
+        let mut second = component("serde", "serde-2");
+        second.licenses = Some(Licenses(vec![LicenseChoice::expression("Apache-2.0")]));
+        second.properties = Some(Properties(vec![Property::new("name", "value")]));
+        let mut parent = component("parent", "parent");
+        parent.components = Some(Components(vec![second]));
+        let mut bom = Bom::builder()
+            .components([first, parent])
+            .dependency(dependency("parent", &["serde-2"]))
+            .dependency(dependency("serde-1", &[]))
+            .dependency(dependency("serde-2", &["other"]))
+            .build_unchecked();
+
+        assert_eq!(bom.dedupe_components(IdentityPolicy::Coordinates), 1);
+
+        let components = bom.components.unwrap().0;
+        assert_eq!(components.len(), 2);
+        assert_eq!(
+            components[0].licenses,
+            Some(Licenses(vec![
+                LicenseChoice::expression("MIT"),
+                LicenseChoice::expression("Apache-2.0"),
+            ]))
+        );
+        assert_eq!(
+            components[0].properties,
+            Some(Properties(vec![Property::new("name", "value")]))
+        );
+        assert_eq!(components[1].components, Some(Components(vec![])));
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                dependency("parent", &["serde-1"]),
+                dependency("serde-1", &["other"]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_respect_the_identity_policy() {
+        let mut first = component("serde", "a");
+        first.purl = Some(Purl::new("cargo", "serde", "1.0.0").unwrap());
+        let mut second = component("serde", "b");
+        second.purl = Some(Purl::new("cargo", "serde", "1.0.0").unwrap());
+        let mut bom = Bom::builder()
+            .components([first, second, component("serde", "c")])
+            .build()
+            .unwrap();
+
+        assert_eq!(bom.clone().dedupe_components(IdentityPolicy::Purl), 1);
+        assert_eq!(bom.dedupe_components(IdentityPolicy::Coordinates), 2);
+    }
+}
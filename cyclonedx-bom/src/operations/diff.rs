@@ -0,0 +1,386 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{bom::Bom, component::Component, hash::Hashes, license::Licenses};
+
+use super::identity::IdentityPolicy;
+
+/// The differences between two BOMs, as returned by [`Bom::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BomDiff {
+    /// Components that only exist in the new BOM.
+    pub added: Vec<Component>,
+    /// Components that only exist in the old BOM.
+    pub removed: Vec<Component>,
+    /// Components that exist in both BOMs, but with a different version, licenses or hashes.
+    pub changed: Vec<ComponentChange>,
+    /// Dependency edges that only exist in the new BOM.
+    pub added_dependencies: Vec<DependencyEdge>,
+    /// Dependency edges that only exist in the old BOM.
+    pub removed_dependencies: Vec<DependencyEdge>,
+}
+
+impl BomDiff {
+    /// Returns `true` if no differences were found.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.added_dependencies.is_empty()
+            && self.removed_dependencies.is_empty()
+    }
+}
+
+/// A component that exists in both BOMs, but differs between them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComponentChange {
+    pub old: Component,
+    pub new: Component,
+    /// Set if the version changed.
+    pub version: Option<FieldChange<Option<String>>>,
+    /// Set if the licenses changed.
+    pub licenses: Option<FieldChange<Option<Licenses>>>,
+    /// Set if the hashes changed.
+    pub hashes: Option<FieldChange<Option<Hashes>>>,
+}
+
+/// The old and new value of a changed field.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange<T> {
+    pub old: T,
+    pub new: T,
+}
+
+/// An edge of the dependency graph, from the `bom-ref` of a component to one of its dependencies.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DependencyEdge {
+    pub from: String,
+    pub to: String,
+}
+
+impl Bom {
+    /// Compares this BOM (the old one) with another BOM (the new one).
+    ///
+    /// Components, including the metadata component and nested components, are first matched
+    /// by package URL or by group, name and version. Remaining components with the same group
+    /// and name are reported as a version change, all others as added or removed.
+    /// Dependency edges are compared by their `bom-ref`s.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let old = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+    ///     .build()
+    ///     .expect("valid BOM");
+    /// let new = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.1", None))
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.changed.len(), 1);
+    /// assert!(diff.changed[0].version.is_some());
+    /// ```
+    pub fn diff(&self, other: &Bom) -> BomDiff {
+        self.diff_with(other, IdentityPolicy::PurlOrCoordinates)
+    }
+
+    /// Compares this BOM with `other` like [`Bom::diff`], matching components according to the
+    /// given [`IdentityPolicy`].
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::operations::identity::IdentityPolicy;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut component = Component::new(Classification::Library, "serde", "1.0.0", None);
+    /// component.purl = Some(Purl::new("cargo", "serde", "1.0.0").expect("valid purl"));
+    /// let old = Bom::builder()
+    ///     .component(component.clone())
+    ///     .build()
+    ///     .expect("valid BOM");
+    /// component.name = NormalizedString::new("serde-renamed");
+    /// let new = Bom::builder()
+    ///     .component(component)
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// assert!(old.diff_with(&new, IdentityPolicy::Purl).added.is_empty());
+    /// assert_eq!(old.diff_with(&new, IdentityPolicy::Coordinates).added.len(), 1);
+    /// ```
+    pub fn diff_with(&self, other: &Bom, policy: IdentityPolicy) -> BomDiff {
+        let old = all_components(self);
+        let new = all_components(other);
+
+        let mut old_by_key = HashMap::new();
+        for (index, component) in old.iter().enumerate() {
+            if let Some(key) = policy.key(component) {
+                old_by_key.entry(key).or_insert(index);
+            }
+        }
+
+        let mut diff = BomDiff::default();
+        let mut matched_old = vec![false; old.len()];
+        let mut unmatched_new = Vec::new();
+
+        for component in &new {
+            let existing = policy
+                .key(component)
+                .and_then(|key| old_by_key.get(&key).copied())
+                .filter(|&index| !matched_old[index]);
+            match existing {
+                Some(index) => {
+                    matched_old[index] = true;
+                    diff.changed.extend(compare(old[index], component));
+                }
+                None => unmatched_new.push(*component),
+            }
+        }
+
+        // Pair up remaining components with the same name as version changes
+        let mut unmatched_old = HashMap::<_, Vec<usize>>::new();
+        for (index, component) in old.iter().enumerate().rev() {
+            if !matched_old[index] {
+                unmatched_old
+                    .entry(name_key(component))
+                    .or_default()
+                    .push(index);
+            }
+        }
+        for component in unmatched_new {
+            match unmatched_old
+                .get_mut(&name_key(component))
+                .and_then(|indices| indices.pop())
+            {
+                Some(index) => {
+                    matched_old[index] = true;
+                    diff.changed.extend(compare(old[index], component));
+                }
+                None => diff.added.push(component.clone()),
+            }
+        }
+
+        diff.removed = old
+            .iter()
+            .zip(matched_old)
+            .filter(|(_, matched)| !matched)
+            .map(|(component, _)| (*component).clone())
+            .collect();
+
+        let old_edges = dependency_edges(self);
+        let new_edges = dependency_edges(other);
+        let old_set: HashSet<_> = old_edges.iter().collect();
+        let new_set: HashSet<_> = new_edges.iter().collect();
+        diff.added_dependencies = new_edges
+            .iter()
+            .filter(|edge| !old_set.contains(edge))
+            .cloned()
+            .collect();
+        diff.removed_dependencies = old_edges
+            .iter()
+            .filter(|edge| !new_set.contains(edge))
+            .cloned()
+            .collect();
+
+        diff
+    }
+}
+
+fn all_components(bom: &Bom) -> Vec<&Component> {
+    fn collect<'a>(component: &'a Component, components: &mut Vec<&'a Component>) {
+        components.push(component);
+        for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+            collect(subcomponent, components);
+        }
+    }
+
+    let mut components = Vec::new();
+    if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+        collect(component, &mut components);
+    }
+    for component in bom.components.iter().flat_map(|c| c.0.iter()) {
+        collect(component, &mut components);
+    }
+    components
+}
+
+fn name_key(component: &Component) -> (Option<&str>, &str) {
+    (
+        component.group.as_ref().map(|group| group.0.as_str()),
+        component.name.0.as_str(),
+    )
+}
+
+fn compare(old: &Component, new: &Component) -> Option<ComponentChange> {
+    fn change<T: PartialEq + Clone>(old: &T, new: &T) -> Option<FieldChange<T>> {
+        (old != new).then(|| FieldChange {
+            old: old.clone(),
+            new: new.clone(),
+        })
+    }
+
+    let version = |component: &Component| component.version.as_ref().map(|v| v.to_string());
+    let version = change(&version(old), &version(new));
+    let licenses = change(&old.licenses, &new.licenses);
+    let hashes = change(&old.hashes, &new.hashes);
+
+    if version.is_none() && licenses.is_none() && hashes.is_none() {
+        return None;
+    }
+    Some(ComponentChange {
+        old: old.clone(),
+        new: new.clone(),
+        version,
+        licenses,
+        hashes,
+    })
+}
+
+fn dependency_edges(bom: &Bom) -> Vec<DependencyEdge> {
+    bom.dependencies
+        .iter()
+        .flat_map(|dependencies| dependencies.0.iter())
+        .flat_map(|dependency| {
+            dependency.dependencies.iter().map(|to| DependencyEdge {
+                from: dependency.dependency_ref.clone(),
+                to: to.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{
+        component::Classification,
+        dependency::Dependency,
+        license::{LicenseChoice, Licenses},
+    };
+
+    fn component(name: &str, version: &str) -> Component {
+        Component::new(
+            Classification::Library,
+            name,
+            version,
+            Some(format!("{name} {version}")),
+        )
+    }
+
+    #[test]
+    fn it_should_report_no_changes_for_identical_boms() {
+        let bom = Bom::builder()
+            .component(component("a", "1.0.0"))
+            .dependency(Dependency {
+                dependency_ref: "a 1.0.0".to_string(),
+                dependencies: vec![],
+            })
+            .build()
+            .unwrap();
+
+        assert!(bom.diff(&bom.clone()).is_empty());
+    }
+
+    #[test]
+    fn it_should_report_added_removed_and_changed_components() {
+        let mut licensed = component("licensed", "1.0.0");
+        let old = Bom::builder()
+            .component(component("removed", "1.0.0"))
+            .component(component("updated", "1.0.0"))
+            .component(licensed.clone())
+            .build()
+            .unwrap();
+        licensed.licenses = Some(Licenses(vec![LicenseChoice::expression("MIT")]));
+        let new = Bom::builder()
+            .component(component("updated", "2.0.0"))
+            .component(licensed.clone())
+            .component(component("added", "1.0.0"))
+            .build()
+            .unwrap();
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.added, vec![component("added", "1.0.0")]);
+        assert_eq!(diff.removed, vec![component("removed", "1.0.0")]);
+        assert_eq!(diff.changed.len(), 2);
+        assert_eq!(
+            diff.changed[0].licenses,
+            Some(FieldChange {
+                old: None,
+                new: licensed.licenses.clone(),
+            })
+        );
+        assert_eq!(
+            diff.changed[1].version,
+            Some(FieldChange {
+                old: Some("1.0.0".to_string()),
+                new: Some("2.0.0".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_pair_multiple_versions_of_a_component() {
+        let old = Bom::builder()
+            .component(component("syn", "1.0.0"))
+            .component(component("syn", "2.0.0"))
+            .build()
+            .unwrap();
+        let new = Bom::builder()
+            .component(component("syn", "2.0.1"))
+            .component(component("syn", "1.0.0"))
+            .build()
+            .unwrap();
+
+        let diff = old.diff(&new);
+
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].old, component("syn", "2.0.0"));
+    }
+
+    #[test]
+    fn it_should_report_dependency_edge_changes() {
+        let dependency = |from: &str, to: &[&str]| Dependency {
+            dependency_ref: from.to_string(),
+            dependencies: to.iter().map(|to| to.to_string()).collect(),
+        };
+        let old = Bom::builder()
+            .dependency(dependency("app", &["a", "b"]))
+            .build_unchecked();
+        let new = Bom::builder()
+            .dependency(dependency("app", &["b", "c"]))
+            .build_unchecked();
+
+        let diff = old.diff(&new);
+
+        let edge = |from: &str, to: &str| DependencyEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+        };
+        assert_eq!(diff.added_dependencies, vec![edge("app", "c")]);
+        assert_eq!(diff.removed_dependencies, vec![edge("app", "a")]);
+    }
+}
@@ -0,0 +1,160 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::fmt::Write;
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::models::bom::Bom;
+
+impl Bom {
+    /// Renders the dependencies section as a [Graphviz](https://graphviz.org/) DOT graph.
+    ///
+    /// Every node is identified by its `bom-ref` and labeled with the name and version of the
+    /// component or service it refers to, or with the `bom-ref` itself if there is none. The
+    /// metadata component is drawn as a box.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::models::dependency::Dependency;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", Some("serde".to_string())))
+    ///     .dependency(Dependency {
+    ///         dependency_ref: "app".to_string(),
+    ///         dependencies: vec!["serde".to_string()],
+    ///     })
+    ///     .build_unchecked();
+    ///
+    /// let dot = bom.dependencies_to_dot();
+    /// assert!(dot.contains(r#""serde" [label="serde\n1.0.0"];"#));
+    /// assert!(dot.contains(r#""app" -> "serde";"#));
+    /// ```
+    pub fn dependencies_to_dot(&self) -> String {
+        let graph = self.dependency_graph();
+        let index = self.index();
+
+        let mut dot = String::from("digraph dependencies {\n");
+        for &node in graph.nodes() {
+            let (name, version) = match (index.component_by_ref(node), index.service_by_ref(node)) {
+                (Some(component), _) => (Some(&component.name), component.version.as_ref()),
+                (None, Some(service)) => (Some(&service.name), service.version.as_ref()),
+                (None, None) => (None, None),
+            };
+            let label = label(node, name, version);
+            let shape = if graph.root() == Some(node) {
+                ", shape=box"
+            } else {
+                ""
+            };
+            let _ = writeln!(dot, "  {} [label={}{shape}];", quote(node), quote(&label));
+        }
+        for &node in graph.nodes() {
+            for dependency in graph.dependencies(node) {
+                let _ = writeln!(dot, "  {} -> {};", quote(node), quote(dependency));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn label(
+    bom_ref: &str,
+    name: Option<&NormalizedString>,
+    version: Option<&NormalizedString>,
+) -> String {
+    match (name, version) {
+        (Some(name), Some(version)) => format!("{name}\n{version}"),
+        (Some(name), None) => name.to_string(),
+        (None, _) => bom_ref.to_string(),
+    }
+}
+
+/// Quotes a DOT identifier, escaping quotes, backslashes and line breaks.
+fn quote(id: &str) -> String {
+    let mut quoted = String::with_capacity(id.len() + 2);
+    quoted.push('"');
+    for c in id.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{
+        component::{Classification, Component},
+        dependency::Dependency,
+        metadata::Metadata,
+        service::Service,
+    };
+
+    #[test]
+    fn it_should_render_the_dependency_graph() {
+        let bom = Bom::builder()
+            .metadata(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "0.1.0",
+                    Some("app".to_string()),
+                )),
+                ..Metadata::default()
+            })
+            .component(Component::new(
+                Classification::Library,
+                "serde",
+                "1.0.0",
+                Some("pkg:cargo/serde@1.0.0".to_string()),
+            ))
+            .service(Service::new("api", Some("api".to_string())))
+            .dependency(Dependency {
+                dependency_ref: "app".to_string(),
+                dependencies: vec![
+                    "pkg:cargo/serde@1.0.0".to_string(),
+                    "api".to_string(),
+                    "say \"hi\"".to_string(),
+                ],
+            })
+            .build_unchecked();
+
+        assert_eq!(
+            bom.dependencies_to_dot(),
+            r#"digraph dependencies {
+  "app" [label="app\n0.1.0", shape=box];
+  "pkg:cargo/serde@1.0.0" [label="serde\n1.0.0"];
+  "api" [label="api"];
+  "say \"hi\"" [label="say \"hi\""];
+  "app" -> "pkg:cargo/serde@1.0.0";
+  "app" -> "api";
+  "app" -> "say \"hi\"";
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,278 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Converting a BOM to an older spec version without losing the elements it can't express.
+
+use crate::{
+    errors::{ExtensionError, JsonWriteError},
+    models::{
+        annotation::Annotations,
+        bom::{Bom, SpecVersion},
+        extension::{Extension, ExtensionReader, ExtensionWriter},
+        lifecycle::Lifecycles,
+        metadata::Metadata,
+        property::Properties,
+    },
+    specs::v1_5,
+};
+
+/// The elements moved aside by [`Bom::downgrade`], stored as JSON documents in the metadata
+/// properties of the `cdx:rust:retained` namespace.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Retained {
+    annotations: Option<String>,
+    lifecycles: Option<String>,
+}
+
+impl Extension for Retained {
+    const NAMESPACE: &'static str = "cdx:rust:retained";
+
+    fn write(&self, writer: &mut ExtensionWriter) {
+        if let Some(annotations) = &self.annotations {
+            writer.value("annotations", annotations);
+        }
+        if let Some(lifecycles) = &self.lifecycles {
+            writer.value("lifecycles", lifecycles);
+        }
+    }
+
+    fn read(reader: &ExtensionReader<'_>) -> Result<Self, ExtensionError> {
+        Ok(Self {
+            annotations: reader.optional_value("annotations")?,
+            lifecycles: reader.optional_value("lifecycles")?,
+        })
+    }
+}
+
+impl Bom {
+    /// Prepares the BOM to be written as an older spec `version`.
+    ///
+    /// Annotations and lifecycles, which were added in 1.5, are moved into properties of the
+    /// metadata, which all spec versions support. [`Bom::upgrade`] restores them after the BOM
+    /// was read again. Returns `true` if any elements were retained.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    /// use cyclonedx_bom::models::lifecycle::{Lifecycle, Lifecycles, Phase};
+    /// use cyclonedx_bom::models::metadata::Metadata;
+    ///
+    /// let mut metadata = Metadata::new().expect("valid timestamp");
+    /// metadata.lifecycles = Some(Lifecycles(vec![Lifecycle::Phase(Phase::Build)]));
+    /// let bom = Bom::builder().metadata(metadata).build().expect("valid BOM");
+    ///
+    /// let mut downgraded = bom.clone();
+    /// assert!(downgraded.downgrade(SpecVersion::V1_3).expect("convertible BOM"));
+    /// let mut output = Vec::new();
+    /// downgraded.output_as_json_v1_3(&mut output).expect("written BOM");
+    ///
+    /// let mut parsed = Bom::parse_from_json(output.as_slice()).expect("parsed BOM");
+    /// assert!(parsed.upgrade().expect("valid retained elements"));
+    /// assert_eq!(parsed.metadata, bom.metadata);
+    /// ```
+    pub fn downgrade(&mut self, version: SpecVersion) -> Result<bool, JsonWriteError> {
+        if version >= SpecVersion::V1_5 {
+            return Ok(false);
+        }
+
+        let lifecycles = self
+            .metadata
+            .as_mut()
+            .and_then(|metadata| metadata.lifecycles.take());
+        let retained = Retained {
+            annotations: self
+                .annotations
+                .take()
+                .map(|annotations| {
+                    serde_json::to_string(&v1_5::annotation::Annotations::try_from(annotations)?)
+                        .map_err(JsonWriteError::from)
+                })
+                .transpose()?,
+            lifecycles: lifecycles
+                .map(|lifecycles| {
+                    serde_json::to_string(&v1_5::lifecycles::Lifecycles::from(lifecycles))
+                })
+                .transpose()?,
+        };
+        if retained == Retained::default() {
+            return Ok(false);
+        }
+
+        let metadata = self.metadata.get_or_insert_with(Metadata::default);
+        metadata
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .set_extension(&retained);
+        Ok(true)
+    }
+
+    /// Restores the elements retained by [`Bom::downgrade`], returns `true` if there were any.
+    ///
+    /// Restored elements replace annotations and lifecycles which are already present.
+    pub fn upgrade(&mut self) -> Result<bool, ExtensionError> {
+        let Some(metadata) = self.metadata.as_mut() else {
+            return Ok(false);
+        };
+        let Some(properties) = metadata.properties.as_mut() else {
+            return Ok(false);
+        };
+        let Some(retained) = properties.get_extension::<Retained>().transpose()? else {
+            return Ok(false);
+        };
+
+        let annotations = retained
+            .annotations
+            .map(|json| parse::<v1_5::annotation::Annotations>("annotations", json))
+            .transpose()?;
+        let lifecycles = retained
+            .lifecycles
+            .map(|json| parse::<v1_5::lifecycles::Lifecycles>("lifecycles", json))
+            .transpose()?;
+
+        properties.remove_extension::<Retained>();
+        if properties.0.is_empty() {
+            metadata.properties = None;
+        }
+        if let Some(lifecycles) = lifecycles {
+            metadata.lifecycles = Some(Lifecycles::from(lifecycles));
+        }
+        if let Some(annotations) = annotations {
+            self.annotations = Some(Annotations::from(annotations));
+        }
+        Ok(true)
+    }
+}
+
+fn parse<T: serde::de::DeserializeOwned>(name: &str, json: String) -> Result<T, ExtensionError> {
+    serde_json::from_str(&json).map_err(|_| ExtensionError::InvalidValue {
+        name: format!("{}:{name}", Retained::NAMESPACE),
+        value: json,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::date_time::DateTime,
+        models::{
+            annotation::{Annotation, Annotator},
+            lifecycle::{Lifecycle, Phase},
+            organization::OrganizationalEntity,
+            property::Property,
+        },
+    };
+
+    fn bom() -> Bom {
+        let mut metadata = Metadata::new().unwrap();
+        metadata.lifecycles = Some(Lifecycles(vec![Lifecycle::Phase(Phase::Design)]));
+        metadata.properties = Some(Properties(vec![Property::new("acme:team", "sbom")]));
+        Bom {
+            metadata: Some(metadata),
+            annotations: Some(Annotations(vec![Annotation {
+                bom_ref: None,
+                subjects: vec!["root".to_string()],
+                annotator: Annotator::Organization(OrganizationalEntity::new("Acme")),
+                timestamp: DateTime::now().unwrap(),
+                text: "Reviewed\tby legal".to_string(),
+                signature: None,
+            }])),
+            ..Bom::default()
+        }
+    }
+
+    #[test]
+    fn it_should_restore_retained_elements_after_a_json_round_trip() {
+        let original = bom();
+        let mut downgraded = original.clone();
+        assert!(downgraded.downgrade(SpecVersion::V1_3).unwrap());
+        assert_eq!(downgraded.annotations, None);
+
+        let mut output = Vec::new();
+        downgraded.output_as_json_v1_3(&mut output).unwrap();
+        let mut parsed = Bom::parse_from_json_v1_3(output.as_slice()).unwrap();
+
+        assert!(parsed.upgrade().unwrap());
+        assert_eq!(parsed.annotations, original.annotations);
+        assert_eq!(parsed.metadata, original.metadata);
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_restore_retained_elements_after_an_xml_round_trip() {
+        let original = bom();
+        let mut downgraded = original.clone();
+        downgraded.downgrade(SpecVersion::V1_4).unwrap();
+
+        let mut output = Vec::new();
+        downgraded.output_as_xml_v1_4(&mut output).unwrap();
+        let mut parsed = Bom::parse_from_xml_v1_4(output.as_slice()).unwrap();
+
+        assert!(parsed.upgrade().unwrap());
+        assert_eq!(parsed.annotations, original.annotations);
+        assert_eq!(parsed.metadata, original.metadata);
+    }
+
+    #[test]
+    fn it_should_not_retain_elements_supported_by_the_target_version() {
+        let original = bom();
+        let mut bom = original.clone();
+
+        assert!(!bom.downgrade(SpecVersion::V1_5).unwrap());
+        assert_eq!(bom, original);
+        assert!(!bom.upgrade().unwrap());
+    }
+
+    #[test]
+    fn it_should_remove_the_retained_properties_on_upgrade() {
+        let mut bom = Bom {
+            annotations: bom().annotations,
+            metadata: None,
+            ..Bom::default()
+        };
+        bom.downgrade(SpecVersion::V1_3).unwrap();
+        assert!(bom.metadata.is_some());
+
+        bom.upgrade().unwrap();
+
+        assert_eq!(bom.metadata, Some(Metadata::default()));
+    }
+
+    #[test]
+    fn it_should_reject_invalid_retained_elements() {
+        let mut bom = Bom {
+            metadata: Some(Metadata {
+                properties: Some(Properties(vec![Property::new(
+                    "cdx:rust:retained:lifecycles",
+                    "not json",
+                )])),
+                ..Metadata::default()
+            }),
+            ..Bom::default()
+        };
+
+        assert_eq!(
+            bom.upgrade(),
+            Err(ExtensionError::InvalidValue {
+                name: "cdx:rust:retained:lifecycles".to_string(),
+                value: "not json".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,304 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::HashMap;
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::models::{
+    bom::Bom,
+    component::{Component, Components},
+    dependency::{Dependencies, Dependency},
+    property::{Properties, Property},
+    service::{Service, Services},
+};
+
+/// Name of the property recording the `bom-ref` of the former parent,
+/// see [`Hierarchy::Properties`].
+pub const FLATTEN_PARENT_PROPERTY: &str = "cyclonedx-bom:flatten:parent";
+
+/// How [`Bom::flatten`] preserves the former nesting of components and services.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Hierarchy {
+    /// Each parent depends on its former children in the dependencies section.
+    #[default]
+    Dependencies,
+    /// Each former child gets a [`FLATTEN_PARENT_PROPERTY`] property with the `bom-ref` of its parent.
+    Properties,
+}
+
+impl Bom {
+    /// Moves all nested components and services to the top-level arrays.
+    ///
+    /// Nested components of the metadata component are moved as well. Each element is directly
+    /// followed by its former children. The hierarchy is preserved as described by
+    /// [`Hierarchy`], which requires parents to have a `bom-ref`.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component, Components};
+    /// use cyclonedx_bom::operations::flatten::Hierarchy;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut parent = Component::new(Classification::Library, "parent", "1.0.0", Some("parent".into()));
+    /// parent.components = Some(Components(vec![Component::new(
+    ///     Classification::Library,
+    ///     "child",
+    ///     "1.0.0",
+    ///     Some("child".into()),
+    /// )]));
+    /// let mut bom = Bom::builder().component(parent).build().expect("valid BOM");
+    ///
+    /// bom.flatten(Hierarchy::Dependencies);
+    /// assert_eq!(bom.components.unwrap().0.len(), 2);
+    /// assert_eq!(bom.dependencies.unwrap().0[0].dependencies, vec!["child"]);
+    /// ```
+    pub fn flatten(&mut self, hierarchy: Hierarchy) {
+        let mut edges = Vec::new();
+
+        let mut components = Vec::new();
+        if let Some(component) = self.metadata.as_mut().and_then(|m| m.component.as_mut()) {
+            if let Some(children) = component.components.take() {
+                let parent = component.bom_ref.clone();
+                for child in children.0 {
+                    flatten_component(
+                        child,
+                        parent.as_deref(),
+                        hierarchy,
+                        &mut components,
+                        &mut edges,
+                    );
+                }
+            }
+        }
+        if let Some(existing) = self.components.take() {
+            for component in existing.0 {
+                flatten_component(component, None, hierarchy, &mut components, &mut edges);
+            }
+        }
+        if !components.is_empty() {
+            self.components = Some(Components(components));
+        }
+
+        if let Some(existing) = self.services.take() {
+            let mut services = Vec::new();
+            for service in existing.0 {
+                flatten_service(service, None, hierarchy, &mut services, &mut edges);
+            }
+            self.services = Some(Services(services));
+        }
+
+        if edges.is_empty() {
+            return;
+        }
+        let dependencies = self
+            .dependencies
+            .get_or_insert_with(|| Dependencies(Vec::new()));
+        let mut index: HashMap<String, usize> = dependencies
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, dependency)| (dependency.dependency_ref.clone(), i))
+            .rev()
+            .collect();
+        for (parent, child) in edges {
+            match index.get(&parent) {
+                Some(&i) => {
+                    let dependency = &mut dependencies.0[i];
+                    if !dependency.dependencies.contains(&child) {
+                        dependency.dependencies.push(child);
+                    }
+                }
+                None => {
+                    index.insert(parent.clone(), dependencies.0.len());
+                    dependencies.0.push(Dependency {
+                        dependency_ref: parent,
+                        dependencies: vec![child],
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn record_parent(
+    properties: &mut Option<Properties>,
+    bom_ref: Option<&String>,
+    parent: Option<&str>,
+    hierarchy: Hierarchy,
+    edges: &mut Vec<(String, String)>,
+) {
+    let Some(parent) = parent else {
+        return;
+    };
+    match hierarchy {
+        Hierarchy::Dependencies => {
+            if let Some(bom_ref) = bom_ref {
+                edges.push((parent.to_string(), bom_ref.clone()));
+            }
+        }
+        Hierarchy::Properties => properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .0
+            .push(Property {
+                name: FLATTEN_PARENT_PROPERTY.to_string(),
+                value: NormalizedString::new(parent),
+            }),
+    }
+}
+
+fn flatten_component(
+    mut component: Component,
+    parent: Option<&str>,
+    hierarchy: Hierarchy,
+    flattened: &mut Vec<Component>,
+    edges: &mut Vec<(String, String)>,
+) {
+    record_parent(
+        &mut component.properties,
+        component.bom_ref.as_ref(),
+        parent,
+        hierarchy,
+        edges,
+    );
+    let children = component.components.take();
+    let bom_ref = component.bom_ref.clone();
+    flattened.push(component);
+    for child in children.into_iter().flat_map(|c| c.0) {
+        flatten_component(child, bom_ref.as_deref(), hierarchy, flattened, edges);
+    }
+}
+
+fn flatten_service(
+    mut service: Service,
+    parent: Option<&str>,
+    hierarchy: Hierarchy,
+    flattened: &mut Vec<Service>,
+    edges: &mut Vec<(String, String)>,
+) {
+    record_parent(
+        &mut service.properties,
+        service.bom_ref.as_ref(),
+        parent,
+        hierarchy,
+        edges,
+    );
+    let children = service.services.take();
+    let bom_ref = service.bom_ref.clone();
+    flattened.push(service);
+    for child in children.into_iter().flat_map(|s| s.0) {
+        flatten_service(child, bom_ref.as_deref(), hierarchy, flattened, edges);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{component::Classification, metadata::Metadata};
+
+    fn component(name: &str, children: Vec<Component>) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(name.to_string()),
+        );
+        if !children.is_empty() {
+            component.components = Some(Components(children));
+        }
+        component
+    }
+
+    fn names(bom: &Bom) -> Vec<String> {
+        bom.components
+            .iter()
+            .flat_map(|c| c.0.iter())
+            .map(|c| c.name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn it_should_flatten_components_into_dependencies() {
+        let mut bom = Bom::builder()
+            .metadata(Metadata {
+                component: Some(component("app", vec![component("module", vec![])])),
+                ..Metadata::default()
+            })
+            .component(component(
+                "a",
+                vec![component("b", vec![component("c", vec![])])],
+            ))
+            .component(component("d", vec![]))
+            .dependency(Dependency {
+                dependency_ref: "a".to_string(),
+                dependencies: vec!["d".to_string()],
+            })
+            .build()
+            .unwrap();
+
+        bom.flatten(Hierarchy::Dependencies);
+
+        assert_eq!(names(&bom), vec!["module", "a", "b", "c", "d"]);
+        assert!(bom
+            .components
+            .iter()
+            .flat_map(|c| c.0.iter())
+            .all(|c| c.components.is_none()));
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                Dependency {
+                    dependency_ref: "a".to_string(),
+                    dependencies: vec!["d".to_string(), "b".to_string()],
+                },
+                Dependency {
+                    dependency_ref: "app".to_string(),
+                    dependencies: vec!["module".to_string()],
+                },
+                Dependency {
+                    dependency_ref: "b".to_string(),
+                    dependencies: vec!["c".to_string()],
+                },
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_flatten_services_into_properties() {
+        let mut parent = Service::new("parent", Some("parent".to_string()));
+        parent.services = Some(Services(vec![Service::new(
+            "child",
+            Some("child".to_string()),
+        )]));
+        let mut bom = Bom::builder().service(parent).build().unwrap();
+
+        bom.flatten(Hierarchy::Properties);
+
+        let services = bom.services.unwrap().0;
+        assert_eq!(services.len(), 2);
+        assert_eq!(services[0].properties, None);
+        assert_eq!(
+            services[1].properties,
+            Some(Properties(vec![Property::new(
+                FLATTEN_PARENT_PROPERTY,
+                "parent"
+            )]))
+        );
+        assert_eq!(bom.dependencies, None);
+    }
+}
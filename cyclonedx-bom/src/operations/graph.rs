@@ -0,0 +1,357 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use thiserror::Error;
+
+use crate::models::bom::Bom;
+
+/// The dependencies section of a [`Bom`] as a directed graph of `bom-ref`s,
+/// created via [`Bom::dependency_graph`].
+///
+/// An edge points from a `bom-ref` to one of its direct dependencies. Every `bom-ref` that
+/// appears in the dependencies section is a node, even if it is not defined by a component or
+/// service of the BOM.
+///
+/// Edges are stored in adjacency lists indexed by node, so building the graph and all
+/// traversals run in time linear in the number of nodes and edges.
+#[derive(Clone, Debug)]
+pub struct DependencyGraph<'a> {
+    root: Option<&'a str>,
+    nodes: Vec<&'a str>,
+    indices: HashMap<&'a str, usize>,
+    dependencies: Vec<Vec<usize>>,
+    dependents: Vec<Vec<usize>>,
+}
+
+/// Returned by [`DependencyGraph::topological_order`] if the graph contains a cycle.
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("The dependency graph contains a cycle involving {}", .nodes.join(", "))]
+pub struct CycleError {
+    /// The `bom-ref`s that are part of, or depend on, a cycle.
+    pub nodes: Vec<String>,
+}
+
+impl Bom {
+    /// Builds a [`DependencyGraph`] from the dependencies section.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::dependency::Dependency;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::builder()
+    ///     .dependency(Dependency {
+    ///         dependency_ref: "app".to_string(),
+    ///         dependencies: vec!["reqwest".to_string()],
+    ///     })
+    ///     .dependency(Dependency {
+    ///         dependency_ref: "reqwest".to_string(),
+    ///         dependencies: vec!["openssl".to_string()],
+    ///     })
+    ///     .build_unchecked();
+    ///
+    /// let graph = bom.dependency_graph();
+    /// assert_eq!(graph.ancestors("openssl"), vec!["reqwest", "app"]);
+    /// ```
+    pub fn dependency_graph(&self) -> DependencyGraph<'_> {
+        let root = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref())
+            .and_then(|component| component.bom_ref.as_deref());
+        let mut graph = DependencyGraph {
+            root,
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            dependencies: Vec::new(),
+            dependents: Vec::new(),
+        };
+        let mut edges = HashSet::new();
+
+        for dependency in self.dependencies.iter().flat_map(|d| d.0.iter()) {
+            let from = graph.add_node(&dependency.dependency_ref);
+            for to in &dependency.dependencies {
+                let to = graph.add_node(to);
+                if edges.insert((from, to)) {
+                    graph.dependencies[from].push(to);
+                    graph.dependents[to].push(from);
+                }
+            }
+        }
+
+        graph
+    }
+}
+
+impl<'a> DependencyGraph<'a> {
+    fn add_node(&mut self, bom_ref: &'a str) -> usize {
+        *self.indices.entry(bom_ref).or_insert_with(|| {
+            self.nodes.push(bom_ref);
+            self.dependencies.push(Vec::new());
+            self.dependents.push(Vec::new());
+            self.nodes.len() - 1
+        })
+    }
+
+    fn refs(&self, indices: impl IntoIterator<Item = usize>) -> Vec<&'a str> {
+        indices.into_iter().map(|index| self.nodes[index]).collect()
+    }
+
+    /// Returns the `bom-ref` of the metadata component, if it has one.
+    pub fn root(&self) -> Option<&'a str> {
+        self.root
+    }
+
+    /// Returns all nodes in the order they first appear in the dependencies section.
+    pub fn nodes(&self) -> &[&'a str] {
+        &self.nodes
+    }
+
+    /// Returns `true` if the `bom-ref` is a node of the graph.
+    pub fn contains(&self, bom_ref: &str) -> bool {
+        self.indices.contains_key(bom_ref)
+    }
+
+    /// Returns the direct dependencies of a `bom-ref`.
+    pub fn dependencies(&self, bom_ref: &str) -> Vec<&'a str> {
+        match self.indices.get(bom_ref) {
+            Some(&index) => self.refs(self.dependencies[index].iter().copied()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the `bom-ref`s that directly depend on a `bom-ref`.
+    pub fn dependents(&self, bom_ref: &str) -> Vec<&'a str> {
+        match self.indices.get(bom_ref) {
+            Some(&index) => self.refs(self.dependents[index].iter().copied()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns all direct and transitive dependencies of a `bom-ref` in breadth-first order.
+    pub fn descendants(&self, bom_ref: &str) -> Vec<&'a str> {
+        self.traverse(bom_ref, &self.dependencies)
+    }
+
+    /// Returns all `bom-ref`s that directly or transitively depend on a `bom-ref`,
+    /// in breadth-first order.
+    pub fn ancestors(&self, bom_ref: &str) -> Vec<&'a str> {
+        self.traverse(bom_ref, &self.dependents)
+    }
+
+    /// Returns `true` if `to` is a direct or transitive dependency of `from`.
+    pub fn is_reachable(&self, from: &str, to: &str) -> bool {
+        let (Some(&start), Some(&target)) = (self.indices.get(from), self.indices.get(to)) else {
+            return false;
+        };
+        let mut visited = vec![false; self.nodes.len()];
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(index) = queue.pop_front() {
+            for &next in &self.dependencies[index] {
+                if next == target {
+                    return true;
+                }
+                if !visited[next] {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Returns all nodes reachable from the metadata component, including the component itself.
+    ///
+    /// Returns an empty list if the metadata component has no `bom-ref` or is not part of the graph.
+    pub fn reachable_from_root(&self) -> Vec<&'a str> {
+        match self.root.filter(|root| self.contains(root)) {
+            Some(root) => {
+                let mut reachable = vec![root];
+                reachable.extend(self.descendants(root));
+                reachable
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns all nodes so that every node comes after its dependencies.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CycleError`] if the graph contains a cycle.
+    pub fn topological_order(&self) -> Result<Vec<&'a str>, CycleError> {
+        let mut remaining: Vec<usize> = self.dependencies.iter().map(Vec::len).collect();
+        let mut queue: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&index| remaining[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(index) = queue.pop_front() {
+            order.push(index);
+            for &dependent in &self.dependents[index] {
+                remaining[dependent] -= 1;
+                if remaining[dependent] == 0 {
+                    queue.push_back(dependent);
+                }
+            }
+        }
+
+        if order.len() != self.nodes.len() {
+            let nodes = (0..self.nodes.len())
+                .filter(|&index| remaining[index] > 0)
+                .map(|index| self.nodes[index].to_string())
+                .collect();
+            return Err(CycleError { nodes });
+        }
+        Ok(self.refs(order))
+    }
+
+    fn traverse(&self, bom_ref: &str, edges: &[Vec<usize>]) -> Vec<&'a str> {
+        let Some(&start) = self.indices.get(bom_ref) else {
+            return Vec::new();
+        };
+        let mut visited = vec![false; self.nodes.len()];
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        let mut found = Vec::new();
+
+        while let Some(index) = queue.pop_front() {
+            for &next in &edges[index] {
+                if !visited[next] {
+                    visited[next] = true;
+                    found.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        self.refs(found)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{
+        component::{Classification, Component},
+        dependency::Dependency,
+        metadata::Metadata,
+    };
+
+    fn bom(edges: &[(&str, &[&str])]) -> Bom {
+        let mut builder = Bom::builder().metadata(Metadata {
+            component: Some(Component::new(
+                Classification::Application,
+                "app",
+                "1.0.0",
+                Some("app".to_string()),
+            )),
+            ..Metadata::default()
+        });
+        for (from, to) in edges {
+            builder = builder.dependency(Dependency {
+                dependency_ref: from.to_string(),
+                dependencies: to.iter().map(|to| to.to_string()).collect(),
+            });
+        }
+        builder.build_unchecked()
+    }
+
+    #[test]
+    fn it_should_traverse_the_graph() {
+        let bom = bom(&[
+            ("app", &["a", "b"]),
+            ("a", &["c"]),
+            ("b", &["c"]),
+            ("c", &[]),
+            ("unused", &["c"]),
+        ]);
+        let graph = bom.dependency_graph();
+
+        assert_eq!(graph.dependencies("app"), vec!["a", "b"]);
+        assert_eq!(graph.dependents("c"), vec!["a", "b", "unused"]);
+        assert_eq!(graph.descendants("app"), vec!["a", "b", "c"]);
+        assert_eq!(graph.ancestors("c"), vec!["a", "b", "unused", "app"]);
+        assert!(graph.is_reachable("app", "c"));
+        assert!(!graph.is_reachable("c", "app"));
+        assert_eq!(graph.reachable_from_root(), vec!["app", "a", "b", "c"]);
+        assert!(graph.descendants("missing").is_empty());
+    }
+
+    #[test]
+    fn it_should_order_dependencies_before_dependents() {
+        let bom = bom(&[("app", &["a", "b"]), ("a", &["b"]), ("b", &[])]);
+
+        assert_eq!(
+            bom.dependency_graph().topological_order(),
+            Ok(vec!["b", "a", "app"])
+        );
+    }
+
+    #[test]
+    fn it_should_report_cycles() {
+        let bom = bom(&[("app", &["a"]), ("a", &["b"]), ("b", &["a"]), ("c", &[])]);
+
+        assert_eq!(
+            bom.dependency_graph().topological_order(),
+            Err(CycleError {
+                nodes: vec!["app".to_string(), "a".to_string(), "b".to_string()]
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_ignore_duplicate_edges() {
+        let bom = bom(&[("app", &["a", "a"]), ("app", &["a", "b"]), ("b", &[])]);
+        let graph = bom.dependency_graph();
+
+        assert_eq!(graph.dependencies("app"), vec!["a", "b"]);
+        assert_eq!(graph.dependents("a"), vec!["app"]);
+    }
+
+    #[test]
+    fn it_should_handle_large_graphs() {
+        let nodes: Vec<String> = (0..50_000).map(|index| format!("node-{index}")).collect();
+        let mut builder = Bom::builder().dependency(Dependency {
+            dependency_ref: "app".to_string(),
+            dependencies: nodes.clone(),
+        });
+        for pair in nodes.windows(2) {
+            builder = builder.dependency(Dependency {
+                dependency_ref: pair[0].clone(),
+                dependencies: vec![pair[1].clone()],
+            });
+        }
+        let bom = builder.build_unchecked();
+        let graph = bom.dependency_graph();
+
+        assert_eq!(graph.dependencies("app").len(), 50_000);
+        assert_eq!(graph.descendants("node-0").len(), 49_999);
+        assert!(graph.is_reachable("node-0", "node-49999"));
+        assert!(!graph.is_reachable("node-49999", "node-0"));
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order.first(), Some(&"node-49999"));
+        assert_eq!(order.last(), Some(&"app"));
+    }
+}
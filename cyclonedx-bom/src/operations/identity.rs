@@ -0,0 +1,178 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::models::component::Component;
+
+/// Decides whether two components describe the same software, e.g. when merging,
+/// deduplicating or diffing BOMs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IdentityPolicy {
+    /// Components are identical if they have the same package URL.
+    ///
+    /// Components without a package URL are never considered identical.
+    Purl,
+    /// Components are identical if they have the same group, name and version.
+    Coordinates,
+    /// Components are compared by package URL if they have one, otherwise by group, name and version.
+    #[default]
+    PurlOrCoordinates,
+    /// Components are identical if they have the same set of hashes, regardless of their order
+    /// and the case of the hash values.
+    ///
+    /// Components without hashes are never considered identical.
+    Hashes,
+    /// Components are identical if they have the same CPE.
+    ///
+    /// Components without a CPE are never considered identical.
+    Cpe,
+}
+
+/// The value a component is identified by under an [`IdentityPolicy`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum IdentityKey {
+    Purl(String),
+    Coordinates {
+        group: Option<String>,
+        name: String,
+        version: Option<String>,
+    },
+    Hashes(Vec<(String, String)>),
+    Cpe(String),
+}
+
+impl IdentityPolicy {
+    /// Returns `true` if both components are identical under this policy.
+    pub fn matches(&self, left: &Component, right: &Component) -> bool {
+        match (self.key(left), self.key(right)) {
+            (Some(left), Some(right)) => left == right,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn key(&self, component: &Component) -> Option<IdentityKey> {
+        let purl = || {
+            component
+                .purl
+                .as_ref()
+                .map(|purl| IdentityKey::Purl(purl.0.clone()))
+        };
+        let coordinates = || IdentityKey::Coordinates {
+            group: component.group.as_ref().map(|group| group.to_string()),
+            name: component.name.to_string(),
+            version: component
+                .version
+                .as_ref()
+                .map(|version| version.to_string()),
+        };
+
+        match self {
+            Self::Purl => purl(),
+            Self::Coordinates => Some(coordinates()),
+            Self::PurlOrCoordinates => purl().or_else(|| Some(coordinates())),
+            Self::Hashes => {
+                let mut hashes = component
+                    .hashes
+                    .iter()
+                    .flat_map(|hashes| hashes.0.iter())
+                    .map(|hash| (hash.alg.to_string(), hash.content.0.to_ascii_lowercase()))
+                    .collect::<Vec<_>>();
+                hashes.sort();
+                hashes.dedup();
+                (!hashes.is_empty()).then_some(IdentityKey::Hashes(hashes))
+            }
+            Self::Cpe => component
+                .cpe
+                .as_ref()
+                .map(|cpe| IdentityKey::Cpe(cpe.0.clone())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        external_models::uri::Purl,
+        models::{
+            component::{Classification, Cpe},
+            hash::{Hash, HashAlgorithm, Hashes},
+        },
+    };
+
+    use super::*;
+
+    fn component(name: &str, version: &str, purl: Option<&str>) -> Component {
+        let mut component = Component::new(Classification::Library, name, version, None);
+        component.purl = purl.map(|purl| Purl(purl.to_string()));
+        component
+    }
+
+    #[test]
+    fn it_should_match_components_by_purl() {
+        let left = component("a", "1.0", Some("pkg:cargo/a@1.0"));
+        let right = component("renamed", "1.0", Some("pkg:cargo/a@1.0"));
+
+        assert!(IdentityPolicy::Purl.matches(&left, &right));
+        assert!(!IdentityPolicy::Coordinates.matches(&left, &right));
+        assert!(!IdentityPolicy::Purl
+            .matches(&component("a", "1.0", None), &component("a", "1.0", None)));
+    }
+
+    #[test]
+    fn it_should_fall_back_to_coordinates() {
+        let left = component("a", "1.0", None);
+        let right = component("a", "1.0", None);
+
+        assert!(IdentityPolicy::PurlOrCoordinates.matches(&left, &right));
+        assert!(!IdentityPolicy::PurlOrCoordinates.matches(&left, &component("a", "2.0", None)));
+    }
+
+    #[test]
+    fn it_should_match_components_by_hashes() {
+        let mut left = component("a", "1.0", None);
+        left.hashes = Some(Hashes(vec![
+            Hash::new(HashAlgorithm::SHA_256, &"AB".repeat(32)),
+            Hash::new(HashAlgorithm::SHA1, &"cd".repeat(20)),
+        ]));
+        let mut right = component("renamed", "2.0", None);
+        right.hashes = Some(Hashes(vec![
+            Hash::new(HashAlgorithm::SHA1, &"cd".repeat(20)),
+            Hash::new(HashAlgorithm::SHA_256, &"ab".repeat(32)),
+        ]));
+
+        assert!(IdentityPolicy::Hashes.matches(&left, &right));
+        right.hashes = Some(Hashes(vec![Hash::new(
+            HashAlgorithm::SHA1,
+            &"cd".repeat(20),
+        )]));
+        assert!(!IdentityPolicy::Hashes.matches(&left, &right));
+        assert!(!IdentityPolicy::Hashes
+            .matches(&component("a", "1.0", None), &component("a", "1.0", None)));
+    }
+
+    #[test]
+    fn it_should_match_components_by_cpe() {
+        let cpe = "cpe:2.3:a:example:a:1.0:*:*:*:*:*:*:*";
+        let mut left = component("a", "1.0", None);
+        left.cpe = Some(Cpe(cpe.to_string()));
+        let mut right = component("renamed", "1.0", None);
+        right.cpe = Some(Cpe(cpe.to_string()));
+
+        assert!(IdentityPolicy::Cpe.matches(&left, &right));
+        assert!(!IdentityPolicy::Cpe.matches(&left, &component("a", "1.0", None)));
+    }
+}
@@ -0,0 +1,235 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::{collections::HashSet, str::FromStr};
+
+use purl::GenericPurl;
+
+use crate::{
+    external_models::{normalized_string::NormalizedString, uri::Purl},
+    models::{
+        bom::Bom,
+        component::{Classification, Component, Components},
+        dependency::{Dependencies, Dependency},
+        hash::Hashes,
+        metadata::{Metadata, MetadataError},
+    },
+};
+
+/// A package URL with optional hashes, the input of [`Bom::from_purls`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InventoryEntry {
+    pub purl: String,
+    pub hashes: Option<Hashes>,
+}
+
+impl InventoryEntry {
+    pub fn new(purl: impl ToString) -> Self {
+        Self {
+            purl: purl.to_string(),
+            hashes: None,
+        }
+    }
+
+    pub fn with_hashes(mut self, hashes: Hashes) -> Self {
+        self.hashes = Some(hashes);
+        self
+    }
+}
+
+impl From<&str> for InventoryEntry {
+    fn from(purl: &str) -> Self {
+        Self::new(purl)
+    }
+}
+
+impl From<String> for InventoryEntry {
+    fn from(purl: String) -> Self {
+        Self::new(purl)
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InventoryError {
+    #[error("Invalid package URL '{purl}': {reason}")]
+    InvalidPurl { purl: String, reason: String },
+
+    #[error("Failed to create metadata: {0}")]
+    Metadata(#[from] MetadataError),
+}
+
+impl Bom {
+    /// Creates a minimal BOM describing the given packages, e.g. to export an inventory from
+    /// another tool.
+    ///
+    /// Every package URL becomes a library component, with the group, name and version taken
+    /// from the package URL and the package URL as its `bom-ref`. Repeated package URLs are only
+    /// added once. The metadata has the current timestamp and an application component named
+    /// `name`, which depends on all packages. Each package gets an empty dependency entry, since
+    /// nothing is known about its own dependencies.
+    ///
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::from_purls("inventory", ["pkg:cargo/serde@1.0.0", "pkg:npm/%40angular/core@16.0.0"])?;
+    ///
+    /// let components = bom.components.as_ref().unwrap();
+    /// assert_eq!(components.0[1].group.as_ref().unwrap().to_string(), "@angular");
+    /// assert!(bom.validate().passed());
+    /// # Ok::<(), cyclonedx_bom::operations::inventory::InventoryError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a package URL is invalid or if unable to generate a timestamp.
+    pub fn from_purls<I>(name: &str, entries: I) -> Result<Bom, InventoryError>
+    where
+        I: IntoIterator,
+        I::Item: Into<InventoryEntry>,
+    {
+        let mut seen = HashSet::new();
+        let mut components = Vec::new();
+        for entry in entries {
+            let entry = entry.into();
+            let purl = GenericPurl::<String>::from_str(&entry.purl).map_err(|error| {
+                InventoryError::InvalidPurl {
+                    purl: entry.purl.clone(),
+                    reason: error.to_string(),
+                }
+            })?;
+            let purl_string = purl.to_string();
+            if !seen.insert(purl_string.clone()) {
+                continue;
+            }
+
+            let mut component = Component::new(
+                Classification::Library,
+                purl.name(),
+                "",
+                Some(purl_string.clone()),
+            );
+            component.group = purl.namespace().map(NormalizedString::new);
+            component.version = purl.version().map(NormalizedString::new);
+            component.purl = Some(Purl(purl_string));
+            component.hashes = entry.hashes;
+            components.push(component);
+        }
+
+        let refs = components
+            .iter()
+            .filter_map(|component| component.bom_ref.clone())
+            .collect::<Vec<_>>();
+        let mut dependencies = vec![Dependency::new(name, &refs)];
+        dependencies.extend(
+            refs.iter()
+                .map(|bom_ref| Dependency::new(bom_ref, Vec::<String>::new())),
+        );
+
+        Ok(Bom {
+            metadata: Some(Metadata {
+                component: Some(Component {
+                    version: None,
+                    ..Component::new(
+                        Classification::Application,
+                        name,
+                        "",
+                        Some(name.to_string()),
+                    )
+                }),
+                ..Metadata::new()?
+            }),
+            components: Some(Components(components)),
+            dependencies: Some(Dependencies(dependencies)),
+            ..Bom::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        models::hash::{Hash, HashAlgorithm},
+        validation::Validate,
+    };
+
+    #[test]
+    fn it_should_create_a_bom_from_purls() {
+        let hashes = Hashes(vec![Hash::new(HashAlgorithm::SHA_256, &"ab".repeat(32))]);
+        let bom = Bom::from_purls(
+            "inventory",
+            [
+                InventoryEntry::new("pkg:cargo/serde@1.0.0").with_hashes(hashes.clone()),
+                InventoryEntry::new("pkg:maven/org.apache/commons-io"),
+                InventoryEntry::new("pkg:cargo/serde@1.0.0"),
+            ],
+        )
+        .unwrap();
+
+        assert!(bom.validate().passed());
+        assert!(bom.serial_number.is_some());
+
+        let metadata = bom.metadata.as_ref().unwrap();
+        assert!(metadata.timestamp.is_some());
+        let root = metadata.component.as_ref().unwrap();
+        assert_eq!(root.component_type, Classification::Application);
+        assert_eq!(root.bom_ref.as_deref(), Some("inventory"));
+        assert_eq!(root.version, None);
+
+        let components = &bom.components.as_ref().unwrap().0;
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].name.to_string(), "serde");
+        assert_eq!(components[0].version, Some(NormalizedString::new("1.0.0")));
+        assert_eq!(components[0].hashes, Some(hashes));
+        assert_eq!(
+            components[1].group,
+            Some(NormalizedString::new("org.apache"))
+        );
+        assert_eq!(components[1].name.to_string(), "commons-io");
+        assert_eq!(components[1].version, None);
+        assert_eq!(
+            components[1].purl,
+            Some(Purl("pkg:maven/org.apache/commons-io".to_string()))
+        );
+
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                Dependency::new(
+                    "inventory",
+                    ["pkg:cargo/serde@1.0.0", "pkg:maven/org.apache/commons-io"]
+                ),
+                Dependency::new("pkg:cargo/serde@1.0.0", Vec::<String>::new()),
+                Dependency::new("pkg:maven/org.apache/commons-io", Vec::<String>::new()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_purls() {
+        let error = Bom::from_purls("inventory", ["serde@1.0.0"]).unwrap_err();
+
+        assert!(matches!(
+            error,
+            InventoryError::InvalidPurl { purl, .. } if purl == "serde@1.0.0"
+        ));
+    }
+}
@@ -0,0 +1,670 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{HashMap, HashSet};
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::models::{
+    annotation::Annotations,
+    bom::{Bom, BomReference},
+    component::{Component, Components},
+    composition::{AggregateType, Composition, Compositions},
+    dependency::{Dependencies, Dependency},
+    external_reference::ExternalReferences,
+    hash::Hashes,
+    license::Licenses,
+    property::{Properties, Property},
+    service::{Service, Services},
+    vulnerability::Vulnerabilities,
+};
+
+use super::identity::{IdentityKey, IdentityPolicy};
+
+/// Name of the property recording which BOM a component was merged from,
+/// see [`Provenance::Properties`].
+pub const MERGE_SOURCE_PROPERTY: &str = "cyclonedx-bom:merge:source";
+
+/// Controls the behaviour of [`Bom::merge`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergePolicy {
+    /// Decides which components are duplicates of each other.
+    pub identity: IdentityPolicy,
+    /// How the origin of each merged component is recorded.
+    pub provenance: Provenance,
+}
+
+/// How [`Bom::merge`] records which input BOM each component came from.
+///
+/// An input BOM is identified by its serial number, or by its position (e.g. `bom[1]`)
+/// if it has none.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Provenance {
+    /// The origin is not recorded.
+    #[default]
+    None,
+    /// Each top-level component gets one [`MERGE_SOURCE_PROPERTY`] property per input BOM
+    /// that contained it.
+    Properties,
+    /// One [`Composition`] is added per input BOM, listing the `bom-ref`s of its top-level
+    /// components as assemblies.
+    Compositions,
+}
+
+impl Bom {
+    /// Combines multiple BOMs into one.
+    ///
+    /// The metadata of the first BOM is kept, the metadata component of every other BOM is
+    /// added as a top-level component. Top-level components that are identical according to
+    /// the [`IdentityPolicy`] are merged into a single entry, which combines their hashes,
+    /// licenses, external references, properties and subcomponents. Services are deduplicated
+    /// by group, name and version.
+    ///
+    /// References to merged components are rewritten to the `bom-ref` of the surviving entry,
+    /// and colliding `bom-ref`s of distinct components are made unique by appending a suffix.
+    /// Dependencies of the same `bom-ref` are unioned.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::operations::merge::MergePolicy;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let first = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+    ///     .build()
+    ///     .expect("valid BOM");
+    /// let second = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+    ///     .component(Component::new(Classification::Library, "regex", "1.9.0", None))
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// let merged = Bom::merge([first, second], MergePolicy::default());
+    /// assert_eq!(merged.components.unwrap().0.len(), 2);
+    /// ```
+    pub fn merge(boms: impl IntoIterator<Item = Bom>, policy: MergePolicy) -> Bom {
+        let mut merger = Merger::new(policy);
+        for (index, bom) in boms.into_iter().enumerate() {
+            merger.add(index, bom);
+        }
+        merger.finish()
+    }
+}
+
+pub(super) struct Merger {
+    pub(super) policy: MergePolicy,
+    bom: Bom,
+    components: Vec<Component>,
+    component_keys: HashMap<IdentityKey, usize>,
+    services: Vec<Service>,
+    service_keys: HashMap<(Option<String>, String, Option<String>), usize>,
+    bom_refs: HashSet<String>,
+    dependencies: IndexMap<String, IndexSet<String>>,
+}
+
+impl Merger {
+    pub(super) fn new(policy: MergePolicy) -> Self {
+        Self {
+            policy,
+            bom: Bom::default(),
+            components: Vec::new(),
+            component_keys: HashMap::new(),
+            services: Vec::new(),
+            service_keys: HashMap::new(),
+            bom_refs: HashSet::new(),
+            dependencies: IndexMap::new(),
+        }
+    }
+
+    fn add(&mut self, index: usize, mut bom: Bom) {
+        let source = source(index, &bom);
+        let mut renames = HashMap::new();
+        let mut assemblies = Vec::new();
+
+        let mut components = Vec::new();
+        components.extend(self.add_metadata(index, &mut bom));
+        components.extend(bom.components.take().map(|c| c.0).unwrap_or_default());
+
+        for mut component in components {
+            if self.policy.provenance == Provenance::Properties {
+                add_property(&mut component.properties, &source);
+            }
+            let bom_ref = self.add_component(component, &mut renames);
+            if let Some(bom_ref) = bom_ref {
+                assemblies.push(BomReference(bom_ref));
+            }
+        }
+
+        self.add_sections(bom, &mut renames, assemblies);
+    }
+
+    /// Keeps the header of the first BOM, returns the metadata component of the others, which
+    /// is merged as a top-level component.
+    pub(super) fn add_metadata(&mut self, index: usize, bom: &mut Bom) -> Option<Component> {
+        if index == 0 {
+            self.bom.version = bom.version;
+            self.bom.serial_number = bom.serial_number.take();
+            if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+                // Keep references to the primary component stable
+                self.reserve_bom_refs(component);
+            }
+            self.bom.metadata = bom.metadata.take();
+            None
+        } else {
+            bom.metadata.take().and_then(|metadata| metadata.component)
+        }
+    }
+
+    /// Adds everything but the components of a BOM, whose renamed `bom-ref`s are in `renames`
+    /// and whose top-level components merged into `assemblies`.
+    pub(super) fn add_sections(
+        &mut self,
+        bom: Bom,
+        renames: &mut HashMap<String, String>,
+        assemblies: Vec<BomReference>,
+    ) {
+        for service in bom.services.map(|s| s.0).unwrap_or_default() {
+            self.add_service(service, renames);
+        }
+
+        let rename = |bom_ref: String| renames.get(&bom_ref).cloned().unwrap_or(bom_ref);
+
+        for dependency in bom.dependencies.map(|d| d.0).unwrap_or_default() {
+            let dependencies = self
+                .dependencies
+                .entry(rename(dependency.dependency_ref))
+                .or_default();
+            dependencies.extend(dependency.dependencies.into_iter().map(rename));
+        }
+
+        let rename_all = |refs: &mut Option<Vec<BomReference>>| {
+            for bom_ref in refs.iter_mut().flatten() {
+                if let Some(renamed) = renames.get(&bom_ref.0) {
+                    bom_ref.0 = renamed.clone();
+                }
+            }
+        };
+        let mut compositions = bom.compositions.map(|c| c.0).unwrap_or_default();
+        for composition in &mut compositions {
+            rename_all(&mut composition.assemblies);
+            rename_all(&mut composition.dependencies);
+        }
+        if self.policy.provenance == Provenance::Compositions {
+            compositions.push(Composition {
+                bom_ref: None,
+                aggregate: AggregateType::Unknown,
+                assemblies: Some(assemblies),
+                dependencies: None,
+                vulnerabilities: None,
+                signature: None,
+            });
+        }
+        if !compositions.is_empty() {
+            self.bom
+                .compositions
+                .get_or_insert_with(|| Compositions(Vec::new()))
+                .0
+                .extend(compositions);
+        }
+
+        let mut vulnerabilities = bom.vulnerabilities.map(|v| v.0).unwrap_or_default();
+        for target in vulnerabilities
+            .iter_mut()
+            .flat_map(|vulnerability| vulnerability.vulnerability_targets.iter_mut())
+            .flat_map(|targets| targets.0.iter_mut())
+        {
+            if let Some(renamed) = renames.get(&target.bom_ref) {
+                target.bom_ref = renamed.clone();
+            }
+        }
+        if !vulnerabilities.is_empty() {
+            self.bom
+                .vulnerabilities
+                .get_or_insert_with(|| Vulnerabilities(Vec::new()))
+                .0
+                .extend(vulnerabilities);
+        }
+
+        if let Some(external_references) = bom.external_references {
+            extend_unique(
+                &mut self
+                    .bom
+                    .external_references
+                    .get_or_insert_with(|| ExternalReferences(Vec::new()))
+                    .0,
+                external_references.0,
+            );
+        }
+        if let Some(properties) = bom.properties {
+            extend_unique(
+                &mut self
+                    .bom
+                    .properties
+                    .get_or_insert_with(|| Properties(Vec::new()))
+                    .0,
+                properties.0,
+            );
+        }
+        if let Some(annotations) = bom.annotations {
+            self.bom
+                .annotations
+                .get_or_insert_with(|| Annotations(Vec::new()))
+                .0
+                .extend(annotations.0);
+        }
+        if let Some(formulation) = bom.formulation {
+            self.bom
+                .formulation
+                .get_or_insert_with(Vec::new)
+                .extend(formulation);
+        }
+    }
+
+    /// Adds a top-level component and returns its `bom-ref` in the merged BOM.
+    fn add_component(
+        &mut self,
+        mut component: Component,
+        renames: &mut HashMap<String, String>,
+    ) -> Option<String> {
+        let key = self.policy.identity.key(&component);
+
+        if let Some(&existing) = key.as_ref().and_then(|key| self.component_keys.get(key)) {
+            if self.components[existing].bom_ref.is_none() {
+                if let Some(bom_ref) = component.bom_ref.take() {
+                    let unique = self.unique_bom_ref(&bom_ref);
+                    if unique != bom_ref {
+                        renames.insert(bom_ref, unique.clone());
+                    }
+                    self.components[existing].bom_ref = Some(unique);
+                }
+            }
+            let existing = &mut self.components[existing];
+            if let (Some(from), Some(to)) = (&component.bom_ref, &existing.bom_ref) {
+                renames.insert(from.clone(), to.clone());
+            }
+            merge_component(existing, component);
+            return existing.bom_ref.clone();
+        }
+
+        if let Some(bom_ref) = &mut component.bom_ref {
+            let unique = self.unique_bom_ref(bom_ref);
+            if unique != *bom_ref {
+                renames.insert(bom_ref.clone(), unique.clone());
+                *bom_ref = unique;
+            }
+        }
+        for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+            self.reserve_bom_refs(subcomponent);
+        }
+        if let Some(key) = key {
+            self.component_keys.insert(key, self.components.len());
+        }
+        let bom_ref = component.bom_ref.clone();
+        self.components.push(component);
+        bom_ref
+    }
+
+    fn add_service(&mut self, mut service: Service, renames: &mut HashMap<String, String>) {
+        let key = (
+            service.group.as_ref().map(|group| group.to_string()),
+            service.name.to_string(),
+            service.version.as_ref().map(|version| version.to_string()),
+        );
+
+        if let Some(&existing) = self.service_keys.get(&key) {
+            let existing = &mut self.services[existing];
+            if let (Some(from), Some(to)) = (&service.bom_ref, &existing.bom_ref) {
+                renames.insert(from.clone(), to.clone());
+            }
+            if let Some(properties) = service.properties {
+                extend_unique(
+                    &mut existing
+                        .properties
+                        .get_or_insert_with(|| Properties(Vec::new()))
+                        .0,
+                    properties.0,
+                );
+            }
+            return;
+        }
+
+        if let Some(bom_ref) = &mut service.bom_ref {
+            let unique = self.unique_bom_ref(bom_ref);
+            if unique != *bom_ref {
+                renames.insert(bom_ref.clone(), unique.clone());
+                *bom_ref = unique;
+            }
+        }
+        self.service_keys.insert(key, self.services.len());
+        self.services.push(service);
+    }
+
+    pub(super) fn reserve_bom_refs(&mut self, component: &Component) {
+        if let Some(bom_ref) = &component.bom_ref {
+            self.bom_refs.insert(bom_ref.clone());
+        }
+        for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+            self.reserve_bom_refs(subcomponent);
+        }
+    }
+
+    /// Returns the given `bom-ref` or a variant with a numeric suffix if it is already taken.
+    pub(super) fn unique_bom_ref(&mut self, bom_ref: &str) -> String {
+        let mut unique = bom_ref.to_string();
+        let mut suffix = 1;
+        while self.bom_refs.contains(&unique) {
+            suffix += 1;
+            unique = format!("{bom_ref}-{suffix}");
+        }
+        self.bom_refs.insert(unique.clone());
+        unique
+    }
+
+    pub(super) fn finish(mut self) -> Bom {
+        if !self.components.is_empty() {
+            self.bom.components = Some(Components(self.components));
+        }
+        if !self.services.is_empty() {
+            self.bom.services = Some(Services(self.services));
+        }
+        if !self.dependencies.is_empty() {
+            self.bom.dependencies = Some(Dependencies(
+                self.dependencies
+                    .into_iter()
+                    .map(|(dependency_ref, dependencies)| Dependency {
+                        dependency_ref,
+                        dependencies: dependencies.into_iter().collect(),
+                    })
+                    .collect(),
+            ));
+        }
+        self.bom
+    }
+}
+
+/// Merges the data of `other` into `target`, both describing the same component.
+///
+/// Lists are unioned, other fields of `target` are only filled in if they are missing.
+pub(crate) fn merge_component(target: &mut Component, other: Component) {
+    fn fill<T>(target: &mut Option<T>, other: Option<T>) {
+        if target.is_none() {
+            *target = other;
+        }
+    }
+
+    fill(&mut target.bom_ref, other.bom_ref);
+    fill(&mut target.supplier, other.supplier);
+    fill(&mut target.author, other.author);
+    fill(&mut target.publisher, other.publisher);
+    fill(&mut target.group, other.group);
+    fill(&mut target.version, other.version);
+    fill(&mut target.description, other.description);
+    fill(&mut target.scope, other.scope);
+    fill(&mut target.copyright, other.copyright);
+    fill(&mut target.cpe, other.cpe);
+    fill(&mut target.purl, other.purl);
+    fill(&mut target.swid, other.swid);
+    fill(&mut target.pedigree, other.pedigree);
+    fill(&mut target.evidence, other.evidence);
+
+    if let Some(hashes) = other.hashes {
+        extend_unique(
+            &mut target.hashes.get_or_insert_with(|| Hashes(Vec::new())).0,
+            hashes.0,
+        );
+    }
+    if let Some(licenses) = other.licenses {
+        extend_unique(
+            &mut target
+                .licenses
+                .get_or_insert_with(|| Licenses(Vec::new()))
+                .0,
+            licenses.0,
+        );
+    }
+    if let Some(external_references) = other.external_references {
+        extend_unique(
+            &mut target
+                .external_references
+                .get_or_insert_with(|| ExternalReferences(Vec::new()))
+                .0,
+            external_references.0,
+        );
+    }
+    if let Some(properties) = other.properties {
+        extend_unique(
+            &mut target
+                .properties
+                .get_or_insert_with(|| Properties(Vec::new()))
+                .0,
+            properties.0,
+        );
+    }
+    if let Some(components) = other.components {
+        extend_unique(
+            &mut target
+                .components
+                .get_or_insert_with(|| Components(Vec::new()))
+                .0,
+            components.0,
+        );
+    }
+}
+
+pub(super) fn add_property(properties: &mut Option<Properties>, source: &str) {
+    properties
+        .get_or_insert_with(|| Properties(Vec::new()))
+        .0
+        .push(Property {
+            name: MERGE_SOURCE_PROPERTY.to_string(),
+            value: NormalizedString::new(source),
+        });
+}
+
+/// Identifies an input BOM by its serial number, or by its position if it has none.
+pub(super) fn source(index: usize, bom: &Bom) -> String {
+    bom.serial_number
+        .as_ref()
+        .map(|serial_number| serial_number.to_string())
+        .unwrap_or_else(|| format!("bom[{index}]"))
+}
+
+fn extend_unique<T: PartialEq>(target: &mut Vec<T>, items: Vec<T>) {
+    for item in items {
+        if !target.contains(&item) {
+            target.push(item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::uri::Purl,
+        models::{
+            component::Classification,
+            hash::{Hash, HashAlgorithm, HashValue},
+            metadata::Metadata,
+        },
+    };
+
+    fn component(name: &str, version: &str, bom_ref: &str) -> Component {
+        let mut component =
+            Component::new(Classification::Library, name, version, Some(bom_ref.into()));
+        component.purl = Some(Purl::new("cargo", name, version).unwrap());
+        component
+    }
+
+    fn dependency(dependency_ref: &str, dependencies: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn it_should_deduplicate_components_and_rewrite_refs() {
+        let mut first_serde = component("serde", "1.0.0", "serde");
+        first_serde.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::SHA_256,
+            content: HashValue("a".repeat(64)),
+        }]));
+        let first = Bom::builder()
+            .component(first_serde)
+            .dependency(dependency("serde", &[]))
+            .build()
+            .unwrap();
+        let second = Bom::builder()
+            .component(component("serde", "1.0.0", "pkg:cargo/serde@1.0.0"))
+            .component(component("app", "0.1.0", "app"))
+            .dependency(dependency("app", &["pkg:cargo/serde@1.0.0"]))
+            .build()
+            .unwrap();
+
+        let merged = Bom::merge([first, second], MergePolicy::default());
+
+        let components = merged.components.unwrap().0;
+        assert_eq!(
+            components
+                .iter()
+                .map(|c| c.bom_ref.clone().unwrap())
+                .collect::<Vec<_>>(),
+            vec!["serde", "app"]
+        );
+        assert!(components[0].hashes.is_some());
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![
+                dependency("serde", &[]),
+                dependency("app", &["serde"]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_make_colliding_bom_refs_unique() {
+        let first = Bom::builder()
+            .component(component("a", "1.0.0", "lib"))
+            .build()
+            .unwrap();
+        let second = Bom::builder()
+            .component(component("b", "1.0.0", "lib"))
+            .dependency(dependency("lib", &[]))
+            .build()
+            .unwrap();
+
+        let merged = Bom::merge([first, second], MergePolicy::default());
+
+        assert_eq!(
+            merged.components.unwrap().0[1].bom_ref,
+            Some("lib-2".to_string())
+        );
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![dependency("lib-2", &[])]))
+        );
+    }
+
+    #[test]
+    fn it_should_add_metadata_components_of_other_boms() {
+        let metadata = |name| Metadata {
+            component: Some(component(name, "1.0.0", name)),
+            ..Metadata::default()
+        };
+        let first = Bom::builder()
+            .metadata(metadata("frontend"))
+            .build()
+            .unwrap();
+        let second = Bom::builder()
+            .metadata(metadata("backend"))
+            .build()
+            .unwrap();
+
+        let merged = Bom::merge([first, second], MergePolicy::default());
+
+        assert_eq!(merged.metadata, Some(metadata("frontend")));
+        assert_eq!(
+            merged.components,
+            Some(Components(vec![component("backend", "1.0.0", "backend")]))
+        );
+    }
+
+    #[test]
+    fn it_should_record_provenance_as_properties() {
+        let first = Bom::builder()
+            .component(component("a", "1.0.0", "a"))
+            .build()
+            .unwrap();
+        let second = Bom::builder()
+            .component(component("a", "1.0.0", "a"))
+            .build()
+            .unwrap();
+        let policy = MergePolicy {
+            provenance: Provenance::Properties,
+            ..MergePolicy::default()
+        };
+
+        let merged = Bom::merge([first, second], policy);
+
+        assert_eq!(
+            merged.components.unwrap().0[0].properties,
+            Some(Properties(vec![
+                Property::new(MERGE_SOURCE_PROPERTY, "bom[0]"),
+                Property::new(MERGE_SOURCE_PROPERTY, "bom[1]"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_record_provenance_as_compositions() {
+        let first = Bom::builder()
+            .component(component("a", "1.0.0", "a"))
+            .build()
+            .unwrap();
+        let second = Bom::builder()
+            .component(component("a", "1.0.0", "other-a"))
+            .component(component("b", "1.0.0", "b"))
+            .build()
+            .unwrap();
+        let policy = MergePolicy {
+            provenance: Provenance::Compositions,
+            ..MergePolicy::default()
+        };
+
+        let merged = Bom::merge([first, second], policy);
+
+        let assemblies = merged
+            .compositions
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|composition| composition.assemblies.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            assemblies,
+            vec![
+                vec![BomReference::new("a")],
+                vec![BomReference::new("a"), BomReference::new("b")],
+            ]
+        );
+    }
+}
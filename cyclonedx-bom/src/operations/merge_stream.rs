@@ -0,0 +1,591 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+//! Merging JSON documents without holding their components in memory.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{BufReader, Read, Write},
+    str::FromStr,
+};
+
+use serde::de::{DeserializeOwned, DeserializeSeed, Error as _, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Value};
+
+use crate::{
+    errors::{BomError, JsonReadError, JsonWriteError},
+    models::{
+        bom::{Bom, BomReference, SpecVersion},
+        component::Component,
+    },
+    specs::{direct, v1_3, v1_4, v1_5},
+};
+
+use super::{
+    identity::IdentityKey,
+    merge::{add_property, source, MergePolicy, Merger, Provenance},
+};
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum MergeStreamError {
+    #[error("Failed to read BOM {index}: {source}")]
+    Read {
+        /// The position of the BOM in the inputs.
+        index: usize,
+        #[source]
+        source: JsonReadError,
+    },
+
+    #[error("Failed to write the merged BOM: {source}")]
+    Write {
+        #[from]
+        source: JsonWriteError,
+    },
+}
+
+impl Bom {
+    /// Merges JSON documents like [`Bom::merge`], writing the merged document to `writer` as
+    /// the components of the inputs are read.
+    ///
+    /// Only the identities and `bom-ref`s of the components written so far are kept in memory,
+    /// so the number and size of the inputs is not limited by the memory needed to hold their
+    /// components. The other sections, like services and dependencies, are merged in memory and
+    /// written after the components.
+    ///
+    /// Because components are written before the later inputs are read, duplicates are not
+    /// combined: the first occurrence of a component is written as is and references to later
+    /// occurrences are rewritten to its `bom-ref`. With [`Provenance::Properties`] only the
+    /// BOM the first occurrence came from is recorded. The metadata component of every input
+    /// but the first is added after the components of that input.
+    ///
+    /// Inputs are read through a [`BufReader`]. Documents should have their `specVersion` before
+    /// their `components`, which are otherwise held in memory until the version is known. The
+    /// output is written without indentation and has the `components` before the other fields.
+    ///
+    /// ```
+    /// use cyclonedx_bom::operations::merge::MergePolicy;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let first = br#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1, "components": [
+    ///     {"type": "library", "name": "serde", "version": "1.0.0"}
+    /// ]}"#;
+    /// let second = br#"{"bomFormat": "CycloneDX", "specVersion": "1.4", "version": 1, "components": [
+    ///     {"type": "library", "name": "serde", "version": "1.0.0"},
+    ///     {"type": "library", "name": "regex", "version": "1.9.0"}
+    /// ]}"#;
+    ///
+    /// let mut output = Vec::new();
+    /// Bom::merge_json_streams([&first[..], &second[..]], MergePolicy::default(), SpecVersion::V1_5, &mut output)
+    ///     .expect("mergeable BOMs");
+    ///
+    /// let merged = Bom::parse_from_json(&output[..]).expect("valid JSON");
+    /// assert_eq!(merged.components.unwrap().0.len(), 2);
+    /// ```
+    pub fn merge_json_streams<R: Read, W: Write>(
+        inputs: impl IntoIterator<Item = R>,
+        policy: MergePolicy,
+        version: SpecVersion,
+        mut writer: W,
+    ) -> Result<(), MergeStreamError> {
+        let mut merger = StreamMerger {
+            merger: Merger::new(policy),
+            written: HashMap::new(),
+            output: Output {
+                writer: &mut writer,
+                version,
+                components: 0,
+            },
+        };
+        for (index, input) in inputs.into_iter().enumerate() {
+            merger
+                .add(index, &mut BufReader::new(input))
+                .map_err(|error| match error {
+                    AddError::Read(source) => MergeStreamError::Read { index, source },
+                    AddError::Write(error) => error.into(),
+                })?;
+        }
+        merger.finish()?;
+        Ok(())
+    }
+}
+
+struct StreamMerger<'w> {
+    merger: Merger,
+    /// The `bom-ref` of the first occurrence of each written component.
+    written: HashMap<IdentityKey, Option<String>>,
+    output: Output<'w>,
+}
+
+enum AddError {
+    Read(JsonReadError),
+    Write(JsonWriteError),
+}
+
+impl StreamMerger<'_> {
+    fn add(&mut self, index: usize, reader: &mut dyn Read) -> Result<(), AddError> {
+        let mut input = Input {
+            merger: self,
+            source: None,
+            index,
+            spec_version: None,
+            unversioned_components: None,
+            rest: Map::new(),
+            renames: HashMap::new(),
+            assemblies: Vec::new(),
+            write_error: None,
+        };
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let read = serde::Deserializer::deserialize_map(&mut deserializer, &mut input)
+            .and_then(|()| deserializer.end());
+        if let Some(error) = input.write_error.take() {
+            return Err(AddError::Write(error));
+        }
+        read.map_err(|error| AddError::Read(error.into()))?;
+
+        if let Some(components) = input.unversioned_components.take() {
+            let version = input.spec_version.ok_or_else(|| {
+                AddError::Read(
+                    BomError::UnsupportedSpecVersion("No field 'specVersion' found".to_string())
+                        .into(),
+                )
+            })?;
+            for component in components {
+                let component = match version {
+                    SpecVersion::V1_3 => {
+                        serde_json::from_value::<v1_3::component::Component>(component)
+                            .map(Into::into)
+                    }
+                    SpecVersion::V1_4 => {
+                        serde_json::from_value::<v1_4::component::Component>(component)
+                            .map(Into::into)
+                    }
+                    SpecVersion::V1_5 => {
+                        serde_json::from_value::<v1_5::component::Component>(component)
+                            .map(Into::into)
+                    }
+                }
+                .map_err(|error| AddError::Read(error.into()))?;
+                input.add_component(component).map_err(AddError::Write)?;
+            }
+        }
+
+        let mut bom = Bom::parse_json_value(Value::Object(std::mem::take(&mut input.rest)))
+            .map_err(AddError::Read)?;
+        input.source = Some(source(index, &bom));
+        if let Some(component) = input.merger.merger.add_metadata(index, &mut bom) {
+            input.add_component(component).map_err(AddError::Write)?;
+        }
+        let Input {
+            mut renames,
+            assemblies,
+            ..
+        } = input;
+        self.merger.add_sections(bom, &mut renames, assemblies);
+        Ok(())
+    }
+
+    /// Writes the merged sections after the components and closes the document.
+    fn finish(self) -> Result<(), JsonWriteError> {
+        let Output {
+            writer,
+            version,
+            components,
+        } = self.output;
+        let mut rest = Vec::new();
+        direct::to_writer(&mut rest, &self.merger.finish(), version)?;
+        let write = |writer: &mut dyn Write, bytes: &[u8]| {
+            writer.write_all(bytes).map_err(serde_json::Error::io)
+        };
+        if components == 0 {
+            write(writer, &rest)?;
+        } else {
+            // The merged BOM has no components, so its format and version are followed by the
+            // other fields
+            let header = header(version);
+            let rest = rest
+                .strip_prefix(&header[..])
+                .expect("the BOM starts with its format and version");
+            write(writer, b"],")?;
+            write(writer, rest)?;
+        }
+        writer.flush().map_err(serde_json::Error::io)?;
+        Ok(())
+    }
+}
+
+/// The start of the merged document up to the first component.
+struct Output<'w> {
+    writer: &'w mut dyn Write,
+    version: SpecVersion,
+    /// The number of components written so far.
+    components: usize,
+}
+
+impl Output<'_> {
+    fn write_component(&mut self, component: Component) -> Result<(), JsonWriteError> {
+        if self.components == 0 {
+            self.writer
+                .write_all(&header(self.version))
+                .and_then(|()| self.writer.write_all(br#""components":["#))
+        } else {
+            self.writer.write_all(b",")
+        }
+        .map_err(serde_json::Error::io)?;
+        direct::component_to_writer(&mut *self.writer, &component, self.version)?;
+        self.components += 1;
+        Ok(())
+    }
+}
+
+/// The format and version of a serialized BOM, followed by the comma before its next field.
+fn header(version: SpecVersion) -> Vec<u8> {
+    let mut header = br#"{"bomFormat":"CycloneDX","specVersion":"#.to_vec();
+    serde_json::to_writer(&mut header, &version).expect("spec versions are serializable");
+    header.push(b',');
+    header
+}
+
+/// The state of reading one input document.
+struct Input<'a, 'w> {
+    merger: &'a mut StreamMerger<'w>,
+    source: Option<String>,
+    index: usize,
+    spec_version: Option<SpecVersion>,
+    /// The components of a document that has them before its `specVersion`.
+    unversioned_components: Option<Vec<Value>>,
+    /// All fields but the components.
+    rest: Map<String, Value>,
+    renames: HashMap<String, String>,
+    assemblies: Vec<BomReference>,
+    /// Set when writing a component failed while reading the document.
+    write_error: Option<JsonWriteError>,
+}
+
+impl Input<'_, '_> {
+    fn add_component(&mut self, mut component: Component) -> Result<(), JsonWriteError> {
+        let merger = &mut self.merger.merger;
+        let key = merger.policy.identity.key(&component);
+
+        if let Some(existing) = key.as_ref().and_then(|key| self.merger.written.get(key)) {
+            if let (Some(from), Some(to)) = (&component.bom_ref, existing) {
+                self.renames.insert(from.clone(), to.clone());
+            }
+            if let Some(bom_ref) = existing {
+                self.assemblies.push(BomReference(bom_ref.clone()));
+            }
+            return Ok(());
+        }
+
+        if let Some(bom_ref) = &mut component.bom_ref {
+            let unique = merger.unique_bom_ref(bom_ref);
+            if unique != *bom_ref {
+                self.renames.insert(bom_ref.clone(), unique.clone());
+                *bom_ref = unique;
+            }
+        }
+        for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+            merger.reserve_bom_refs(subcomponent);
+        }
+        if merger.policy.provenance == Provenance::Properties {
+            let source = self
+                .source
+                .get_or_insert_with(|| format!("bom[{}]", self.index));
+            add_property(&mut component.properties, source);
+        }
+        if let Some(bom_ref) = &component.bom_ref {
+            self.assemblies.push(BomReference(bom_ref.clone()));
+        }
+        if let Some(key) = key {
+            self.merger.written.insert(key, component.bom_ref.clone());
+        }
+        self.merger.output.write_component(component)
+    }
+
+    fn read_components<'de, A: MapAccess<'de>>(&mut self, map: &mut A) -> Result<(), A::Error> {
+        match self.spec_version {
+            Some(SpecVersion::V1_3) => {
+                map.next_value_seed(ComponentsSeed::<v1_3::component::Component>::new(self))
+            }
+            Some(SpecVersion::V1_4) => {
+                map.next_value_seed(ComponentsSeed::<v1_4::component::Component>::new(self))
+            }
+            Some(SpecVersion::V1_5) => {
+                map.next_value_seed(ComponentsSeed::<v1_5::component::Component>::new(self))
+            }
+            None => {
+                self.unversioned_components = map.next_value()?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for &mut Input<'_, '_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a CycloneDX BOM")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "components" => self.read_components(&mut map)?,
+                "specVersion" => {
+                    let version = map.next_value::<Value>()?;
+                    if let Some(version) = version.as_str() {
+                        self.spec_version =
+                            Some(SpecVersion::from_str(version).map_err(A::Error::custom)?);
+                    }
+                    self.rest.insert(key, version);
+                }
+                "serialNumber" => {
+                    let serial_number = map.next_value::<Value>()?;
+                    if let Some(serial_number) = serial_number.as_str() {
+                        self.source = Some(serial_number.to_string());
+                    }
+                    self.rest.insert(key, serial_number);
+                }
+                _ => {
+                    let value = map.next_value()?;
+                    self.rest.insert(key, value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the components of a document one by one and adds them to the merged output.
+struct ComponentsSeed<'i, 'a, 'w, C> {
+    input: &'i mut Input<'a, 'w>,
+    component: std::marker::PhantomData<C>,
+}
+
+impl<'i, 'a, 'w, C> ComponentsSeed<'i, 'a, 'w, C> {
+    fn new(input: &'i mut Input<'a, 'w>) -> Self {
+        Self {
+            input,
+            component: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, C> DeserializeSeed<'de> for ComponentsSeed<'_, '_, '_, C>
+where
+    C: DeserializeOwned + Into<Component>,
+{
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_option(self)
+    }
+}
+
+impl<'de, C> Visitor<'de> for ComponentsSeed<'_, '_, '_, C>
+where
+    C: DeserializeOwned + Into<Component>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list of components")
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_some<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(component) = seq.next_element::<C>()? {
+            if let Err(error) = self.input.add_component(component.into()) {
+                self.input.write_error = Some(error);
+                return Err(A::Error::custom("failed to write a component"));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{
+        component::{Classification, Components},
+        dependency::{Dependencies, Dependency},
+        metadata::Metadata,
+    };
+
+    fn component(name: &str, version: &str, bom_ref: &str) -> Component {
+        Component::new(Classification::Library, name, version, Some(bom_ref.into()))
+    }
+
+    fn to_json(bom: Bom, version: SpecVersion) -> Vec<u8> {
+        let mut output = Vec::new();
+        bom.output_as_json(&mut output, version).unwrap();
+        output
+    }
+
+    fn example_boms() -> Vec<Bom> {
+        vec![
+            Bom {
+                metadata: Some(Metadata {
+                    component: Some(component("app", "1.0", "app")),
+                    ..Metadata::default()
+                }),
+                components: Some(Components(vec![
+                    component("serde", "1.0", "serde"),
+                    component("regex", "1.9", "regex"),
+                ])),
+                dependencies: Some(Dependencies(vec![Dependency {
+                    dependency_ref: "app".to_string(),
+                    dependencies: vec!["serde".to_string(), "regex".to_string()],
+                }])),
+                ..Bom::default()
+            },
+            Bom {
+                metadata: Some(Metadata {
+                    component: Some(component("tool", "2.0", "app")),
+                    ..Metadata::default()
+                }),
+                components: Some(Components(vec![
+                    component("serde", "1.0", "pkg:serde"),
+                    component("log", "0.4", "serde"),
+                ])),
+                dependencies: Some(Dependencies(vec![Dependency {
+                    dependency_ref: "app".to_string(),
+                    dependencies: vec!["pkg:serde".to_string(), "serde".to_string()],
+                }])),
+                ..Bom::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn it_should_merge_like_merge_in_memory() {
+        let boms = example_boms();
+        let inputs = [
+            to_json(boms[0].clone(), SpecVersion::V1_5),
+            to_json(boms[1].clone(), SpecVersion::V1_4),
+        ];
+
+        let mut output = Vec::new();
+        Bom::merge_json_streams(
+            inputs.iter().map(|input| &input[..]),
+            MergePolicy::default(),
+            SpecVersion::V1_5,
+            &mut output,
+        )
+        .unwrap();
+        let streamed = Bom::parse_from_json(&output[..]).unwrap();
+        let merged = Bom::merge(boms, MergePolicy::default());
+
+        assert_eq!(streamed.serial_number, merged.serial_number);
+        assert_eq!(streamed.metadata, merged.metadata);
+        let refs = |bom: &Bom| {
+            let mut refs: Vec<_> = bom
+                .components
+                .iter()
+                .flat_map(|c| c.0.iter())
+                .map(|c| (c.name.to_string(), c.bom_ref.clone().unwrap()))
+                .collect();
+            refs.sort();
+            refs
+        };
+        assert_eq!(
+            refs(&streamed),
+            vec![
+                ("log".to_string(), "serde-2".to_string()),
+                ("regex".to_string(), "regex".to_string()),
+                ("serde".to_string(), "serde".to_string()),
+                ("tool".to_string(), "app-2".to_string()),
+            ]
+        );
+        assert_eq!(refs(&streamed), refs(&merged));
+        assert_eq!(streamed.dependencies, merged.dependencies);
+    }
+
+    #[test]
+    fn it_should_read_components_before_the_spec_version() {
+        let input = br#"{
+            "components": [{"type": "library", "name": "serde", "version": "1.0", "bom-ref": "serde"}],
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.3",
+            "version": 1
+        }"#;
+
+        let mut output = Vec::new();
+        Bom::merge_json_streams(
+            [&input[..], &input[..]],
+            MergePolicy::default(),
+            SpecVersion::V1_3,
+            &mut output,
+        )
+        .unwrap();
+
+        let merged = Bom::parse_from_json(&output[..]).unwrap();
+        assert_eq!(merged.components.unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn it_should_write_a_bom_without_components() {
+        let input = to_json(Bom::builder().build().unwrap(), SpecVersion::V1_5);
+
+        let mut output = Vec::new();
+        Bom::merge_json_streams(
+            [&input[..]],
+            MergePolicy::default(),
+            SpecVersion::V1_5,
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"{"bomFormat":"CycloneDX","specVersion":"1.5","version":1,"serialNumber":null}"#
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_failing_input() {
+        let valid = to_json(Bom::builder().build().unwrap(), SpecVersion::V1_5);
+        let invalid = br#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "components": [{}]}"#;
+
+        let mut output = Vec::new();
+        let error = Bom::merge_json_streams(
+            [&valid[..], &invalid[..]],
+            MergePolicy::default(),
+            SpecVersion::V1_5,
+            &mut output,
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, MergeStreamError::Read { index: 1, .. }));
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Operations that inspect or transform a whole [`Bom`](crate::models::bom::Bom).
+//!
+//! The operations are exposed as methods on `Bom`, this module contains the types they
+//! accept and return.
+
+#[cfg(feature = "hashing")]
+pub mod artifact;
+pub mod bom_ref;
+pub mod dedupe;
+pub mod diff;
+pub mod dot;
+#[cfg(feature = "json")]
+pub mod downgrade;
+pub mod flatten;
+pub mod graph;
+pub mod identity;
+pub mod inventory;
+pub mod merge;
+#[cfg(feature = "json")]
+pub mod merge_stream;
+pub mod normalize;
+pub mod query;
+pub mod redact;
+#[cfg(feature = "json")]
+pub mod resolve;
+pub mod retain;
+pub mod revision;
+pub mod scope;
+#[cfg(feature = "signatures")]
+pub mod signature;
+pub mod sort;
+pub mod split;
+pub mod subtree;
+pub mod summary;
+pub mod walk;
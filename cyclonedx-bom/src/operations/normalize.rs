@@ -0,0 +1,522 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::{normalized_string::NormalizedString, spdx::SpdxExpression};
+use crate::models::{
+    bom::Bom,
+    component::{Component, Components},
+    external_reference::ExternalReferences,
+    hash::Hashes,
+    license::{LicenseChoice, LicenseIdentifier, Licenses},
+    metadata::Metadata,
+    organization::{OrganizationalContact, OrganizationalEntity},
+    property::Properties,
+    service::{Service, Services},
+    tool::Tools,
+};
+
+/// The kind of fix applied by [`Bom::normalize`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FixKind {
+    /// Invalid whitespace was replaced, collapsed or trimmed in a [`NormalizedString`].
+    Whitespace,
+    /// A hash value was converted to lowercase hex.
+    HashCase,
+    /// A list of properties was sorted by name and value.
+    PropertiesSorted,
+    /// Duplicate entries were removed from a list of properties.
+    PropertiesDeduplicated,
+    /// A legacy license expression (e.g. `MIT/Apache-2.0`) was converted to a valid SPDX expression.
+    LicenseExpression,
+}
+
+/// A single entry in the changelog returned by [`Bom::normalize`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NormalizationFix {
+    /// Location of the fixed value inside the BOM, e.g. `components[0].name`.
+    pub path: String,
+    pub kind: FixKind,
+    /// Human readable description of the change.
+    pub description: String,
+}
+
+impl Bom {
+    /// Applies safe fixes for issues that would otherwise be reported by validation.
+    ///
+    /// The following fixes are applied:
+    ///
+    /// - invalid whitespace in [`NormalizedString`] values is replaced with spaces, collapsed and trimmed
+    /// - hash values are converted to lowercase hex
+    /// - properties are sorted by name and value, and exact duplicates are removed
+    /// - legacy license expressions like `MIT/Apache-2.0` are converted to `MIT OR Apache-2.0`
+    ///
+    /// Returns a changelog with one entry per applied fix.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::property::{Properties, Property};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut bom = Bom {
+    ///     properties: Some(Properties(vec![
+    ///         Property::new("b", "value"),
+    ///         Property::new("a", "value"),
+    ///     ])),
+    ///     ..Bom::default()
+    /// };
+    ///
+    /// let fixes = bom.normalize();
+    /// assert_eq!(fixes.len(), 1);
+    /// assert_eq!(bom.properties.unwrap().0[0].name, "a");
+    /// ```
+    pub fn normalize(&mut self) -> Vec<NormalizationFix> {
+        let mut normalizer = Normalizer::default();
+
+        if let Some(metadata) = &mut self.metadata {
+            normalizer.metadata("metadata", metadata);
+        }
+        if let Some(components) = &mut self.components {
+            normalizer.components("components", components);
+        }
+        if let Some(services) = &mut self.services {
+            normalizer.services("services", services);
+        }
+        if let Some(external_references) = &mut self.external_references {
+            normalizer.external_references("externalReferences", external_references);
+        }
+        if let Some(properties) = &mut self.properties {
+            normalizer.properties("properties", properties);
+        }
+
+        normalizer.fixes
+    }
+}
+
+#[derive(Default)]
+struct Normalizer {
+    fixes: Vec<NormalizationFix>,
+}
+
+impl Normalizer {
+    fn record(&mut self, path: &str, kind: FixKind, description: String) {
+        self.fixes.push(NormalizationFix {
+            path: path.to_string(),
+            kind,
+            description,
+        });
+    }
+
+    fn string(&mut self, path: &str, value: &mut NormalizedString) {
+        let normalized = value
+            .0
+            .split([' ', '\r', '\n', '\t'])
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if normalized != **value {
+            self.record(
+                path,
+                FixKind::Whitespace,
+                format!("{:?} -> {:?}", value.0, normalized),
+            );
+            *value = NormalizedString::new_unchecked(normalized);
+        }
+    }
+
+    fn string_option(&mut self, path: &str, value: Option<&mut NormalizedString>) {
+        if let Some(value) = value {
+            self.string(path, value);
+        }
+    }
+
+    fn metadata(&mut self, path: &str, metadata: &mut Metadata) {
+        if let Some(tools) = &mut metadata.tools {
+            let path = format!("{path}.tools");
+            match tools {
+                Tools::List(tools) => {
+                    for (index, tool) in tools.iter_mut().enumerate() {
+                        let path = format!("{path}[{index}]");
+                        self.string_option(&format!("{path}.vendor"), tool.vendor.as_mut());
+                        self.string_option(&format!("{path}.name"), tool.name.as_mut());
+                        self.string_option(&format!("{path}.version"), tool.version.as_mut());
+                        if let Some(hashes) = &mut tool.hashes {
+                            self.hashes(&format!("{path}.hashes"), hashes);
+                        }
+                        if let Some(references) = &mut tool.external_references {
+                            self.external_references(
+                                &format!("{path}.externalReferences"),
+                                references,
+                            );
+                        }
+                    }
+                }
+                Tools::Object {
+                    services,
+                    components,
+                } => {
+                    if let Some(components) = components {
+                        self.components(&format!("{path}.components"), components);
+                    }
+                    if let Some(services) = services {
+                        self.services(&format!("{path}.services"), services);
+                    }
+                }
+            }
+        }
+        if let Some(authors) = &mut metadata.authors {
+            for (index, author) in authors.iter_mut().enumerate() {
+                self.contact(&format!("{path}.authors[{index}]"), author);
+            }
+        }
+        if let Some(component) = &mut metadata.component {
+            self.component(&format!("{path}.component"), component);
+        }
+        if let Some(manufacture) = &mut metadata.manufacture {
+            self.entity(&format!("{path}.manufacture"), manufacture);
+        }
+        if let Some(supplier) = &mut metadata.supplier {
+            self.entity(&format!("{path}.supplier"), supplier);
+        }
+        if let Some(licenses) = &mut metadata.licenses {
+            self.licenses(&format!("{path}.licenses"), licenses);
+        }
+        if let Some(properties) = &mut metadata.properties {
+            self.properties(&format!("{path}.properties"), properties);
+        }
+    }
+
+    fn contact(&mut self, path: &str, contact: &mut OrganizationalContact) {
+        self.string_option(&format!("{path}.name"), contact.name.as_mut());
+        self.string_option(&format!("{path}.email"), contact.email.as_mut());
+        self.string_option(&format!("{path}.phone"), contact.phone.as_mut());
+    }
+
+    fn entity(&mut self, path: &str, entity: &mut OrganizationalEntity) {
+        self.string_option(&format!("{path}.name"), entity.name.as_mut());
+        if let Some(contacts) = &mut entity.contact {
+            for (index, contact) in contacts.iter_mut().enumerate() {
+                self.contact(&format!("{path}.contact[{index}]"), contact);
+            }
+        }
+    }
+
+    fn components(&mut self, path: &str, components: &mut Components) {
+        for (index, component) in components.0.iter_mut().enumerate() {
+            self.component(&format!("{path}[{index}]"), component);
+        }
+    }
+
+    fn component(&mut self, path: &str, component: &mut Component) {
+        if let Some(supplier) = &mut component.supplier {
+            self.entity(&format!("{path}.supplier"), supplier);
+        }
+        self.string_option(&format!("{path}.author"), component.author.as_mut());
+        self.string_option(&format!("{path}.publisher"), component.publisher.as_mut());
+        self.string_option(&format!("{path}.group"), component.group.as_mut());
+        self.string(&format!("{path}.name"), &mut component.name);
+        self.string_option(&format!("{path}.version"), component.version.as_mut());
+        self.string_option(
+            &format!("{path}.description"),
+            component.description.as_mut(),
+        );
+        if let Some(hashes) = &mut component.hashes {
+            self.hashes(&format!("{path}.hashes"), hashes);
+        }
+        if let Some(licenses) = &mut component.licenses {
+            self.licenses(&format!("{path}.licenses"), licenses);
+        }
+        self.string_option(&format!("{path}.copyright"), component.copyright.as_mut());
+        if let Some(pedigree) = &mut component.pedigree {
+            if let Some(ancestors) = &mut pedigree.ancestors {
+                self.components(&format!("{path}.pedigree.ancestors"), ancestors);
+            }
+            if let Some(descendants) = &mut pedigree.descendants {
+                self.components(&format!("{path}.pedigree.descendants"), descendants);
+            }
+            if let Some(variants) = &mut pedigree.variants {
+                self.components(&format!("{path}.pedigree.variants"), variants);
+            }
+        }
+        if let Some(references) = &mut component.external_references {
+            self.external_references(&format!("{path}.externalReferences"), references);
+        }
+        if let Some(properties) = &mut component.properties {
+            self.properties(&format!("{path}.properties"), properties);
+        }
+        if let Some(components) = &mut component.components {
+            self.components(&format!("{path}.components"), components);
+        }
+    }
+
+    fn services(&mut self, path: &str, services: &mut Services) {
+        for (index, service) in services.0.iter_mut().enumerate() {
+            self.service(&format!("{path}[{index}]"), service);
+        }
+    }
+
+    fn service(&mut self, path: &str, service: &mut Service) {
+        if let Some(provider) = &mut service.provider {
+            self.entity(&format!("{path}.provider"), provider);
+        }
+        self.string_option(&format!("{path}.group"), service.group.as_mut());
+        self.string(&format!("{path}.name"), &mut service.name);
+        self.string_option(&format!("{path}.version"), service.version.as_mut());
+        self.string_option(&format!("{path}.description"), service.description.as_mut());
+        if let Some(licenses) = &mut service.licenses {
+            self.licenses(&format!("{path}.licenses"), licenses);
+        }
+        if let Some(references) = &mut service.external_references {
+            self.external_references(&format!("{path}.externalReferences"), references);
+        }
+        if let Some(properties) = &mut service.properties {
+            self.properties(&format!("{path}.properties"), properties);
+        }
+        if let Some(services) = &mut service.services {
+            self.services(&format!("{path}.services"), services);
+        }
+        self.string_option(&format!("{path}.trustZone"), service.trust_zone.as_mut());
+    }
+
+    fn external_references(&mut self, path: &str, references: &mut ExternalReferences) {
+        for (index, reference) in references.0.iter_mut().enumerate() {
+            if let Some(hashes) = &mut reference.hashes {
+                self.hashes(&format!("{path}[{index}].hashes"), hashes);
+            }
+        }
+    }
+
+    fn hashes(&mut self, path: &str, hashes: &mut Hashes) {
+        for (index, hash) in hashes.0.iter_mut().enumerate() {
+            let lowercase = hash.content.0.to_ascii_lowercase();
+            if lowercase != hash.content.0 {
+                self.record(
+                    &format!("{path}[{index}].content"),
+                    FixKind::HashCase,
+                    format!("{:?} -> {:?}", hash.content.0, lowercase),
+                );
+                hash.content.0 = lowercase;
+            }
+        }
+    }
+
+    fn licenses(&mut self, path: &str, licenses: &mut Licenses) {
+        for (index, choice) in licenses.0.iter_mut().enumerate() {
+            let path = format!("{path}[{index}]");
+            match choice {
+                LicenseChoice::License(license) => {
+                    if let LicenseIdentifier::Name(name) = &mut license.license_identifier {
+                        self.string(&format!("{path}.license.name"), name);
+                    }
+                    if let Some(properties) = &mut license.properties {
+                        self.properties(&format!("{path}.license.properties"), properties);
+                    }
+                }
+                LicenseChoice::Expression(expression) => {
+                    // Only touch expressions that are invalid in strict mode
+                    if spdx::Expression::parse(&expression.expression).is_ok() {
+                        continue;
+                    }
+                    if let Ok(converted) = SpdxExpression::parse_lax(expression.expression.clone())
+                    {
+                        self.record(
+                            &format!("{path}.expression"),
+                            FixKind::LicenseExpression,
+                            format!("{:?} -> {:?}", expression.expression, converted.expression),
+                        );
+                        expression.expression = converted.expression;
+                    }
+                }
+            }
+        }
+    }
+
+    fn properties(&mut self, path: &str, properties: &mut Properties) {
+        for (index, property) in properties.0.iter_mut().enumerate() {
+            self.string(&format!("{path}[{index}].value"), &mut property.value);
+        }
+
+        let is_sorted = properties
+            .0
+            .windows(2)
+            .all(|pair| (&pair[0].name, &pair[0].value.0) <= (&pair[1].name, &pair[1].value.0));
+        if !is_sorted {
+            properties
+                .0
+                .sort_by(|a, b| (&a.name, &a.value.0).cmp(&(&b.name, &b.value.0)));
+            self.record(
+                path,
+                FixKind::PropertiesSorted,
+                "sorted properties by name and value".to_string(),
+            );
+        }
+
+        let length = properties.0.len();
+        properties.0.dedup();
+        if properties.0.len() != length {
+            self.record(
+                path,
+                FixKind::PropertiesDeduplicated,
+                format!(
+                    "removed {} duplicate properties",
+                    length - properties.0.len()
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        models::{
+            component::Classification,
+            hash::{Hash, HashAlgorithm, HashValue},
+            property::Property,
+        },
+        validation::Validate,
+    };
+
+    #[test]
+    fn it_should_fix_normalized_strings() {
+        let mut component = Component::new(Classification::Library, "name", "1.0", None);
+        component.name = NormalizedString("  lib\tx \r\n".into());
+        component.description = Some(NormalizedString("a\n\nmultiline  text".into()));
+        let mut bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+        assert!(bom.validate().has_errors());
+
+        let fixes = bom.normalize();
+
+        let component = &bom.components.as_ref().unwrap().0[0];
+        assert_eq!(component.name, NormalizedString::new("lib x"));
+        assert_eq!(
+            component.description,
+            Some(NormalizedString::new("a multiline text"))
+        );
+        assert_eq!(
+            fixes
+                .iter()
+                .map(|fix| (fix.path.as_str(), fix.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                ("components[0].name", FixKind::Whitespace),
+                ("components[0].description", FixKind::Whitespace),
+            ]
+        );
+        assert!(bom.validate().passed());
+    }
+
+    #[test]
+    fn it_should_lowercase_hashes() {
+        let mut component = Component::new(Classification::Library, "name", "1.0", None);
+        component.hashes = Some(Hashes(vec![Hash {
+            alg: HashAlgorithm::MD5,
+            content: HashValue("A3BF1F3D584747E2569483783DDEE45B".to_string()),
+        }]));
+        let mut bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        let fixes = bom.normalize();
+
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].path, "components[0].hashes[0].content");
+        assert_eq!(fixes[0].kind, FixKind::HashCase);
+        assert_eq!(
+            bom.components.unwrap().0[0].hashes.as_ref().unwrap().0[0].content,
+            HashValue("a3bf1f3d584747e2569483783ddee45b".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_sort_and_dedupe_properties() {
+        let mut bom = Bom {
+            properties: Some(Properties(vec![
+                Property::new("b", "1"),
+                Property::new("a", "2"),
+                Property::new("b", "1"),
+                Property::new("a", "1"),
+            ])),
+            ..Bom::default()
+        };
+
+        let fixes = bom.normalize();
+
+        assert_eq!(
+            bom.properties,
+            Some(Properties(vec![
+                Property::new("a", "1"),
+                Property::new("a", "2"),
+                Property::new("b", "1"),
+            ]))
+        );
+        assert_eq!(
+            fixes.iter().map(|fix| fix.kind).collect::<Vec<_>>(),
+            vec![FixKind::PropertiesSorted, FixKind::PropertiesDeduplicated]
+        );
+    }
+
+    #[test]
+    fn it_should_convert_legacy_license_expressions() {
+        let mut component = Component::new(Classification::Library, "name", "1.0", None);
+        component.licenses = Some(Licenses(vec![
+            LicenseChoice::expression("MIT/Apache-2.0"),
+            LicenseChoice::expression("MIT"),
+        ]));
+        let mut bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        let fixes = bom.normalize();
+
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].kind, FixKind::LicenseExpression);
+        assert_eq!(
+            bom.components.unwrap().0[0].licenses,
+            Some(Licenses(vec![
+                LicenseChoice::expression("MIT OR Apache-2.0"),
+                LicenseChoice::expression("MIT"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_not_report_fixes_for_a_clean_bom() {
+        let mut bom = Bom {
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "name",
+                "1.0",
+                None,
+            )])),
+            properties: Some(Properties(vec![Property::new("a", "1")])),
+            ..Bom::default()
+        };
+        let expected = bom.clone();
+
+        assert!(bom.normalize().is_empty());
+        assert_eq!(bom, expected);
+    }
+}
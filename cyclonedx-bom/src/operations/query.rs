@@ -0,0 +1,296 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{bom::Bom, component::Component, service::Service};
+
+/// Lookup tables over the components, services and dependencies of a [`Bom`],
+/// created via [`Bom::index`].
+///
+/// The index covers the metadata component as well as nested components and services.
+/// If multiple entries share a key, the lookups returning a single entry return the first one.
+#[derive(Clone, Debug)]
+pub struct BomIndex<'a> {
+    components_by_ref: HashMap<&'a str, &'a Component>,
+    components_by_purl: HashMap<&'a str, &'a Component>,
+    components_by_coordinates: HashMap<Coordinates<'a>, &'a Component>,
+    services_by_name: HashMap<&'a str, Vec<&'a Service>>,
+    services_by_ref: HashMap<&'a str, &'a Service>,
+    dependents: HashMap<&'a str, Vec<&'a str>>,
+}
+
+type Coordinates<'a> = (Option<&'a str>, &'a str, Option<&'a str>);
+
+impl Bom {
+    /// Builds a [`BomIndex`] for repeated lookups of components, services and dependents.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", Some("serde".into())))
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// let index = bom.index();
+    /// assert!(index.component_by_ref("serde").is_some());
+    /// assert!(index.component_by_coordinates(None, "serde", Some("1.0.0")).is_some());
+    /// ```
+    pub fn index(&self) -> BomIndex<'_> {
+        let mut index = BomIndex {
+            components_by_ref: HashMap::new(),
+            components_by_purl: HashMap::new(),
+            components_by_coordinates: HashMap::new(),
+            services_by_name: HashMap::new(),
+            services_by_ref: HashMap::new(),
+            dependents: HashMap::new(),
+        };
+
+        if let Some(component) = self.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+            index.add_component(component);
+        }
+        for component in self.components.iter().flat_map(|c| c.0.iter()) {
+            index.add_component(component);
+        }
+        for service in self.services.iter().flat_map(|s| s.0.iter()) {
+            index.add_service(service);
+        }
+        let mut edges = HashSet::new();
+        for dependency in self.dependencies.iter().flat_map(|d| d.0.iter()) {
+            for dependency_ref in &dependency.dependencies {
+                let from = dependency.dependency_ref.as_str();
+                if edges.insert((from, dependency_ref.as_str())) {
+                    index
+                        .dependents
+                        .entry(dependency_ref.as_str())
+                        .or_default()
+                        .push(from);
+                }
+            }
+        }
+
+        index
+    }
+}
+
+impl<'a> BomIndex<'a> {
+    fn add_component(&mut self, component: &'a Component) {
+        if let Some(bom_ref) = &component.bom_ref {
+            self.components_by_ref
+                .entry(bom_ref.as_str())
+                .or_insert(component);
+        }
+        if let Some(purl) = &component.purl {
+            self.components_by_purl
+                .entry(purl.as_ref())
+                .or_insert(component);
+        }
+        let coordinates = (
+            component.group.as_ref().map(|group| group.0.as_str()),
+            component.name.0.as_str(),
+            component.version.as_ref().map(|version| version.0.as_str()),
+        );
+        self.components_by_coordinates
+            .entry(coordinates)
+            .or_insert(component);
+
+        for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+            self.add_component(subcomponent);
+        }
+    }
+
+    fn add_service(&mut self, service: &'a Service) {
+        if let Some(bom_ref) = &service.bom_ref {
+            self.services_by_ref
+                .entry(bom_ref.as_str())
+                .or_insert(service);
+        }
+        self.services_by_name
+            .entry(service.name.0.as_str())
+            .or_default()
+            .push(service);
+
+        for subservice in service.services.iter().flat_map(|s| s.0.iter()) {
+            self.add_service(subservice);
+        }
+    }
+
+    /// Finds the component with the given `bom-ref`.
+    pub fn component_by_ref(&self, bom_ref: &str) -> Option<&'a Component> {
+        self.components_by_ref.get(bom_ref).copied()
+    }
+
+    /// Finds the component with the given package URL, which has to match exactly.
+    pub fn component_by_purl(&self, purl: &str) -> Option<&'a Component> {
+        self.components_by_purl.get(purl).copied()
+    }
+
+    /// Finds the component with the given group, name and version.
+    pub fn component_by_coordinates(
+        &self,
+        group: Option<&str>,
+        name: &str,
+        version: Option<&str>,
+    ) -> Option<&'a Component> {
+        self.components_by_coordinates
+            .get(&(group, name, version))
+            .copied()
+    }
+
+    /// Finds the service with the given `bom-ref`.
+    pub fn service_by_ref(&self, bom_ref: &str) -> Option<&'a Service> {
+        self.services_by_ref.get(bom_ref).copied()
+    }
+
+    /// Finds all services with the given name.
+    pub fn services_by_name(&self, name: &str) -> &[&'a Service] {
+        self.services_by_name
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the `bom-ref`s of all entries that directly depend on the given `bom-ref`.
+    pub fn dependents(&self, bom_ref: &str) -> &[&'a str] {
+        self.dependents
+            .get(bom_ref)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns all components that directly depend on the given `bom-ref`.
+    ///
+    /// Dependents that are not components, e.g. services, are skipped.
+    pub fn dependent_components(&self, bom_ref: &str) -> Vec<&'a Component> {
+        self.dependents(bom_ref)
+            .iter()
+            .filter_map(|dependent| self.component_by_ref(dependent))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::{normalized_string::NormalizedString, uri::Purl},
+        models::{
+            component::{Classification, Components},
+            dependency::Dependency,
+            metadata::Metadata,
+            service::Services,
+        },
+    };
+
+    fn bom() -> Bom {
+        let mut library =
+            Component::new(Classification::Library, "lib", "1.0.0", Some("lib".into()));
+        library.group = Some(NormalizedString::new("org"));
+        library.purl = Some(Purl::new("cargo", "lib", "1.0.0").unwrap());
+        let mut parent = Component::new(
+            Classification::Library,
+            "parent",
+            "1.0.0",
+            Some("parent".into()),
+        );
+        parent.components = Some(Components(vec![library]));
+        let mut api = Service::new("api", Some("api".into()));
+        api.services = Some(Services(vec![Service::new("api", Some("api-v2".into()))]));
+
+        Bom::builder()
+            .metadata(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "1.0.0",
+                    Some("app".into()),
+                )),
+                ..Metadata::default()
+            })
+            .component(parent)
+            .service(api)
+            .dependency(Dependency {
+                dependency_ref: "app".to_string(),
+                dependencies: vec!["lib".to_string(), "parent".to_string()],
+            })
+            .dependency(Dependency {
+                dependency_ref: "api".to_string(),
+                dependencies: vec!["lib".to_string()],
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_find_components() {
+        let bom = bom();
+        let index = bom.index();
+
+        assert_eq!(
+            index.component_by_ref("app").map(|c| c.name.to_string()),
+            Some("app".to_string())
+        );
+        assert_eq!(
+            index
+                .component_by_purl("pkg:cargo/lib@1.0.0")
+                .and_then(|c| c.bom_ref.clone()),
+            Some("lib".to_string())
+        );
+        assert_eq!(
+            index
+                .component_by_coordinates(Some("org"), "lib", Some("1.0.0"))
+                .and_then(|c| c.bom_ref.clone()),
+            Some("lib".to_string())
+        );
+        assert!(index
+            .component_by_coordinates(None, "lib", Some("1.0.0"))
+            .is_none());
+        assert!(index.component_by_ref("missing").is_none());
+    }
+
+    #[test]
+    fn it_should_find_services() {
+        let bom = bom();
+        let index = bom.index();
+
+        assert_eq!(index.services_by_name("api").len(), 2);
+        assert!(index.service_by_ref("api-v2").is_some());
+        assert!(index.services_by_name("missing").is_empty());
+    }
+
+    #[test]
+    fn it_should_find_dependents() {
+        let bom = bom();
+        let index = bom.index();
+
+        assert_eq!(index.dependents("lib"), &["app", "api"]);
+        assert_eq!(
+            index
+                .dependent_components("lib")
+                .into_iter()
+                .filter_map(|c| c.bom_ref.clone())
+                .collect::<Vec<_>>(),
+            vec!["app".to_string()]
+        );
+        assert!(index.dependents("app").is_empty());
+    }
+}
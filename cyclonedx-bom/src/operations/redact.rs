@@ -0,0 +1,325 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::models::{
+    bom::Bom,
+    component::Component,
+    external_reference::{ExternalReferences, Uri},
+    organization::{OrganizationalContact, OrganizationalEntity},
+    property::Properties,
+    service::Service,
+};
+
+use super::walk::BomVisitorMut;
+
+/// Describes which content [`Bom::redact`] removes.
+///
+/// Everything is kept by default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RedactionPolicy {
+    /// Remove the endpoints of all services.
+    pub service_endpoints: bool,
+    /// Remove URLs pointing to these hosts or their subdomains, e.g. `corp.example.com`.
+    ///
+    /// Matching external references and service endpoints are removed, as are matching URLs of
+    /// organizations.
+    pub hostnames: Vec<String>,
+    /// Remove properties in these namespaces, e.g. `internal` removes `internal:build-host`.
+    pub property_namespaces: Vec<String>,
+    /// Remove the email addresses of authors and contacts.
+    pub emails: bool,
+}
+
+impl RedactionPolicy {
+    fn matches_host(&self, url: &str) -> bool {
+        let Some(host) = host(url) else {
+            return false;
+        };
+        let host = host.to_ascii_lowercase();
+        self.hostnames.iter().any(|hostname| {
+            let hostname = hostname.to_ascii_lowercase();
+            host == hostname || host.ends_with(&format!(".{hostname}"))
+        })
+    }
+
+    fn matches_property(&self, name: &str) -> bool {
+        self.property_namespaces.iter().any(|namespace| {
+            name == namespace
+                || name
+                    .strip_prefix(namespace.as_str())
+                    .is_some_and(|rest| rest.starts_with(':'))
+        })
+    }
+}
+
+impl Bom {
+    /// Removes sensitive content according to the policy before a BOM is shared, and returns
+    /// the number of removed values.
+    ///
+    /// Only optional values are removed, so a valid BOM stays valid.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::property::Property;
+    /// use cyclonedx_bom::operations::redact::RedactionPolicy;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut bom = Bom::builder()
+    ///     .property(Property::new("internal:build-host", "ci-runner-17"))
+    ///     .property(Property::new("cdx:reproducible", "true"))
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// let removed = bom.redact(&RedactionPolicy {
+    ///     property_namespaces: vec!["internal".to_string()],
+    ///     ..RedactionPolicy::default()
+    /// });
+    /// assert_eq!(removed, 1);
+    /// assert_eq!(bom.properties.unwrap().0.len(), 1);
+    /// ```
+    pub fn redact(&mut self, policy: &RedactionPolicy) -> usize {
+        let mut redactor = Redactor { policy, removed: 0 };
+
+        self.walk_mut(&mut redactor);
+        if let Some(metadata) = &mut self.metadata {
+            for author in metadata.authors.iter_mut().flatten() {
+                redactor.redact_contact(author);
+            }
+            for entity in [&mut metadata.manufacture, &mut metadata.supplier]
+                .into_iter()
+                .flatten()
+            {
+                redactor.redact_entity(entity);
+            }
+            redactor.redact_properties(&mut metadata.properties);
+        }
+        redactor.redact_external_references(&mut self.external_references);
+        redactor.redact_properties(&mut self.properties);
+
+        redactor.removed
+    }
+}
+
+struct Redactor<'a> {
+    policy: &'a RedactionPolicy,
+    removed: usize,
+}
+
+impl Redactor<'_> {
+    fn redact_contact(&mut self, contact: &mut OrganizationalContact) {
+        if self.policy.emails && contact.email.take().is_some() {
+            self.removed += 1;
+        }
+    }
+
+    fn redact_entity(&mut self, entity: &mut OrganizationalEntity) {
+        if let Some(urls) = &mut entity.url {
+            let before = urls.len();
+            urls.retain(|url| !self.policy.matches_host(url.as_ref()));
+            self.removed += before - urls.len();
+        }
+        for contact in entity.contact.iter_mut().flatten() {
+            self.redact_contact(contact);
+        }
+    }
+
+    fn redact_external_references(&mut self, external_references: &mut Option<ExternalReferences>) {
+        if let Some(external_references) = external_references {
+            let before = external_references.0.len();
+            external_references
+                .0
+                .retain(|reference| match &reference.url {
+                    Uri::Url(url) => !self.policy.matches_host(url.as_ref()),
+                    Uri::BomLink(_) => true,
+                });
+            self.removed += before - external_references.0.len();
+        }
+    }
+
+    fn redact_properties(&mut self, properties: &mut Option<Properties>) {
+        if let Some(properties) = properties {
+            let before = properties.0.len();
+            properties
+                .0
+                .retain(|property| !self.policy.matches_property(&property.name));
+            self.removed += before - properties.0.len();
+        }
+    }
+}
+
+impl BomVisitorMut for Redactor<'_> {
+    fn visit_component(&mut self, component: &mut Component) {
+        if let Some(supplier) = &mut component.supplier {
+            self.redact_entity(supplier);
+        }
+        self.redact_external_references(&mut component.external_references);
+        self.redact_properties(&mut component.properties);
+    }
+
+    fn visit_service(&mut self, service: &mut Service) {
+        if let Some(provider) = &mut service.provider {
+            self.redact_entity(provider);
+        }
+        if let Some(endpoints) = &mut service.endpoints {
+            let before = endpoints.len();
+            if self.policy.service_endpoints {
+                endpoints.clear();
+            } else {
+                endpoints.retain(|endpoint| !self.policy.matches_host(endpoint.as_ref()));
+            }
+            self.removed += before - endpoints.len();
+        }
+        if service.endpoints.as_ref().is_some_and(Vec::is_empty) {
+            service.endpoints = None;
+        }
+        self.redact_external_references(&mut service.external_references);
+        self.redact_properties(&mut service.properties);
+    }
+}
+
+/// Returns the host of an URL, without user info and port.
+fn host(url: &str) -> Option<&str> {
+    let (_, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = match host.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next()?,
+        None => host.split(':').next()?,
+    };
+    (!host.is_empty()).then_some(host)
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::normalized_string::NormalizedString,
+        external_models::uri::Uri as Url,
+        models::{
+            bom::SpecVersion,
+            component::Classification,
+            external_reference::{ExternalReference, ExternalReferenceType},
+            metadata::Metadata,
+            property::Property,
+            service::Services,
+        },
+        validation::Validate,
+    };
+
+    fn reference(url: &str) -> ExternalReference {
+        ExternalReference::new(ExternalReferenceType::Vcs, Uri::Url(Url::new(url)))
+    }
+
+    #[test]
+    fn it_should_find_the_host_of_an_url() {
+        assert_eq!(
+            host("https://user@git.corp.example.com:8443/repo"),
+            Some("git.corp.example.com")
+        );
+        assert_eq!(host("http://[::1]:80/"), Some("::1"));
+        assert_eq!(host("https://example.com?query"), Some("example.com"));
+        assert_eq!(host("mailto:someone@example.com"), None);
+    }
+
+    #[test]
+    fn it_should_redact_according_to_the_policy() {
+        let mut component = Component::new(Classification::Library, "internal-lib", "1.0.0", None);
+        component.external_references = Some(ExternalReferences(vec![
+            reference("https://git.corp.example.com/internal-lib"),
+            reference("https://github.com/example/internal-lib"),
+        ]));
+        component.properties = Some(Properties(vec![
+            Property::new("internal:owner", "team-a"),
+            Property::new("internals", "kept"),
+        ]));
+        let mut service = Service::new("api", None);
+        service.endpoints = Some(vec![Url::new("https://api.corp.example.com/v1")]);
+        service.services = Some(Services(vec![Service {
+            endpoints: Some(vec![Url::new("https://example.com/health")]),
+            ..Service::new("health", None)
+        }]));
+
+        let mut bom = Bom::builder()
+            .metadata(Metadata {
+                authors: Some(vec![OrganizationalContact::new(
+                    "Jane Doe",
+                    Some("jane@corp.example.com"),
+                )]),
+                ..Metadata::default()
+            })
+            .component(component)
+            .service(service)
+            .build()
+            .unwrap();
+
+        let removed = bom.redact(&RedactionPolicy {
+            service_endpoints: false,
+            hostnames: vec!["CORP.example.com".to_string()],
+            property_namespaces: vec!["internal".to_string()],
+            emails: true,
+        });
+
+        assert_eq!(removed, 4);
+        let metadata = bom.metadata.as_ref().unwrap();
+        assert_eq!(
+            metadata.authors.as_ref().unwrap()[0].name,
+            Some(NormalizedString::new("Jane Doe"))
+        );
+        assert_eq!(metadata.authors.as_ref().unwrap()[0].email, None);
+        let component = &bom.components.as_ref().unwrap().0[0];
+        assert_eq!(
+            component.external_references,
+            Some(ExternalReferences(vec![reference(
+                "https://github.com/example/internal-lib"
+            )]))
+        );
+        assert_eq!(
+            component.properties,
+            Some(Properties(vec![Property::new("internals", "kept")]))
+        );
+        let service = &bom.services.as_ref().unwrap().0[0];
+        assert_eq!(service.endpoints, None);
+        assert_eq!(
+            service.services.as_ref().unwrap().0[0].endpoints,
+            Some(vec![Url::new("https://example.com/health")])
+        );
+        assert!(bom.validate_version(SpecVersion::V1_5).passed());
+    }
+
+    #[test]
+    fn it_should_remove_all_service_endpoints() {
+        let mut bom = Bom::builder()
+            .service(Service {
+                endpoints: Some(vec![Url::new("https://example.com/health")]),
+                ..Service::new("health", None)
+            })
+            .build()
+            .unwrap();
+
+        let removed = bom.redact(&RedactionPolicy {
+            service_endpoints: true,
+            ..RedactionPolicy::default()
+        });
+
+        assert_eq!(removed, 1);
+        assert_eq!(bom.services.unwrap().0[0].endpoints, None);
+    }
+}
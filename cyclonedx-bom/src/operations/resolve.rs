@@ -0,0 +1,544 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Resolving BOMs that are linked from another BOM through external references.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    errors::JsonReadError,
+    models::{
+        bom::Bom,
+        external_reference::{BomLink, ExternalReference, ExternalReferenceType, Uri},
+    },
+    operations::walk::BomVisitor,
+};
+
+#[cfg(feature = "xml")]
+use crate::errors::XmlReadError;
+
+/// The error returned by a [`BomFetcher`].
+pub type FetchError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Depth up to which [`BomResolver`] follows links unless configured otherwise.
+pub const DEFAULT_MAX_DEPTH: usize = 16;
+
+/// Retrieves the raw document an external reference points to.
+///
+/// Implemented for closures taking the [`Uri`] to fetch. BOM-Links are passed without their
+/// fragment, i.e. as `urn:cdx:<uuid>/<version>`.
+pub trait BomFetcher {
+    fn fetch(&mut self, uri: &Uri) -> Result<Vec<u8>, FetchError>;
+}
+
+impl<F> BomFetcher for F
+where
+    F: FnMut(&Uri) -> Result<Vec<u8>, FetchError>,
+{
+    fn fetch(&mut self, uri: &Uri) -> Result<Vec<u8>, FetchError> {
+        self(uri)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ResolveError {
+    #[error("Failed to fetch linked BOM '{uri}': {source}")]
+    Fetch {
+        uri: String,
+        #[source]
+        source: FetchError,
+    },
+
+    #[error("Failed to parse linked BOM '{uri}' as JSON: {source}")]
+    Json {
+        uri: String,
+        #[source]
+        source: JsonReadError,
+    },
+
+    #[cfg(feature = "xml")]
+    #[error("Failed to parse linked BOM '{uri}' as XML: {source}")]
+    Xml {
+        uri: String,
+        #[source]
+        source: XmlReadError,
+    },
+}
+
+/// A BOM that was reached by following the external references of another BOM.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkedBom {
+    /// The reference the BOM was fetched from.
+    pub uri: Uri,
+    pub bom: Bom,
+    /// The number of links followed from the root BOM, `1` for BOMs it links directly.
+    pub depth: usize,
+}
+
+/// Follows the external references of type `bom` of a BOM and those of the BOMs it links to.
+///
+/// References are collected from the BOM itself, its metadata component and all components and
+/// services. Every document is fetched once, links back to the root BOM or to an already
+/// resolved BOM are skipped.
+pub struct BomResolver<F> {
+    fetcher: F,
+    max_depth: usize,
+}
+
+impl<F: BomFetcher> BomResolver<F> {
+    pub fn new(fetcher: F) -> Self {
+        Self {
+            fetcher,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    /// Limits how many links are followed from the root BOM, `1` only resolves its direct links.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Returns the linked BOMs in breadth-first order, failing on the first document that
+    /// cannot be fetched or parsed.
+    /// ```
+    /// use cyclonedx_bom::models::{
+    ///     bom::Bom,
+    ///     external_reference::{ExternalReference, ExternalReferenceType, ExternalReferences, Uri},
+    /// };
+    /// use cyclonedx_bom::external_models::uri::Uri as Url;
+    /// use cyclonedx_bom::operations::resolve::{BomResolver, FetchError};
+    ///
+    /// let mut root = Bom::default();
+    /// root.external_references = Some(ExternalReferences(vec![ExternalReference::new(
+    ///     ExternalReferenceType::Bom,
+    ///     Url::new("https://example.com/sbom.json"),
+    /// )]));
+    ///
+    /// let fetch = |uri: &Uri| -> Result<Vec<u8>, FetchError> {
+    ///     assert_eq!(uri.to_string(), "https://example.com/sbom.json");
+    ///     Ok(br#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1}"#.to_vec())
+    /// };
+    /// let linked = BomResolver::new(fetch).resolve(&root).expect("resolved");
+    /// assert_eq!(linked.len(), 1);
+    /// assert_eq!(linked[0].depth, 1);
+    /// ```
+    pub fn resolve(&mut self, root: &Bom) -> Result<Vec<LinkedBom>, ResolveError> {
+        let mut seen = HashSet::new();
+        if let Some(serial_number) = &root.serial_number {
+            seen.insert(Uri::BomLink(BomLink::new(serial_number, root.version)));
+        }
+
+        let mut queue = VecDeque::new();
+        enqueue_links(root, 1, &mut seen, &mut queue);
+
+        let mut linked = Vec::new();
+        while let Some((uri, depth)) = queue.pop_front() {
+            if depth > self.max_depth {
+                continue;
+            }
+            let document = self
+                .fetcher
+                .fetch(&uri)
+                .map_err(|source| ResolveError::Fetch {
+                    uri: uri.to_string(),
+                    source,
+                })?;
+            let bom = parse_document(&uri, &document)?;
+            if let Some(serial_number) = &bom.serial_number {
+                seen.insert(Uri::BomLink(BomLink::new(serial_number, bom.version)));
+            }
+            enqueue_links(&bom, depth + 1, &mut seen, &mut queue);
+            linked.push(LinkedBom { uri, bom, depth });
+        }
+        Ok(linked)
+    }
+}
+
+#[cfg(feature = "http")]
+type BomLinkUrl = Box<dyn Fn(&BomLink) -> Option<String>>;
+
+/// A [`BomFetcher`] that downloads documents over HTTP(S).
+///
+/// BOM-Links don't carry a location, they are only resolved if a mapping to a URL is
+/// configured with [`HttpFetcher::with_bom_link_url`].
+#[cfg(feature = "http")]
+pub struct HttpFetcher {
+    agent: ureq::Agent,
+    bom_link_url: Option<BomLinkUrl>,
+    max_size: u64,
+}
+
+#[cfg(feature = "http")]
+impl HttpFetcher {
+    /// Size in bytes above which a document is rejected unless configured otherwise.
+    pub const DEFAULT_MAX_SIZE: u64 = 64 * 1024 * 1024;
+
+    pub fn new() -> Self {
+        Self::with_agent(ureq::Agent::new())
+    }
+
+    /// Uses a preconfigured agent, e.g. with a proxy or timeouts.
+    pub fn with_agent(agent: ureq::Agent) -> Self {
+        Self {
+            agent,
+            bom_link_url: None,
+            max_size: Self::DEFAULT_MAX_SIZE,
+        }
+    }
+
+    /// Sets the function that maps a BOM-Link, without fragment, to the URL of the document.
+    pub fn with_bom_link_url(
+        mut self,
+        bom_link_url: impl Fn(&BomLink) -> Option<String> + 'static,
+    ) -> Self {
+        self.bom_link_url = Some(Box::new(bom_link_url));
+        self
+    }
+
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    fn get(&self, url: &str) -> Result<Vec<u8>, FetchError> {
+        use std::io::Read;
+
+        let response = self.agent.get(url).call().map_err(Box::new)?;
+        let mut document = Vec::new();
+        response
+            .into_reader()
+            .take(self.max_size + 1)
+            .read_to_end(&mut document)?;
+        if document.len() as u64 > self.max_size {
+            return Err(format!("document exceeds {} bytes", self.max_size).into());
+        }
+        Ok(document)
+    }
+}
+
+#[cfg(feature = "http")]
+impl Default for HttpFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "http")]
+impl BomFetcher for HttpFetcher {
+    fn fetch(&mut self, uri: &Uri) -> Result<Vec<u8>, FetchError> {
+        match uri {
+            Uri::Url(url) => self.get(url.as_ref()),
+            Uri::BomLink(bom_link) => {
+                let url = self
+                    .bom_link_url
+                    .as_ref()
+                    .and_then(|bom_link_url| bom_link_url(bom_link))
+                    .ok_or_else(|| format!("no URL known for BOM-Link '{bom_link}'"))?;
+                self.get(&url)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct BomReferences(Vec<Uri>);
+
+impl BomVisitor for BomReferences {
+    fn visit_external_reference(&mut self, external_reference: &ExternalReference) {
+        if external_reference.external_reference_type == ExternalReferenceType::Bom {
+            self.0.push(document_uri(&external_reference.url));
+        }
+    }
+}
+
+fn enqueue_links(
+    bom: &Bom,
+    depth: usize,
+    seen: &mut HashSet<Uri>,
+    queue: &mut VecDeque<(Uri, usize)>,
+) {
+    let mut references = BomReferences::default();
+    bom.walk(&mut references);
+    for uri in references.0 {
+        if seen.insert(uri.clone()) {
+            queue.push_back((uri, depth));
+        }
+    }
+}
+
+/// Strips the fragment of a BOM-Link, which refers to an element of the linked BOM.
+fn document_uri(uri: &Uri) -> Uri {
+    match uri {
+        Uri::BomLink(bom_link) => match bom_link.document() {
+            Some(document) => Uri::BomLink(BomLink(format!("urn:cdx:{document}"))),
+            None => uri.clone(),
+        },
+        Uri::Url(_) => uri.clone(),
+    }
+}
+
+/// Parses a document as XML if it starts with a tag, as JSON otherwise.
+fn parse_document(uri: &Uri, document: &[u8]) -> Result<Bom, ResolveError> {
+    #[cfg(feature = "xml")]
+    if document.iter().find(|byte| !byte.is_ascii_whitespace()) == Some(&b'<') {
+        return Bom::parse_from_xml_with_version(document, xml_spec_version(document)).map_err(
+            |source| ResolveError::Xml {
+                uri: uri.to_string(),
+                source,
+            },
+        );
+    }
+    Bom::parse_from_json(document).map_err(|source| ResolveError::Json {
+        uri: uri.to_string(),
+        source,
+    })
+}
+
+/// Reads the specification version from the CycloneDX namespace of an XML document, the parser
+/// reports a mismatching namespace if none is found.
+#[cfg(feature = "xml")]
+fn xml_spec_version(document: &[u8]) -> crate::models::bom::SpecVersion {
+    use crate::models::bom::SpecVersion;
+    use std::str::FromStr;
+
+    const NAMESPACE: &str = "cyclonedx.org/schema/bom/";
+    let document = String::from_utf8_lossy(document);
+    document
+        .find(NAMESPACE)
+        .and_then(|start| document.get(start + NAMESPACE.len()..start + NAMESPACE.len() + 3))
+        .and_then(|version| SpecVersion::from_str(version).ok())
+        .unwrap_or(SpecVersion::V1_5)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::uri::Uri as Url,
+        models::{
+            bom::UrnUuid,
+            component::{Classification, Component, Components},
+            external_reference::ExternalReferences,
+        },
+    };
+
+    const SERIAL_A: &str = "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79";
+    const SERIAL_B: &str = "urn:uuid:5e671687-395b-41f5-a30f-a58921a69b79";
+
+    fn bom_reference(uri: &str) -> ExternalReference {
+        ExternalReference::new(ExternalReferenceType::Bom, Url::new(uri))
+    }
+
+    fn json(bom: Bom) -> Vec<u8> {
+        let mut output = Vec::new();
+        bom.output_as_json_v1_5(&mut output).unwrap();
+        output
+    }
+
+    fn documents(
+        documents: Vec<(&str, Vec<u8>)>,
+    ) -> impl FnMut(&Uri) -> Result<Vec<u8>, FetchError> {
+        let documents: HashMap<String, Vec<u8>> = documents
+            .into_iter()
+            .map(|(uri, document)| (uri.to_string(), document))
+            .collect();
+        move |uri: &Uri| {
+            documents
+                .get(&uri.to_string())
+                .cloned()
+                .ok_or_else(|| format!("not found: {uri}").into())
+        }
+    }
+
+    #[test]
+    fn it_should_resolve_links_recursively() {
+        let mut component = Component::new(Classification::Library, "lib", "1.0", None);
+        component.external_references = Some(ExternalReferences(vec![
+            bom_reference("https://example.com/a.json"),
+            ExternalReference::new(
+                ExternalReferenceType::Website,
+                Url::new("https://example.com"),
+            ),
+        ]));
+        let root = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        let a = Bom {
+            serial_number: Some(UrnUuid::new(SERIAL_A.to_string()).unwrap()),
+            external_references: Some(ExternalReferences(vec![bom_reference(&format!(
+                "urn:cdx:{}/1#pkg",
+                &SERIAL_B[9..]
+            ))])),
+            ..Bom::default()
+        };
+        let b = Bom {
+            serial_number: Some(UrnUuid::new(SERIAL_B.to_string()).unwrap()),
+            external_references: Some(ExternalReferences(vec![
+                bom_reference("https://example.com/a.json"),
+                bom_reference(&format!("urn:cdx:{}/1", &SERIAL_A[9..])),
+            ])),
+            ..Bom::default()
+        };
+
+        let fetcher = documents(vec![
+            ("https://example.com/a.json", json(a.clone())),
+            (&format!("urn:cdx:{}/1", &SERIAL_B[9..]), json(b.clone())),
+        ]);
+        let linked = BomResolver::new(fetcher).resolve(&root).unwrap();
+
+        let summary: Vec<_> = linked
+            .iter()
+            .map(|linked| (linked.uri.to_string(), linked.depth))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("https://example.com/a.json".to_string(), 1),
+                (format!("urn:cdx:{}/1", &SERIAL_B[9..]), 2),
+            ]
+        );
+        assert_eq!(linked[0].bom.serial_number, a.serial_number);
+        assert_eq!(linked[1].bom.serial_number, b.serial_number);
+    }
+
+    #[test]
+    fn it_should_stop_at_the_maximum_depth() {
+        let root = Bom {
+            external_references: Some(ExternalReferences(vec![bom_reference(
+                "https://example.com/a",
+            )])),
+            ..Bom::default()
+        };
+        let a = Bom {
+            external_references: Some(ExternalReferences(vec![bom_reference(
+                "https://example.com/b",
+            )])),
+            ..Bom::default()
+        };
+
+        let fetcher = documents(vec![("https://example.com/a", json(a))]);
+        let linked = BomResolver::new(fetcher)
+            .with_max_depth(1)
+            .resolve(&root)
+            .unwrap();
+
+        assert_eq!(linked.len(), 1);
+    }
+
+    #[test]
+    fn it_should_fail_on_missing_documents() {
+        let root = Bom {
+            external_references: Some(ExternalReferences(vec![bom_reference(
+                "https://example.com/a",
+            )])),
+            ..Bom::default()
+        };
+
+        let error = BomResolver::new(documents(vec![]))
+            .resolve(&root)
+            .unwrap_err();
+
+        assert!(matches!(error, ResolveError::Fetch { uri, .. } if uri == "https://example.com/a"));
+    }
+
+    #[test]
+    fn it_should_fail_on_invalid_documents() {
+        let root = Bom {
+            external_references: Some(ExternalReferences(vec![bom_reference(
+                "https://example.com/a",
+            )])),
+            ..Bom::default()
+        };
+
+        let fetcher = documents(vec![("https://example.com/a", b"not a bom".to_vec())]);
+        let error = BomResolver::new(fetcher).resolve(&root).unwrap_err();
+
+        assert!(matches!(error, ResolveError::Json { .. }));
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_parse_xml_documents() {
+        let root = Bom {
+            external_references: Some(ExternalReferences(vec![bom_reference(
+                "https://example.com/a.xml",
+            )])),
+            ..Bom::default()
+        };
+        let mut document = Vec::new();
+        Bom::default().output_as_xml_v1_4(&mut document).unwrap();
+
+        let fetcher = documents(vec![("https://example.com/a.xml", document)]);
+        let linked = BomResolver::new(fetcher).resolve(&root).unwrap();
+
+        assert_eq!(linked.len(), 1);
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn it_should_fetch_documents_over_http() {
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let body = json(Bom::default());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(&body).unwrap();
+        });
+
+        let root = Bom {
+            external_references: Some(ExternalReferences(vec![bom_reference(&format!(
+                "urn:cdx:{}/1",
+                &SERIAL_A[9..]
+            ))])),
+            ..Bom::default()
+        };
+        let fetcher = HttpFetcher::new()
+            .with_bom_link_url(move |bom_link| Some(format!("http://{address}/{bom_link}")));
+        let linked = BomResolver::new(fetcher).resolve(&root).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(linked.len(), 1);
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn it_should_reject_unmapped_bom_links() {
+        let uri = Uri::BomLink(BomLink(format!("urn:cdx:{}/1", &SERIAL_A[9..])));
+
+        assert!(HttpFetcher::new().fetch(&uri).is_err());
+    }
+}
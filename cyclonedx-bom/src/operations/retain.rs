@@ -0,0 +1,278 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::HashSet;
+
+use crate::models::{
+    bom::Bom,
+    component::{Component, Components},
+    service::{Service, Services},
+};
+
+impl Bom {
+    /// Retains only the components for which the predicate returns `true`.
+    ///
+    /// The predicate is called for top-level and nested components, removing a component also
+    /// removes its nested components. The metadata component is never removed.
+    ///
+    /// References to removed components are cleaned up afterwards: their entries in the
+    /// dependencies section are dropped, and they are removed from the dependencies of other
+    /// entries, from compositions and from vulnerability targets.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component, Scope};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut excluded = Component::new(Classification::Library, "test-helper", "1.0.0", None);
+    /// excluded.scope = Some(Scope::Excluded);
+    /// let mut bom = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+    ///     .component(excluded)
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// bom.retain_components(|component| component.scope != Some(Scope::Excluded));
+    /// assert_eq!(bom.components.unwrap().0.len(), 1);
+    /// ```
+    pub fn retain_components(&mut self, mut predicate: impl FnMut(&Component) -> bool) {
+        let mut removed = HashSet::new();
+        if let Some(components) = &mut self.components {
+            retain_components(components, &mut predicate, &mut removed);
+        }
+        self.remove_references(&removed);
+    }
+
+    /// Retains only the services for which the predicate returns `true`.
+    ///
+    /// Works like [`Bom::retain_components`], including the clean up of references.
+    pub fn retain_services(&mut self, mut predicate: impl FnMut(&Service) -> bool) {
+        let mut removed = HashSet::new();
+        if let Some(services) = &mut self.services {
+            retain_services(services, &mut predicate, &mut removed);
+        }
+        self.remove_references(&removed);
+    }
+
+    /// Removes all references to the given `bom-ref`s from the dependencies, compositions and
+    /// vulnerability targets.
+    pub(crate) fn remove_references(&mut self, removed: &HashSet<String>) {
+        if removed.is_empty() {
+            return;
+        }
+
+        if let Some(dependencies) = &mut self.dependencies {
+            dependencies
+                .0
+                .retain(|dependency| !removed.contains(&dependency.dependency_ref));
+            for dependency in &mut dependencies.0 {
+                dependency
+                    .dependencies
+                    .retain(|dependency| !removed.contains(dependency));
+            }
+        }
+
+        for composition in self.compositions.iter_mut().flat_map(|c| c.0.iter_mut()) {
+            for refs in [&mut composition.assemblies, &mut composition.dependencies]
+                .into_iter()
+                .flatten()
+            {
+                refs.retain(|bom_ref| !removed.contains(&bom_ref.0));
+            }
+        }
+
+        for targets in self
+            .vulnerabilities
+            .iter_mut()
+            .flat_map(|v| v.0.iter_mut())
+            .filter_map(|vulnerability| vulnerability.vulnerability_targets.as_mut())
+        {
+            targets
+                .0
+                .retain(|target| !removed.contains(&target.bom_ref));
+        }
+    }
+}
+
+fn retain_components(
+    components: &mut Components,
+    predicate: &mut impl FnMut(&Component) -> bool,
+    removed: &mut HashSet<String>,
+) {
+    components.0.retain(|component| {
+        let retain = predicate(component);
+        if !retain {
+            collect_component_refs(component, removed);
+        }
+        retain
+    });
+    for component in &mut components.0 {
+        if let Some(subcomponents) = &mut component.components {
+            retain_components(subcomponents, predicate, removed);
+        }
+    }
+}
+
+fn collect_component_refs(component: &Component, refs: &mut HashSet<String>) {
+    if let Some(bom_ref) = &component.bom_ref {
+        refs.insert(bom_ref.clone());
+    }
+    for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+        collect_component_refs(subcomponent, refs);
+    }
+}
+
+fn retain_services(
+    services: &mut Services,
+    predicate: &mut impl FnMut(&Service) -> bool,
+    removed: &mut HashSet<String>,
+) {
+    services.0.retain(|service| {
+        let retain = predicate(service);
+        if !retain {
+            collect_service_refs(service, removed);
+        }
+        retain
+    });
+    for service in &mut services.0 {
+        if let Some(subservices) = &mut service.services {
+            retain_services(subservices, predicate, removed);
+        }
+    }
+}
+
+fn collect_service_refs(service: &Service, refs: &mut HashSet<String>) {
+    if let Some(bom_ref) = &service.bom_ref {
+        refs.insert(bom_ref.clone());
+    }
+    for subservice in service.services.iter().flat_map(|s| s.0.iter()) {
+        collect_service_refs(subservice, refs);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{
+        bom::BomReference,
+        component::Classification,
+        composition::{AggregateType, Composition},
+        dependency::{Dependencies, Dependency},
+    };
+
+    fn component(name: &str) -> Component {
+        Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(name.to_string()),
+        )
+    }
+
+    fn dependency(from: &str, to: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: from.to_string(),
+            dependencies: to.iter().map(|to| to.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn it_should_remove_components_and_dangling_references() {
+        let mut parent = component("parent");
+        parent.components = Some(Components(vec![component("child"), component("keep")]));
+        let mut bom = Bom::builder()
+            .component(parent)
+            .component(component("removed"))
+            .dependency(dependency("parent", &["child", "keep", "removed"]))
+            .dependency(dependency("removed", &["keep"]))
+            .compositions([Composition {
+                bom_ref: None,
+                aggregate: AggregateType::Complete,
+                assemblies: Some(vec![
+                    BomReference::new("parent"),
+                    BomReference::new("removed"),
+                ]),
+                dependencies: Some(vec![BomReference::new("child")]),
+                vulnerabilities: None,
+                signature: None,
+            }])
+            .build()
+            .unwrap();
+
+        bom.retain_components(|component| {
+            component.name.0 != "removed" && component.name.0 != "child"
+        });
+
+        let mut parent = component("parent");
+        parent.components = Some(Components(vec![component("keep")]));
+        assert_eq!(bom.components, Some(Components(vec![parent])));
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![dependency("parent", &["keep"])]))
+        );
+        let composition = &bom.compositions.as_ref().unwrap().0[0];
+        assert_eq!(
+            composition.assemblies,
+            Some(vec![BomReference::new("parent")])
+        );
+        assert_eq!(composition.dependencies, Some(vec![]));
+    }
+
+    #[test]
+    fn it_should_remove_nested_components_of_removed_components() {
+        let mut parent = component("parent");
+        parent.components = Some(Components(vec![component("child")]));
+        let mut bom = Bom::builder()
+            .component(parent)
+            .dependency(dependency("child", &[]))
+            .build()
+            .unwrap();
+
+        bom.retain_components(|component| component.name.0 != "parent");
+
+        assert_eq!(bom.components, Some(Components(vec![])));
+        assert_eq!(bom.dependencies, Some(Dependencies(vec![])));
+    }
+
+    #[test]
+    fn it_should_remove_services() {
+        let mut bom = Bom::builder()
+            .services([
+                Service::new("keep", Some("keep".to_string())),
+                Service::new("removed", Some("removed".to_string())),
+            ])
+            .dependency(dependency("keep", &["removed"]))
+            .build()
+            .unwrap();
+
+        bom.retain_services(|service| service.name.0 == "keep");
+
+        assert_eq!(
+            bom.services,
+            Some(Services(vec![Service::new(
+                "keep",
+                Some("keep".to_string())
+            )]))
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![dependency("keep", &[])]))
+        );
+    }
+}
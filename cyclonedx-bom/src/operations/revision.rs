@@ -0,0 +1,122 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::external_models::date_time::{DateTime, DateTimeError};
+use crate::models::{
+    bom::{Bom, UrnUuid},
+    external_reference::{
+        BomLink, ExternalReference, ExternalReferenceType, ExternalReferences, Uri,
+    },
+    metadata::Metadata,
+};
+
+impl Bom {
+    /// Turns this BOM into the next revision of itself.
+    ///
+    /// The serial number is kept, a new one is generated only if the BOM has none. The
+    /// `version` is incremented and the metadata timestamp is set to the current time.
+    /// If `record_previous` is `true`, a reference of type `bom` to the previous version is
+    /// added to the external references as a [`BomLink`], which requires spec version 1.5.
+    ///
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut bom = Bom::default();
+    /// let serial_number = bom.serial_number.clone();
+    ///
+    /// bom.next_revision(false)?;
+    ///
+    /// assert_eq!(bom.version, 2);
+    /// assert_eq!(bom.serial_number, serial_number);
+    /// # Ok::<(), cyclonedx_bom::external_models::date_time::DateTimeError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if unable to generate a valid timestamp
+    pub fn next_revision(&mut self, record_previous: bool) -> Result<(), DateTimeError> {
+        let timestamp = DateTime::now()?;
+        let serial_number = self
+            .serial_number
+            .get_or_insert_with(UrnUuid::generate)
+            .clone();
+
+        if record_previous {
+            let previous = ExternalReference {
+                comment: Some("Previous version of this BOM".to_string()),
+                ..ExternalReference::new(
+                    ExternalReferenceType::Bom,
+                    Uri::BomLink(BomLink::new(&serial_number, self.version)),
+                )
+            };
+            self.external_references
+                .get_or_insert_with(|| ExternalReferences(Vec::new()))
+                .0
+                .push(previous);
+        }
+
+        self.version += 1;
+        self.metadata
+            .get_or_insert_with(Metadata::default)
+            .timestamp = Some(timestamp);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{models::bom::SpecVersion, validation::Validate};
+
+    #[test]
+    fn it_should_create_the_next_revision() {
+        let serial_number =
+            UrnUuid::new("urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string()).unwrap();
+        let mut bom = Bom::builder()
+            .serial_number(serial_number.clone())
+            .version(3)
+            .build()
+            .unwrap();
+
+        bom.next_revision(true).unwrap();
+
+        assert_eq!(bom.version, 4);
+        assert_eq!(bom.serial_number, Some(serial_number));
+        assert!(bom.metadata.as_ref().unwrap().timestamp.is_some());
+        assert_eq!(
+            bom.external_references.as_ref().unwrap().0[0].url,
+            Uri::BomLink(BomLink(
+                "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/3".to_string()
+            ))
+        );
+        assert!(bom.validate_version(SpecVersion::V1_5).passed());
+    }
+
+    #[test]
+    fn it_should_generate_a_missing_serial_number() {
+        let mut bom = Bom::builder().build().unwrap();
+
+        bom.next_revision(false).unwrap();
+
+        assert!(bom.serial_number.is_some());
+        assert_eq!(bom.version, 2);
+        assert_eq!(bom.external_references, None);
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::HashSet;
+
+use crate::models::{bom::Bom, component::Scope};
+
+impl Bom {
+    /// Retains only the components with one of the given scopes.
+    ///
+    /// Components without a scope are treated as [`Scope::Required`], which is the default of
+    /// the specification. References to removed components are cleaned up as in
+    /// [`Bom::retain_components`]. Combine with [`Bom::remove_unreachable_components`] to also
+    /// drop the dependencies that were only pulled in by removed components.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component, Scope};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut dev_dependency = Component::new(Classification::Library, "insta", "1.0.0", None);
+    /// dev_dependency.scope = Some(Scope::Excluded);
+    /// let mut bom = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+    ///     .component(dev_dependency)
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// bom.retain_scopes(&[Scope::Required]);
+    /// assert_eq!(bom.components.unwrap().0.len(), 1);
+    /// ```
+    pub fn retain_scopes(&mut self, scopes: &[Scope]) {
+        self.retain_components(|component| {
+            scopes.contains(component.scope.as_ref().unwrap_or(&Scope::Required))
+        });
+    }
+
+    /// Removes the components that the metadata component does not transitively depend on and
+    /// returns how many were removed.
+    ///
+    /// Only components whose `bom-ref` appears in the dependencies section are considered, so
+    /// components the BOM has no dependency information for are kept. Does nothing if the BOM has
+    /// no metadata component with a `bom-ref`.
+    pub fn remove_unreachable_components(&mut self) -> usize {
+        let graph = self.dependency_graph();
+        let Some(root) = graph.root() else {
+            return 0;
+        };
+        let unreachable: HashSet<String> = {
+            let mut reachable: HashSet<&str> = graph.descendants(root).into_iter().collect();
+            reachable.insert(root);
+            graph
+                .nodes()
+                .iter()
+                .filter(|bom_ref| !reachable.contains(*bom_ref))
+                .map(|bom_ref| bom_ref.to_string())
+                .collect()
+        };
+
+        let mut removed = 0;
+        self.retain_components(|component| {
+            let retain = component
+                .bom_ref
+                .as_ref()
+                .map_or(true, |bom_ref| !unreachable.contains(bom_ref));
+            if !retain {
+                removed += 1;
+            }
+            retain
+        });
+        self.remove_references(&unreachable);
+        removed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::{
+        component::{Classification, Component, Components},
+        dependency::{Dependencies, Dependency},
+        metadata::Metadata,
+    };
+
+    fn component(name: &str, scope: Option<Scope>) -> Component {
+        Component {
+            scope,
+            ..Component::new(
+                Classification::Library,
+                name,
+                "1.0.0",
+                Some(name.to_string()),
+            )
+        }
+    }
+
+    fn dependency(from: &str, to: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: from.to_string(),
+            dependencies: to.iter().map(|to| to.to_string()).collect(),
+        }
+    }
+
+    fn bom() -> Bom {
+        Bom::builder()
+            .metadata(Metadata {
+                component: Some(component("app", None)),
+                ..Metadata::default()
+            })
+            .components([
+                component("serde", Some(Scope::Required)),
+                component("insta", Some(Scope::Excluded)),
+                component("similar", None),
+                component("log", Some(Scope::Optional)),
+                component("undocumented", None),
+            ])
+            .dependencies([
+                dependency("app", &["serde", "insta", "log"]),
+                dependency("insta", &["similar"]),
+                dependency("similar", &[]),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_retain_components_by_scope() {
+        let mut bom = bom();
+        bom.retain_scopes(&[Scope::Required, Scope::Optional]);
+
+        assert_eq!(
+            bom.components,
+            Some(Components(vec![
+                component("serde", Some(Scope::Required)),
+                component("similar", None),
+                component("log", Some(Scope::Optional)),
+                component("undocumented", None),
+            ]))
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                dependency("app", &["serde", "log"]),
+                dependency("similar", &[]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_remove_unreachable_components() {
+        let mut bom = bom();
+        bom.retain_scopes(&[Scope::Required]);
+
+        assert_eq!(bom.remove_unreachable_components(), 1);
+        assert_eq!(
+            bom.components,
+            Some(Components(vec![
+                component("serde", Some(Scope::Required)),
+                component("undocumented", None),
+            ]))
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![dependency("app", &["serde"])]))
+        );
+    }
+
+    #[test]
+    fn it_should_keep_components_without_a_root() {
+        let mut bom = Bom::builder()
+            .component(component("serde", None))
+            .dependency(dependency("serde", &[]))
+            .build()
+            .unwrap();
+
+        assert_eq!(bom.remove_unreachable_components(), 0);
+        assert_eq!(bom.components.unwrap().0.len(), 1);
+    }
+}
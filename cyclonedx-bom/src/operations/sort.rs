@@ -0,0 +1,244 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::models::{
+    bom::Bom,
+    component::{Component, Components},
+    property::Properties,
+    service::Service,
+};
+
+/// How [`Bom::sort`] orders components.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SortPolicy {
+    /// Components are ordered by package URL. Components without a package URL come last,
+    /// ordered by group, name and version.
+    #[default]
+    Purl,
+    /// Components are ordered by group, name and version.
+    NameVersion,
+}
+
+impl Bom {
+    /// Orders the components, dependencies and properties of the BOM deterministically.
+    ///
+    /// Components are ordered according to the [`SortPolicy`] at every level of nesting, ties
+    /// are broken by `bom-ref`. Dependencies are ordered by their `bom-ref`, as are the
+    /// dependencies of each entry. Properties are ordered by name and value. All values are
+    /// compared lexicographically and the sort is stable, so the result does not depend on the
+    /// order of the input or the serialization format.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::operations::sort::SortPolicy;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut bom = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+    ///     .component(Component::new(Classification::Library, "anyhow", "1.0.0", None))
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// bom.sort(SortPolicy::NameVersion);
+    /// assert_eq!(bom.components.unwrap().0[0].name.to_string(), "anyhow");
+    /// ```
+    pub fn sort(&mut self, policy: SortPolicy) {
+        if let Some(metadata) = &mut self.metadata {
+            if let Some(component) = &mut metadata.component {
+                sort_component(component, policy);
+            }
+            sort_properties(&mut metadata.properties);
+        }
+        if let Some(components) = &mut self.components {
+            sort_components(components, policy);
+        }
+        for service in self.services.iter_mut().flat_map(|s| s.0.iter_mut()) {
+            sort_service(service);
+        }
+        if let Some(dependencies) = &mut self.dependencies {
+            for dependency in &mut dependencies.0 {
+                dependency.dependencies.sort();
+            }
+            dependencies
+                .0
+                .sort_by(|a, b| a.dependency_ref.cmp(&b.dependency_ref));
+        }
+        sort_properties(&mut self.properties);
+    }
+}
+
+fn sort_components(components: &mut Components, policy: SortPolicy) {
+    for component in &mut components.0 {
+        sort_component(component, policy);
+    }
+    components.0.sort_by_cached_key(|component| {
+        let purl = match policy {
+            SortPolicy::Purl => component.purl.as_ref().map(|purl| purl.0.clone()),
+            SortPolicy::NameVersion => None,
+        };
+        (
+            purl.is_none(),
+            purl,
+            component.group.as_ref().map(|group| group.0.clone()),
+            component.name.0.clone(),
+            component.version.as_ref().map(|version| version.0.clone()),
+            component.bom_ref.clone(),
+        )
+    });
+}
+
+fn sort_component(component: &mut Component, policy: SortPolicy) {
+    if let Some(components) = &mut component.components {
+        sort_components(components, policy);
+    }
+    sort_properties(&mut component.properties);
+}
+
+fn sort_service(service: &mut Service) {
+    for service in service.services.iter_mut().flat_map(|s| s.0.iter_mut()) {
+        sort_service(service);
+    }
+    sort_properties(&mut service.properties);
+}
+
+fn sort_properties(properties: &mut Option<Properties>) {
+    if let Some(properties) = properties {
+        properties
+            .0
+            .sort_by(|a, b| (&a.name, &a.value.0).cmp(&(&b.name, &b.value.0)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::uri::Purl,
+        models::{
+            component::Classification,
+            dependency::{Dependencies, Dependency},
+            property::Property,
+        },
+    };
+
+    fn component(name: &str, version: &str, purl: Option<&str>) -> Component {
+        let mut component = Component::new(
+            Classification::Library,
+            name,
+            version,
+            Some(format!("{name}@{version}")),
+        );
+        component.purl = purl.map(|purl| Purl(purl.to_string()));
+        component
+    }
+
+    fn names(components: &Option<Components>) -> Vec<String> {
+        components
+            .iter()
+            .flat_map(|components| components.0.iter())
+            .map(|component| format!("{}@{}", component.name, component.version.as_ref().unwrap()))
+            .collect()
+    }
+
+    fn bom() -> Bom {
+        let mut parent = component("parent", "1.0", None);
+        parent.components = Some(Components(vec![
+            component("z", "1.0", None),
+            component("y", "1.0", None),
+        ]));
+        parent.properties = Some(Properties(vec![
+            Property::new("b", "1"),
+            Property::new("a", "2"),
+            Property::new("a", "1"),
+        ]));
+        Bom::builder()
+            .components([
+                component("b", "2.0", None),
+                parent,
+                component("b", "1.0", None),
+                component("c", "1.0", Some("pkg:cargo/c@1.0")),
+                component("a", "1.0", Some("pkg:npm/a@1.0")),
+            ])
+            .dependencies([
+                Dependency::new("b@1.0", ["c@1.0", "a@1.0"]),
+                Dependency::new("a@1.0", Vec::<String>::new()),
+            ])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_sort_components_by_purl() {
+        let mut bom = bom();
+        bom.sort(SortPolicy::Purl);
+
+        assert_eq!(
+            names(&bom.components),
+            vec!["c@1.0", "a@1.0", "b@1.0", "b@2.0", "parent@1.0"]
+        );
+        let parent = &bom.components.as_ref().unwrap().0[4];
+        assert_eq!(names(&parent.components), vec!["y@1.0", "z@1.0"]);
+        assert_eq!(
+            parent.properties,
+            Some(Properties(vec![
+                Property::new("a", "1"),
+                Property::new("a", "2"),
+                Property::new("b", "1"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_sort_components_by_name_and_version() {
+        let mut bom = bom();
+        bom.sort(SortPolicy::NameVersion);
+
+        assert_eq!(
+            names(&bom.components),
+            vec!["a@1.0", "b@1.0", "b@2.0", "c@1.0", "parent@1.0"]
+        );
+    }
+
+    #[test]
+    fn it_should_sort_dependencies() {
+        let mut bom = bom();
+        bom.sort(SortPolicy::default());
+
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                Dependency::new("a@1.0", Vec::<String>::new()),
+                Dependency::new("b@1.0", ["a@1.0", "c@1.0"]),
+            ]))
+        );
+    }
+
+    #[test]
+    fn it_should_be_independent_of_the_input_order() {
+        let mut bom = bom();
+        let mut reversed = bom.clone();
+        reversed.components.as_mut().unwrap().0.reverse();
+        reversed.dependencies.as_mut().unwrap().0.reverse();
+
+        bom.sort(SortPolicy::Purl);
+        reversed.sort(SortPolicy::Purl);
+        assert_eq!(bom, reversed);
+    }
+}
@@ -0,0 +1,250 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::HashSet;
+
+use crate::models::{
+    bom::{Bom, UrnUuid},
+    component::Component,
+    dependency::{Dependencies, Dependency},
+    external_reference::{
+        BomLink, ExternalReference, ExternalReferenceType, ExternalReferences, Uri,
+    },
+    metadata::Metadata,
+};
+
+impl Bom {
+    /// Moves the nested components of the given assemblies into separate BOMs and links them
+    /// from this BOM, returning the new child BOMs.
+    ///
+    /// For every component with one of the given `bom-ref`s and nested components, a child BOM
+    /// with a new serial number is created. Its metadata component is a copy of the assembly,
+    /// its components are the nested components of the assembly, and it receives the dependency
+    /// entries of these components. The rest of the metadata, except licenses and properties,
+    /// is copied from this BOM. In this BOM the nested components are removed, references to
+    /// them are cleaned up as in [`Bom::retain_components`], and the assembly gets an external
+    /// reference of type `bom` with a [`BomLink`] to the child BOM, which requires spec version
+    /// 1.5.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component, Components};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let mut firmware = Component::new(Classification::Firmware, "firmware", "1.0.0", Some("firmware".to_string()));
+    /// firmware.components = Some(Components(vec![Component::new(Classification::Library, "zlib", "1.3.0", None)]));
+    /// let mut bom = Bom::builder().component(firmware).build().expect("valid BOM");
+    ///
+    /// let children = bom.split_assemblies(&["firmware"]);
+    ///
+    /// assert_eq!(children.len(), 1);
+    /// assert_eq!(children[0].components.as_ref().unwrap().0.len(), 1);
+    /// assert_eq!(bom.components.unwrap().0[0].components, None);
+    /// ```
+    pub fn split_assemblies(&mut self, bom_refs: &[&str]) -> Vec<Bom> {
+        let mut children = Vec::new();
+        let mut removed = HashSet::new();
+
+        for bom_ref in bom_refs {
+            let Some(assembly) = self
+                .components
+                .iter_mut()
+                .flat_map(|c| c.0.iter_mut())
+                .find_map(|component| find_component_mut(component, bom_ref))
+            else {
+                continue;
+            };
+            let Some(components) = assembly.components.take() else {
+                continue;
+            };
+
+            let component = assembly.clone();
+            let serial_number = UrnUuid::generate();
+            assembly
+                .external_references
+                .get_or_insert_with(|| ExternalReferences(Vec::new()))
+                .0
+                .push(ExternalReference::new(
+                    ExternalReferenceType::Bom,
+                    Uri::BomLink(BomLink::new(&serial_number, 1)),
+                ));
+
+            let mut child_refs = HashSet::new();
+            for component in &components.0 {
+                collect_component_refs(component, &mut child_refs);
+            }
+            let dependencies: Vec<Dependency> = self
+                .dependencies
+                .iter()
+                .flat_map(|d| d.0.iter())
+                .filter(|dependency| {
+                    dependency.dependency_ref == *bom_ref
+                        || child_refs.contains(&dependency.dependency_ref)
+                })
+                .map(|dependency| Dependency {
+                    dependency_ref: dependency.dependency_ref.clone(),
+                    dependencies: dependency
+                        .dependencies
+                        .iter()
+                        .filter(|to| child_refs.contains(*to))
+                        .cloned()
+                        .collect(),
+                })
+                .collect();
+
+            children.push(Bom {
+                version: 1,
+                serial_number: Some(serial_number),
+                metadata: Some(Metadata {
+                    component: Some(component),
+                    licenses: None,
+                    properties: None,
+                    ..self.metadata.clone().unwrap_or_default()
+                }),
+                components: Some(components),
+                dependencies: (!dependencies.is_empty()).then_some(Dependencies(dependencies)),
+                ..Bom::default()
+            });
+            removed.extend(child_refs);
+        }
+
+        self.remove_references(&removed);
+        children
+    }
+}
+
+fn find_component_mut<'a>(
+    component: &'a mut Component,
+    bom_ref: &str,
+) -> Option<&'a mut Component> {
+    if component.bom_ref.as_deref() == Some(bom_ref) {
+        return Some(component);
+    }
+    component
+        .components
+        .iter_mut()
+        .flat_map(|c| c.0.iter_mut())
+        .find_map(|subcomponent| find_component_mut(subcomponent, bom_ref))
+}
+
+fn collect_component_refs(component: &Component, refs: &mut HashSet<String>) {
+    if let Some(bom_ref) = &component.bom_ref {
+        refs.insert(bom_ref.clone());
+    }
+    for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+        collect_component_refs(subcomponent, refs);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        models::{
+            bom::SpecVersion,
+            component::{Classification, Components},
+        },
+        validation::Validate,
+    };
+
+    fn component(name: &str) -> Component {
+        Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(name.to_string()),
+        )
+    }
+
+    fn dependency(from: &str, to: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: from.to_string(),
+            dependencies: to.iter().map(|to| to.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn it_should_split_assemblies_into_linked_boms() {
+        let mut device = component("device");
+        let mut firmware = component("firmware");
+        firmware.components = Some(Components(vec![component("zlib"), component("libc")]));
+        device.components = Some(Components(vec![firmware]));
+        let mut bom = Bom::builder()
+            .metadata(Metadata {
+                authors: Some(Vec::new()),
+                ..Metadata::default()
+            })
+            .components([device, component("unrelated")])
+            .dependencies([
+                dependency("device", &["firmware"]),
+                dependency("firmware", &["zlib", "unrelated"]),
+                dependency("zlib", &["libc"]),
+            ])
+            .build()
+            .unwrap();
+
+        let children = bom.split_assemblies(&["firmware", "unrelated", "unknown"]);
+
+        assert_eq!(children.len(), 1);
+        let child = &children[0];
+        assert_eq!(
+            child.metadata,
+            Some(Metadata {
+                authors: Some(Vec::new()),
+                component: Some(component("firmware")),
+                ..Metadata::default()
+            })
+        );
+        assert_eq!(
+            child.components,
+            Some(Components(vec![component("zlib"), component("libc")]))
+        );
+        assert_eq!(
+            child.dependencies,
+            Some(Dependencies(vec![
+                dependency("firmware", &["zlib"]),
+                dependency("zlib", &["libc"]),
+            ]))
+        );
+        assert!(child.validate_version(SpecVersion::V1_5).passed());
+
+        let firmware = &bom.components.as_ref().unwrap().0[0]
+            .components
+            .as_ref()
+            .unwrap()
+            .0[0];
+        assert_eq!(firmware.components, None);
+        let serial_number = child.serial_number.as_ref().unwrap();
+        assert_eq!(
+            firmware.external_references,
+            Some(ExternalReferences(vec![ExternalReference::new(
+                ExternalReferenceType::Bom,
+                Uri::BomLink(BomLink::new(serial_number, 1)),
+            )]))
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                dependency("device", &["firmware"]),
+                dependency("firmware", &["unrelated"]),
+            ]))
+        );
+        assert!(bom.validate_version(SpecVersion::V1_5).passed());
+    }
+}
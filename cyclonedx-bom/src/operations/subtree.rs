@@ -0,0 +1,304 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::models::{
+    bom::{Bom, UrnUuid},
+    component::{Component, Components},
+    metadata::Metadata,
+    service::Service,
+};
+
+impl Bom {
+    /// Extracts the component with the given `bom-ref` and everything it transitively depends on
+    /// into a new BOM, e.g. to share the SBOM of a single binary built from a monorepo.
+    ///
+    /// The extracted component becomes the metadata component of the new BOM, the rest of the
+    /// metadata is carried over. Components that are kept retain their nested components, which
+    /// are followed like dependencies. Kept components nested in a component that is not kept are
+    /// moved to the top level. Services are kept if they are depended on. Dependencies,
+    /// compositions and vulnerabilities only refer to kept `bom-ref`s, vulnerabilities that
+    /// affect none of them are dropped.
+    ///
+    /// The new BOM gets a fresh serial number and version 1, the signature is dropped.
+    /// Returns `None` if no component has the given `bom-ref`.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::models::dependency::Dependency;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::builder()
+    ///     .component(Component::new(Classification::Application, "cli", "1.0.0", Some("cli".to_string())))
+    ///     .component(Component::new(Classification::Library, "clap", "4.0.0", Some("clap".to_string())))
+    ///     .component(Component::new(Classification::Application, "server", "1.0.0", Some("server".to_string())))
+    ///     .dependency(Dependency {
+    ///         dependency_ref: "cli".to_string(),
+    ///         dependencies: vec!["clap".to_string()],
+    ///     })
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// let cli = bom.extract_subtree("cli").unwrap();
+    /// let metadata = cli.metadata.unwrap();
+    /// assert_eq!(metadata.component.unwrap().name.to_string(), "cli");
+    /// assert_eq!(cli.components.unwrap().0.len(), 1);
+    /// ```
+    pub fn extract_subtree(&self, root_bom_ref: &str) -> Option<Bom> {
+        let metadata_component = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref());
+
+        let mut nested = HashMap::new();
+        let mut all_refs = HashSet::new();
+        for component in metadata_component
+            .into_iter()
+            .chain(self.components.iter().flat_map(|c| c.0.iter()))
+        {
+            collect_nested_refs(component, &mut nested, &mut all_refs);
+        }
+        if !all_refs.contains(root_bom_ref) {
+            return None;
+        }
+        for service in self.services.iter().flat_map(|s| s.0.iter()) {
+            collect_service_refs(service, &mut all_refs);
+        }
+
+        let graph = self.dependency_graph();
+        let mut kept = HashSet::from([root_bom_ref.to_string()]);
+        let mut queue = VecDeque::from([root_bom_ref.to_string()]);
+        while let Some(bom_ref) = queue.pop_front() {
+            let children = nested.get(bom_ref.as_str()).into_iter().flatten().copied();
+            for next in graph.dependencies(&bom_ref).into_iter().chain(children) {
+                if kept.insert(next.to_string()) {
+                    queue.push_back(next.to_string());
+                }
+            }
+        }
+
+        let mut components = Vec::new();
+        for component in metadata_component
+            .into_iter()
+            .chain(self.components.iter().flat_map(|c| c.0.iter()))
+        {
+            collect_kept_components(component, &kept, &mut components);
+        }
+        let root = components
+            .iter()
+            .position(|component| component.bom_ref.as_deref() == Some(root_bom_ref))
+            .map(|index| components.remove(index));
+
+        let mut bom = Bom {
+            version: 1,
+            serial_number: Some(UrnUuid::generate()),
+            metadata: Some(Metadata {
+                component: root,
+                ..self.metadata.clone().unwrap_or_default()
+            }),
+            components: Some(Components(components)),
+            signature: None,
+            ..self.clone()
+        };
+
+        let removed = all_refs
+            .iter()
+            .map(String::as_str)
+            .chain(graph.nodes().iter().copied())
+            .filter(|bom_ref| !kept.contains(*bom_ref))
+            .map(str::to_string)
+            .collect();
+        bom.retain_services(|service| {
+            service
+                .bom_ref
+                .as_ref()
+                .is_some_and(|bom_ref| kept.contains(bom_ref))
+        });
+        bom.remove_references(&removed);
+        if let Some(vulnerabilities) = &mut bom.vulnerabilities {
+            vulnerabilities.0.retain(|vulnerability| {
+                vulnerability
+                    .vulnerability_targets
+                    .as_ref()
+                    .map_or(true, |targets| !targets.0.is_empty())
+            });
+        }
+
+        Some(bom)
+    }
+}
+
+fn collect_nested_refs<'a>(
+    component: &'a Component,
+    nested: &mut HashMap<&'a str, Vec<&'a str>>,
+    all_refs: &mut HashSet<String>,
+) {
+    if let Some(bom_ref) = &component.bom_ref {
+        all_refs.insert(bom_ref.clone());
+    }
+    for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+        if let (Some(parent), Some(child)) = (&component.bom_ref, &subcomponent.bom_ref) {
+            nested
+                .entry(parent.as_str())
+                .or_default()
+                .push(child.as_str());
+        }
+        collect_nested_refs(subcomponent, nested, all_refs);
+    }
+}
+
+fn collect_service_refs(service: &Service, refs: &mut HashSet<String>) {
+    if let Some(bom_ref) = &service.bom_ref {
+        refs.insert(bom_ref.clone());
+    }
+    for subservice in service.services.iter().flat_map(|s| s.0.iter()) {
+        collect_service_refs(subservice, refs);
+    }
+}
+
+/// Copies kept components with all their nested components, and searches the nested components
+/// of components that are not kept.
+fn collect_kept_components(
+    component: &Component,
+    kept: &HashSet<String>,
+    components: &mut Vec<Component>,
+) {
+    if component
+        .bom_ref
+        .as_ref()
+        .is_some_and(|bom_ref| kept.contains(bom_ref))
+    {
+        components.push(component.clone());
+        return;
+    }
+    for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+        collect_kept_components(subcomponent, kept, components);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::normalized_string::NormalizedString,
+        models::{
+            bom::SpecVersion,
+            component::Classification,
+            dependency::{Dependencies, Dependency},
+            vulnerability::{Vulnerabilities, Vulnerability},
+            vulnerability_target::{VulnerabilityTarget, VulnerabilityTargets},
+        },
+        validation::Validate,
+    };
+
+    fn component(name: &str) -> Component {
+        Component::new(
+            Classification::Library,
+            name,
+            "1.0.0",
+            Some(name.to_string()),
+        )
+    }
+
+    fn dependency(from: &str, to: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: from.to_string(),
+            dependencies: to.iter().map(|to| to.to_string()).collect(),
+        }
+    }
+
+    fn vulnerability(id: &str, target: &str) -> Vulnerability {
+        Vulnerability {
+            id: Some(NormalizedString::new(id)),
+            vulnerability_targets: Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                target.to_string(),
+            )])),
+            ..Vulnerability::new(None)
+        }
+    }
+
+    fn monorepo() -> Bom {
+        let mut workspace = component("workspace");
+        workspace.components = Some(Components(vec![component("cli"), component("server")]));
+        let mut tokio = component("tokio");
+        tokio.components = Some(Components(vec![component("tokio-macros")]));
+        Bom::builder()
+            .metadata(Metadata {
+                component: Some(workspace),
+                ..Metadata::default()
+            })
+            .components([component("clap"), component("anstyle"), tokio])
+            .dependencies([
+                dependency("workspace", &["cli", "server"]),
+                dependency("cli", &["clap"]),
+                dependency("clap", &["anstyle"]),
+                dependency("server", &["tokio"]),
+            ])
+            .vulnerabilities([vulnerability("A", "anstyle"), vulnerability("B", "tokio")])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_extract_a_component_and_its_dependencies() {
+        let bom = monorepo();
+        let cli = bom.extract_subtree("cli").unwrap();
+
+        let metadata = cli.metadata.as_ref().unwrap();
+        assert_eq!(metadata.component, Some(component("cli")));
+        assert_eq!(
+            cli.components,
+            Some(Components(vec![component("clap"), component("anstyle")]))
+        );
+        assert_eq!(
+            cli.dependencies,
+            Some(Dependencies(vec![
+                dependency("cli", &["clap"]),
+                dependency("clap", &["anstyle"]),
+            ]))
+        );
+        assert_eq!(
+            cli.vulnerabilities,
+            Some(Vulnerabilities(vec![vulnerability("A", "anstyle")]))
+        );
+        assert_eq!(cli.version, 1);
+        assert_ne!(cli.serial_number, bom.serial_number);
+        assert!(cli.validate_version(SpecVersion::V1_5).passed());
+    }
+
+    #[test]
+    fn it_should_keep_nested_components_of_kept_components() {
+        let server = monorepo().extract_subtree("server").unwrap();
+
+        let mut tokio = component("tokio");
+        tokio.components = Some(Components(vec![component("tokio-macros")]));
+        assert_eq!(server.components, Some(Components(vec![tokio])));
+        assert_eq!(
+            server.vulnerabilities,
+            Some(Vulnerabilities(vec![vulnerability("B", "tokio")]))
+        );
+    }
+
+    #[test]
+    fn it_should_return_none_for_an_unknown_bom_ref() {
+        assert_eq!(monorepo().extract_subtree("unknown"), None);
+    }
+}
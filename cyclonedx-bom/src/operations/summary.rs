@@ -0,0 +1,258 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::models::{
+    bom::Bom,
+    component::{Classification, Component},
+    license::{LicenseChoice, LicenseIdentifier},
+};
+
+/// Statistics about a [`Bom`], created via [`Bom::summary`].
+///
+/// All component counts include nested components and the metadata component.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BomSummary {
+    pub components: usize,
+    pub services: usize,
+    pub components_by_type: HashMap<Classification, usize>,
+    /// Number of components per SPDX id, license name or SPDX expression.
+    pub licenses: BTreeMap<String, usize>,
+    pub components_without_licenses: usize,
+    pub components_without_hashes: usize,
+    pub components_without_purl: usize,
+    pub components_without_version: usize,
+    /// Number of dependency edges from the metadata component to the `bom-ref` furthest away
+    /// from it, or from the `bom-ref`s nothing depends on if there is no metadata component.
+    pub dependency_depth: usize,
+}
+
+impl Bom {
+    /// Counts components by type and license, components lacking common fields, and determines
+    /// the depth of the dependency graph.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component};
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// let bom = Bom::builder()
+    ///     .component(Component::new(Classification::Library, "serde", "1.0.0", None))
+    ///     .build()
+    ///     .expect("valid BOM");
+    ///
+    /// let summary = bom.summary();
+    /// assert_eq!(summary.components, 1);
+    /// assert_eq!(summary.components_by_type[&Classification::Library], 1);
+    /// assert_eq!(summary.components_without_purl, 1);
+    /// ```
+    pub fn summary(&self) -> BomSummary {
+        let mut summary = BomSummary::default();
+
+        let metadata_component = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.component.as_ref());
+        for component in metadata_component
+            .into_iter()
+            .chain(self.components.iter().flat_map(|c| c.0.iter()))
+        {
+            summary.add_component(component);
+        }
+
+        let mut services: Vec<_> = self.services.iter().flat_map(|s| s.0.iter()).collect();
+        while let Some(service) = services.pop() {
+            summary.services += 1;
+            services.extend(service.services.iter().flat_map(|s| s.0.iter()));
+        }
+
+        summary.dependency_depth = self.dependency_depth();
+        summary
+    }
+
+    fn dependency_depth(&self) -> usize {
+        let graph = self.dependency_graph();
+        let starts: Vec<&str> = match graph.root() {
+            Some(root) => vec![root],
+            None => graph
+                .nodes()
+                .iter()
+                .copied()
+                .filter(|bom_ref| graph.dependents(bom_ref).is_empty())
+                .collect(),
+        };
+
+        let mut depths: HashMap<&str, usize> = starts.iter().map(|&start| (start, 0)).collect();
+        let mut queue: VecDeque<&str> = starts.into_iter().collect();
+        let mut max_depth = 0;
+        while let Some(bom_ref) = queue.pop_front() {
+            let depth = depths[bom_ref] + 1;
+            for dependency in graph.dependencies(bom_ref) {
+                if !depths.contains_key(dependency) {
+                    depths.insert(dependency, depth);
+                    max_depth = max_depth.max(depth);
+                    queue.push_back(dependency);
+                }
+            }
+        }
+        max_depth
+    }
+}
+
+impl BomSummary {
+    fn add_component(&mut self, component: &Component) {
+        self.components += 1;
+        *self
+            .components_by_type
+            .entry(component.component_type.clone())
+            .or_default() += 1;
+
+        let licenses = component.licenses.as_ref().map_or(&[][..], |l| &l.0);
+        if licenses.is_empty() {
+            self.components_without_licenses += 1;
+        }
+        for license in licenses {
+            let key = match license {
+                LicenseChoice::License(license) => match &license.license_identifier {
+                    LicenseIdentifier::SpdxId(id) => id.to_string(),
+                    LicenseIdentifier::Name(name) => name.to_string(),
+                },
+                LicenseChoice::Expression(expression) => expression.to_string(),
+            };
+            *self.licenses.entry(key).or_default() += 1;
+        }
+
+        if component.hashes.as_ref().map_or(true, |h| h.0.is_empty()) {
+            self.components_without_hashes += 1;
+        }
+        if component.purl.is_none() {
+            self.components_without_purl += 1;
+        }
+        if component.version.is_none() {
+            self.components_without_version += 1;
+        }
+
+        for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+            self.add_component(subcomponent);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::uri::Purl,
+        models::{
+            component::Components,
+            dependency::Dependency,
+            license::Licenses,
+            metadata::Metadata,
+            service::{Service, Services},
+        },
+    };
+
+    fn component(name: &str, licenses: &[LicenseChoice]) -> Component {
+        Component {
+            licenses: Some(Licenses(licenses.to_vec())),
+            purl: Some(Purl::new("cargo", name, "1.0.0").unwrap()),
+            ..Component::new(
+                Classification::Library,
+                name,
+                "1.0.0",
+                Some(name.to_string()),
+            )
+        }
+    }
+
+    fn dependency(from: &str, to: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: from.to_string(),
+            dependencies: to.iter().map(|to| to.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn it_should_summarize_a_bom() {
+        let mut app = Component::new(Classification::Application, "app", "", None);
+        app.version = None;
+        app.bom_ref = Some("app".to_string());
+        let mut serde = component("serde", &[LicenseChoice::expression("MIT OR Apache-2.0")]);
+        serde.components = Some(Components(vec![component(
+            "serde_derive",
+            &[LicenseChoice::expression("MIT OR Apache-2.0")],
+        )]));
+        let mut api = Service::new("api", None);
+        api.services = Some(Services(vec![Service::new("health", None)]));
+
+        let bom = Bom::builder()
+            .metadata(Metadata {
+                component: Some(app),
+                ..Metadata::default()
+            })
+            .components([
+                serde,
+                component("ring", &[LicenseChoice::license("ISC-like")]),
+            ])
+            .service(api)
+            .dependencies([
+                dependency("app", &["serde", "ring"]),
+                dependency("serde", &["serde_derive"]),
+                dependency("serde_derive", &["ring"]),
+            ])
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            bom.summary(),
+            BomSummary {
+                components: 4,
+                services: 2,
+                components_by_type: HashMap::from([
+                    (Classification::Application, 1),
+                    (Classification::Library, 3),
+                ]),
+                licenses: BTreeMap::from([
+                    ("ISC-like".to_string(), 1),
+                    ("MIT OR Apache-2.0".to_string(), 2),
+                ]),
+                components_without_licenses: 1,
+                components_without_hashes: 4,
+                components_without_purl: 1,
+                components_without_version: 1,
+                dependency_depth: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_measure_the_depth_without_a_root() {
+        let bom = Bom::builder()
+            .dependencies([
+                dependency("a", &["b"]),
+                dependency("b", &["c"]),
+                dependency("c", &["a"]),
+                dependency("d", &["e"]),
+            ])
+            .build_unchecked();
+
+        assert_eq!(bom.summary().dependency_depth, 1);
+    }
+}
@@ -0,0 +1,337 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use crate::models::{
+    bom::Bom, component::Component, external_reference::ExternalReference, license::LicenseChoice,
+    property::Property, service::Service,
+};
+
+/// Visits the elements of a [`Bom`], see [`Bom::walk`].
+///
+/// All methods do nothing by default, so implementations only need to override the
+/// elements they are interested in.
+pub trait BomVisitor {
+    fn visit_component(&mut self, _component: &Component) {}
+    fn visit_service(&mut self, _service: &Service) {}
+    fn visit_license(&mut self, _license: &LicenseChoice) {}
+    fn visit_external_reference(&mut self, _external_reference: &ExternalReference) {}
+    fn visit_property(&mut self, _property: &Property) {}
+}
+
+/// Visits and modifies the elements of a [`Bom`], see [`Bom::walk_mut`].
+pub trait BomVisitorMut {
+    fn visit_component(&mut self, _component: &mut Component) {}
+    fn visit_service(&mut self, _service: &mut Service) {}
+    fn visit_license(&mut self, _license: &mut LicenseChoice) {}
+    fn visit_external_reference(&mut self, _external_reference: &mut ExternalReference) {}
+    fn visit_property(&mut self, _property: &mut Property) {}
+}
+
+impl Bom {
+    /// Calls the visitor for every component, service, license, external reference and property
+    /// of the BOM.
+    ///
+    /// This includes the metadata component and nested components and services. A component
+    /// or service is visited before its licenses, external references, properties and nested
+    /// elements.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::component::{Classification, Component, Components};
+    /// use cyclonedx_bom::operations::walk::BomVisitor;
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// #[derive(Default)]
+    /// struct ComponentCounter(usize);
+    ///
+    /// impl BomVisitor for ComponentCounter {
+    ///     fn visit_component(&mut self, _component: &Component) {
+    ///         self.0 += 1;
+    ///     }
+    /// }
+    ///
+    /// let mut parent = Component::new(Classification::Library, "parent", "1.0.0", None);
+    /// parent.components = Some(Components(vec![Component::new(
+    ///     Classification::Library,
+    ///     "child",
+    ///     "1.0.0",
+    ///     None,
+    /// )]));
+    /// let bom = Bom::builder().component(parent).build().expect("valid BOM");
+    ///
+    /// let mut counter = ComponentCounter::default();
+    /// bom.walk(&mut counter);
+    /// assert_eq!(counter.0, 2);
+    /// ```
+    pub fn walk(&self, visitor: &mut impl BomVisitor) {
+        if let Some(metadata) = &self.metadata {
+            if let Some(component) = &metadata.component {
+                walk_component(component, visitor);
+            }
+            for license in metadata.licenses.iter().flat_map(|l| l.0.iter()) {
+                visitor.visit_license(license);
+            }
+            for property in metadata.properties.iter().flat_map(|p| p.0.iter()) {
+                visitor.visit_property(property);
+            }
+        }
+        for component in self.components.iter().flat_map(|c| c.0.iter()) {
+            walk_component(component, visitor);
+        }
+        for service in self.services.iter().flat_map(|s| s.0.iter()) {
+            walk_service(service, visitor);
+        }
+        for external_reference in self.external_references.iter().flat_map(|e| e.0.iter()) {
+            visitor.visit_external_reference(external_reference);
+        }
+        for property in self.properties.iter().flat_map(|p| p.0.iter()) {
+            visitor.visit_property(property);
+        }
+    }
+
+    /// Same as [`Bom::walk`], but allows the visitor to modify the visited elements.
+    ///
+    /// Changes to the nested components or services of a visited element are picked up,
+    /// i.e. the walk descends into the modified children.
+    pub fn walk_mut(&mut self, visitor: &mut impl BomVisitorMut) {
+        if let Some(metadata) = &mut self.metadata {
+            if let Some(component) = &mut metadata.component {
+                walk_component_mut(component, visitor);
+            }
+            for license in metadata.licenses.iter_mut().flat_map(|l| l.0.iter_mut()) {
+                visitor.visit_license(license);
+            }
+            for property in metadata.properties.iter_mut().flat_map(|p| p.0.iter_mut()) {
+                visitor.visit_property(property);
+            }
+        }
+        for component in self.components.iter_mut().flat_map(|c| c.0.iter_mut()) {
+            walk_component_mut(component, visitor);
+        }
+        for service in self.services.iter_mut().flat_map(|s| s.0.iter_mut()) {
+            walk_service_mut(service, visitor);
+        }
+        for external_reference in self
+            .external_references
+            .iter_mut()
+            .flat_map(|e| e.0.iter_mut())
+        {
+            visitor.visit_external_reference(external_reference);
+        }
+        for property in self.properties.iter_mut().flat_map(|p| p.0.iter_mut()) {
+            visitor.visit_property(property);
+        }
+    }
+}
+
+fn walk_component(component: &Component, visitor: &mut impl BomVisitor) {
+    visitor.visit_component(component);
+    for license in component.licenses.iter().flat_map(|l| l.0.iter()) {
+        visitor.visit_license(license);
+    }
+    for external_reference in component
+        .external_references
+        .iter()
+        .flat_map(|e| e.0.iter())
+    {
+        visitor.visit_external_reference(external_reference);
+    }
+    for property in component.properties.iter().flat_map(|p| p.0.iter()) {
+        visitor.visit_property(property);
+    }
+    for subcomponent in component.components.iter().flat_map(|c| c.0.iter()) {
+        walk_component(subcomponent, visitor);
+    }
+}
+
+fn walk_service(service: &Service, visitor: &mut impl BomVisitor) {
+    visitor.visit_service(service);
+    for license in service.licenses.iter().flat_map(|l| l.0.iter()) {
+        visitor.visit_license(license);
+    }
+    for external_reference in service.external_references.iter().flat_map(|e| e.0.iter()) {
+        visitor.visit_external_reference(external_reference);
+    }
+    for property in service.properties.iter().flat_map(|p| p.0.iter()) {
+        visitor.visit_property(property);
+    }
+    for subservice in service.services.iter().flat_map(|s| s.0.iter()) {
+        walk_service(subservice, visitor);
+    }
+}
+
+fn walk_component_mut(component: &mut Component, visitor: &mut impl BomVisitorMut) {
+    visitor.visit_component(component);
+    for license in component.licenses.iter_mut().flat_map(|l| l.0.iter_mut()) {
+        visitor.visit_license(license);
+    }
+    for external_reference in component
+        .external_references
+        .iter_mut()
+        .flat_map(|e| e.0.iter_mut())
+    {
+        visitor.visit_external_reference(external_reference);
+    }
+    for property in component.properties.iter_mut().flat_map(|p| p.0.iter_mut()) {
+        visitor.visit_property(property);
+    }
+    for subcomponent in component.components.iter_mut().flat_map(|c| c.0.iter_mut()) {
+        walk_component_mut(subcomponent, visitor);
+    }
+}
+
+fn walk_service_mut(service: &mut Service, visitor: &mut impl BomVisitorMut) {
+    visitor.visit_service(service);
+    for license in service.licenses.iter_mut().flat_map(|l| l.0.iter_mut()) {
+        visitor.visit_license(license);
+    }
+    for external_reference in service
+        .external_references
+        .iter_mut()
+        .flat_map(|e| e.0.iter_mut())
+    {
+        visitor.visit_external_reference(external_reference);
+    }
+    for property in service.properties.iter_mut().flat_map(|p| p.0.iter_mut()) {
+        visitor.visit_property(property);
+    }
+    for subservice in service.services.iter_mut().flat_map(|s| s.0.iter_mut()) {
+        walk_service_mut(subservice, visitor);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::uri::Uri,
+        models::{
+            component::{Classification, Components},
+            external_reference::{ExternalReferenceType, ExternalReferences},
+            license::Licenses,
+            metadata::Metadata,
+            property::Properties,
+            service::Services,
+        },
+    };
+
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl BomVisitor for Recorder {
+        fn visit_component(&mut self, component: &Component) {
+            self.0.push(format!("component {}", component.name));
+        }
+
+        fn visit_service(&mut self, service: &Service) {
+            self.0.push(format!("service {}", service.name));
+        }
+
+        fn visit_license(&mut self, license: &LicenseChoice) {
+            if let LicenseChoice::Expression(expression) = license {
+                self.0.push(format!("license {}", expression.expression));
+            }
+        }
+
+        fn visit_external_reference(&mut self, external_reference: &ExternalReference) {
+            self.0.push(format!(
+                "external reference {}",
+                external_reference.external_reference_type
+            ));
+        }
+
+        fn visit_property(&mut self, property: &Property) {
+            self.0.push(format!("property {}", property.name));
+        }
+    }
+
+    struct Uppercase;
+
+    impl BomVisitorMut for Uppercase {
+        fn visit_property(&mut self, property: &mut Property) {
+            property.name = property.name.to_uppercase();
+        }
+    }
+
+    fn bom() -> Bom {
+        let mut child = Component::new(Classification::Library, "child", "1.0.0", None);
+        child.licenses = Some(Licenses(vec![LicenseChoice::expression("MIT")]));
+        child.external_references = Some(ExternalReferences(vec![ExternalReference::new(
+            ExternalReferenceType::Vcs,
+            Uri::new("https://example.com"),
+        )]));
+        let mut parent = Component::new(Classification::Library, "parent", "1.0.0", None);
+        parent.components = Some(Components(vec![child]));
+        let mut service = Service::new("service", None);
+        service.services = Some(Services(vec![Service::new("subservice", None)]));
+        service.properties = Some(Properties(vec![Property::new("service", "value")]));
+
+        Bom::builder()
+            .metadata(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "1.0.0",
+                    None,
+                )),
+                ..Metadata::default()
+            })
+            .component(parent)
+            .service(service)
+            .property(Property::new("bom", "value"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_visit_all_elements() {
+        let mut recorder = Recorder::default();
+        bom().walk(&mut recorder);
+
+        assert_eq!(
+            recorder.0,
+            vec![
+                "component app",
+                "component parent",
+                "component child",
+                "license MIT",
+                "external reference vcs",
+                "service service",
+                "property service",
+                "service subservice",
+                "property bom",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_modify_visited_elements() {
+        let mut bom = bom();
+        bom.walk_mut(&mut Uppercase);
+
+        assert_eq!(
+            bom.properties,
+            Some(Properties(vec![Property::new("BOM", "value")]))
+        );
+        assert_eq!(
+            bom.services.unwrap().0[0].properties,
+            Some(Properties(vec![Property::new("SERVICE", "value")]))
+        );
+    }
+}
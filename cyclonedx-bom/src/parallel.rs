@@ -0,0 +1,332 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Writing BOMs with many components on multiple threads, enabled by the `parallel` feature.
+//!
+//! The top-level components are converted and serialized in parallel with `rayon`. The
+//! rest of the document is written with an empty component list, which is then replaced by the
+//! serialized components. The output is identical to the one of the sequential writers.
+
+use std::io::Write;
+
+use rayon::prelude::*;
+
+use crate::{
+    errors::BomError,
+    models::{
+        self,
+        bom::{Bom, SpecVersion},
+        component::Components,
+    },
+    specs,
+};
+
+#[cfg(feature = "json")]
+use {crate::errors::JsonWriteError, serde::Serialize};
+
+#[cfg(feature = "xml")]
+use crate::{errors::XmlWriteError, xml::ToXml};
+
+impl Bom {
+    /// Same as [`output_as_json`](Self::output_as_json), but converts and serializes the
+    /// top-level components in parallel.
+    #[cfg(feature = "json")]
+    pub fn output_as_json_parallel<W: Write>(
+        self,
+        writer: &mut W,
+        version: SpecVersion,
+    ) -> Result<(), JsonWriteError> {
+        match version {
+            SpecVersion::V1_3 => {
+                write_json::<specs::v1_3::bom::Bom, specs::v1_3::component::Component, W>(
+                    self, writer,
+                )
+            }
+            SpecVersion::V1_4 => {
+                write_json::<specs::v1_4::bom::Bom, specs::v1_4::component::Component, W>(
+                    self, writer,
+                )
+            }
+            SpecVersion::V1_5 => {
+                write_json::<specs::v1_5::bom::Bom, specs::v1_5::component::Component, W>(
+                    self, writer,
+                )
+            }
+        }
+    }
+
+    /// Same as [`output_as_xml`](Self::output_as_xml), but converts and serializes the
+    /// top-level components in parallel.
+    #[cfg(feature = "xml")]
+    pub fn output_as_xml_parallel<W: Write>(
+        self,
+        writer: &mut W,
+        version: SpecVersion,
+    ) -> Result<(), XmlWriteError> {
+        match version {
+            SpecVersion::V1_3 => {
+                write_xml::<specs::v1_3::bom::Bom, specs::v1_3::component::Component, W>(
+                    self, writer,
+                )
+            }
+            SpecVersion::V1_4 => {
+                write_xml::<specs::v1_4::bom::Bom, specs::v1_4::component::Component, W>(
+                    self, writer,
+                )
+            }
+            SpecVersion::V1_5 => {
+                write_xml::<specs::v1_5::bom::Bom, specs::v1_5::component::Component, W>(
+                    self, writer,
+                )
+            }
+        }
+    }
+}
+
+/// Splits off the top-level components, leaving an empty list in their place.
+fn split_components(mut bom: Bom) -> (Bom, Vec<models::component::Component>) {
+    let components = bom
+        .components
+        .replace(Components(Vec::new()))
+        .map(|components| components.0);
+    match components {
+        Some(components) if !components.is_empty() => (bom, components),
+        components => {
+            bom.components = components.map(Components);
+            (bom, Vec::new())
+        }
+    }
+}
+
+/// Replaces the first occurrence of `placeholder` by the serialized `parts`.
+fn stitch(
+    document: &[u8],
+    placeholder: &[u8],
+    open: &[u8],
+    separator: &[u8],
+    close: &[u8],
+    parts: Vec<Vec<u8>>,
+) -> Vec<u8> {
+    let position = document
+        .windows(placeholder.len())
+        .position(|window| window == placeholder)
+        .expect("document contains the empty component list");
+    let length = parts.iter().map(Vec::len).sum::<usize>() + document.len();
+    let mut output = Vec::with_capacity(length + parts.len() * separator.len());
+    output.extend_from_slice(&document[..position]);
+    output.extend_from_slice(open);
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            output.extend_from_slice(separator);
+        }
+        output.extend_from_slice(part);
+    }
+    output.extend_from_slice(close);
+    output.extend_from_slice(&document[position + placeholder.len()..]);
+    output
+}
+
+/// Indents all lines but the first, the line breaks of the pretty printed output.
+fn indent(part: &[u8], line_break: &[u8], indentation: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(part.len() + part.len() / 8);
+    let mut rest = part;
+    while let Some(position) = rest
+        .windows(line_break.len())
+        .position(|window| window == line_break)
+    {
+        output.extend_from_slice(&rest[..position]);
+        output.push(b'\n');
+        output.extend_from_slice(indentation);
+        rest = &rest[position + line_break.len()..];
+    }
+    output.extend_from_slice(rest);
+    output
+}
+
+#[cfg(feature = "json")]
+fn write_json<B, C, W>(bom: Bom, writer: &mut W) -> Result<(), JsonWriteError>
+where
+    B: TryFrom<Bom, Error = BomError> + Serialize,
+    C: TryFrom<models::component::Component, Error = BomError> + Serialize,
+    W: Write,
+{
+    let (bom, components) = split_components(bom);
+    let bom = B::try_from(bom)?;
+    if components.is_empty() {
+        serde_json::to_writer_pretty(writer, &bom)?;
+        return Ok(());
+    }
+
+    let parts = components
+        .into_par_iter()
+        .map(|component| {
+            let component = C::try_from(component)?;
+            let part = serde_json::to_vec_pretty(&component)?;
+            // JSON strings escape line breaks, all line breaks are between tokens.
+            Ok(indent(&part, b"\n", b"    "))
+        })
+        .collect::<Result<Vec<_>, JsonWriteError>>()?;
+
+    let document = serde_json::to_vec_pretty(&bom)?;
+    let output = stitch(
+        &document,
+        b"\n  \"components\": []",
+        b"\n  \"components\": [\n    ",
+        b",\n    ",
+        b"\n  ]",
+        parts,
+    );
+    writer.write_all(&output).map_err(serde_json::Error::io)?;
+    Ok(())
+}
+
+#[cfg(feature = "xml")]
+fn write_xml<B, C, W>(bom: Bom, writer: &mut W) -> Result<(), XmlWriteError>
+where
+    B: TryFrom<Bom, Error = BomError> + ToXml,
+    C: TryFrom<models::component::Component, Error = BomError> + ToXml + Send,
+    W: Write,
+{
+    use xml::EventWriter;
+
+    // Text content may contain line breaks, the line breaks of the indentation are marked to
+    // tell them apart. The marker is not a valid XML character, so it can't be part of the text.
+    const LINE_BREAK: &str = "\n\u{1}";
+
+    let (bom, components) = split_components(bom);
+    let bom = B::try_from(bom)?;
+    let config = crate::xml::emitter_config();
+    if components.is_empty() {
+        return crate::xml::write_xml_document(writer, &bom, config);
+    }
+
+    let parts = components
+        .into_par_iter()
+        .map(|component| {
+            let component = C::try_from(component)?;
+            let mut part = Vec::new();
+            let config = crate::xml::emitter_config()
+                .write_document_declaration(false)
+                .line_separator(LINE_BREAK);
+            component.write_xml_element(&mut EventWriter::new_with_config(&mut part, config))?;
+            Ok(indent(&part, LINE_BREAK.as_bytes(), b"    "))
+        })
+        .collect::<Result<Vec<_>, XmlWriteError>>()?;
+
+    let mut document = Vec::new();
+    bom.write_xml_element(&mut EventWriter::new_with_config(&mut document, config))?;
+    let output = stitch(
+        &document,
+        b"\n  <components />",
+        b"\n  <components>\n    ",
+        b"\n    ",
+        b"\n  </components>",
+        parts,
+    );
+    writer
+        .write_all(&output)
+        .map_err(|error| XmlWriteError::XmlElementWriteError {
+            error: error.into(),
+            element: "bom".to_string(),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        external_models::normalized_string::NormalizedString,
+        models::{
+            component::{Classification, Component},
+            metadata::Metadata,
+        },
+    };
+
+    fn bom() -> Bom {
+        let mut root = Component::new(Classification::Application, "root", "1.0.0", None);
+        root.components = Some(Components(Vec::new()));
+        let mut metadata = Metadata::new().unwrap();
+        metadata.component = Some(root);
+
+        let components = (0..50)
+            .map(|i| {
+                let mut component = Component::new(
+                    Classification::Library,
+                    &format!("component-{i}"),
+                    "1.0.0",
+                    Some(format!("component-{i}")),
+                );
+                component.description = Some(NormalizedString::new_unchecked(format!(
+                    "line\n  <components />\r\nline {i}\n"
+                )));
+                component.components = Some(Components(vec![Component::new(
+                    Classification::Library,
+                    &format!("nested-{i}"),
+                    "0.1.0",
+                    None,
+                )]));
+                component
+            })
+            .collect();
+        Bom {
+            metadata: Some(metadata),
+            components: Some(Components(components)),
+            ..Bom::default()
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_write_the_same_json_as_the_sequential_writer() {
+        for version in [SpecVersion::V1_3, SpecVersion::V1_4, SpecVersion::V1_5] {
+            for bom in [bom(), Bom::default()] {
+                let mut sequential = Vec::new();
+                bom.clone()
+                    .output_as_json(&mut sequential, version)
+                    .unwrap();
+                let mut parallel = Vec::new();
+                bom.output_as_json_parallel(&mut parallel, version).unwrap();
+
+                assert_eq!(
+                    String::from_utf8(parallel).unwrap(),
+                    String::from_utf8(sequential).unwrap()
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn it_should_write_the_same_xml_as_the_sequential_writer() {
+        for version in [SpecVersion::V1_3, SpecVersion::V1_4, SpecVersion::V1_5] {
+            for bom in [bom(), Bom::default()] {
+                let mut sequential = Vec::new();
+                bom.clone().output_as_xml(&mut sequential, version).unwrap();
+                let mut parallel = Vec::new();
+                bom.output_as_xml_parallel(&mut parallel, version).unwrap();
+
+                assert_eq!(
+                    String::from_utf8(parallel).unwrap(),
+                    String::from_utf8(sequential).unwrap()
+                );
+            }
+        }
+    }
+}
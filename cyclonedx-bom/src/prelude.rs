@@ -23,7 +23,7 @@ pub use crate::external_models::{
     uri::{Purl, Uri},
 };
 pub use crate::models::{
-    bom::{Bom, SpecVersion, UrnUuid},
+    bom::{Bom, BomBuilder, SpecVersion, UrnUuid},
     component::{Component, Components},
     metadata::Metadata,
 };
@@ -0,0 +1,359 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Storage of BOMs identified by their serial number and version.
+//!
+//! [`BomRepository`] is the extension point for BOM stores such as Dependency-Track or an object
+//! storage bucket. [`InMemoryRepository`] and, with the `json` feature, [`FileSystemRepository`]
+//! are provided.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::{
+    bom::{Bom, UrnUuid},
+    external_reference::BomLink,
+};
+
+#[cfg(feature = "json")]
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "json")]
+use crate::models::bom::SpecVersion;
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RepositoryError {
+    #[error("BOM has no serial number")]
+    MissingSerialNumber,
+
+    #[error("Invalid serial number '{0}'")]
+    InvalidSerialNumber(String),
+
+    #[error("Version {version} of BOM {serial_number} was already published")]
+    AlreadyPublished {
+        serial_number: UrnUuid,
+        version: u32,
+    },
+
+    #[error("Failed to access the repository: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[cfg(feature = "json")]
+    #[error("Failed to read stored BOM: {0}")]
+    Read(#[from] crate::errors::JsonReadError),
+
+    #[cfg(feature = "json")]
+    #[error("Failed to write BOM: {0}")]
+    Write(#[from] crate::errors::JsonWriteError),
+
+    /// An error of a repository implemented outside of this crate.
+    #[error(transparent)]
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// A store of BOMs, each identified by its serial number and version.
+///
+/// Published versions are immutable, publishing a BOM with a serial number and version that
+/// is already stored fails with [`RepositoryError::AlreadyPublished`].
+pub trait BomRepository {
+    /// Returns the given version of a BOM, or `None` if it was not published.
+    fn fetch(&self, serial_number: &UrnUuid, version: u32) -> Result<Option<Bom>, RepositoryError>;
+
+    /// Stores a BOM under its serial number and version.
+    fn publish(&mut self, bom: &Bom) -> Result<(), RepositoryError>;
+
+    /// Returns the published versions of a BOM in ascending order.
+    fn versions(&self, serial_number: &UrnUuid) -> Result<Vec<u32>, RepositoryError>;
+
+    /// Returns the highest published version of a BOM.
+    fn latest(&self, serial_number: &UrnUuid) -> Result<Option<Bom>, RepositoryError> {
+        match self.versions(serial_number)?.last() {
+            Some(version) => self.fetch(serial_number, *version),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the BOM a BOM-Link refers to, ignoring any reference to an element inside it.
+    fn fetch_link(&self, bom_link: &BomLink) -> Result<Option<Bom>, RepositoryError> {
+        match (bom_link.serial_number(), bom_link.version()) {
+            (Some(serial_number), Some(version)) => self.fetch(&serial_number, version),
+            _ => Err(RepositoryError::InvalidSerialNumber(bom_link.to_string())),
+        }
+    }
+}
+
+/// Returns the validated serial number of a BOM to publish.
+fn serial_number(bom: &Bom) -> Result<&UrnUuid, RepositoryError> {
+    let serial_number = bom
+        .serial_number
+        .as_ref()
+        .ok_or(RepositoryError::MissingSerialNumber)?;
+    UrnUuid::new(serial_number.0.clone())
+        .map_err(|_| RepositoryError::InvalidSerialNumber(serial_number.to_string()))?;
+    Ok(serial_number)
+}
+
+/// A [`BomRepository`] that keeps the BOMs in memory, e.g. for tests.
+/// ```
+/// use cyclonedx_bom::models::bom::{Bom, UrnUuid};
+/// use cyclonedx_bom::repository::{BomRepository, InMemoryRepository};
+///
+/// let serial_number = UrnUuid::generate();
+/// let mut repository = InMemoryRepository::new();
+/// for version in [1, 2] {
+///     let bom = Bom::builder()
+///         .serial_number(serial_number.clone())
+///         .version(version)
+///         .build()
+///         .expect("valid BOM");
+///     repository.publish(&bom).expect("published");
+/// }
+///
+/// assert_eq!(repository.versions(&serial_number).unwrap(), vec![1, 2]);
+/// assert_eq!(repository.latest(&serial_number).unwrap().unwrap().version, 2);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryRepository {
+    boms: HashMap<String, BTreeMap<u32, Bom>>,
+}
+
+impl InMemoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl BomRepository for InMemoryRepository {
+    fn fetch(&self, serial_number: &UrnUuid, version: u32) -> Result<Option<Bom>, RepositoryError> {
+        Ok(self
+            .boms
+            .get(&serial_number.0)
+            .and_then(|versions| versions.get(&version))
+            .cloned())
+    }
+
+    fn publish(&mut self, bom: &Bom) -> Result<(), RepositoryError> {
+        let serial_number = serial_number(bom)?;
+        let versions = self.boms.entry(serial_number.0.clone()).or_default();
+        if versions.contains_key(&bom.version) {
+            return Err(RepositoryError::AlreadyPublished {
+                serial_number: serial_number.clone(),
+                version: bom.version,
+            });
+        }
+        versions.insert(bom.version, bom.clone());
+        Ok(())
+    }
+
+    fn versions(&self, serial_number: &UrnUuid) -> Result<Vec<u32>, RepositoryError> {
+        Ok(self
+            .boms
+            .get(&serial_number.0)
+            .map(|versions| versions.keys().copied().collect())
+            .unwrap_or_default())
+    }
+}
+
+/// A [`BomRepository`] that stores each BOM as a JSON document at
+/// `<root>/<uuid>/<version>.json`.
+#[cfg(feature = "json")]
+#[derive(Clone, Debug)]
+pub struct FileSystemRepository {
+    root: PathBuf,
+    spec_version: SpecVersion,
+}
+
+#[cfg(feature = "json")]
+impl FileSystemRepository {
+    /// Uses the directory `root`, which is created on the first publish.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            spec_version: SpecVersion::V1_5,
+        }
+    }
+
+    /// Sets the specification version of the stored documents, 1.5 by default.
+    pub fn with_spec_version(mut self, spec_version: SpecVersion) -> Self {
+        self.spec_version = spec_version;
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn directory(&self, serial_number: &UrnUuid) -> Result<PathBuf, RepositoryError> {
+        let uuid = serial_number
+            .0
+            .strip_prefix("urn:uuid:")
+            .filter(|_| UrnUuid::new(serial_number.0.clone()).is_ok())
+            .ok_or_else(|| RepositoryError::InvalidSerialNumber(serial_number.to_string()))?;
+        Ok(self.root.join(uuid))
+    }
+}
+
+#[cfg(feature = "json")]
+impl BomRepository for FileSystemRepository {
+    fn fetch(&self, serial_number: &UrnUuid, version: u32) -> Result<Option<Bom>, RepositoryError> {
+        let path = self
+            .directory(serial_number)?
+            .join(format!("{version}.json"));
+        match fs::File::open(path) {
+            Ok(file) => Ok(Some(Bom::parse_from_json(io::BufReader::new(file))?)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn publish(&mut self, bom: &Bom) -> Result<(), RepositoryError> {
+        let serial_number = serial_number(bom)?;
+        let directory = self.directory(serial_number)?;
+
+        let mut document = Vec::new();
+        bom.clone()
+            .output_as_json(&mut document, self.spec_version)?;
+
+        fs::create_dir_all(&directory)?;
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(directory.join(format!("{}.json", bom.version)));
+        match file {
+            Ok(mut file) => Ok(io::Write::write_all(&mut file, &document)?),
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {
+                Err(RepositoryError::AlreadyPublished {
+                    serial_number: serial_number.clone(),
+                    version: bom.version,
+                })
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn versions(&self, serial_number: &UrnUuid) -> Result<Vec<u32>, RepositoryError> {
+        let entries = match fs::read_dir(self.directory(serial_number)?) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error.into()),
+        };
+        let mut versions = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                if let Some(version) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.parse().ok())
+                {
+                    versions.push(version);
+                }
+            }
+        }
+        versions.sort_unstable();
+        Ok(versions)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn bom(serial_number: &UrnUuid, version: u32) -> Bom {
+        Bom {
+            serial_number: Some(serial_number.clone()),
+            version,
+            ..Bom::default()
+        }
+    }
+
+    fn exercise(repository: &mut impl BomRepository) {
+        let serial_number = UrnUuid::generate();
+        let other = UrnUuid::generate();
+
+        assert_eq!(
+            repository.versions(&serial_number).unwrap(),
+            Vec::<u32>::new()
+        );
+        assert!(repository.latest(&serial_number).unwrap().is_none());
+
+        repository.publish(&bom(&serial_number, 2)).unwrap();
+        repository.publish(&bom(&serial_number, 1)).unwrap();
+        repository.publish(&bom(&other, 7)).unwrap();
+
+        assert_eq!(repository.versions(&serial_number).unwrap(), vec![1, 2]);
+        assert_eq!(
+            repository.fetch(&serial_number, 1).unwrap(),
+            Some(bom(&serial_number, 1))
+        );
+        assert!(repository.fetch(&serial_number, 3).unwrap().is_none());
+        assert_eq!(
+            repository.latest(&serial_number).unwrap().unwrap().version,
+            2
+        );
+        assert_eq!(
+            repository
+                .fetch_link(&BomLink::new(&other, 7))
+                .unwrap()
+                .unwrap()
+                .version,
+            7
+        );
+
+        assert!(matches!(
+            repository.publish(&bom(&serial_number, 2)),
+            Err(RepositoryError::AlreadyPublished { version: 2, .. })
+        ));
+        assert!(matches!(
+            repository.publish(&Bom {
+                serial_number: None,
+                ..Bom::default()
+            }),
+            Err(RepositoryError::MissingSerialNumber)
+        ));
+        assert!(matches!(
+            repository.publish(&bom(&UrnUuid("urn:uuid:../../etc".to_string()), 1)),
+            Err(RepositoryError::InvalidSerialNumber(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_store_boms_in_memory() {
+        exercise(&mut InMemoryRepository::new());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_should_store_boms_in_the_file_system() {
+        let root =
+            std::env::temp_dir().join(format!("cyclonedx-bom-repository-{}", std::process::id()));
+        let mut repository = FileSystemRepository::new(&root);
+
+        exercise(&mut repository);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -16,18 +16,22 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{normalized_string::NormalizedString, uri::Uri},
-    models,
-    utilities::convert_vec,
     xml::{
         read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
         write_start_tag, FromXml, ToXml,
     },
 };
+use crate::{
+    external_models::{normalized_string::NormalizedString, uri::Uri},
+    models,
+    utilities::convert_vec,
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -46,8 +50,10 @@ impl From<Advisories> for models::advisory::Advisories {
     }
 }
 
+#[cfg(feature = "xml")]
 const ADVISORIES_TAG: &str = "advisories";
 
+#[cfg(feature = "xml")]
 impl ToXml for Advisories {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -65,6 +71,7 @@ impl ToXml for Advisories {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Advisories {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -104,10 +111,14 @@ impl From<Advisory> for models::advisory::Advisory {
     }
 }
 
+#[cfg(feature = "xml")]
 const ADVISORY_TAG: &str = "advisory";
+#[cfg(feature = "xml")]
 const TITLE_TAG: &str = "title";
+#[cfg(feature = "xml")]
 const URL_TAG: &str = "url";
 
+#[cfg(feature = "xml")]
 impl ToXml for Advisory {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -126,13 +137,14 @@ impl ToXml for Advisory {
         write_simple_tag(writer, URL_TAG, &self.url)?;
 
         writer
-            .write(XmlEvent::end_element())
+            .write(XmlEvent::end_element().name(ADVISORY_TAG))
             .map_err(to_xml_write_error(ADVISORY_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Advisory {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -182,6 +194,7 @@ impl FromXml for Advisory {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_advisories() -> Advisories {
@@ -206,12 +219,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_advisories());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
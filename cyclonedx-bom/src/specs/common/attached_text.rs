@@ -16,13 +16,17 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::external_models::normalized_string::NormalizedString;
+use crate::models;
+#[cfg(feature = "xml")]
+use crate::xml::to_xml_write_error;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlWriteError,
-    external_models::normalized_string::NormalizedString,
-    xml::{closing_tag_or_error, inner_text_or_error, to_xml_read_error, FromXml, ToInnerXml},
+    xml::{read_simple_tag, FromXml, ToInnerXml},
 };
-use crate::{models, xml::to_xml_write_error};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer::{EventWriter, XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -38,7 +42,7 @@ pub(crate) struct AttachedText {
 impl From<models::attached_text::AttachedText> for AttachedText {
     fn from(other: models::attached_text::AttachedText) -> Self {
         Self {
-            content_type: other.content_type.map(|n| n.0),
+            content_type: other.content_type.map(String::from),
             encoding: other.encoding.map(|e| e.to_string()),
             content: other.content,
         }
@@ -57,9 +61,12 @@ impl From<AttachedText> for models::attached_text::AttachedText {
     }
 }
 
+#[cfg(feature = "xml")]
 const CONTENT_TYPE_ATTR: &str = "content-type";
+#[cfg(feature = "xml")]
 const ENCODING_ATTR: &str = "encoding";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for AttachedText {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -83,13 +90,14 @@ impl ToInnerXml for AttachedText {
             .write(XmlEvent::characters(&self.content))
             .map_err(to_xml_write_error(tag))?;
         writer
-            .write(XmlEvent::end_element())
+            .write(XmlEvent::end_element().name(tag))
             .map_err(to_xml_write_error(tag))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for AttachedText {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -110,15 +118,7 @@ impl FromXml for AttachedText {
             }
         }
 
-        let content = event_reader
-            .next()
-            .map_err(to_xml_read_error(&element_name.local_name))
-            .and_then(inner_text_or_error(&element_name.local_name))?;
-
-        event_reader
-            .next()
-            .map_err(to_xml_read_error(&element_name.local_name))
-            .and_then(closing_tag_or_error(element_name))?;
+        let content = read_simple_tag(event_reader, element_name)?;
 
         Ok(Self {
             content_type,
@@ -131,6 +131,7 @@ impl FromXml for AttachedText {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_named_element_to_string};
 
     pub(crate) fn example_attached_text() -> AttachedText {
@@ -151,12 +152,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_named_element_to_string(example_attached_text(), "text");
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_no_attributes() {
         let xml_output = write_named_element_to_string(
@@ -170,6 +173,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -180,6 +184,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_no_attributes() {
         let input = r#"
@@ -33,15 +33,19 @@ pub(crate) mod base {
             vulnerability::Vulnerabilities,
         },
     };
+    #[versioned("1.5")]
+    #[cfg(feature = "xml")]
+    use crate::xml::write_list_tag;
+    #[cfg(feature = "xml")]
+    use crate::xml::{
+        expected_namespace_or_error, optional_attribute, read_lax_validation_tag,
+        to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, FromXmlDocument,
+        FromXmlType,
+    };
     use crate::{
         errors::BomError,
         models::{self, bom::SpecVersion},
         utilities::{convert_optional, try_convert_optional},
-        xml::{
-            expected_namespace_or_error, optional_attribute, read_lax_validation_tag,
-            to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml,
-            FromXmlDocument, FromXmlType,
-        },
     };
     #[versioned("1.5")]
     use crate::{
@@ -55,11 +59,13 @@ pub(crate) mod base {
             },
         },
         utilities::convert_optional_vec,
-        xml::write_list_tag,
     };
 
-    use crate::{specs::common::dependency::Dependencies, xml::ToXml};
+    use crate::specs::common::dependency::Dependencies;
+    #[cfg(feature = "xml")]
+    use crate::xml::ToXml;
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::{reader, writer::XmlEvent};
 
     #[versioned("1.3")]
@@ -70,10 +76,13 @@ pub(crate) mod base {
     const SPEC_VERSION: SpecVersion = SpecVersion::V1_5;
 
     #[versioned("1.3")]
+    #[cfg(feature = "xml")]
     const NS: &str = "http://cyclonedx.org/schema/bom/1.3";
     #[versioned("1.4")]
+    #[cfg(feature = "xml")]
     const NS: &str = "http://cyclonedx.org/schema/bom/1.4";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const NS: &str = "http://cyclonedx.org/schema/bom/1.5";
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -184,10 +193,14 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const BOM_TAG: &str = "bom";
+    #[cfg(feature = "xml")]
     const SERIAL_NUMBER_ATTR: &str = "serialNumber";
+    #[cfg(feature = "xml")]
     const VERSION_ATTR: &str = "version";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Bom {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -246,32 +259,45 @@ pub(crate) mod base {
             }
 
             writer
-                .write(XmlEvent::end_element())
+                .write(XmlEvent::end_element().name(BOM_TAG))
                 .map_err(to_xml_write_error(BOM_TAG))?;
 
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     const METADATA_TAG: &str = "metadata";
+    #[cfg(feature = "xml")]
     const COMPONENTS_TAG: &str = "components";
+    #[cfg(feature = "xml")]
     const SERVICES_TAG: &str = "services";
+    #[cfg(feature = "xml")]
     const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
+    #[cfg(feature = "xml")]
     const DEPENDENCIES_TAG: &str = "dependencies";
+    #[cfg(feature = "xml")]
     const COMPOSITIONS_TAG: &str = "compositions";
     #[versioned("1.4", "1.5")]
+    #[cfg(feature = "xml")]
     const VULNERABILITIES_TAG: &str = "vulnerabilities";
     #[versioned("1.4", "1.5")]
+    #[cfg(feature = "xml")]
     const SIGNATURE_TAG: &str = "signature";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const ANNOTATIONS_TAG: &str = "annotations";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const PROPERTIES_TAG: &str = "properties";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const FORMULATION_TAG: &str = "formulation";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const FORMULA_TAG: &str = "formula";
 
+    #[cfg(feature = "xml")]
     impl FromXmlDocument for Bom {
         fn read_xml_document<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -279,41 +305,7 @@ pub(crate) mod base {
         where
             Self: Sized,
         {
-            event_reader
-                .next()
-                .map_err(to_xml_read_error(BOM_TAG))
-                .and_then(|event| match event {
-                    reader::XmlEvent::StartDocument { .. } => Ok(()),
-                    unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
-                })?;
-
-            let (version, serial_number) = event_reader
-                .next()
-                .map_err(to_xml_read_error(BOM_TAG))
-                .and_then(|event| match event {
-                    reader::XmlEvent::StartElement {
-                        name,
-                        attributes,
-                        namespace,
-                    } if name.local_name == BOM_TAG => {
-                        #[versioned("1.3")]
-                        expected_namespace_or_error("1.3", &namespace)?;
-                        #[versioned("1.4")]
-                        expected_namespace_or_error("1.4", &namespace)?;
-                        #[versioned("1.5")]
-                        expected_namespace_or_error("1.5", &namespace)?;
-                        let version =
-                            if let Some(version) = optional_attribute(&attributes, VERSION_ATTR) {
-                                u32::from_xml_value(VERSION_ATTR, version)?
-                            } else {
-                                1
-                            };
-                        let serial_number =
-                            optional_attribute(&attributes, SERIAL_NUMBER_ATTR).map(UrnUuid);
-                        Ok((version, serial_number))
-                    }
-                    unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
-                })?;
+            let (version, serial_number) = read_xml_bom_start(event_reader)?;
 
             let mut metadata: Option<Metadata> = None;
             let mut components: Option<Components> = None;
@@ -482,6 +474,86 @@ pub(crate) mod base {
         }
     }
 
+    /// Reads the start of the document up to the `bom` start tag, returning its `version` and
+    /// `serialNumber` attributes.
+    #[cfg(feature = "xml")]
+    fn read_xml_bom_start<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+    ) -> Result<(u32, Option<UrnUuid>), crate::errors::XmlReadError> {
+        event_reader
+            .next()
+            .map_err(to_xml_read_error(BOM_TAG))
+            .and_then(|event| match event {
+                reader::XmlEvent::StartDocument { .. } => Ok(()),
+                unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
+            })?;
+
+        event_reader
+            .next()
+            .map_err(to_xml_read_error(BOM_TAG))
+            .and_then(|event| match event {
+                reader::XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } if name.local_name == BOM_TAG => {
+                    #[versioned("1.3")]
+                    expected_namespace_or_error("1.3", &namespace)?;
+                    #[versioned("1.4")]
+                    expected_namespace_or_error("1.4", &namespace)?;
+                    #[versioned("1.5")]
+                    expected_namespace_or_error("1.5", &namespace)?;
+                    let version =
+                        if let Some(version) = optional_attribute(&attributes, VERSION_ATTR) {
+                            u32::from_xml_value(VERSION_ATTR, version)?
+                        } else {
+                            1
+                        };
+                    let serial_number =
+                        optional_attribute(&attributes, SERIAL_NUMBER_ATTR).map(UrnUuid);
+                    Ok((version, serial_number))
+                }
+                unexpected => Err(unexpected_element_error(BOM_TAG, unexpected)),
+            })
+    }
+
+    /// Reads the `version`, `serialNumber` and `metadata` of a BOM document and stops at the
+    /// first element after the metadata, the schema requires the metadata to come first.
+    #[cfg(feature = "xml")]
+    pub(crate) fn read_xml_metadata_only<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+    ) -> Result<models::bom::Bom, crate::errors::XmlReadError> {
+        let (version, serial_number) = read_xml_bom_start(event_reader)?;
+
+        let metadata = loop {
+            match event_reader.next().map_err(to_xml_read_error(BOM_TAG))? {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == METADATA_TAG => {
+                    break Some(Metadata::read_xml_element(
+                        event_reader,
+                        &name,
+                        &attributes,
+                    )?);
+                }
+                reader::XmlEvent::StartElement { .. } | reader::XmlEvent::EndElement { .. } => {
+                    break None
+                }
+                unexpected @ reader::XmlEvent::EndDocument => {
+                    return Err(unexpected_element_error(BOM_TAG, unexpected))
+                }
+                _ => {}
+            }
+        };
+
+        Ok(models::bom::Bom {
+            version,
+            serial_number: convert_optional(serial_number),
+            metadata: convert_optional(metadata),
+            ..models::bom::Bom::default()
+        })
+    }
+
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
     enum BomFormat {
         CycloneDX,
@@ -504,6 +576,9 @@ pub(crate) mod base {
 
     #[cfg(test)]
     pub(crate) mod test {
+        use crate::specs::common::dependency::test::{
+            corresponding_dependencies, example_dependencies,
+        };
         #[versioned("1.3")]
         use crate::specs::v1_3::{
             component::test::{corresponding_components, example_components},
@@ -545,10 +620,8 @@ pub(crate) mod base {
                 vulnerability::test::{corresponding_vulnerabilities, example_vulnerabilities},
             },
         };
-        use crate::{
-            specs::common::dependency::test::{corresponding_dependencies, example_dependencies},
-            xml::test::{read_document_from_string, write_element_to_string},
-        };
+        #[cfg(feature = "xml")]
+        use crate::xml::test::{read_document_from_string, write_element_to_string};
 
         use super::*;
         use pretty_assertions::assert_eq;
@@ -641,6 +714,7 @@ pub(crate) mod base {
             insta::assert_json_snapshot!(minimal_bom_example());
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_serialize_to_xml() {
             let xml_output = write_element_to_string(minimal_bom_example());
@@ -654,6 +728,7 @@ pub(crate) mod base {
             insta::assert_json_snapshot!(actual);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_serialize_a_complex_example_to_xml() {
             let xml_output = write_element_to_string(full_bom_example());
@@ -674,6 +749,7 @@ pub(crate) mod base {
             assert_eq!(spec, full_bom_example());
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_deserialize_from_xml() {
             let input = format!(
@@ -687,6 +763,7 @@ pub(crate) mod base {
             assert_eq!(actual, expected);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_deserialize_a_complex_example_from_xml() {
             #[versioned("1.3")]
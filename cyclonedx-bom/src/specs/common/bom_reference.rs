@@ -17,11 +17,13 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer::XmlEvent;
 
+use crate::models;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
     xml::{
         attribute_or_error, closing_tag_or_error, to_xml_read_error, to_xml_write_error,
         write_close_tag, FromXml, ToInnerXml,
@@ -59,8 +61,10 @@ impl From<BomReference> for models::bom::BomReference {
     }
 }
 
+#[cfg(feature = "xml")]
 const REF_ATTR: &str = "ref";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for BomReference {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -77,6 +81,7 @@ impl ToInnerXml for BomReference {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for BomReference {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -16,19 +16,23 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
-    models,
-    specs::common::attached_text::AttachedText,
-    utilities::{convert_optional, convert_optional_vec, convert_vec},
     xml::{
         attribute_or_error, read_lax_validation_list_tag, read_lax_validation_tag, read_list_tag,
         read_simple_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
         write_simple_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{
+    external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
+    models,
+    specs::common::attached_text::AttachedText,
+    utilities::{convert_optional, convert_optional_vec, convert_vec},
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -47,8 +51,10 @@ impl From<Commits> for models::code::Commits {
     }
 }
 
+#[cfg(feature = "xml")]
 const COMMITS_TAG: &str = "commits";
 
+#[cfg(feature = "xml")]
 impl ToXml for Commits {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -63,12 +69,13 @@ impl ToXml for Commits {
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(COMMITS_TAG))
             .map_err(to_xml_write_error(COMMITS_TAG))?;
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Commits {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -121,13 +128,20 @@ impl From<Commit> for models::code::Commit {
     }
 }
 
+#[cfg(feature = "xml")]
 const COMMIT_TAG: &str = "commit";
+#[cfg(feature = "xml")]
 const UID_TAG: &str = "uid";
+#[cfg(feature = "xml")]
 const URL_TAG: &str = "url";
+#[cfg(feature = "xml")]
 const AUTHOR_TAG: &str = "author";
+#[cfg(feature = "xml")]
 const COMMITTER_TAG: &str = "committer";
+#[cfg(feature = "xml")]
 const MESSAGE_TAG: &str = "message";
 
+#[cfg(feature = "xml")]
 impl ToXml for Commit {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -158,13 +172,14 @@ impl ToXml for Commit {
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(COMMIT_TAG))
             .map_err(to_xml_write_error(COMMIT_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Commit {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -262,10 +277,14 @@ impl From<IdentifiableAction> for models::code::IdentifiableAction {
     }
 }
 
+#[cfg(feature = "xml")]
 const TIMESTAMP_TAG: &str = "timestamp";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const EMAIL_TAG: &str = "email";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for IdentifiableAction {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -289,13 +308,14 @@ impl ToInnerXml for IdentifiableAction {
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(tag))
             .map_err(to_xml_write_error(tag))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for IdentifiableAction {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -359,8 +379,10 @@ impl From<Patches> for models::code::Patches {
     }
 }
 
+#[cfg(feature = "xml")]
 const PATCHES_TAG: &str = "patches";
 
+#[cfg(feature = "xml")]
 impl ToXml for Patches {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -375,12 +397,13 @@ impl ToXml for Patches {
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(PATCHES_TAG))
             .map_err(to_xml_write_error(PATCHES_TAG))?;
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Patches {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -424,10 +447,14 @@ impl From<Patch> for models::code::Patch {
     }
 }
 
+#[cfg(feature = "xml")]
 const PATCH_TAG: &str = "patch";
+#[cfg(feature = "xml")]
 const TYPE_ATTR: &str = "type";
+#[cfg(feature = "xml")]
 const RESOLVES_TAG: &str = "resolves";
 
+#[cfg(feature = "xml")]
 impl ToXml for Patch {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -453,18 +480,19 @@ impl ToXml for Patch {
             }
 
             writer
-                .write(writer::XmlEvent::end_element())
+                .write(writer::XmlEvent::end_element().name(RESOLVES_TAG))
                 .map_err(to_xml_write_error(RESOLVES_TAG))?;
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(PATCH_TAG))
             .map_err(to_xml_write_error(PATCH_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Patch {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -536,9 +564,12 @@ impl From<Diff> for models::code::Diff {
     }
 }
 
+#[cfg(feature = "xml")]
 const DIFF_TAG: &str = "diff";
+#[cfg(feature = "xml")]
 const TEXT_TAG: &str = "text";
 
+#[cfg(feature = "xml")]
 impl ToXml for Diff {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -557,7 +588,7 @@ impl ToXml for Diff {
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(DIFF_TAG))
             .map_err(to_xml_write_error(DIFF_TAG))?;
 
         Ok(())
@@ -568,6 +599,7 @@ impl ToXml for Diff {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Diff {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -658,11 +690,16 @@ impl From<Issue> for models::code::Issue {
     }
 }
 
+#[cfg(feature = "xml")]
 const ISSUE_TAG: &str = "issue";
+#[cfg(feature = "xml")]
 const ID_TAG: &str = "id";
+#[cfg(feature = "xml")]
 const DESCRIPTION_TAG: &str = "description";
+#[cfg(feature = "xml")]
 const REFERENCES_TAG: &str = "references";
 
+#[cfg(feature = "xml")]
 impl ToXml for Issue {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -700,18 +737,19 @@ impl ToXml for Issue {
             }
 
             writer
-                .write(writer::XmlEvent::end_element())
+                .write(writer::XmlEvent::end_element().name(REFERENCES_TAG))
                 .map_err(to_xml_write_error(REFERENCES_TAG))?;
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(ISSUE_TAG))
             .map_err(to_xml_write_error(ISSUE_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Issue {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -802,8 +840,10 @@ impl From<Source> for models::code::Source {
     }
 }
 
+#[cfg(feature = "xml")]
 const SOURCE_TAG: &str = "source";
 
+#[cfg(feature = "xml")]
 impl ToXml for Source {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -822,7 +862,7 @@ impl ToXml for Source {
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(SOURCE_TAG))
             .map_err(to_xml_write_error(SOURCE_TAG))?;
 
         Ok(())
@@ -833,6 +873,7 @@ impl ToXml for Source {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Source {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -871,10 +912,11 @@ impl FromXml for Source {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        specs::common::attached_text::test::{corresponding_attached_text, example_attached_text},
-        xml::test::{read_element_from_string, write_element_to_string},
+    use crate::specs::common::attached_text::test::{
+        corresponding_attached_text, example_attached_text,
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -1000,18 +1042,21 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_commits_xml_full() {
         let xml_output = write_element_to_string(example_commits());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_patches_xml_full() {
         let xml_output = write_element_to_string(example_patches());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_commits_xml_full() {
         let input = r#"
@@ -1039,6 +1084,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_patches_xml_full() {
         let input = r#"
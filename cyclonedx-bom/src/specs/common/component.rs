@@ -39,12 +39,12 @@ pub(crate) mod base {
     };
 
     use crate::{
-        errors::{BomError, XmlReadError},
+        errors::BomError,
         external_models::{
             normalized_string::NormalizedString,
             uri::{Purl, Uri},
         },
-        models::{self},
+        models,
         specs::common::{
             attached_text::AttachedText,
             code::{Commits, Patches},
@@ -52,15 +52,24 @@ pub(crate) mod base {
             organization::OrganizationalEntity,
             property::Properties,
         },
-        utilities::{convert_optional, convert_vec, try_convert_optional, try_convert_vec},
+        utilities::{
+            convert_optional, convert_optional_boxed, convert_optional_into_boxed, convert_vec,
+            try_convert_optional, try_convert_optional_boxed, try_convert_vec,
+        },
+    };
+    #[cfg(feature = "xml")]
+    use crate::{
+        errors::XmlReadError,
         xml::{
-            attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_list_tag,
-            read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-            to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
-            write_start_tag, FromXml, FromXmlType, ToInnerXml, ToXml,
+            attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_tag,
+            read_list_tag, read_nested_list_tag, read_simple_tag, to_xml_read_error,
+            to_xml_write_error, unexpected_element_error, write_close_tag,
+            write_simple_display_tag, write_simple_tag, write_start_tag, FromXml, FromXmlType,
+            ToInnerXml, ToXml,
         },
     };
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::{reader, writer::XmlEvent};
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -81,6 +90,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl ToInnerXml for Components {
         fn write_xml_named_element<W: std::io::Write>(
             &self,
@@ -99,8 +109,10 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const COMPONENTS_TAG: &str = "components";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Components {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -110,6 +122,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Components {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -119,7 +132,7 @@ pub(crate) mod base {
         where
             Self: Sized,
         {
-            read_lax_validation_list_tag(event_reader, element_name, COMPONENT_TAG).map(Components)
+            read_nested_list_tag(event_reader, element_name, COMPONENT_TAG).map(Components)
         }
     }
 
@@ -212,22 +225,22 @@ pub(crate) mod base {
                 copyright: other.copyright.map(|c| c.to_string()),
                 cpe: convert_optional(other.cpe),
                 purl: other.purl.map(|p| p.0),
-                swid: convert_optional(other.swid),
+                swid: convert_optional_boxed(other.swid),
                 modified: other.modified,
-                pedigree: try_convert_optional(other.pedigree)?,
+                pedigree: try_convert_optional_boxed(other.pedigree)?,
                 #[versioned("1.3", "1.4")]
                 external_references: try_convert_optional(other.external_references)?,
                 #[versioned("1.5")]
                 external_references: convert_optional(other.external_references),
                 properties: convert_optional(other.properties),
                 components: try_convert_optional(other.components)?,
-                evidence: convert_optional(other.evidence),
+                evidence: convert_optional_boxed(other.evidence),
                 #[versioned("1.4", "1.5")]
-                signature: convert_optional(other.signature),
+                signature: convert_optional_boxed(other.signature),
                 #[versioned("1.5")]
-                model_card: convert_optional(other.model_card),
+                model_card: convert_optional_boxed(other.model_card),
                 #[versioned("1.5")]
-                data: convert_optional(other.data),
+                data: convert_optional_boxed(other.data),
             })
         }
     }
@@ -256,49 +269,67 @@ pub(crate) mod base {
                 copyright: other.copyright.map(NormalizedString::new_unchecked),
                 cpe: convert_optional(other.cpe),
                 purl: other.purl.map(Purl),
-                swid: convert_optional(other.swid),
+                swid: convert_optional_into_boxed(other.swid),
                 modified: other.modified,
-                pedigree: convert_optional(other.pedigree),
+                pedigree: convert_optional_into_boxed(other.pedigree),
                 external_references: convert_optional(other.external_references),
                 properties: convert_optional(other.properties),
                 components: convert_optional(other.components),
-                evidence: convert_optional(other.evidence),
+                evidence: convert_optional_into_boxed(other.evidence),
                 #[versioned("1.3")]
                 signature: None,
                 #[versioned("1.4", "1.5")]
-                signature: convert_optional(other.signature),
+                signature: convert_optional_into_boxed(other.signature),
                 #[versioned("1.3", "1.4")]
                 model_card: None,
                 #[versioned("1.5")]
-                model_card: convert_optional(other.model_card),
+                model_card: convert_optional_into_boxed(other.model_card),
                 #[versioned("1.3", "1.4")]
                 data: None,
                 #[versioned("1.5")]
-                data: convert_optional(other.data),
+                data: convert_optional_into_boxed(other.data),
             }
         }
     }
 
+    #[cfg(feature = "xml")]
     const COMPONENT_TAG: &str = "component";
+    #[cfg(feature = "xml")]
     const TYPE_ATTR: &str = "type";
+    #[cfg(feature = "xml")]
     const MIME_TYPE_ATTR: &str = "mime-type";
+    #[cfg(feature = "xml")]
     const BOM_REF_ATTR: &str = "bom-ref";
+    #[cfg(feature = "xml")]
     const SUPPLIER_TAG: &str = "supplier";
+    #[cfg(feature = "xml")]
     const AUTHOR_TAG: &str = "author";
+    #[cfg(feature = "xml")]
     const PUBLISHER_TAG: &str = "publisher";
+    #[cfg(feature = "xml")]
     const GROUP_TAG: &str = "group";
+    #[cfg(feature = "xml")]
     const NAME_TAG: &str = "name";
+    #[cfg(feature = "xml")]
     const VERSION_TAG: &str = "version";
+    #[cfg(feature = "xml")]
     const DESCRIPTION_TAG: &str = "description";
+    #[cfg(feature = "xml")]
     const SCOPE_TAG: &str = "scope";
+    #[cfg(feature = "xml")]
     const COPYRIGHT_TAG: &str = "copyright";
+    #[cfg(feature = "xml")]
     const PURL_TAG: &str = "purl";
+    #[cfg(feature = "xml")]
     const MODIFIED_TAG: &str = "modified";
     #[versioned("1.4", "1.5")]
+    #[cfg(feature = "xml")]
     const SIGNATURE_TAG: &str = "signature";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const COMPONENT_DATA_TAG: &str = "data";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Component {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -379,7 +410,7 @@ pub(crate) mod base {
             }
 
             if let Some(modified) = &self.modified {
-                write_simple_tag(writer, MODIFIED_TAG, &format!("{}", modified))?;
+                write_simple_display_tag(writer, MODIFIED_TAG, modified)?;
             }
 
             if let Some(pedigree) = &self.pedigree {
@@ -420,20 +451,26 @@ pub(crate) mod base {
             }
 
             writer
-                .write(XmlEvent::end_element())
+                .write(XmlEvent::end_element().name(COMPONENT_TAG))
                 .map_err(to_xml_write_error(COMPONENT_TAG))?;
 
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     const HASHES_TAG: &str = "hashes";
+    #[cfg(feature = "xml")]
     const LICENSES_TAG: &str = "licenses";
+    #[cfg(feature = "xml")]
     const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
+    #[cfg(feature = "xml")]
     const PROPERTIES_TAG: &str = "properties";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const MODEL_CARD_TAG: &str = "modelCard";
 
+    #[cfg(feature = "xml")]
     impl FromXml for Component {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -736,22 +773,31 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const SWID_TAG: &str = "swid";
+    #[cfg(feature = "xml")]
     const TAG_ID_ATTR: &str = "tagId";
+    #[cfg(feature = "xml")]
     const NAME_ATTR: &str = "name";
+    #[cfg(feature = "xml")]
     const VERSION_ATTR: &str = "version";
+    #[cfg(feature = "xml")]
     const TAG_VERSION_ATTR: &str = "tagVersion";
+    #[cfg(feature = "xml")]
     const PATCH_ATTR: &str = "patch";
+    #[cfg(feature = "xml")]
     const TEXT_TAG: &str = "text";
+    #[cfg(feature = "xml")]
     const URL_TAG: &str = "url";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Swid {
         fn write_xml_element<W: std::io::Write>(
             &self,
             writer: &mut xml::EventWriter<W>,
         ) -> Result<(), crate::errors::XmlWriteError> {
             let tag_version = self.tag_version.map(|tv| format!("{}", tv));
-            let patch = self.patch.map(|p| format!("{}", p));
+            let patch = self.patch.map(|p| if p { "true" } else { "false" });
 
             let mut swid_start_tag = XmlEvent::start_element(SWID_TAG)
                 .attr(TAG_ID_ATTR, &self.tag_id)
@@ -782,13 +828,14 @@ pub(crate) mod base {
             }
 
             writer
-                .write(XmlEvent::end_element())
+                .write(XmlEvent::end_element().name(SWID_TAG))
                 .map_err(to_xml_write_error(SWID_TAG))?;
 
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Swid {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -871,8 +918,10 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const CPE_TAG: &str = "cpe";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Cpe {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -882,6 +931,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Cpe {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -949,8 +999,10 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const EVIDENCE_TAG: &str = "evidence";
 
+    #[cfg(feature = "xml")]
     impl ToXml for ComponentEvidence {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -977,12 +1029,16 @@ pub(crate) mod base {
     }
 
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const OCCURRENCES_TAG: &str = "occurrences";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const CALLSTACK_TAG: &str = "callstack";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const IDENTITY_TAG: &str = "identity";
 
+    #[cfg(feature = "xml")]
     impl FromXml for ComponentEvidence {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -1124,12 +1180,18 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const PEDIGREE_TAG: &str = "pedigree";
+    #[cfg(feature = "xml")]
     const ANCESTORS_TAG: &str = "ancestors";
+    #[cfg(feature = "xml")]
     const DESCENDANTS_TAG: &str = "descendants";
+    #[cfg(feature = "xml")]
     const VARIANTS_TAG: &str = "variants";
+    #[cfg(feature = "xml")]
     const NOTES_TAG: &str = "notes";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Pedigree {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -1176,9 +1238,12 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const COMMITS_TAG: &str = "commits";
+    #[cfg(feature = "xml")]
     const PATCHES_TAG: &str = "patches";
 
+    #[cfg(feature = "xml")]
     impl FromXml for Pedigree {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -1280,6 +1345,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl ToXml for Copyright {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -1297,6 +1363,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Copyright {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -1326,6 +1393,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl ToXml for CopyrightTexts {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -1343,6 +1411,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for CopyrightTexts {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -1398,6 +1467,17 @@ pub(crate) mod base {
             modelcard::test::{corresponding_modelcard, example_modelcard},
         };
 
+        use crate::specs::common::{
+            attached_text::test::{corresponding_attached_text, example_attached_text},
+            code::test::{
+                corresponding_commits, corresponding_patches, example_commits, example_patches,
+            },
+            hash::test::{corresponding_hashes, example_hashes},
+            organization::test::{corresponding_entity, example_entity},
+            property::test::{corresponding_properties, example_properties},
+        };
+        #[cfg(feature = "xml")]
+        use crate::xml::test::{read_element_from_string, write_element_to_string};
         #[versioned("1.3")]
         use crate::{
             models::bom::SpecVersion,
@@ -1408,18 +1488,6 @@ pub(crate) mod base {
                 license::test::{corresponding_licenses, example_licenses},
             },
         };
-        use crate::{
-            specs::common::{
-                attached_text::test::{corresponding_attached_text, example_attached_text},
-                code::test::{
-                    corresponding_commits, corresponding_patches, example_commits, example_patches,
-                },
-                hash::test::{corresponding_hashes, example_hashes},
-                organization::test::{corresponding_entity, example_entity},
-                property::test::{corresponding_properties, example_properties},
-            },
-            xml::test::{read_element_from_string, write_element_to_string},
-        };
 
         use super::*;
         use pretty_assertions::assert_eq;
@@ -1489,25 +1557,25 @@ pub(crate) mod base {
                 copyright: Some(NormalizedString::new_unchecked("copyright".to_string())),
                 cpe: Some(corresponding_cpe()),
                 purl: Some(Purl("purl".to_string())),
-                swid: Some(corresponding_swid()),
+                swid: Some(Box::new(corresponding_swid())),
                 modified: Some(true),
-                pedigree: Some(corresponding_pedigree()),
+                pedigree: Some(Box::new(corresponding_pedigree())),
                 external_references: Some(corresponding_external_references()),
                 properties: Some(corresponding_properties()),
                 components: Some(corresponding_empty_components()),
-                evidence: Some(corresponding_evidence()),
+                evidence: Some(Box::new(corresponding_evidence())),
                 #[versioned("1.3")]
                 signature: None,
                 #[versioned("1.4", "1.5")]
-                signature: Some(corresponding_signature()),
+                signature: Some(Box::new(corresponding_signature())),
                 #[versioned("1.3", "1.4")]
                 model_card: None,
                 #[versioned("1.5")]
-                model_card: Some(corresponding_modelcard()),
+                model_card: Some(Box::new(corresponding_modelcard())),
                 #[versioned("1.3", "1.4")]
                 data: None,
                 #[versioned("1.5")]
-                data: Some(corresponding_component_data()),
+                data: Some(Box::new(corresponding_component_data())),
             }
         }
 
@@ -1632,12 +1700,14 @@ pub(crate) mod base {
             models::component::Copyright("copyright".to_string())
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full() {
             let xml_output = write_element_to_string(example_components());
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_full() {
             #[versioned("1.3")]
@@ -20,20 +20,24 @@ use cyclonedx_bom_macros::versioned;
 
 #[versioned("1.3", "1.4", "1.5")]
 pub(crate) mod base {
+    #[cfg(feature = "xml")]
     use crate::{
         errors::XmlReadError,
-        models,
-        specs::common::bom_reference::BomReference,
-        utilities::{convert_optional_vec, convert_vec},
         xml::{
             read_lax_validation_list_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
             unexpected_element_error, write_close_tag, write_simple_tag, write_start_tag, FromXml,
             ToInnerXml, ToXml,
         },
     };
+    use crate::{
+        models,
+        specs::common::bom_reference::BomReference,
+        utilities::{convert_optional_vec, convert_vec},
+    };
     #[versioned("1.4", "1.5")]
     use crate::{specs::common::signature::Signature, utilities::convert_optional};
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::reader;
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -52,8 +56,10 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const COMPOSITIONS_TAG: &str = "compositions";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Compositions {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -71,6 +77,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Compositions {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -143,20 +150,31 @@ pub(crate) mod base {
     }
 
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const BOM_REF_ATTR: &str = "bom-ref";
+    #[cfg(feature = "xml")]
     const COMPOSITION_TAG: &str = "composition";
+    #[cfg(feature = "xml")]
     const AGGREGATE_TAG: &str = "aggregate";
+    #[cfg(feature = "xml")]
     const ASSEMBLIES_TAG: &str = "assemblies";
+    #[cfg(feature = "xml")]
     const ASSEMBLY_TAG: &str = "assembly";
+    #[cfg(feature = "xml")]
     const DEPENDENCIES_TAG: &str = "dependencies";
+    #[cfg(feature = "xml")]
     const DEPENDENCY_TAG: &str = "dependency";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const VULNERABILITIES_TAG: &str = "vulnerabilities";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const VULNERABILITY_TAG: &str = "vulnerability";
     #[versioned("1.4", "1.5")]
+    #[cfg(feature = "xml")]
     const SIGNATURE_TAG: &str = "signature";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Composition {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -219,6 +237,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Composition {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -320,6 +339,7 @@ pub(crate) mod base {
 
         #[versioned("1.4", "1.5")]
         use crate::specs::common::signature::test::{corresponding_signature, example_signature};
+        #[cfg(feature = "xml")]
         use crate::xml::test::{read_element_from_string, write_element_to_string};
 
         pub(crate) fn example_compositions() -> Compositions {
@@ -366,12 +386,14 @@ pub(crate) mod base {
             }
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full() {
             let xml_output = write_element_to_string(example_compositions());
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_full() {
             #[versioned("1.3")]
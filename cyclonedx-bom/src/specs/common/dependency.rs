@@ -16,9 +16,10 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use crate::models;
+#[cfg(feature = "xml")]
 use crate::{
     errors::{XmlReadError, XmlWriteError},
-    models,
     xml::{
         attribute_or_error, closing_tag_or_error, read_list_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_close_tag, write_start_tag, FromXml,
@@ -26,6 +27,7 @@ use crate::{
     },
 };
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Default)]
@@ -43,8 +45,10 @@ impl From<Dependencies> for models::dependency::Dependencies {
     }
 }
 
+#[cfg(feature = "xml")]
 const DEPENDENCIES_TAG: &str = "dependencies";
 
+#[cfg(feature = "xml")]
 impl ToXml for Dependencies {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -62,6 +66,7 @@ impl ToXml for Dependencies {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Dependencies {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -102,9 +107,12 @@ impl From<models::dependency::Dependency> for Dependency {
     }
 }
 
+#[cfg(feature = "xml")]
 const DEPENDENCY_TAG: &str = "dependency";
+#[cfg(feature = "xml")]
 const REF_ATTR: &str = "ref";
 
+#[cfg(feature = "xml")]
 impl ToXml for Dependency {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -120,18 +128,19 @@ impl ToXml for Dependency {
                 .map_err(to_xml_write_error(DEPENDENCY_TAG))?;
 
             writer
-                .write(XmlEvent::end_element())
+                .write(XmlEvent::end_element().name(DEPENDENCY_TAG))
                 .map_err(to_xml_write_error(DEPENDENCY_TAG))?;
         }
 
         writer
-            .write(XmlEvent::end_element())
+            .write(XmlEvent::end_element().name(DEPENDENCY_TAG))
             .map_err(to_xml_write_error(DEPENDENCY_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Dependency {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -177,6 +186,7 @@ impl FromXml for Dependency {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_dependencies() -> Dependencies {
@@ -208,18 +218,21 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_dependencies());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_empty_dependencies() {
         let xml_output = write_element_to_string(Dependencies(Vec::new()));
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_dependencies_with_no_children() {
         let xml_output = write_element_to_string(Dependencies(vec![Dependency {
@@ -229,6 +242,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -243,6 +257,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_empty_dependencies() {
         let input = r#"
@@ -253,6 +268,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_dependencies_with_no_children() {
         let input = r#"
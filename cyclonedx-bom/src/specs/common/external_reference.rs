@@ -20,18 +20,22 @@ use cyclonedx_bom_macros::versioned;
 
 #[versioned("1.3", "1.4", "1.5")]
 pub(crate) mod base {
+    #[cfg(feature = "xml")]
     use crate::{
         errors::XmlReadError,
-        models,
-        specs::common::hash::Hashes,
-        utilities::{convert_optional, convert_vec},
         xml::{
             attribute_or_error, read_list_tag, read_simple_tag, to_xml_read_error,
             to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
             write_start_tag, FromXml, ToXml,
         },
     };
+    use crate::{
+        models,
+        specs::common::hash::Hashes,
+        utilities::{convert_optional, convert_vec},
+    };
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::{reader, writer::XmlEvent};
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -50,8 +54,10 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
 
+    #[cfg(feature = "xml")]
     impl ToXml for ExternalReferences {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -69,6 +75,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for ExternalReferences {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -119,11 +126,16 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const REFERENCE_TAG: &str = "reference";
+    #[cfg(feature = "xml")]
     const TYPE_ATTR: &str = "type";
+    #[cfg(feature = "xml")]
     const URL_TAG: &str = "url";
+    #[cfg(feature = "xml")]
     const COMMENT_TAG: &str = "comment";
 
+    #[cfg(feature = "xml")]
     impl ToXml for ExternalReference {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -147,15 +159,17 @@ pub(crate) mod base {
             }
 
             writer
-                .write(XmlEvent::end_element())
+                .write(XmlEvent::end_element().name(REFERENCE_TAG))
                 .map_err(to_xml_write_error(REFERENCE_TAG))?;
 
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     const HASHES_TAG: &str = "hashes";
 
+    #[cfg(feature = "xml")]
     impl FromXml for ExternalReference {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -213,10 +227,11 @@ pub(crate) mod base {
     #[cfg(test)]
     pub(crate) mod test {
         use super::*;
+        #[cfg(feature = "xml")]
+        use crate::xml::test::{read_element_from_string, write_element_to_string};
         use crate::{
             external_models,
             specs::common::hash::test::{corresponding_hashes, example_hashes},
-            xml::test::{read_element_from_string, write_element_to_string},
         };
 
         pub(crate) fn example_external_references() -> ExternalReferences {
@@ -252,12 +267,14 @@ pub(crate) mod base {
             }
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full() {
             let xml_output = write_element_to_string(example_external_references());
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_full() {
             let input = r#"
@@ -16,13 +16,14 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    utilities::convert_vec,
     xml::{attribute_or_error, read_list_tag, read_simple_tag, to_xml_write_error, FromXml, ToXml},
 };
+use crate::{models, utilities::convert_vec};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -41,8 +42,10 @@ impl From<Hashes> for models::hash::Hashes {
     }
 }
 
+#[cfg(feature = "xml")]
 const HASHES_TAG: &str = "hashes";
 
+#[cfg(feature = "xml")]
 impl ToXml for Hashes {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -57,12 +60,13 @@ impl ToXml for Hashes {
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(HASHES_TAG))
             .map_err(to_xml_write_error(HASHES_TAG))?;
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Hashes {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -101,9 +105,12 @@ impl From<Hash> for models::hash::Hash {
     }
 }
 
+#[cfg(feature = "xml")]
 const HASH_TAG: &str = "hash";
+#[cfg(feature = "xml")]
 const ALG_ATTR: &str = "alg";
 
+#[cfg(feature = "xml")]
 impl ToXml for Hash {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -118,12 +125,13 @@ impl ToXml for Hash {
             .map_err(to_xml_write_error(HASH_TAG))?;
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(HASH_TAG))
             .map_err(to_xml_write_error(HASH_TAG))?;
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Hash {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -160,6 +168,7 @@ impl From<HashValue> for models::hash::HashValue {
 
 #[cfg(test)]
 pub(crate) mod test {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
@@ -186,12 +195,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_hashes());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
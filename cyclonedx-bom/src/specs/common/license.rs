@@ -24,23 +24,31 @@ pub(crate) mod base {
     use crate::models::bom::BomReference;
     #[versioned("1.5")]
     use crate::specs::{common::property::Properties, v1_5::licensing::Licensing};
+    #[versioned("1.5")]
+    use crate::utilities::{convert_optional_boxed, convert_optional_into_boxed};
+    #[cfg(feature = "xml")]
     use crate::xml::{optional_attribute, write_close_tag, write_simple_tag};
+    #[cfg(feature = "xml")]
     use crate::{
         errors::XmlReadError,
+        xml::{
+            closing_tag_or_error, inner_text_or_error, read_lax_validation_tag, read_simple_tag,
+            to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, ToInnerXml,
+            ToXml,
+        },
+    };
+    use crate::{
         external_models::{
             normalized_string::NormalizedString,
             spdx::{SpdxExpression, SpdxIdentifier},
+            text,
             uri::Uri,
         },
         utilities::convert_vec,
-        xml::{
-            closing_tag_or_error, inner_text_or_error, read_lax_validation_tag, read_simple_tag,
-            to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, ToInnerXml,
-            ToXml,
-        },
     };
     use crate::{specs::common::attached_text::AttachedText, utilities::convert_optional};
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::{name::OwnedName, reader, writer};
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -59,8 +67,10 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const LICENSES_TAG: &str = "licenses";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Licenses {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -75,12 +85,13 @@ pub(crate) mod base {
             }
 
             writer
-                .write(writer::XmlEvent::end_element())
+                .write(writer::XmlEvent::end_element().name(LICENSES_TAG))
                 .map_err(to_xml_write_error(LICENSES_TAG))?;
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Licenses {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -145,9 +156,12 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const BOM_REF_ATTR: &str = "bom-ref";
+    #[cfg(feature = "xml")]
     const EXPRESSION_TAG: &str = "expression";
 
+    #[cfg(feature = "xml")]
     impl ToXml for LicenseChoice {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -166,6 +180,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for LicenseChoice {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -223,7 +238,7 @@ pub(crate) mod base {
                 text: convert_optional(other.text),
                 url: other.url.map(|u| u.to_string()),
                 #[versioned("1.5")]
-                licensing: convert_optional(other.licensing),
+                licensing: convert_optional_boxed(other.licensing),
                 #[versioned("1.5")]
                 properties: convert_optional(other.properties),
             }
@@ -243,7 +258,7 @@ pub(crate) mod base {
                 #[versioned("1.3", "1.4")]
                 licensing: None,
                 #[versioned("1.5")]
-                licensing: convert_optional(other.licensing),
+                licensing: convert_optional_into_boxed(other.licensing),
                 #[versioned("1.3", "1.4")]
                 properties: None,
                 #[versioned("1.5")]
@@ -252,14 +267,20 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const LICENSE_TAG: &str = "license";
+    #[cfg(feature = "xml")]
     const TEXT_TAG: &str = "text";
+    #[cfg(feature = "xml")]
     const URL_TAG: &str = "url";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const LICENSING_TAG: &str = "licensing";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const PROPERTIES_TAG: &str = "properties";
 
+    #[cfg(feature = "xml")]
     impl ToXml for License {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -295,13 +316,14 @@ pub(crate) mod base {
             }
 
             writer
-                .write(writer::XmlEvent::end_element())
+                .write(writer::XmlEvent::end_element().name(LICENSE_TAG))
                 .map_err(to_xml_write_error(LICENSE_TAG))?;
 
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for License {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -421,7 +443,7 @@ pub(crate) mod base {
     impl From<models::license::LicenseIdentifier> for LicenseIdentifier {
         fn from(other: models::license::LicenseIdentifier) -> Self {
             match other {
-                models::license::LicenseIdentifier::SpdxId(spdx) => Self::SpdxId(spdx.0),
+                models::license::LicenseIdentifier::SpdxId(spdx) => Self::SpdxId(spdx.to_string()),
                 models::license::LicenseIdentifier::Name(name) => Self::Name(name.to_string()),
             }
         }
@@ -430,15 +452,18 @@ pub(crate) mod base {
     impl From<LicenseIdentifier> for models::license::LicenseIdentifier {
         fn from(other: LicenseIdentifier) -> Self {
             match other {
-                LicenseIdentifier::SpdxId(spdx) => Self::SpdxId(SpdxIdentifier(spdx)),
+                LicenseIdentifier::SpdxId(spdx) => Self::SpdxId(SpdxIdentifier(text(spdx))),
                 LicenseIdentifier::Name(name) => Self::Name(NormalizedString::new_unchecked(name)),
             }
         }
     }
 
+    #[cfg(feature = "xml")]
     const ID_TAG: &str = "id";
+    #[cfg(feature = "xml")]
     const NAME_TAG: &str = "name";
 
+    #[cfg(feature = "xml")]
     impl ToXml for LicenseIdentifier {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -457,6 +482,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for LicenseIdentifier {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -537,6 +563,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl ToXml for Expression {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -562,6 +589,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Expression {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -592,12 +620,13 @@ pub(crate) mod base {
             v1_5::licensing::test::{corresponding_licensing, example_licensing},
         };
 
+        #[cfg(feature = "xml")]
+        use crate::xml::test::{read_element_from_string, write_element_to_string};
         use crate::{
             external_models::spdx::SpdxExpression,
             specs::common::attached_text::test::{
                 corresponding_attached_text, example_attached_text,
             },
-            xml::test::{read_element_from_string, write_element_to_string},
         };
 
         pub(crate) fn example_licenses() -> Licenses {
@@ -635,7 +664,7 @@ pub(crate) mod base {
             models::license::LicenseChoice::License(models::license::License {
                 bom_ref: None,
                 license_identifier: models::license::LicenseIdentifier::SpdxId(SpdxIdentifier(
-                    "spdx id".to_string(),
+                    "spdx id".into(),
                 )),
                 text: Some(corresponding_attached_text()),
                 url: Some(Uri("url".to_string())),
@@ -650,11 +679,11 @@ pub(crate) mod base {
             models::license::LicenseChoice::License(models::license::License {
                 bom_ref: Some(models::bom::BomReference::new("license-id")),
                 license_identifier: models::license::LicenseIdentifier::SpdxId(SpdxIdentifier(
-                    "spdx id".to_string(),
+                    "spdx id".into(),
                 )),
                 text: Some(corresponding_attached_text()),
                 url: Some(Uri("url".to_string())),
-                licensing: Some(corresponding_licensing()),
+                licensing: Some(Box::new(corresponding_licensing())),
                 properties: Some(corresponding_properties()),
             })
         }
@@ -705,7 +734,7 @@ pub(crate) mod base {
                 ),
                 text: Some(corresponding_attached_text()),
                 url: Some(Uri("url".to_string())),
-                licensing: Some(corresponding_licensing()),
+                licensing: Some(Box::new(corresponding_licensing())),
                 properties: Some(corresponding_properties()),
             })
         }
@@ -754,6 +783,7 @@ pub(crate) mod base {
             insta::assert_json_snapshot!(actual);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full_license_choice_licenses() {
             let xml_output = write_element_to_string(Licenses(vec![
@@ -763,6 +793,7 @@ pub(crate) mod base {
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full_license_choice_expressions() {
             let xml_output = write_element_to_string(Licenses(vec![
@@ -772,6 +803,7 @@ pub(crate) mod base {
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[versioned("1.3", "1.4")]
         #[test]
         fn it_should_read_xml_full_license_choice_licenses() {
@@ -794,6 +826,7 @@ pub(crate) mod base {
             assert_eq!(actual, expected);
         }
 
+        #[cfg(feature = "xml")]
         #[versioned("1.5")]
         #[test]
         fn it_should_read_xml_full_license_choice_licenses() {
@@ -874,6 +907,7 @@ pub(crate) mod base {
             assert_eq!(actual, expected);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_full_license_choice_expressions() {
             let input = r#"
@@ -30,6 +30,12 @@ pub(crate) mod base {
     };
 
     use crate::errors::BomError;
+    #[cfg(feature = "xml")]
+    use crate::xml::{
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
+    };
+    #[cfg(feature = "xml")]
     use crate::xml::{write_close_tag, write_start_tag};
     use crate::{
         external_models::date_time::DateTime,
@@ -39,13 +45,10 @@ pub(crate) mod base {
             property::Properties,
         },
         utilities::{convert_optional, convert_optional_vec, try_convert_optional},
-        xml::{
-            read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-            unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
-        },
     };
     use serde::{Deserialize, Serialize};
     use std::convert::TryFrom;
+    #[cfg(feature = "xml")]
     use xml::reader;
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -110,13 +113,20 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const METADATA_TAG: &str = "metadata";
+    #[cfg(feature = "xml")]
     const TIMESTAMP_TAG: &str = "timestamp";
+    #[cfg(feature = "xml")]
     const AUTHORS_TAG: &str = "authors";
+    #[cfg(feature = "xml")]
     const AUTHOR_TAG: &str = "author";
+    #[cfg(feature = "xml")]
     const MANUFACTURE_TAG: &str = "manufacture";
+    #[cfg(feature = "xml")]
     const SUPPLIER_TAG: &str = "supplier";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Metadata {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -186,13 +196,19 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const TOOLS_TAG: &str = "tools";
+    #[cfg(feature = "xml")]
     const COMPONENT_TAG: &str = "component";
+    #[cfg(feature = "xml")]
     const LICENSES_TAG: &str = "licenses";
+    #[cfg(feature = "xml")]
     const PROPERTIES_TAG: &str = "properties";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const LIFECYCLES_TAG: &str = "lifecycles";
 
+    #[cfg(feature = "xml")]
     impl FromXml for Metadata {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -320,6 +336,12 @@ pub(crate) mod base {
         use super::*;
         use pretty_assertions::assert_eq;
 
+        use crate::specs::common::{
+            organization::test::{
+                corresponding_contact, corresponding_entity, example_contact, example_entity,
+            },
+            property::test::{corresponding_properties, example_properties},
+        };
         #[versioned("1.3")]
         use crate::specs::v1_3::{
             component::test::{corresponding_component, example_component},
@@ -339,15 +361,8 @@ pub(crate) mod base {
             lifecycles::test::{corresponding_lifecycles, example_lifecycles},
             tool::test::{corresponding_tools, example_tools},
         };
-        use crate::{
-            specs::common::{
-                organization::test::{
-                    corresponding_contact, corresponding_entity, example_contact, example_entity,
-                },
-                property::test::{corresponding_properties, example_properties},
-            },
-            xml::test::{read_element_from_string, write_element_to_string},
-        };
+        #[cfg(feature = "xml")]
+        use crate::xml::test::{read_element_from_string, write_element_to_string};
 
         pub(crate) fn example_metadata() -> Metadata {
             Metadata {
@@ -381,12 +396,14 @@ pub(crate) mod base {
             }
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full() {
             let xml_output = write_element_to_string(example_metadata());
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_full() {
             #[versioned("1.3")]
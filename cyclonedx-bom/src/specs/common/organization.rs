@@ -16,18 +16,22 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlWriteError,
-    external_models::{normalized_string::NormalizedString, uri::Uri},
-    models::{self, bom::BomReference},
-    utilities::convert_optional_vec,
     xml::{
         optional_attribute, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag, FromXml,
         ToInnerXml,
     },
 };
+use crate::{
+    external_models::{normalized_string::NormalizedString, uri::Uri},
+    models::{self, bom::BomReference},
+    utilities::convert_optional_vec,
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -65,11 +69,16 @@ impl From<OrganizationalContact> for models::organization::OrganizationalContact
     }
 }
 
+#[cfg(feature = "xml")]
 const BOM_REF_ATTR: &str = "bom-ref";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const EMAIL_TAG: &str = "email";
+#[cfg(feature = "xml")]
 const PHONE_TAG: &str = "phone";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for OrganizationalContact {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -106,6 +115,7 @@ impl ToInnerXml for OrganizationalContact {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for OrganizationalContact {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -205,9 +215,12 @@ impl From<OrganizationalEntity> for models::organization::OrganizationalEntity {
     }
 }
 
+#[cfg(feature = "xml")]
 const URL_TAG: &str = "url";
+#[cfg(feature = "xml")]
 const CONTACT_TAG: &str = "contact";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for OrganizationalEntity {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -241,7 +254,7 @@ impl ToInnerXml for OrganizationalEntity {
         }
 
         writer
-            .write(XmlEvent::end_element())
+            .write(XmlEvent::end_element().name(tag))
             .map_err(to_xml_write_error(tag))?;
 
         Ok(())
@@ -252,6 +265,7 @@ impl ToInnerXml for OrganizationalEntity {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for OrganizationalEntity {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -313,6 +327,7 @@ impl FromXml for OrganizationalEntity {
 
 #[cfg(test)]
 pub(crate) mod test {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_named_element_to_string};
 
     use super::*;
@@ -354,6 +369,7 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_organizational_contact() {
         let input = r#"
@@ -373,12 +389,14 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_named_element_to_string(example_entity(), "supplier");
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_not_write_xml_empty_contacts() {
         let xml_output = write_named_element_to_string(
@@ -398,6 +416,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_multiple_urls_contacts() {
         let xml_output = write_named_element_to_string(
@@ -412,6 +431,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -430,6 +450,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_multiple_urls_contacts() {
         let input = r#"
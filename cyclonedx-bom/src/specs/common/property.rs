@@ -16,16 +16,17 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlWriteError,
-    external_models::normalized_string::NormalizedString,
-    models,
     xml::{
         attribute_or_error, read_lax_validation_list_tag, read_simple_tag, to_xml_write_error,
         write_close_tag, write_start_tag, FromXml, ToXml,
     },
 };
+use crate::{external_models::normalized_string::NormalizedString, models};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer::XmlEvent;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Default)]
@@ -44,8 +45,10 @@ impl From<Properties> for models::property::Properties {
     }
 }
 
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl ToXml for Properties {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -63,6 +66,7 @@ impl ToXml for Properties {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Properties {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -87,7 +91,7 @@ impl From<models::property::Property> for Property {
     fn from(other: models::property::Property) -> Self {
         Self {
             name: other.name,
-            value: other.value.0,
+            value: other.value.into(),
         }
     }
 }
@@ -101,9 +105,12 @@ impl From<Property> for models::property::Property {
     }
 }
 
+#[cfg(feature = "xml")]
 const PROPERTY_TAG: &str = "property";
+#[cfg(feature = "xml")]
 const NAME_ATTR: &str = "name";
 
+#[cfg(feature = "xml")]
 impl ToXml for Property {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -123,6 +130,7 @@ impl ToXml for Property {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Property {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -141,6 +149,7 @@ impl FromXml for Property {
 #[cfg(test)]
 pub(crate) mod test {
     use super::*;
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_properties() -> Properties {
@@ -157,18 +166,21 @@ pub(crate) mod test {
         }])
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_properties());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_properties_with_no_children() {
         let xml_output = write_element_to_string(Properties(Vec::new()));
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -181,6 +193,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_properties_with_no_children() {
         let input = r#"
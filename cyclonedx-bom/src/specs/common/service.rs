@@ -25,19 +25,24 @@ pub(crate) mod base {
         errors::BomError,
         utilities::{try_convert_optional, try_convert_vec},
     };
+    #[cfg(feature = "xml")]
     use crate::{
         errors::XmlReadError,
+        xml::{
+            attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_tag,
+            read_list_tag, read_nested_list_tag, read_simple_tag, to_xml_read_error,
+            to_xml_write_error, unexpected_element_error, write_close_tag,
+            write_simple_display_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml,
+            ToXml,
+        },
+    };
+    use crate::{
         external_models::{normalized_string::NormalizedString, uri::Uri},
         models,
         utilities::{convert_optional, convert_vec},
-        xml::{
-            attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_list_tag,
-            read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-            to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
-            write_start_tag, FromXml, ToInnerXml, ToXml,
-        },
     };
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::{reader, writer::XmlEvent};
 
     #[versioned("1.4", "1.5")]
@@ -78,8 +83,10 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const SERVICES_TAG: &str = "services";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Services {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -97,6 +104,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Services {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -106,7 +114,7 @@ pub(crate) mod base {
         where
             Self: Sized,
         {
-            read_lax_validation_list_tag(event_reader, element_name, SERVICE_TAG).map(Services)
+            read_nested_list_tag(event_reader, element_name, SERVICE_TAG).map(Services)
         }
     }
 
@@ -237,23 +245,38 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const SERVICE_TAG: &str = "service";
+    #[cfg(feature = "xml")]
     const BOM_REF_ATTR: &str = "bom-ref";
+    #[cfg(feature = "xml")]
     const PROVIDER_TAG: &str = "provider";
+    #[cfg(feature = "xml")]
     const GROUP_TAG: &str = "group";
+    #[cfg(feature = "xml")]
     const NAME_TAG: &str = "name";
+    #[cfg(feature = "xml")]
     const VERSION_TAG: &str = "version";
+    #[cfg(feature = "xml")]
     const DESCRIPTION_TAG: &str = "description";
+    #[cfg(feature = "xml")]
     const ENDPOINTS_TAG: &str = "endpoints";
+    #[cfg(feature = "xml")]
     const ENDPOINT_TAG: &str = "endpoint";
+    #[cfg(feature = "xml")]
     const AUTHENTICATED_TAG: &str = "authenticated";
+    #[cfg(feature = "xml")]
     const X_TRUST_BOUNDARY_TAG: &str = "x-trust-boundary";
+    #[cfg(feature = "xml")]
     const DATA_TAG: &str = "data";
     #[versioned("1.4", "1.5")]
+    #[cfg(feature = "xml")]
     const SIGNATURE_TAG: &str = "signature";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const TRUST_ZONE_TAG: &str = "trustZone";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Service {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -298,7 +321,7 @@ pub(crate) mod base {
             }
 
             if let Some(authenticated) = &self.authenticated {
-                write_simple_tag(writer, AUTHENTICATED_TAG, &format!("{}", authenticated))?;
+                write_simple_display_tag(writer, AUTHENTICATED_TAG, authenticated)?;
             }
 
             if let Some(x_trust_boundary) = &self.x_trust_boundary {
@@ -342,17 +365,21 @@ pub(crate) mod base {
             }
 
             writer
-                .write(XmlEvent::end_element())
+                .write(XmlEvent::end_element().name(SERVICE_TAG))
                 .map_err(to_xml_write_error(SERVICE_TAG))?;
 
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     const LICENSES_TAG: &str = "licenses";
+    #[cfg(feature = "xml")]
     const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
+    #[cfg(feature = "xml")]
     const PROPERTIES_TAG: &str = "properties";
 
+    #[cfg(feature = "xml")]
     impl FromXml for Service {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -578,9 +605,11 @@ pub(crate) mod base {
     }
 
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const DATAFLOW_TAG: &str = "dataflow";
 
     #[versioned("1.3", "1.4")]
+    #[cfg(feature = "xml")]
     impl FromXml for Data {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -621,6 +650,7 @@ pub(crate) mod base {
     }
 
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     impl FromXml for Data {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -675,6 +705,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl ToXml for Data {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -733,9 +764,12 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const CLASSIFICATION_TAG: &str = "classification";
+    #[cfg(feature = "xml")]
     const FLOW_ATTR: &str = "flow";
 
+    #[cfg(feature = "xml")]
     impl ToXml for DataClassification {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -755,6 +789,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for DataClassification {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -781,6 +816,10 @@ pub(crate) mod base {
 
         #[versioned("1.4", "1.5")]
         use crate::specs::common::signature::test::{corresponding_signature, example_signature};
+        use crate::specs::common::{
+            organization::test::{corresponding_entity, example_entity},
+            property::test::{corresponding_properties, example_properties},
+        };
         #[versioned("1.3")]
         use crate::specs::v1_3::{
             external_reference::test::{
@@ -803,13 +842,8 @@ pub(crate) mod base {
             },
             license::test::{corresponding_licenses, example_licenses},
         };
-        use crate::{
-            specs::common::{
-                organization::test::{corresponding_entity, example_entity},
-                property::test::{corresponding_properties, example_properties},
-            },
-            xml::test::{read_element_from_string, write_element_to_string},
-        };
+        #[cfg(feature = "xml")]
+        use crate::xml::test::{read_element_from_string, write_element_to_string};
 
         pub(crate) fn example_services() -> Services {
             Services(vec![example_service()])
@@ -945,6 +979,7 @@ pub(crate) mod base {
             }])
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full() {
             // NOTE: this only tests version 1.3 currently
@@ -952,6 +987,7 @@ pub(crate) mod base {
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_data_classifications() {
             let input = r#"
@@ -973,6 +1009,7 @@ pub(crate) mod base {
             assert_eq!(actual, expected);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_full() {
             #[versioned("1.3")]
@@ -17,14 +17,17 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+use crate::models;
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
     xml::{
         read_list_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
-        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToXml,
+        write_close_tag, write_simple_display_tag, write_simple_tag, write_start_tag, FromXml,
+        ToXml,
     },
 };
 
@@ -46,18 +49,20 @@ impl Signer {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Signer {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
         writer: &mut xml::EventWriter<W>,
     ) -> Result<(), crate::errors::XmlWriteError> {
-        write_simple_tag(writer, ALGORITHM_TAG, &self.algorithm.to_string())?;
+        write_simple_display_tag(writer, ALGORITHM_TAG, &self.algorithm)?;
         write_simple_tag(writer, VALUE_TAG, &self.value)?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Signer {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -247,6 +252,7 @@ impl From<Algorithm> for models::signature::Algorithm {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Signature {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -282,14 +288,22 @@ impl ToXml for Signature {
     }
 }
 
+#[cfg(feature = "xml")]
 const SIGNERS_TAG: &str = "signers";
+#[cfg(feature = "xml")]
 const SIGNER_TAG: &str = "signer";
+#[cfg(feature = "xml")]
 const CHAIN_TAG: &str = "chain";
+#[cfg(feature = "xml")]
 const CHAIN_INNER_TAG: &str = "chain";
+#[cfg(feature = "xml")]
 const SIGNATURE_TAG: &str = "signature";
+#[cfg(feature = "xml")]
 const ALGORITHM_TAG: &str = "algorithm";
+#[cfg(feature = "xml")]
 const VALUE_TAG: &str = "value";
 
+#[cfg(feature = "xml")]
 impl FromXml for Signature {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -357,12 +371,12 @@ impl FromXml for Signature {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use xml::{name::OwnedName, EmitterConfig, EventReader, EventWriter, ParserConfig};
+    #[cfg(feature = "xml")]
+    use xml::{name::OwnedName, EventReader, EventWriter, ParserConfig};
 
-    use crate::{
-        models,
-        xml::{test::read_element_from_string, FromXml, ToXml},
-    };
+    use crate::models;
+    #[cfg(feature = "xml")]
+    use crate::xml::{test::read_element_from_string, FromXml, ToXml};
 
     use super::Signature;
 
@@ -391,12 +405,11 @@ pub(crate) mod test {
         assert!(actual.is_err());
     }
 
+    #[cfg(feature = "xml")]
     #[track_caller]
     fn assert_write_xml(signature: Signature, expected_output: &str) {
         let mut writer = Vec::new();
-        let config = EmitterConfig::default()
-            .perform_indent(true)
-            .write_document_declaration(false);
+        let config = crate::xml::emitter_config().write_document_declaration(false);
         let mut event_writer = EventWriter::new_with_config(&mut writer, config);
 
         signature
@@ -452,6 +465,7 @@ pub(crate) mod test {
         assert_invalid_signature(input);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_successfully() {
         let expected = r#"
@@ -29,16 +29,21 @@ pub(crate) mod base {
 
     use crate::models;
     use crate::{
-        errors::{BomError, XmlReadError},
+        errors::BomError,
         external_models::normalized_string::NormalizedString,
         specs::common::hash::Hashes,
         utilities::{convert_optional, convert_vec},
+    };
+    #[cfg(feature = "xml")]
+    use crate::{
+        errors::XmlReadError,
         xml::{
             read_lax_validation_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
             unexpected_element_error, write_simple_tag, FromXml, ToXml,
         },
     };
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::{reader, writer};
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -92,12 +97,16 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const TOOLS_TAG: &str = "tools";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const COMPONENTS_TAG: &str = "components";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const SERVICES_TAG: &str = "services";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Tools {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -124,12 +133,13 @@ pub(crate) mod base {
             }
 
             writer
-                .write(writer::XmlEvent::end_element())
+                .write(writer::XmlEvent::end_element().name(TOOLS_TAG))
                 .map_err(to_xml_write_error(TOOLS_TAG))?;
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Tools {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -259,11 +269,16 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const TOOL_TAG: &str = "tool";
+    #[cfg(feature = "xml")]
     const VENDOR_TAG: &str = "vendor";
+    #[cfg(feature = "xml")]
     const NAME_TAG: &str = "name";
+    #[cfg(feature = "xml")]
     const VERSION_TAG: &str = "version";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Tool {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -297,7 +312,7 @@ pub(crate) mod base {
             }
 
             writer
-                .write(writer::XmlEvent::end_element())
+                .write(writer::XmlEvent::end_element().name(TOOL_TAG))
                 .map_err(to_xml_write_error(TOOL_TAG))?;
 
             Ok(())
@@ -311,10 +326,13 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const HASHES_TAG: &str = "hashes";
     #[versioned("1.4", "1.5")]
+    #[cfg(feature = "xml")]
     const EXTERNAL_REFERENCES_TAG: &str = "externalReferences";
 
+    #[cfg(feature = "xml")]
     impl FromXml for Tool {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -406,10 +424,9 @@ pub(crate) mod base {
             },
         };
 
-        use crate::{
-            specs::common::hash::test::{corresponding_hashes, example_hashes},
-            xml::test::{read_element_from_string, write_element_to_string},
-        };
+        use crate::specs::common::hash::test::{corresponding_hashes, example_hashes};
+        #[cfg(feature = "xml")]
+        use crate::xml::test::{read_element_from_string, write_element_to_string};
 
         use super::*;
         use pretty_assertions::assert_eq;
@@ -465,12 +482,14 @@ pub(crate) mod base {
             }
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full() {
             let xml_output = write_element_to_string(example_tools());
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_full() {
             let input = r#"
@@ -499,6 +518,7 @@ pub(crate) mod base {
             assert_eq!(actual, expected);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         #[versioned("1.5")]
         fn it_should_read_xml_with_services_and_components() {
@@ -22,21 +22,26 @@ use cyclonedx_bom_macros::versioned;
 pub(crate) mod base {
 
     use crate::{
-        errors::{BomError, XmlReadError},
+        errors::BomError,
         external_models::{date_time::DateTime, normalized_string::NormalizedString},
-        models::{self},
+        models,
         utilities::{
             convert_optional, convert_optional_vec, convert_vec, try_convert_optional,
             try_convert_vec,
         },
+    };
+    #[cfg(feature = "xml")]
+    use crate::{
+        errors::XmlReadError,
         xml::{
             optional_attribute, read_lax_validation_list_tag, read_lax_validation_tag,
             read_list_tag, read_optional_tag, read_simple_tag, to_xml_read_error,
-            to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
-            write_start_tag, FromXml, ToXml,
+            to_xml_write_error, unexpected_element_error, write_close_tag,
+            write_simple_display_tag, write_simple_tag, write_start_tag, FromXml, ToXml,
         },
     };
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::{reader, writer::XmlEvent};
 
     use crate::specs::common::{
@@ -71,8 +76,10 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const VULNERABILITIES_TAG: &str = "vulnerabilities";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Vulnerabilities {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -90,6 +97,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Vulnerabilities {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -224,33 +232,57 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const VULNERABILITY_TAG: &str = "vulnerability";
+    #[cfg(feature = "xml")]
     const BOM_REF_ATTR: &str = "bom-ref";
+    #[cfg(feature = "xml")]
     const ID_TAG: &str = "id";
+    #[cfg(feature = "xml")]
     const VULNERABILITY_SOURCE_TAG: &str = "source";
+    #[cfg(feature = "xml")]
     const VULNERABILITY_REFERENCES_TAG: &str = "references";
+    #[cfg(feature = "xml")]
     const VULNERABILITY_RATINGS_TAG: &str = "ratings";
+    #[cfg(feature = "xml")]
     const CWES_TAG: &str = "cwes";
+    #[cfg(feature = "xml")]
     const CWE_TAG: &str = "cwe";
+    #[cfg(feature = "xml")]
     const DESCRIPTION_TAG: &str = "description";
+    #[cfg(feature = "xml")]
     const DETAIL_TAG: &str = "detail";
+    #[cfg(feature = "xml")]
     const RECOMMENDATION_TAG: &str = "recommendation";
+    #[cfg(feature = "xml")]
     const ADVISORIES_TAG: &str = "advisories";
+    #[cfg(feature = "xml")]
     const CREATED_TAG: &str = "created";
+    #[cfg(feature = "xml")]
     const PUBLISHED_TAG: &str = "published";
+    #[cfg(feature = "xml")]
     const UPDATED_TAG: &str = "updated";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const REJECTED_TAG: &str = "rejected";
+    #[cfg(feature = "xml")]
     const VULNERABILITY_CREDITS_TAG: &str = "credits";
+    #[cfg(feature = "xml")]
     const TOOLS_TAG: &str = "tools";
+    #[cfg(feature = "xml")]
     const VULNERABILITY_ANALYSIS_TAG: &str = "analysis";
+    #[cfg(feature = "xml")]
     const VULNERABILITY_TARGETS_TAG: &str = "affects";
+    #[cfg(feature = "xml")]
     const PROPERTIES_TAG: &str = "properties";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const WORKAROUND_TAG: &str = "workaround";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const PROOF_OF_CONCEPT_TAG: &str = "proofOfConcept";
 
+    #[cfg(feature = "xml")]
     impl ToXml for Vulnerability {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -286,7 +318,7 @@ pub(crate) mod base {
                 write_start_tag(writer, CWES_TAG)?;
 
                 for &cwe in cwes {
-                    write_simple_tag(writer, CWE_TAG, format!("{}", cwe).as_ref())?;
+                    write_simple_display_tag(writer, CWE_TAG, cwe)?;
                 }
 
                 write_close_tag(writer, CWES_TAG)?;
@@ -353,13 +385,14 @@ pub(crate) mod base {
             }
 
             writer
-                .write(XmlEvent::end_element())
+                .write(XmlEvent::end_element().name(VULNERABILITY_TAG))
                 .map_err(to_xml_write_error(VULNERABILITY_TAG))?;
 
             Ok(())
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for Vulnerability {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -601,6 +634,25 @@ pub(crate) mod base {
         use super::*;
         use pretty_assertions::assert_eq;
 
+        use crate::specs::common::{
+            advisory::test::{corresponding_advisories, example_advisories},
+            property::test::{corresponding_properties, example_properties},
+            vulnerability_credits::test::{
+                corresponding_vulnerability_credits, example_vulnerability_credits,
+            },
+            vulnerability_rating::test::{
+                corresponding_vulnerability_ratings, example_vulnerability_ratings,
+            },
+            vulnerability_reference::test::{
+                corresponding_vulnerability_references, example_vulnerability_references,
+            },
+            vulnerability_source::test::{
+                corresponding_vulnerability_source, example_vulnerability_source,
+            },
+            vulnerability_target::test::{
+                corresponding_vulnerability_targets, example_vulnerability_targets,
+            },
+        };
         #[versioned("1.4")]
         use crate::specs::v1_4::{
             tool::test::{corresponding_tools, example_tools},
@@ -616,28 +668,8 @@ pub(crate) mod base {
                 corresponding_vulnerability_analysis, example_vulnerability_analysis,
             },
         };
-        use crate::{
-            specs::common::{
-                advisory::test::{corresponding_advisories, example_advisories},
-                property::test::{corresponding_properties, example_properties},
-                vulnerability_credits::test::{
-                    corresponding_vulnerability_credits, example_vulnerability_credits,
-                },
-                vulnerability_rating::test::{
-                    corresponding_vulnerability_ratings, example_vulnerability_ratings,
-                },
-                vulnerability_reference::test::{
-                    corresponding_vulnerability_references, example_vulnerability_references,
-                },
-                vulnerability_source::test::{
-                    corresponding_vulnerability_source, example_vulnerability_source,
-                },
-                vulnerability_target::test::{
-                    corresponding_vulnerability_targets, example_vulnerability_targets,
-                },
-            },
-            xml::test::{read_element_from_string, write_element_to_string},
-        };
+        #[cfg(feature = "xml")]
+        use crate::xml::test::{read_element_from_string, write_element_to_string};
 
         pub(crate) fn example_vulnerabilities() -> Vulnerabilities {
             Vulnerabilities(vec![example_vulnerability()])
@@ -752,12 +784,14 @@ pub(crate) mod base {
             }
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full() {
             let xml_output = write_element_to_string(example_vulnerabilities());
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[versioned("1.4")]
         #[test]
         fn it_should_read_xml_full() {
@@ -881,6 +915,7 @@ pub(crate) mod base {
             assert_eq!(actual, expected);
         }
 
+        #[cfg(feature = "xml")]
         #[versioned("1.5")]
         #[test]
         fn it_should_read_xml_full() {
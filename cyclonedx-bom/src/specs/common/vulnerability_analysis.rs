@@ -23,17 +23,19 @@ pub(crate) mod base {
     #[versioned("1.5")]
     use crate::external_models::date_time::DateTime;
     use crate::utilities::convert_optional_vec;
+    #[cfg(feature = "xml")]
     use crate::xml::{write_close_tag, write_start_tag};
+    #[cfg(feature = "xml")]
     use crate::{
         errors::XmlReadError,
-        models,
-        utilities::convert_optional,
         xml::{
             read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
             unexpected_element_error, write_simple_tag, FromXml, ToXml,
         },
     };
+    use crate::{models, utilities::convert_optional};
     use serde::{Deserialize, Serialize};
+    #[cfg(feature = "xml")]
     use xml::reader;
 
     #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -89,17 +91,26 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     const VULNERABILITY_ANALYSIS_TAG: &str = "analysis";
+    #[cfg(feature = "xml")]
     const STATE_TAG: &str = "state";
+    #[cfg(feature = "xml")]
     const JUSTIFICATION_TAG: &str = "justification";
+    #[cfg(feature = "xml")]
     const RESPONSES_TAG: &str = "responses";
+    #[cfg(feature = "xml")]
     const RESPONSE_TAG: &str = "response";
+    #[cfg(feature = "xml")]
     const DETAIL_TAG: &str = "detail";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const FIRST_ISSUED_TAG: &str = "firstIssued";
     #[versioned("1.5")]
+    #[cfg(feature = "xml")]
     const LAST_UPDATED_TAG: &str = "lastUpdated";
 
+    #[cfg(feature = "xml")]
     impl ToXml for VulnerabilityAnalysis {
         fn write_xml_element<W: std::io::Write>(
             &self,
@@ -145,6 +156,7 @@ pub(crate) mod base {
         }
     }
 
+    #[cfg(feature = "xml")]
     impl FromXml for VulnerabilityAnalysis {
         fn read_xml_element<R: std::io::Read>(
             event_reader: &mut xml::EventReader<R>,
@@ -293,6 +305,7 @@ pub(crate) mod base {
 
         #[versioned("1.5")]
         use crate::external_models::date_time::DateTime;
+        #[cfg(feature = "xml")]
         use crate::xml::test::{read_element_from_string, write_element_to_string};
 
         #[versioned("1.4")]
@@ -355,12 +368,14 @@ pub(crate) mod base {
             }
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_write_xml_full() {
             let xml_output = write_element_to_string(example_vulnerability_analysis());
             insta::assert_snapshot!(xml_output);
         }
 
+        #[cfg(feature = "xml")]
         #[test]
         fn it_should_read_xml_full() {
             let input = r#"
@@ -16,16 +16,17 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    utilities::convert_optional_vec,
     xml::{
         read_lax_validation_tag, read_list_tag, to_xml_read_error, unexpected_element_error,
         write_close_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{models, utilities::convert_optional_vec};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
 use crate::specs::common::organization::{OrganizationalContact, OrganizationalEntity};
@@ -57,12 +58,18 @@ impl From<VulnerabilityCredits> for models::vulnerability_credits::Vulnerability
     }
 }
 
+#[cfg(feature = "xml")]
 const VULNERABILITY_CREDITS_TAG: &str = "credits";
+#[cfg(feature = "xml")]
 const ORGANIZATIONS_TAG: &str = "organizations";
+#[cfg(feature = "xml")]
 const ORGANIZATION_TAG: &str = "organization";
+#[cfg(feature = "xml")]
 const INDIVIDUALS_TAG: &str = "individuals";
+#[cfg(feature = "xml")]
 const INDIVIDUAL_TAG: &str = "individual";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityCredits {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -100,6 +107,7 @@ impl ToXml for VulnerabilityCredits {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityCredits {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -153,12 +161,11 @@ pub(crate) mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
-    use crate::{
-        specs::common::organization::test::{
-            corresponding_contact, corresponding_entity, example_contact, example_entity,
-        },
-        xml::test::{read_element_from_string, write_element_to_string},
+    use crate::specs::common::organization::test::{
+        corresponding_contact, corresponding_entity, example_contact, example_entity,
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_credits() -> VulnerabilityCredits {
         VulnerabilityCredits {
@@ -175,12 +182,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_credits());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
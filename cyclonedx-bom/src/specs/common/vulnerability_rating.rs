@@ -16,19 +16,23 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
+    xml::{
+        read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_display_tag,
+        write_simple_tag, write_start_tag, FromXml, FromXmlType, ToXml,
+    },
+};
+use crate::{
     external_models::normalized_string::NormalizedString,
     models,
     specs::common::vulnerability_source::VulnerabilitySource,
     utilities::{convert_optional, convert_vec},
-    xml::{
-        read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
-        to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
-        write_start_tag, FromXml, FromXmlType, ToXml,
-    },
 };
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -47,8 +51,10 @@ impl From<VulnerabilityRatings> for models::vulnerability_rating::VulnerabilityR
     }
 }
 
+#[cfg(feature = "xml")]
 const VULNERABILITY_RATINGS_TAG: &str = "ratings";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityRatings {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -66,6 +72,7 @@ impl ToXml for VulnerabilityRatings {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityRatings {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -124,14 +131,22 @@ impl From<VulnerabilityRating> for models::vulnerability_rating::VulnerabilityRa
     }
 }
 
+#[cfg(feature = "xml")]
 const VULNERABILITY_RATING_TAG: &str = "rating";
+#[cfg(feature = "xml")]
 const VULNERABILITY_SOURCE_TAG: &str = "source";
+#[cfg(feature = "xml")]
 const SCORE_TAG: &str = "score";
+#[cfg(feature = "xml")]
 const SEVERITY_TAG: &str = "severity";
+#[cfg(feature = "xml")]
 const SCORE_METHOD_TAG: &str = "method";
+#[cfg(feature = "xml")]
 const VECTOR_TAG: &str = "vector";
+#[cfg(feature = "xml")]
 const JUSTIFICATION_TAG: &str = "justification";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityRating {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -148,7 +163,7 @@ impl ToXml for VulnerabilityRating {
         }
 
         if let Some(score) = &self.score {
-            write_simple_tag(writer, SCORE_TAG, format!("{}", score).as_ref())?;
+            write_simple_display_tag(writer, SCORE_TAG, score)?;
         }
 
         if let Some(severity) = &self.severity {
@@ -168,13 +183,14 @@ impl ToXml for VulnerabilityRating {
         }
 
         writer
-            .write(XmlEvent::end_element())
+            .write(XmlEvent::end_element().name(VULNERABILITY_RATING_TAG))
             .map_err(to_xml_write_error(VULNERABILITY_RATING_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityRating {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -293,12 +309,11 @@ pub(crate) mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
-    use crate::{
-        specs::common::vulnerability_source::test::{
-            corresponding_vulnerability_source, example_vulnerability_source,
-        },
-        xml::test::{read_element_from_string, write_element_to_string},
+    use crate::specs::common::vulnerability_source::test::{
+        corresponding_vulnerability_source, example_vulnerability_source,
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_ratings() -> VulnerabilityRatings {
         VulnerabilityRatings(vec![example_vulnerability_rating()])
@@ -334,12 +349,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_ratings());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -16,19 +16,21 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::normalized_string::NormalizedString,
-    models,
-    specs::common::vulnerability_source::VulnerabilitySource,
-    utilities::convert_vec,
     xml::{
         read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_close_tag, write_simple_tag,
         write_start_tag, FromXml, ToXml,
     },
 };
+use crate::{
+    external_models::normalized_string::NormalizedString, models,
+    specs::common::vulnerability_source::VulnerabilitySource, utilities::convert_vec,
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -47,8 +49,10 @@ impl From<VulnerabilityReferences> for models::vulnerability_reference::Vulnerab
     }
 }
 
+#[cfg(feature = "xml")]
 const VULNERABILITY_REFERENCES_TAG: &str = "references";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityReferences {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -66,6 +70,7 @@ impl ToXml for VulnerabilityReferences {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityReferences {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -109,10 +114,14 @@ impl From<VulnerabilityReference> for models::vulnerability_reference::Vulnerabi
     }
 }
 
+#[cfg(feature = "xml")]
 const VULNERABILITY_REFERENCE_TAG: &str = "reference";
+#[cfg(feature = "xml")]
 const ID_TAG: &str = "id";
+#[cfg(feature = "xml")]
 const VULNERABILITY_SOURCE_TAG: &str = "source";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityReference {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -130,13 +139,14 @@ impl ToXml for VulnerabilityReference {
         self.vulnerability_source.write_xml_element(writer)?;
 
         writer
-            .write(XmlEvent::end_element())
+            .write(XmlEvent::end_element().name(VULNERABILITY_REFERENCE_TAG))
             .map_err(to_xml_write_error(VULNERABILITY_REFERENCE_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityReference {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -203,12 +213,11 @@ pub(crate) mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
-    use crate::{
-        specs::common::vulnerability_source::test::{
-            corresponding_vulnerability_source, example_vulnerability_source,
-        },
-        xml::test::{read_element_from_string, write_element_to_string},
+    use crate::specs::common::vulnerability_source::test::{
+        corresponding_vulnerability_source, example_vulnerability_source,
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_references() -> VulnerabilityReferences {
         VulnerabilityReferences(vec![example_vulnerability_reference()])
@@ -236,12 +245,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_references());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
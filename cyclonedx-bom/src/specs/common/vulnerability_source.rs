@@ -16,16 +16,20 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::{normalized_string::NormalizedString, uri::Uri},
-    models,
     xml::{
         read_lax_validation_tag, read_simple_tag, to_xml_read_error, to_xml_write_error,
         unexpected_element_error, write_simple_tag, FromXml, ToXml,
     },
 };
+use crate::{
+    external_models::{normalized_string::NormalizedString, uri::Uri},
+    models,
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer::XmlEvent};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -55,10 +59,14 @@ impl From<VulnerabilitySource> for models::vulnerability_source::VulnerabilitySo
     }
 }
 
+#[cfg(feature = "xml")]
 const VULNERABILITY_SOURCE_TAG: &str = "source";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const URL_TAG: &str = "url";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilitySource {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -79,13 +87,14 @@ impl ToXml for VulnerabilitySource {
         }
 
         writer
-            .write(XmlEvent::end_element())
+            .write(XmlEvent::end_element().name(VULNERABILITY_SOURCE_TAG))
             .map_err(to_xml_write_error(VULNERABILITY_SOURCE_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilitySource {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -135,6 +144,7 @@ pub(crate) mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_source() -> VulnerabilitySource {
@@ -152,12 +162,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_source());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
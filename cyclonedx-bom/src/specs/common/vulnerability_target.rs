@@ -16,18 +16,22 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::normalized_string::NormalizedString,
-    models,
-    utilities::{convert_optional, convert_vec},
     xml::{
         read_lax_validation_list_tag, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         unexpected_element_error, write_close_tag, write_simple_tag, write_start_tag, FromXml,
         ToXml,
     },
 };
+use crate::{
+    external_models::normalized_string::NormalizedString,
+    models,
+    utilities::{convert_optional, convert_vec},
+};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -46,8 +50,10 @@ impl From<VulnerabilityTargets> for models::vulnerability_target::VulnerabilityT
     }
 }
 
+#[cfg(feature = "xml")]
 const VULNERABILITY_TARGETS_TAG: &str = "affects";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityTargets {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -65,6 +71,7 @@ impl ToXml for VulnerabilityTargets {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityTargets {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -106,10 +113,14 @@ impl From<VulnerabilityTarget> for models::vulnerability_target::VulnerabilityTa
     }
 }
 
+#[cfg(feature = "xml")]
 const VULNERABILITY_TARGET_TAG: &str = "target";
+#[cfg(feature = "xml")]
 const REF_TAG: &str = "ref";
+#[cfg(feature = "xml")]
 const VERSIONS_TAG: &str = "versions";
 
+#[cfg(feature = "xml")]
 impl ToXml for VulnerabilityTarget {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -136,6 +147,7 @@ impl ToXml for VulnerabilityTarget {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VulnerabilityTarget {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -204,6 +216,7 @@ impl From<Versions> for models::vulnerability_target::Versions {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Versions {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -221,8 +234,10 @@ impl ToXml for Versions {
     }
 }
 
+#[cfg(feature = "xml")]
 const VERSION_TAG: &str = "version";
 
+#[cfg(feature = "xml")]
 impl FromXml for Versions {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -262,6 +277,7 @@ impl From<Version> for models::vulnerability_target::Version {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Version {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -278,8 +294,10 @@ impl ToXml for Version {
     }
 }
 
+#[cfg(feature = "xml")]
 const RANGE_TAG: &str = "range";
 
+#[cfg(feature = "xml")]
 impl FromXml for Version {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -370,6 +388,7 @@ impl From<VersionRange> for models::vulnerability_target::VersionRange {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for VersionRange {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -389,6 +408,7 @@ impl ToXml for VersionRange {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for VersionRange {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -434,8 +454,10 @@ impl From<Status> for models::vulnerability_target::Status {
     }
 }
 
+#[cfg(feature = "xml")]
 const STATUS_TAG: &str = "status";
 
+#[cfg(feature = "xml")]
 impl ToXml for Status {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -445,6 +467,7 @@ impl ToXml for Status {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Status {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -463,10 +486,9 @@ pub(crate) mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
-    use crate::{
-        external_models::normalized_string::NormalizedString,
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::external_models::normalized_string::NormalizedString;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     pub(crate) fn example_vulnerability_targets() -> VulnerabilityTargets {
         VulnerabilityTargets(vec![example_vulnerability_target()])
@@ -591,12 +613,14 @@ pub(crate) mod test {
         Status("unknown".to_string())
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn write_xml_versions_defined() {
         let xml_output = write_element_to_string(example_versions_defined());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn read_xml_versions_defined() {
         let input = r#"
@@ -632,6 +656,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_vulnerability_targets());
@@ -639,6 +664,7 @@ pub(crate) mod test {
     }
 
     // todo: check encoding for '>', see '<' => '&lt;'
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
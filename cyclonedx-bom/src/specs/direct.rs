@@ -0,0 +1,652 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Writes the models as JSON without converting them to the structs of a specification version.
+//!
+//! The structs in [`crate::specs`] own their data, so writing a BOM through them first copies
+//! the whole tree, strings included. [`Versioned`] serializes the models in place instead and
+//! produces the same JSON. Fields that are rare or small in practice, like pedigrees or the
+//! metadata, are still converted one value at a time with the conversions of the version.
+
+use std::{cell::RefCell, fmt::Display, marker::PhantomData};
+
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::{
+    errors::{BomError, JsonWriteError},
+    external_models::normalized_string::NormalizedString,
+    models::{
+        bom::{Bom, SpecVersion},
+        component::Component,
+        dependency::Dependency,
+        external_reference::ExternalReference,
+        formulation::Formula,
+        hash::Hash,
+        license::{LicenseChoice, LicenseIdentifier},
+        property::Property,
+        service::Service,
+        vulnerability::Vulnerability,
+    },
+    specs::{
+        common::{
+            attached_text::AttachedText, organization::OrganizationalEntity, signature::Signature,
+        },
+        v1_3, v1_4, v1_5,
+    },
+};
+
+/// Writes `bom` as pretty-printed JSON conforming to `version`.
+pub(crate) fn to_writer_pretty(
+    writer: &mut dyn std::io::Write,
+    bom: &Bom,
+    version: SpecVersion,
+) -> Result<(), JsonWriteError> {
+    let context = Context::new(version);
+    let result = serde_json::to_writer_pretty(writer, &context.wrap(bom));
+    context.finish(result)
+}
+
+/// Writes `bom` as compact JSON conforming to `version`.
+pub(crate) fn to_writer(
+    writer: &mut dyn std::io::Write,
+    bom: &Bom,
+    version: SpecVersion,
+) -> Result<(), JsonWriteError> {
+    let context = Context::new(version);
+    let result = serde_json::to_writer(writer, &context.wrap(bom));
+    context.finish(result)
+}
+
+/// Converts `bom` into a JSON [`Value`] conforming to `version`.
+pub(crate) fn to_value(bom: &Bom, version: SpecVersion) -> Result<Value, JsonWriteError> {
+    let context = Context::new(version);
+    let result = serde_json::to_value(context.wrap(bom));
+    context.finish(result)
+}
+
+/// Writes a single component as compact JSON conforming to `version`.
+pub(crate) fn component_to_writer(
+    writer: &mut dyn std::io::Write,
+    component: &Component,
+    version: SpecVersion,
+) -> Result<(), JsonWriteError> {
+    let context = Context::new(version);
+    let result = serde_json::to_writer(writer, &context.wrap(component));
+    context.finish(result)
+}
+
+/// Writes a single service as compact JSON conforming to `version`.
+pub(crate) fn service_to_writer(
+    writer: &mut dyn std::io::Write,
+    service: &Service,
+    version: SpecVersion,
+) -> Result<(), JsonWriteError> {
+    let context = Context::new(version);
+    let result = serde_json::to_writer(writer, &context.wrap(service));
+    context.finish(result)
+}
+
+/// The version being written and the first conversion error, which `serde` can only report as a
+/// message.
+struct Context {
+    version: SpecVersion,
+    error: RefCell<Option<BomError>>,
+}
+
+impl Context {
+    fn new(version: SpecVersion) -> Self {
+        Self {
+            version,
+            error: RefCell::new(None),
+        }
+    }
+
+    fn wrap<'a, T: ?Sized>(&'a self, value: &'a T) -> Versioned<'a, T> {
+        Versioned {
+            value,
+            context: self,
+        }
+    }
+
+    fn fail<E: serde::ser::Error>(&self, error: BomError) -> E {
+        let message = error.to_string();
+        self.error.borrow_mut().get_or_insert(error);
+        E::custom(message)
+    }
+
+    fn finish<T>(self, result: Result<T, serde_json::Error>) -> Result<T, JsonWriteError> {
+        match self.error.into_inner() {
+            Some(error) => Err(error.into()),
+            None => Ok(result?),
+        }
+    }
+}
+
+/// A model serialized as the JSON of the version in its [`Context`].
+struct Versioned<'a, T: ?Sized> {
+    value: &'a T,
+    context: &'a Context,
+}
+
+impl<T: ?Sized> Clone for Versioned<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Versioned<'_, T> {}
+
+impl<'a, T: ?Sized> Versioned<'a, T> {
+    fn wrap<U: ?Sized>(&self, value: &'a U) -> Versioned<'a, U> {
+        self.context.wrap(value)
+    }
+
+    fn wrap_all<U>(&self, values: &'a [U]) -> impl Iterator<Item = Versioned<'a, U>> + 'a {
+        let context = self.context;
+        values.iter().map(move |value| context.wrap(value))
+    }
+
+    /// Serializes `value` through the spec struct `S`, like the conversion of a whole BOM does.
+    fn converted<S, M>(&self, value: &'a M) -> Converted<'a, M, S> {
+        Converted {
+            value,
+            context: self.context,
+            target: PhantomData,
+        }
+    }
+
+    fn version(&self) -> SpecVersion {
+        self.context.version
+    }
+}
+
+/// A model cloned and converted to the spec struct `S` when it is serialized.
+struct Converted<'a, M, S> {
+    value: &'a M,
+    context: &'a Context,
+    target: PhantomData<fn() -> S>,
+}
+
+impl<M, S> Serialize for Converted<'_, M, S>
+where
+    M: Clone,
+    S: TryFrom<M> + Serialize,
+    BomError: From<S::Error>,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        match S::try_from(self.value.clone()) {
+            Ok(converted) => converted.serialize(serializer),
+            Err(error) => Err(self.context.fail(error.into())),
+        }
+    }
+}
+
+fn as_str(value: &NormalizedString) -> &str {
+    value.as_ref()
+}
+
+/// Serializes a value with its [`Display`] implementation.
+struct Displayed<'a, T: ?Sized>(&'a T);
+
+impl<T: Display + ?Sized> Serialize for Displayed<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
+
+/// Serializes an entry converted to the spec struct at `$path` in the module of the version
+/// being written.
+macro_rules! converted_entry {
+    ($map:ident, $versioned:ident, $key:expr, $value:expr, $($path:ident)::+) => {
+        match $versioned.version() {
+            SpecVersion::V1_3 => $map
+                .serialize_entry($key, &$versioned.converted::<v1_3::$($path)::+, _>($value)),
+            SpecVersion::V1_4 => $map
+                .serialize_entry($key, &$versioned.converted::<v1_4::$($path)::+, _>($value)),
+            SpecVersion::V1_5 => $map
+                .serialize_entry($key, &$versioned.converted::<v1_5::$($path)::+, _>($value)),
+        }
+    };
+}
+
+impl Serialize for Versioned<'_, Bom> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bom = self.value;
+        let version = self.version();
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("bomFormat", "CycloneDX")?;
+        map.serialize_entry("specVersion", &version)?;
+        map.serialize_entry("version", &bom.version)?;
+        map.serialize_entry(
+            "serialNumber",
+            &bom.serial_number.as_ref().map(|serial| &serial.0),
+        )?;
+        if let Some(metadata) = &bom.metadata {
+            converted_entry!(map, self, "metadata", metadata, metadata::Metadata)?;
+        }
+        if let Some(components) = &bom.components {
+            map.serialize_entry("components", &self.wrap(components.0.as_slice()))?;
+        }
+        if let Some(services) = &bom.services {
+            map.serialize_entry("services", &self.wrap(services.0.as_slice()))?;
+        }
+        if let Some(external_references) = &bom.external_references {
+            map.serialize_entry(
+                "externalReferences",
+                &self.wrap(external_references.0.as_slice()),
+            )?;
+        }
+        if let Some(dependencies) = &bom.dependencies {
+            map.serialize_entry("dependencies", &self.wrap(dependencies.0.as_slice()))?;
+        }
+        if let Some(compositions) = &bom.compositions {
+            converted_entry!(
+                map,
+                self,
+                "compositions",
+                compositions,
+                composition::Compositions
+            )?;
+        }
+        if version >= SpecVersion::V1_4 {
+            if let Some(vulnerabilities) = &bom.vulnerabilities {
+                map.serialize_entry("vulnerabilities", &self.wrap(vulnerabilities.0.as_slice()))?;
+            }
+            if let Some(signature) = &bom.signature {
+                map.serialize_entry("signature", &self.converted::<Signature, _>(signature))?;
+            }
+        }
+        if version >= SpecVersion::V1_5 {
+            if let Some(annotations) = &bom.annotations {
+                map.serialize_entry(
+                    "annotations",
+                    &self.converted::<v1_5::annotation::Annotations, _>(annotations),
+                )?;
+            }
+            if let Some(properties) = &bom.properties {
+                map.serialize_entry("properties", &self.wrap(properties.0.as_slice()))?;
+            }
+            if let Some(formulation) = &bom.formulation {
+                map.serialize_entry("formulation", &self.wrap(formulation.as_slice()))?;
+            }
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Versioned<'_, [Component]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.wrap_all(self.value))
+    }
+}
+
+impl Serialize for Versioned<'_, Component> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let component = self.value;
+        let version = self.version();
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &Displayed(&component.component_type))?;
+        if let Some(mime_type) = &component.mime_type {
+            map.serialize_entry("mime-type", &mime_type.0)?;
+        }
+        if let Some(bom_ref) = &component.bom_ref {
+            map.serialize_entry("bom-ref", bom_ref)?;
+        }
+        if let Some(supplier) = &component.supplier {
+            map.serialize_entry(
+                "supplier",
+                &self.converted::<OrganizationalEntity, _>(supplier),
+            )?;
+        }
+        if let Some(author) = &component.author {
+            map.serialize_entry("author", as_str(author))?;
+        }
+        if let Some(publisher) = &component.publisher {
+            map.serialize_entry("publisher", as_str(publisher))?;
+        }
+        if let Some(group) = &component.group {
+            map.serialize_entry("group", as_str(group))?;
+        }
+        map.serialize_entry("name", as_str(&component.name))?;
+        match &component.version {
+            Some(component_version) => map.serialize_entry("version", as_str(component_version))?,
+            None if version == SpecVersion::V1_3 => {
+                return Err(self.context.fail(BomError::BomSerializationError(
+                    SpecVersion::V1_3,
+                    "version missing".to_string(),
+                )))
+            }
+            None => {}
+        }
+        if let Some(description) = &component.description {
+            map.serialize_entry("description", as_str(description))?;
+        }
+        if let Some(scope) = &component.scope {
+            map.serialize_entry("scope", &Displayed(scope))?;
+        }
+        if let Some(hashes) = &component.hashes {
+            map.serialize_entry("hashes", &self.wrap(hashes.0.as_slice()))?;
+        }
+        if let Some(licenses) = &component.licenses {
+            map.serialize_entry("licenses", &self.wrap(licenses.0.as_slice()))?;
+        }
+        if let Some(copyright) = &component.copyright {
+            map.serialize_entry("copyright", as_str(copyright))?;
+        }
+        if let Some(cpe) = &component.cpe {
+            map.serialize_entry("cpe", &cpe.0)?;
+        }
+        if let Some(purl) = &component.purl {
+            map.serialize_entry("purl", &purl.0)?;
+        }
+        if let Some(swid) = component.swid.as_deref() {
+            converted_entry!(map, self, "swid", swid, component::Swid)?;
+        }
+        if let Some(modified) = &component.modified {
+            map.serialize_entry("modified", modified)?;
+        }
+        if let Some(pedigree) = component.pedigree.as_deref() {
+            converted_entry!(map, self, "pedigree", pedigree, component::Pedigree)?;
+        }
+        if let Some(external_references) = &component.external_references {
+            map.serialize_entry(
+                "externalReferences",
+                &self.wrap(external_references.0.as_slice()),
+            )?;
+        }
+        if let Some(properties) = &component.properties {
+            map.serialize_entry("properties", &self.wrap(properties.0.as_slice()))?;
+        }
+        if let Some(components) = &component.components {
+            map.serialize_entry("components", &self.wrap(components.0.as_slice()))?;
+        }
+        if let Some(evidence) = component.evidence.as_deref() {
+            converted_entry!(
+                map,
+                self,
+                "evidence",
+                evidence,
+                component::ComponentEvidence
+            )?;
+        }
+        if version >= SpecVersion::V1_4 {
+            if let Some(signature) = component.signature.as_deref() {
+                map.serialize_entry("signature", &self.converted::<Signature, _>(signature))?;
+            }
+        }
+        if version >= SpecVersion::V1_5 {
+            if let Some(model_card) = component.model_card.as_deref() {
+                map.serialize_entry(
+                    "modelCard",
+                    &self.converted::<v1_5::modelcard::ModelCard, _>(model_card),
+                )?;
+            }
+            if let Some(data) = component.data.as_deref() {
+                map.serialize_entry(
+                    "data",
+                    &self.converted::<v1_5::component_data::ComponentData, _>(data),
+                )?;
+            }
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Versioned<'_, [Service]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.wrap_all(self.value))
+    }
+}
+
+impl Serialize for Versioned<'_, Service> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.version() {
+            SpecVersion::V1_3 => self
+                .converted::<v1_3::service::Service, _>(self.value)
+                .serialize(serializer),
+            SpecVersion::V1_4 => self
+                .converted::<v1_4::service::Service, _>(self.value)
+                .serialize(serializer),
+            SpecVersion::V1_5 => self
+                .converted::<v1_5::service::Service, _>(self.value)
+                .serialize(serializer),
+        }
+    }
+}
+
+/// Only written for version 1.4 and later.
+impl Serialize for Versioned<'_, [Vulnerability]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.version() == SpecVersion::V1_5 {
+            serializer.collect_seq(self.value.iter().map(|vulnerability| {
+                self.converted::<v1_5::vulnerability::Vulnerability, _>(vulnerability)
+            }))
+        } else {
+            serializer.collect_seq(self.value.iter().map(|vulnerability| {
+                self.converted::<v1_4::vulnerability::Vulnerability, _>(vulnerability)
+            }))
+        }
+    }
+}
+
+impl Serialize for Versioned<'_, [Formula]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.value
+                .iter()
+                .map(|formula| self.converted::<v1_5::formulation::Formula, _>(formula)),
+        )
+    }
+}
+
+impl Serialize for Versioned<'_, [Hash]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.wrap_all(self.value))
+    }
+}
+
+impl Serialize for Versioned<'_, Hash> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("alg", &Displayed(&self.value.alg))?;
+        map.serialize_entry("content", &self.value.content.0)?;
+        map.end()
+    }
+}
+
+impl Serialize for Versioned<'_, [LicenseChoice]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.wrap_all(self.value))
+    }
+}
+
+impl Serialize for Versioned<'_, LicenseChoice> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        match self.value {
+            LicenseChoice::License(license) => {
+                map.serialize_entry("license", &self.wrap(license))?;
+            }
+            LicenseChoice::Expression(expression) => {
+                if let Some(bom_ref) = &expression.bom_ref {
+                    map.serialize_entry("bomRef", &bom_ref.0)?;
+                }
+                map.serialize_entry("expression", &expression.expression)?;
+            }
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Versioned<'_, crate::models::license::License> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let license = self.value;
+        let is_v1_5 = self.version() >= SpecVersion::V1_5;
+        let mut map = serializer.serialize_map(None)?;
+        if is_v1_5 {
+            if let Some(bom_ref) = &license.bom_ref {
+                map.serialize_entry("bomRef", &bom_ref.0)?;
+            }
+        }
+        match &license.license_identifier {
+            LicenseIdentifier::SpdxId(id) => map.serialize_entry("id", &Displayed(id))?,
+            LicenseIdentifier::Name(name) => map.serialize_entry("name", as_str(name))?,
+        }
+        if let Some(text) = &license.text {
+            map.serialize_entry("text", &self.converted::<AttachedText, _>(text))?;
+        }
+        if let Some(url) = &license.url {
+            map.serialize_entry("url", &Displayed(url))?;
+        }
+        if is_v1_5 {
+            if let Some(licensing) = license.licensing.as_deref() {
+                map.serialize_entry(
+                    "licensing",
+                    &self.converted::<v1_5::licensing::Licensing, _>(licensing),
+                )?;
+            }
+            if let Some(properties) = &license.properties {
+                map.serialize_entry("properties", &self.wrap(properties.0.as_slice()))?;
+            }
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Versioned<'_, [ExternalReference]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.wrap_all(self.value))
+    }
+}
+
+impl Serialize for Versioned<'_, ExternalReference> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let reference = self.value;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &Displayed(&reference.external_reference_type))?;
+        map.serialize_entry("url", &Displayed(&reference.url))?;
+        if let Some(comment) = &reference.comment {
+            map.serialize_entry("comment", comment)?;
+        }
+        if let Some(hashes) = &reference.hashes {
+            map.serialize_entry("hashes", &self.wrap(hashes.0.as_slice()))?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Versioned<'_, [Property]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.wrap_all(self.value))
+    }
+}
+
+impl Serialize for Versioned<'_, Property> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("name", &self.value.name)?;
+        map.serialize_entry("value", as_str(&self.value.value))?;
+        map.end()
+    }
+}
+
+impl Serialize for Versioned<'_, [Dependency]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.wrap_all(self.value))
+    }
+}
+
+impl Serialize for Versioned<'_, Dependency> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("ref", &self.value.dependency_ref)?;
+        map.serialize_entry("dependsOn", &self.value.dependencies)?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::models::component::{Classification, Components};
+
+    /// Writes `bom` the way it was written before, through the structs of the version.
+    fn converted_json(bom: &Bom, version: SpecVersion) -> String {
+        let bom = bom.clone();
+        match version {
+            SpecVersion::V1_3 => {
+                serde_json::to_string_pretty(&v1_3::bom::Bom::try_from(bom).unwrap()).unwrap()
+            }
+            SpecVersion::V1_4 => {
+                serde_json::to_string_pretty(&v1_4::bom::Bom::try_from(bom).unwrap()).unwrap()
+            }
+            SpecVersion::V1_5 => {
+                serde_json::to_string_pretty(&v1_5::bom::Bom::try_from(bom).unwrap()).unwrap()
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_write_the_same_json_as_the_spec_structs() {
+        let boms = [
+            (
+                SpecVersion::V1_3,
+                v1_3::bom::test::corresponding_internal_model(),
+            ),
+            (
+                SpecVersion::V1_4,
+                v1_4::bom::test::corresponding_internal_model(),
+            ),
+            (
+                SpecVersion::V1_5,
+                v1_5::bom::test::corresponding_internal_model(),
+            ),
+        ];
+        for (version, bom) in boms {
+            let mut actual = Vec::new();
+            to_writer_pretty(&mut actual, &bom, version).unwrap();
+            assert_eq!(
+                String::from_utf8(actual).unwrap(),
+                converted_json(&bom, version)
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_report_conversion_errors() {
+        let mut component = Component::new(Classification::Library, "no-version", "", None);
+        component.version = None;
+        let bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        let result = to_value(&bom, SpecVersion::V1_3);
+
+        assert!(matches!(
+            result,
+            Err(JsonWriteError::BomError {
+                error: BomError::BomSerializationError(SpecVersion::V1_3, _)
+            })
+        ));
+        assert!(to_value(&bom, SpecVersion::V1_4).is_ok());
+    }
+}
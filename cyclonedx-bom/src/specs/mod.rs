@@ -17,6 +17,8 @@
  */
 
 pub(crate) mod common;
+#[cfg(feature = "json")]
+pub(crate) mod direct;
 pub(crate) mod v1_3;
 pub(crate) mod v1_4;
 pub(crate) mod v1_5;
@@ -17,10 +17,14 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::name::OwnedName;
+#[cfg(feature = "xml")]
 use xml::{reader, writer};
 
-use crate::errors::{BomError, XmlReadError};
+use crate::errors::BomError;
+#[cfg(feature = "xml")]
+use crate::errors::XmlReadError;
 use crate::models;
 use crate::prelude::DateTime;
 use crate::specs::common::organization::{OrganizationalContact, OrganizationalEntity};
@@ -28,6 +32,7 @@ use crate::specs::common::service::v1_5::Service;
 use crate::specs::common::signature::Signature;
 use crate::specs::v1_5::component::Component;
 use crate::utilities::{convert_optional, convert_vec, try_convert_vec};
+#[cfg(feature = "xml")]
 use crate::xml::{
     read_simple_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
     write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
@@ -52,8 +57,10 @@ impl From<Annotations> for models::annotation::Annotations {
     }
 }
 
+#[cfg(feature = "xml")]
 const ANNOTATIONS_TAG: &str = "annotations";
 
+#[cfg(feature = "xml")]
 impl ToXml for Annotations {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -71,6 +78,7 @@ impl ToXml for Annotations {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Annotations {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -199,11 +207,16 @@ impl From<Annotator> for models::annotation::Annotator {
     }
 }
 
+#[cfg(feature = "xml")]
 const ORGANIZATION_TAG: &str = "organization";
+#[cfg(feature = "xml")]
 const INDIVIDUAL_TAG: &str = "individual";
+#[cfg(feature = "xml")]
 const COMPONENT_TAG: &str = "component";
+#[cfg(feature = "xml")]
 const SERVICE_TAG: &str = "service";
 
+#[cfg(feature = "xml")]
 impl FromXml for Annotator {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -274,6 +287,7 @@ impl FromXml for Annotator {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Annotator {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -299,8 +313,10 @@ impl ToXml for Annotator {
     }
 }
 
+#[cfg(feature = "xml")]
 const ANNOTATION_TAG: &str = "annotation";
 
+#[cfg(feature = "xml")]
 impl ToXml for Annotation {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -336,20 +352,27 @@ impl ToXml for Annotation {
         }
 
         writer
-            .write(writer::XmlEvent::end_element())
+            .write(writer::XmlEvent::end_element().name(ANNOTATION_TAG))
             .map_err(to_xml_write_error(ANNOTATION_TAG))?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 const SUBJECTS_TAG: &str = "subjects";
+#[cfg(feature = "xml")]
 const SUBJECT_TAG: &str = "subject";
+#[cfg(feature = "xml")]
 const ANNOTATOR_TAG: &str = "annotator";
+#[cfg(feature = "xml")]
 const TIMESTAMP_TAG: &str = "timestamp";
+#[cfg(feature = "xml")]
 const TEXT_TAG: &str = "text";
+#[cfg(feature = "xml")]
 const SIGNATURE_TAG: &str = "signature";
 
+#[cfg(feature = "xml")]
 fn read_subject<R: std::io::Read>(
     event_reader: &mut xml::EventReader<R>,
     element_name: &OwnedName,
@@ -388,6 +411,7 @@ fn read_subject<R: std::io::Read>(
     Ok(ref_name)
 }
 
+#[cfg(feature = "xml")]
 fn read_subjects<R: std::io::Read>(
     event_reader: &mut xml::EventReader<R>,
     element_name: &OwnedName,
@@ -425,6 +449,7 @@ fn read_subjects<R: std::io::Read>(
     Ok(subjects)
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Annotation {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -519,8 +544,11 @@ impl FromXml for Annotation {
 #[cfg(test)]
 pub(crate) mod test {
     use pretty_assertions::assert_eq;
+    #[cfg(feature = "xml")]
     use xml::{EventReader, ParserConfig};
 
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
     use crate::{
         models,
         specs::{
@@ -534,7 +562,6 @@ pub(crate) mod test {
             },
             v1_5::component::test::example_component,
         },
-        xml::test::{read_element_from_string, write_element_to_string},
     };
 
     use super::{read_subject, read_subjects, Annotation, Annotations, Annotator};
@@ -566,6 +593,7 @@ pub(crate) mod test {
         example_annotation().into()
     }
 
+    #[cfg(feature = "xml")]
     fn event_reader<R: std::io::Read>(input: R) -> EventReader<R> {
         EventReader::new_with_config(input, ParserConfig::default().trim_whitespace(true))
     }
@@ -611,6 +639,7 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_annotator() {
         let input = r#"
@@ -633,6 +662,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full_annotations() {
         let input = r#"
@@ -679,6 +709,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full_annotations() {
         let annotations = vec![
@@ -17,21 +17,18 @@
  */
 
 use serde::{Deserialize, Serialize};
-use xml::{
-    name::OwnedName,
-    reader::{self},
-    writer,
-};
+#[cfg(feature = "xml")]
+use xml::{name::OwnedName, reader, writer};
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    utilities::convert_optional,
     xml::{
         optional_attribute, to_xml_read_error, to_xml_write_error, write_close_tag, FromXml,
         ToInnerXml,
     },
 };
+use crate::{models, utilities::convert_optional};
 
 /// bom-1.5.schema.json #definitions/attachment
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -64,9 +61,12 @@ impl From<Attachment> for models::attachment::Attachment {
     }
 }
 
+#[cfg(feature = "xml")]
 const ENCODING_ATTR: &str = "encoding";
+#[cfg(feature = "xml")]
 const CONTENT_TYPE_ATTR: &str = "content-type";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for Attachment {
     fn write_xml_named_element<W: std::io::prelude::Write>(
         &self,
@@ -92,6 +92,7 @@ impl ToInnerXml for Attachment {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Attachment {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -17,14 +17,18 @@
  */
 
 use serde::{Deserialize, Serialize};
-use xml::{
-    name::OwnedName,
-    reader::{self},
-    writer,
-};
+#[cfg(feature = "xml")]
+use xml::{name::OwnedName, reader, writer};
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
+    xml::{
+        optional_attribute, read_simple_tag, to_xml_read_error, to_xml_write_error,
+        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use crate::{
     models,
     prelude::Uri,
     specs::{
@@ -32,10 +36,6 @@ use crate::{
         v1_5::data_governance::DataGovernance,
     },
     utilities::{convert_optional, convert_vec},
-    xml::{
-        optional_attribute, read_simple_tag, to_xml_read_error, to_xml_write_error,
-        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
-    },
 };
 
 use super::attachment::Attachment;
@@ -100,14 +100,22 @@ impl From<ComponentData> for models::component_data::ComponentData {
     }
 }
 
+#[cfg(feature = "xml")]
 const CONTENTS_TAG: &str = "contents";
+#[cfg(feature = "xml")]
 const GRAPHICS_TAG: &str = "graphics";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const CLASSIFICATION_TAG: &str = "classification";
+#[cfg(feature = "xml")]
 const SENSITIVE_DATA_TAG: &str = "sensitiveData";
+#[cfg(feature = "xml")]
 const GOVERNANCE_TAG: &str = "governance";
+#[cfg(feature = "xml")]
 const BOM_REF_ATTR: &str = "bom-ref";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for ComponentData {
     fn write_xml_named_element<W: std::io::prelude::Write>(
         &self,
@@ -168,8 +176,10 @@ impl ToInnerXml for ComponentData {
     }
 }
 
+#[cfg(feature = "xml")]
 const TYPE_TAG: &str = "type";
 
+#[cfg(feature = "xml")]
 impl FromXml for ComponentData {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -310,8 +320,10 @@ impl From<DataContents> for models::component_data::DataContents {
     }
 }
 
+#[cfg(feature = "xml")]
 const ATTACHMENT_TAG: &str = "attachment";
 
+#[cfg(feature = "xml")]
 impl ToXml for DataContents {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -337,9 +349,12 @@ impl ToXml for DataContents {
     }
 }
 
+#[cfg(feature = "xml")]
 const URL_TAG: &str = "url";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl FromXml for DataContents {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -426,9 +441,12 @@ impl From<GraphicsCollection> for models::component_data::GraphicsCollection {
     }
 }
 
+#[cfg(feature = "xml")]
 const COLLECTION_TAG: &str = "collection";
+#[cfg(feature = "xml")]
 const DESCRIPTION_TAG: &str = "description";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for GraphicsCollection {
     fn write_xml_named_element<W: std::io::prelude::Write>(
         &self,
@@ -451,8 +469,10 @@ impl ToInnerXml for GraphicsCollection {
     }
 }
 
+#[cfg(feature = "xml")]
 const OUTPUT_TAG: &str = "output";
 
+#[cfg(feature = "xml")]
 impl FromXml for GraphicsCollection {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -522,8 +542,10 @@ impl From<Collection> for Vec<models::component_data::Graphic> {
     }
 }
 
+#[cfg(feature = "xml")]
 const GRAPHIC_TAG: &str = "graphic";
 
+#[cfg(feature = "xml")]
 impl ToXml for Collection {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -541,6 +563,7 @@ impl ToXml for Collection {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Collection {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -601,8 +624,10 @@ impl From<Graphic> for models::component_data::Graphic {
     }
 }
 
+#[cfg(feature = "xml")]
 const IMAGE_TAG: &str = "image";
 
+#[cfg(feature = "xml")]
 impl ToXml for Graphic {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -624,6 +649,7 @@ impl ToXml for Graphic {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Graphic {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -17,18 +17,22 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{name::OwnedName, reader};
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    specs::common::organization::{OrganizationalContact, OrganizationalEntity},
-    utilities::convert_vec,
     xml::{
         read_list_tag, to_xml_read_error, unexpected_element_error, write_close_tag,
         write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{
+    models,
+    specs::common::organization::{OrganizationalContact, OrganizationalEntity},
+    utilities::convert_vec,
+};
 
 /// bom-1.5.schema.json #definitions/dataGovernance
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -61,13 +65,20 @@ impl From<DataGovernance> for models::data_governance::DataGovernance {
     }
 }
 
+#[cfg(feature = "xml")]
 const CUSTODIANS_TAG: &str = "custodians";
+#[cfg(feature = "xml")]
 const CUSTODIAN_TAG: &str = "custodian";
+#[cfg(feature = "xml")]
 const STEWARDS_TAG: &str = "stewards";
+#[cfg(feature = "xml")]
 const STEWARD_TAG: &str = "steward";
+#[cfg(feature = "xml")]
 const OWNERS_TAG: &str = "owners";
+#[cfg(feature = "xml")]
 const OWNER_TAG: &str = "owner";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for DataGovernance {
     fn write_xml_named_element<W: std::io::prelude::Write>(
         &self,
@@ -112,6 +123,7 @@ impl ToInnerXml for DataGovernance {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for DataGovernance {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -196,9 +208,12 @@ impl From<DataGovernanceResponsibleParty>
     }
 }
 
+#[cfg(feature = "xml")]
 const ORGANIZATION_TAG: &str = "organization";
+#[cfg(feature = "xml")]
 const CONTACT_TAG: &str = "contact";
 
+#[cfg(feature = "xml")]
 impl ToXml for DataGovernanceResponsibleParty {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -217,6 +232,7 @@ impl ToXml for DataGovernanceResponsibleParty {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for DataGovernanceResponsibleParty {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
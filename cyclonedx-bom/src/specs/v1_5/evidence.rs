@@ -17,27 +17,35 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    prelude::NormalizedString,
-    utilities::{convert_optional, convert_vec},
     xml::{
         attribute_or_error, optional_attribute, read_f32_tag, read_list_tag, read_simple_tag,
         read_u32_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
-        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
+        write_close_tag, write_simple_display_tag, write_simple_tag, write_start_tag, FromXml,
+        ToInnerXml, ToXml,
     },
 };
+use crate::{
+    models,
+    prelude::NormalizedString,
+    utilities::{convert_optional, convert_vec},
+};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct Occurrences(Vec<Occurrence>);
 
+#[cfg(feature = "xml")]
 const OCCURRENCE_TAG: &str = "occurrence";
+#[cfg(feature = "xml")]
 const OCCURRENCES_TAG: &str = "occurrences";
 
+#[cfg(feature = "xml")]
 impl ToXml for Occurrences {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -47,6 +55,7 @@ impl ToXml for Occurrences {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for Occurrences {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -65,6 +74,7 @@ impl ToInnerXml for Occurrences {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Occurrences {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -116,9 +126,12 @@ impl From<models::component::Occurrence> for Occurrence {
     }
 }
 
+#[cfg(feature = "xml")]
 const BOM_REF_ATTR: &str = "bom-ref";
+#[cfg(feature = "xml")]
 const LOCATION_TAG: &str = "location";
 
+#[cfg(feature = "xml")]
 impl ToXml for Occurrence {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -142,6 +155,7 @@ impl ToXml for Occurrence {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Occurrence {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -183,6 +197,7 @@ pub(crate) struct Callstack {
     pub(crate) frames: Frames,
 }
 
+#[cfg(feature = "xml")]
 impl Callstack {
     pub fn new(frames: Frames) -> Self {
         Self { frames }
@@ -205,10 +220,14 @@ impl From<models::component::Callstack> for Callstack {
     }
 }
 
+#[cfg(feature = "xml")]
 const CALLSTACK_TAG: &str = "callstack";
+#[cfg(feature = "xml")]
 const FRAMES_TAG: &str = "frames";
+#[cfg(feature = "xml")]
 const FRAME_TAG: &str = "frame";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for Callstack {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -231,6 +250,7 @@ impl ToInnerXml for Callstack {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Callstack {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -240,6 +260,7 @@ impl ToXml for Callstack {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Callstack {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -331,28 +352,37 @@ impl From<Frame> for models::component::Frame {
 impl From<models::component::Frame> for Frame {
     fn from(other: models::component::Frame) -> Self {
         Self {
-            package: other.package.map(|p| p.0),
-            module: other.module.0,
-            function: other.function.map(|f| f.0),
+            package: other.package.map(String::from),
+            module: other.module.into(),
+            function: other.function.map(String::from),
             parameters: other
                 .parameters
-                .map(|params| params.into_iter().map(|p| p.0).collect()),
+                .map(|params| params.into_iter().map(String::from).collect()),
             line: convert_optional(other.line),
             column: convert_optional(other.column),
-            full_filename: other.full_filename.map(|f| f.0),
+            full_filename: other.full_filename.map(String::from),
         }
     }
 }
 
+#[cfg(feature = "xml")]
 const PACKAGE_TAG: &str = "package";
+#[cfg(feature = "xml")]
 const MODULE_TAG: &str = "module";
+#[cfg(feature = "xml")]
 const FUNCTION_TAG: &str = "function";
+#[cfg(feature = "xml")]
 const PARAMETERS_TAG: &str = "parameters";
+#[cfg(feature = "xml")]
 const PARAMETER_TAG: &str = "parameter";
+#[cfg(feature = "xml")]
 const LINE_TAG: &str = "line";
+#[cfg(feature = "xml")]
 const COLUMN_TAG: &str = "column";
+#[cfg(feature = "xml")]
 const FULL_FILENAME_TAG: &str = "fullFilename";
 
+#[cfg(feature = "xml")]
 impl ToXml for Frame {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -379,11 +409,11 @@ impl ToXml for Frame {
         }
 
         if let Some(line) = self.line {
-            write_simple_tag(writer, LINE_TAG, line.to_string().as_str())?;
+            write_simple_display_tag(writer, LINE_TAG, line)?;
         }
 
         if let Some(column) = self.column {
-            write_simple_tag(writer, COLUMN_TAG, column.to_string().as_str())?;
+            write_simple_display_tag(writer, COLUMN_TAG, column)?;
         }
 
         if let Some(full_filename) = &self.full_filename {
@@ -396,6 +426,7 @@ impl ToXml for Frame {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Frame {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -511,17 +542,28 @@ impl From<models::component::Identity> for Identity {
     }
 }
 
+#[cfg(feature = "xml")]
 const IDENTITY_TAG: &str = "identity";
+#[cfg(feature = "xml")]
 const FIELD_TAG: &str = "field";
+#[cfg(feature = "xml")]
 const CONFIDENCE_TAG: &str = "confidence";
+#[cfg(feature = "xml")]
 const METHODS_TAG: &str = "methods";
+#[cfg(feature = "xml")]
 const METHOD_TAG: &str = "method";
+#[cfg(feature = "xml")]
 const TECHNIQUE_TAG: &str = "technique";
+#[cfg(feature = "xml")]
 const VALUE_TAG: &str = "value";
+#[cfg(feature = "xml")]
 const TOOLS_TAG: &str = "tools";
+#[cfg(feature = "xml")]
 const TOOL_TAG: &str = "tool";
+#[cfg(feature = "xml")]
 const REF_ATTR: &str = "ref";
 
+#[cfg(feature = "xml")]
 impl ToXml for Identity {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -532,7 +574,7 @@ impl ToXml for Identity {
         write_simple_tag(writer, FIELD_TAG, &self.field)?;
 
         if let Some(confidence) = self.confidence {
-            write_simple_tag(writer, CONFIDENCE_TAG, confidence.to_string().as_str())?;
+            write_simple_display_tag(writer, CONFIDENCE_TAG, confidence)?;
         }
 
         if let Some(methods) = &self.methods {
@@ -549,6 +591,7 @@ impl ToXml for Identity {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Identity {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -631,6 +674,7 @@ impl From<models::component::Methods> for Methods {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for Methods {
     fn write_xml_named_element<W: std::io::Write>(
         &self,
@@ -649,6 +693,7 @@ impl ToInnerXml for Methods {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Methods {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -658,6 +703,7 @@ impl ToXml for Methods {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Methods {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -722,6 +768,7 @@ impl From<models::component::Method> for Method {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Method {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -731,18 +778,19 @@ impl ToXml for Method {
 
         write_simple_tag(writer, TECHNIQUE_TAG, &self.technique)?;
 
-        write_simple_tag(writer, CONFIDENCE_TAG, self.confidence.to_string().as_str())?;
+        write_simple_display_tag(writer, CONFIDENCE_TAG, self.confidence)?;
 
         if let Some(value) = &self.value {
             write_simple_tag(writer, VALUE_TAG, value)?;
         }
 
-        write_close_tag(writer, METHODS_TAG)?;
+        write_close_tag(writer, METHOD_TAG)?;
 
         Ok(())
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Method {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -814,6 +862,7 @@ impl From<models::component::ToolsReferences> for ToolsReferences {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for ToolsReferences {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -837,6 +886,7 @@ impl ToXml for ToolsReferences {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ToolsReferences {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -877,6 +927,7 @@ impl FromXml for ToolsReferences {
 
 #[cfg(test)]
 pub(crate) mod test {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
@@ -952,6 +1003,7 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_occurrences() {
         let input = r#"
@@ -978,12 +1030,14 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_occurrences() {
         let xml_output = write_element_to_string(example_occurrences());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_frame() {
         let input = r#"
@@ -1019,6 +1073,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_callstack() {
         let input = r#"
@@ -1076,6 +1131,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_callstack() {
         let callstack = Callstack::new(Frames(vec![
@@ -1107,6 +1163,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_tools() {
         let input = r#"
@@ -1118,12 +1175,14 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_identity() {
         let xml_output = write_element_to_string(example_identity());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_identity() {
         let input = r#"
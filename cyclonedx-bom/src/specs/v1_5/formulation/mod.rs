@@ -1,20 +1,23 @@
 mod workflow;
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer;
 
+#[cfg(feature = "xml")]
 use crate::{
-    elem_tag,
-    errors::BomError,
-    get_elements,
-    models::formulation as models,
-    specs::common::property::Properties,
-    utilities::{convert_optional, convert_optional_vec, try_convert_optional},
+    elem_tag, get_elements,
     xml::{
         attribute_or_error, to_xml_write_error, write_close_tag, write_list_tag, FromXml, ToXml,
         VecXmlReader,
     },
 };
+use crate::{
+    errors::BomError,
+    models::formulation as models,
+    specs::common::property::Properties,
+    utilities::{convert_optional, convert_optional_vec, try_convert_optional},
+};
 
 use self::workflow::Workflow;
 
@@ -62,15 +65,23 @@ impl From<Formula> for models::Formula {
     }
 }
 
+#[cfg(feature = "xml")]
 const FORMULA_TAG: &str = "formula";
+#[cfg(feature = "xml")]
 const BOM_REF_ATTR: &str = "bom-ref";
+#[cfg(feature = "xml")]
 const COMPONENTS_TAG: &str = "components";
+#[cfg(feature = "xml")]
 const SERVICES_TAG: &str = "services";
+#[cfg(feature = "xml")]
 const WORKFLOWS_TAG: &str = "workflows";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 elem_tag!(WorkflowTag = "workflow");
 
+#[cfg(feature = "xml")]
 impl ToXml for Formula {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -97,6 +108,7 @@ impl ToXml for Formula {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Formula {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -128,10 +140,9 @@ impl FromXml for Formula {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        specs::common::component::v1_5::Component,
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::specs::common::component::v1_5::Component;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -211,12 +222,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_input = write_element_to_string(example_formula());
         insta::assert_snapshot!(xml_input);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -1,18 +1,24 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    specs::{common::property::Properties, v1_5::attachment::Attachment},
-    utilities::{convert_optional, convert_vec},
     xml::{
         read_simple_tag, to_xml_read_error, unexpected_element_error, write_close_tag,
         write_list_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{
+    models,
+    specs::{common::property::Properties, v1_5::attachment::Attachment},
+    utilities::{convert_optional, convert_vec},
+};
 
-use super::{resource_reference::ResourceReference, EnvironmentVars, ENVIRONMENT_VARS_TAG};
+#[cfg(feature = "xml")]
+use super::ENVIRONMENT_VARS_TAG;
+use super::{resource_reference::ResourceReference, EnvironmentVars};
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) struct Input {
@@ -86,14 +92,22 @@ impl From<Input> for models::formulation::workflow::input::Input {
     }
 }
 
+#[cfg(feature = "xml")]
 const INPUT_TAG: &str = "input";
+#[cfg(feature = "xml")]
 const RESOURCE_TAG: &str = "resource";
+#[cfg(feature = "xml")]
 const DATA_TAG: &str = "data";
+#[cfg(feature = "xml")]
 const PARAMETERS_TAG: &str = "parameters";
+#[cfg(feature = "xml")]
 const SOURCE_TAG: &str = "source";
+#[cfg(feature = "xml")]
 const TARGET_TAG: &str = "target";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl ToXml for Input {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -132,6 +146,7 @@ impl ToXml for Input {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Input {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -265,11 +280,16 @@ impl From<Parameter> for models::formulation::workflow::input::Parameter {
     }
 }
 
+#[cfg(feature = "xml")]
 const PARAMETER_TAG: &str = "parameter";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const VALUE_TAG: &str = "value";
+#[cfg(feature = "xml")]
 const DATA_TYPE_TAG: &str = "dataType";
 
+#[cfg(feature = "xml")]
 impl ToXml for Parameter {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -293,6 +313,7 @@ impl ToXml for Parameter {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Parameter {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -344,6 +365,7 @@ impl FromXml for Parameter {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
@@ -365,12 +387,14 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_input = write_element_to_string(example_input());
         insta::assert_snapshot!(xml_input);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
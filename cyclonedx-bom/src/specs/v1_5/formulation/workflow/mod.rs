@@ -5,13 +5,11 @@ mod step;
 mod trigger;
 mod workspace;
 
+#[cfg(feature = "xml")]
 use crate::{
     elem_tag,
     errors::XmlReadError,
     get_elements_lax,
-    models::formulation::workflow as models,
-    specs::common::{dependency::Dependency, property::Properties},
-    utilities::{convert_optional, convert_optional_vec},
     xml::{
         attribute_or_error, read_lax_validation_tag, read_simple_tag, to_xml_read_error,
         to_xml_write_error, unexpected_element_error, write_close_tag, write_list_tag,
@@ -19,6 +17,11 @@ use crate::{
         VecXmlReader,
     },
 };
+use crate::{
+    models::formulation::workflow as models,
+    specs::common::{dependency::Dependency, property::Properties},
+    utilities::{convert_optional, convert_optional_vec},
+};
 
 use self::{
     input::Input, output::Output, resource_reference::ResourceReferences, step::Step,
@@ -26,6 +29,7 @@ use self::{
 };
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer};
 
 /// bom-1.5.schema.json #definitions/workflow
@@ -123,34 +127,61 @@ impl From<Workflow> for models::Workflow {
     }
 }
 
+#[cfg(feature = "xml")]
 const WORKFLOW_TAG: &str = "workflow";
+#[cfg(feature = "xml")]
 const BOM_REF_ATTR: &str = "bom-ref";
+#[cfg(feature = "xml")]
 const UID_TAG: &str = "uid";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const DESCRIPTION_TAG: &str = "description";
+#[cfg(feature = "xml")]
 const RESOURCE_REFERENCES_TAG: &str = "resourceReferences";
+#[cfg(feature = "xml")]
 const TASKS_TAG: &str = "tasks";
+#[cfg(feature = "xml")]
 const TASK_DEPENDENCIES_TAG: &str = "taskDependencies";
+#[cfg(feature = "xml")]
 const TASK_TYPES_TAG: &str = "taskTypes";
+#[cfg(feature = "xml")]
 const TRIGGER_TAG: &str = "trigger";
+#[cfg(feature = "xml")]
 const STEPS_TAG: &str = "steps";
+#[cfg(feature = "xml")]
 const INPUTS_TAG: &str = "inputs";
+#[cfg(feature = "xml")]
 const OUTPUTS_TAG: &str = "outputs";
+#[cfg(feature = "xml")]
 const TIME_START_TAG: &str = "timeStart";
+#[cfg(feature = "xml")]
 const TIME_END_TAG: &str = "timeEnd";
+#[cfg(feature = "xml")]
 const WORKSPACES_TAG: &str = "workspaces";
+#[cfg(feature = "xml")]
 const RUNTIME_TOPOLOGY_TAG: &str = "runtimeTopology";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 elem_tag!(TaskTag = "task");
+#[cfg(feature = "xml")]
 elem_tag!(TaskTypeTag = "taskType");
+#[cfg(feature = "xml")]
 elem_tag!(TaskDependencyTag = "dependency");
+#[cfg(feature = "xml")]
 elem_tag!(StepTag = "step");
+#[cfg(feature = "xml")]
 elem_tag!(InputTag = "input");
+#[cfg(feature = "xml")]
 elem_tag!(OutputTag = "output");
+#[cfg(feature = "xml")]
 elem_tag!(WorkspaceTag = "workspace");
+#[cfg(feature = "xml")]
 elem_tag!(DependencyTag = "dependency");
 
+#[cfg(feature = "xml")]
 impl ToXml for Workflow {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -198,6 +229,7 @@ impl ToXml for Workflow {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Workflow {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -256,6 +288,7 @@ impl FromXml for Workflow {
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) struct TaskType(String);
 
+#[cfg(feature = "xml")]
 impl ToXml for TaskType {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -265,6 +298,7 @@ impl ToXml for TaskType {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for TaskType {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -364,6 +398,7 @@ impl From<Task> for models::Task {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Task {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -400,10 +435,11 @@ impl ToXml for Task {
         }
         self.properties.write_xml_element(writer)?;
 
-        write_close_tag(writer, WORKFLOW_TAG)
+        write_close_tag(writer, TaskTag::VALUE)
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Task {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -458,8 +494,10 @@ impl FromXml for Task {
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) struct EnvironmentVars(pub(crate) Vec<EnvironmentVar>);
 
+#[cfg(feature = "xml")]
 const ENVIRONMENT_VARS_TAG: &str = "environmentVars";
 
+#[cfg(feature = "xml")]
 impl ToXml for EnvironmentVars {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -475,6 +513,7 @@ impl ToXml for EnvironmentVars {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for EnvironmentVars {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -543,10 +582,14 @@ impl From<EnvironmentVar> for models::EnvironmentVar {
     }
 }
 
+#[cfg(feature = "xml")]
 const ENVIRONMENT_VAR_TAG: &str = "environmentVar";
+#[cfg(feature = "xml")]
 const VALUE_TAG: &str = "value";
+#[cfg(feature = "xml")]
 const NAME_ATTR: &str = "name";
 
+#[cfg(feature = "xml")]
 impl ToXml for EnvironmentVar {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -575,10 +618,9 @@ impl ToXml for EnvironmentVar {
 
 #[cfg(test)]
 mod test {
-    use crate::{
-        specs::{common::property::Property, v1_5::attachment::Attachment},
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::specs::{common::property::Property, v1_5::attachment::Attachment};
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use self::{
         input::{self, Input, RequiredInputField},
@@ -879,12 +921,14 @@ mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_workflow());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
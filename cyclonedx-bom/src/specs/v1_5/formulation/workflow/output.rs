@@ -1,18 +1,24 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    specs::{common::property::Properties, v1_5::attachment::Attachment},
-    utilities::{convert_optional, convert_vec},
     xml::{
         read_simple_tag, to_xml_read_error, unexpected_element_error, write_close_tag,
         write_simple_option_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{
+    models,
+    specs::{common::property::Properties, v1_5::attachment::Attachment},
+    utilities::{convert_optional, convert_vec},
+};
 
-use super::{resource_reference::ResourceReference, EnvironmentVars, ENVIRONMENT_VARS_TAG};
+#[cfg(feature = "xml")]
+use super::ENVIRONMENT_VARS_TAG;
+use super::{resource_reference::ResourceReference, EnvironmentVars};
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) struct Output {
     #[serde(flatten)]
@@ -81,14 +87,22 @@ impl From<Output> for models::formulation::workflow::output::Output {
     }
 }
 
+#[cfg(feature = "xml")]
 const OUTPUT_TAG: &str = "output";
+#[cfg(feature = "xml")]
 const RESOURCE_TAG: &str = "resource";
+#[cfg(feature = "xml")]
 const DATA_TAG: &str = "data";
+#[cfg(feature = "xml")]
 const TYPE_TAG: &str = "type";
+#[cfg(feature = "xml")]
 const SOURCE_TAG: &str = "source";
+#[cfg(feature = "xml")]
 const TARGET_TAG: &str = "target";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl ToXml for Output {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -126,6 +140,7 @@ impl ToXml for Output {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Output {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -232,6 +247,7 @@ pub(crate) enum RequiredOutputField {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
@@ -254,12 +270,14 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_output());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -1,21 +1,24 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    specs::v1_5::external_reference::ExternalReference,
     xml::{
         read_lax_validation_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
         write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{models, specs::v1_5::external_reference::ExternalReference};
 
 #[derive(Debug, PartialEq, Serialize, Deserialize, Default)]
 pub(crate) struct ResourceReferences(pub(crate) Vec<ResourceReference>);
 
+#[cfg(feature = "xml")]
 const RESOURCE_REFERENCES_TAG: &str = "resourceReferences";
 
+#[cfg(feature = "xml")]
 impl ToXml for ResourceReferences {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -33,6 +36,7 @@ impl ToXml for ResourceReferences {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ResourceReferences {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -111,10 +115,14 @@ impl From<ResourceReference>
     }
 }
 
+#[cfg(feature = "xml")]
 const RESOURCE_REFERENCE_TAG: &str = "resourceReference";
+#[cfg(feature = "xml")]
 const REF_TAG: &str = "ref";
+#[cfg(feature = "xml")]
 const EXTERNAL_REFERENCE_TAG: &str = "externalReference";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for ResourceReference {
     fn write_xml_named_element<W: std::io::prelude::Write>(
         &self,
@@ -136,6 +144,7 @@ impl ToInnerXml for ResourceReference {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ResourceReference {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -196,6 +205,7 @@ impl FromXml for ResourceReference {
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
@@ -206,12 +216,14 @@ mod test {
         }])
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_resource_references());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
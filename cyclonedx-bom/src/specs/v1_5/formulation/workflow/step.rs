@@ -1,15 +1,17 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+#[cfg(feature = "xml")]
+use crate::xml::{
+    read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+    unexpected_element_error, write_close_tag, write_list_tag, write_simple_option_tag,
+    write_start_tag, FromXml, ToXml,
+};
 use crate::{
     models::formulation::workflow::step as models,
     specs::common::property::Properties,
     utilities::{convert_optional, convert_optional_vec},
-    xml::{
-        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-        unexpected_element_error, write_close_tag, write_list_tag, write_simple_option_tag,
-        write_start_tag, FromXml, ToXml,
-    },
 };
 
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
@@ -46,11 +48,16 @@ impl From<Step> for models::Step {
     }
 }
 
+#[cfg(feature = "xml")]
 const COMMANDS_TAG: &str = "commands";
+#[cfg(feature = "xml")]
 const STEP_TAG: &str = "step";
+#[cfg(feature = "xml")]
 const DESCRIPTION_TAG: &str = "description";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
 
+#[cfg(feature = "xml")]
 impl ToXml for Step {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -72,6 +79,7 @@ impl ToXml for Step {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Step {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -155,10 +163,14 @@ impl From<Command> for models::Command {
     }
 }
 
+#[cfg(feature = "xml")]
 const COMMAND_TAG: &str = "command";
+#[cfg(feature = "xml")]
 const EXECUTED_TAG: &str = "executed";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl ToXml for Command {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -174,6 +186,7 @@ impl ToXml for Command {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Command {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -223,10 +236,9 @@ impl FromXml for Command {
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        specs::common::property::Property,
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::specs::common::property::Property;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -248,12 +260,14 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_step());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -1,11 +1,10 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{reader, writer};
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models::formulation::workflow::trigger as models,
-    specs::{common::property::Properties, v1_5::attachment::Attachment},
-    utilities::{convert_optional, convert_optional_vec},
     xml::{
         optional_attribute, read_lax_validation_tag, read_list_tag, read_simple_tag,
         to_xml_read_error, to_xml_write_error, unexpected_element_error, write_close_tag,
@@ -13,6 +12,11 @@ use crate::{
         ToInnerXml, ToXml,
     },
 };
+use crate::{
+    models::formulation::workflow::trigger as models,
+    specs::{common::property::Properties, v1_5::attachment::Attachment},
+    utilities::{convert_optional, convert_optional_vec},
+};
 
 use super::{
     input::Input,
@@ -86,18 +90,30 @@ impl From<Trigger> for models::Trigger {
     }
 }
 
+#[cfg(feature = "xml")]
 const TRIGGER_TAG: &str = "trigger";
+#[cfg(feature = "xml")]
 const BOM_REF_ATTR: &str = "bom-ref";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const RESOURCE_REFERENCES_TAG: &str = "resourceReferences";
+#[cfg(feature = "xml")]
 const TYPE_TAG: &str = "type";
+#[cfg(feature = "xml")]
 const TIME_ACTIVATED_TAG: &str = "timeActivated";
+#[cfg(feature = "xml")]
 const CONDITIONS_TAG: &str = "conditions";
+#[cfg(feature = "xml")]
 const INPUTS_TAG: &str = "inputs";
+#[cfg(feature = "xml")]
 const INPUT_TAG: &str = "input";
+#[cfg(feature = "xml")]
 const OUTPUTS_TAG: &str = "outputs";
+#[cfg(feature = "xml")]
 const OUTPUT_TAG: &str = "output";
 
+#[cfg(feature = "xml")]
 impl ToXml for Trigger {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -129,6 +145,7 @@ impl ToXml for Trigger {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Trigger {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -280,14 +297,22 @@ impl From<Event> for models::Event {
     }
 }
 
+#[cfg(feature = "xml")]
 const EVENT_TAG: &str = "event";
+#[cfg(feature = "xml")]
 const UID_TAG: &str = "uid";
+#[cfg(feature = "xml")]
 const DESCRIPTION_TAG: &str = "description";
+#[cfg(feature = "xml")]
 const TIME_RECEIVED_TAG: &str = "timeReceived";
+#[cfg(feature = "xml")]
 const DATA_TAG: &str = "data";
+#[cfg(feature = "xml")]
 const SOURCE_TAG: &str = "source";
+#[cfg(feature = "xml")]
 const TARGET_TAG: &str = "target";
 
+#[cfg(feature = "xml")]
 impl ToXml for Event {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -307,6 +332,7 @@ impl ToXml for Event {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Event {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -421,10 +447,14 @@ impl From<Condition> for models::Condition {
     }
 }
 
+#[cfg(feature = "xml")]
 const CONDITION_TAG: &str = "condition";
+#[cfg(feature = "xml")]
 const EXPRESSION_TAG: &str = "expression";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl ToXml for Condition {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -440,6 +470,7 @@ impl ToXml for Condition {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Condition {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -494,16 +525,15 @@ impl FromXml for Condition {
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        specs::{
-            common::property::Property,
-            v1_5::formulation::workflow::{
-                input::{Input, RequiredInputField},
-                output::{Output, RequiredOutputField},
-            },
+    use crate::specs::{
+        common::property::Property,
+        v1_5::formulation::workflow::{
+            input::{Input, RequiredInputField},
+            output::{Output, RequiredOutputField},
         },
-        xml::test::{read_element_from_string, write_element_to_string},
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -582,12 +612,14 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_trigger());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
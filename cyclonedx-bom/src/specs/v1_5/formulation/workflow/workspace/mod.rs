@@ -1,19 +1,24 @@
 pub(crate) mod volume;
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::writer;
 
+#[cfg(feature = "xml")]
 use crate::{
     elem_tag,
     errors::XmlReadError,
-    get_elements_lax, models,
-    specs::common::property::Properties,
-    utilities::{convert_optional, convert_optional_vec},
+    get_elements_lax,
     xml::{
         attribute_or_error, to_xml_write_error, write_close_tag, write_list_string_tag,
         write_simple_option_tag, write_simple_tag, FromXml, ToXml, VecElemTag, VecXmlReader,
     },
 };
+use crate::{
+    models,
+    specs::common::property::Properties,
+    utilities::{convert_optional, convert_optional_vec},
+};
 
 use super::resource_reference::ResourceReferences;
 use volume::Volume;
@@ -89,21 +94,36 @@ impl From<Workspace> for models::formulation::workflow::workspace::Workspace {
     }
 }
 
+#[cfg(feature = "xml")]
 const WORKSPACE_TAG: &str = "workspace";
+#[cfg(feature = "xml")]
 const BOM_REF_ATTR: &str = "bom-ref";
+#[cfg(feature = "xml")]
 const UID_TAG: &str = "uid";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const ALIASES_TAG: &str = "aliases";
+#[cfg(feature = "xml")]
 const DESCRIPTION_TAG: &str = "description";
+#[cfg(feature = "xml")]
 const RESOURCE_REFERENCES_TAG: &str = "resourceReferences";
+#[cfg(feature = "xml")]
 const ACCESS_MODE_TAG: &str = "accessMode";
+#[cfg(feature = "xml")]
 const MOUNT_PATH_TAG: &str = "mountPath";
+#[cfg(feature = "xml")]
 const MANAGED_DATA_TYPE_TAG: &str = "managedDataType";
+#[cfg(feature = "xml")]
 const VOLUME_REQUEST_TAG: &str = "volumeRequest";
+#[cfg(feature = "xml")]
 const VOLUME_TAG: &str = "volume";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
+#[cfg(feature = "xml")]
 elem_tag!(AliasTag = "alias");
 
+#[cfg(feature = "xml")]
 impl ToXml for Workspace {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -133,6 +153,7 @@ impl ToXml for Workspace {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Workspace {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -178,10 +199,9 @@ impl FromXml for Workspace {
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        specs::v1_5::formulation::workflow::resource_reference::ResourceReference,
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::specs::v1_5::formulation::workflow::resource_reference::ResourceReference;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
 
@@ -213,12 +233,14 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_workspace());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "xml")]
 use crate::{
-    get_elements_lax, models,
-    specs::common::property::Properties,
-    utilities::convert_optional,
-    xml::{write_close_tag, write_simple_tag, write_start_tag, FromXml, ToXml},
+    get_elements_lax,
+    xml::{
+        write_close_tag, write_simple_display_tag, write_simple_tag, write_start_tag, FromXml,
+        ToXml,
+    },
 };
+use crate::{models, specs::common::property::Properties, utilities::convert_optional};
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) struct Volume {
@@ -60,16 +63,26 @@ impl From<Volume> for models::formulation::workflow::workspace::Volume {
     }
 }
 
+#[cfg(feature = "xml")]
 const VOLUME_TAG: &str = "volume";
+#[cfg(feature = "xml")]
 const UID_TAG: &str = "uid";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
+#[cfg(feature = "xml")]
 const MODE_TAG: &str = "mode";
+#[cfg(feature = "xml")]
 const PATH_TAG: &str = "path";
+#[cfg(feature = "xml")]
 const SIZE_ALLOCATED_TAG: &str = "sizeAllocated";
+#[cfg(feature = "xml")]
 const PERSISTENT_TAG: &str = "persistent";
+#[cfg(feature = "xml")]
 const REMOTE_TAG: &str = "remote";
+#[cfg(feature = "xml")]
 const PROPERTIES_TAG: &str = "properties";
 
+#[cfg(feature = "xml")]
 impl ToXml for Volume {
     fn write_xml_element<W: std::io::prelude::Write>(
         &self,
@@ -98,11 +111,11 @@ impl ToXml for Volume {
         }
 
         if let Some(persistent) = &self.persistent {
-            write_simple_tag(writer, PERSISTENT_TAG, &persistent.to_string())?
+            write_simple_display_tag(writer, PERSISTENT_TAG, persistent)?
         }
 
         if let Some(remote) = &self.remote {
-            write_simple_tag(writer, REMOTE_TAG, &remote.to_string())?
+            write_simple_display_tag(writer, REMOTE_TAG, remote)?
         }
 
         if let Some(properties) = &self.properties {
@@ -115,6 +128,7 @@ impl ToXml for Volume {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Volume {
     fn read_xml_element<R: std::io::prelude::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -151,6 +165,7 @@ impl FromXml for Volume {
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "xml")]
     use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::*;
@@ -168,12 +183,14 @@ mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_full() {
         let xml_output = write_element_to_string(example_volume());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_full() {
         let input = r#"
@@ -17,19 +17,23 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
+    xml::{
+        read_list_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
+        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use crate::{
     external_models::date_time::DateTime,
     models,
     prelude::NormalizedString,
     specs::common::organization::{OrganizationalContact, OrganizationalEntity},
     utilities::convert_optional,
-    xml::{
-        read_list_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
-        write_close_tag, write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
-    },
 };
 
 /// Represents Licensing Information.
@@ -75,7 +79,7 @@ impl From<models::license::Licensing> for Licensing {
         Self {
             alt_ids: other
                 .alt_ids
-                .map(|ids| ids.into_iter().map(|id| id.0).collect()),
+                .map(|ids| ids.into_iter().map(String::from).collect()),
             licensor: other.licensor.map(From::from),
             licensee: other.licensee.map(From::from),
             purchaser: other.purchaser.map(From::from),
@@ -114,9 +118,12 @@ impl From<models::license::LicenseContact> for LicenseContact {
     }
 }
 
+#[cfg(feature = "xml")]
 const ORGANIZATION_TAG: &str = "organization";
+#[cfg(feature = "xml")]
 const INDIVIDUAL_TAG: &str = "individual";
 
+#[cfg(feature = "xml")]
 impl ToInnerXml for LicenseContact {
     fn write_xml_named_element<W: std::io::prelude::Write>(
         &self,
@@ -131,6 +138,7 @@ impl ToInnerXml for LicenseContact {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for LicenseContact {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -147,6 +155,7 @@ impl ToXml for LicenseContact {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for LicenseContact {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -200,18 +209,30 @@ impl FromXml for LicenseContact {
     }
 }
 
+#[cfg(feature = "xml")]
 const LICENSING_TAG: &str = "licensing";
+#[cfg(feature = "xml")]
 const ALT_IDS_TAG: &str = "altIds";
+#[cfg(feature = "xml")]
 const ALT_ID_TAG: &str = "altId";
+#[cfg(feature = "xml")]
 const LICENSOR_TAG: &str = "licensor";
+#[cfg(feature = "xml")]
 const LICENSEE_TAG: &str = "licensee";
+#[cfg(feature = "xml")]
 const PURCHASER_TAG: &str = "purchaser";
+#[cfg(feature = "xml")]
 const PURCHASE_ORDER_TAG: &str = "purchaseOrder";
+#[cfg(feature = "xml")]
 const LICENSE_TYPES_TAG: &str = "licenseTypes";
+#[cfg(feature = "xml")]
 const LICENSE_TYPE_TAG: &str = "licenseType";
+#[cfg(feature = "xml")]
 const LAST_RENEWAL_TAG: &str = "lastRenewal";
+#[cfg(feature = "xml")]
 const EXPIRATION_TAG: &str = "expiration";
 
+#[cfg(feature = "xml")]
 impl ToXml for Licensing {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -269,6 +290,7 @@ impl ToXml for Licensing {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Licensing {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -373,11 +395,10 @@ impl FromXml for Licensing {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        prelude::{DateTime, NormalizedString},
-        xml::test::{
-            read_element_from_string, write_element_to_string, write_named_element_to_string,
-        },
+    use crate::prelude::{DateTime, NormalizedString};
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{
+        read_element_from_string, write_element_to_string, write_named_element_to_string,
     };
 
     use super::*;
@@ -465,6 +486,7 @@ pub(crate) mod test {
         })
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_license_contact() {
         let input = r#"
@@ -493,6 +515,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_named_license_contact() {
         let expected = LicenseContact::Organization(OrganizationalEntity {
@@ -510,12 +533,14 @@ pub(crate) mod test {
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_licensing() {
         let xml_output = write_element_to_string(example_licensing());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_licensing() {
         let input = r#"
@@ -17,18 +17,18 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::{name::OwnedName, reader};
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    prelude::NormalizedString,
-    utilities::convert_vec,
     xml::{
         read_list_tag, read_simple_tag, to_xml_read_error, unexpected_element_error,
         write_close_tag, write_simple_tag, write_start_tag, FromXml, ToXml,
     },
 };
+use crate::{models, prelude::NormalizedString, utilities::convert_vec};
 
 /// Represents a list of `Lifecycle`.
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -47,8 +47,10 @@ impl From<Lifecycles> for models::lifecycle::Lifecycles {
     }
 }
 
+#[cfg(feature = "xml")]
 const LIFECYCLES_TAG: &str = "lifecycles";
 
+#[cfg(feature = "xml")]
 impl ToXml for Lifecycles {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -66,11 +68,16 @@ impl ToXml for Lifecycles {
     }
 }
 
+#[cfg(feature = "xml")]
 const LIFECYCLE_TAG: &str = "lifecycle";
+#[cfg(feature = "xml")]
 const PHASE_TAG: &str = "phase";
+#[cfg(feature = "xml")]
 const DESCRIPTION_TAG: &str = "description";
+#[cfg(feature = "xml")]
 const NAME_TAG: &str = "name";
 
+#[cfg(feature = "xml")]
 impl FromXml for Lifecycles {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -111,6 +118,7 @@ impl From<Lifecycle> for models::lifecycle::Lifecycle {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Lifecycle {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -136,6 +144,7 @@ impl ToXml for Lifecycle {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Lifecycle {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -242,10 +251,9 @@ impl From<Description> for models::lifecycle::Description {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use crate::{
-        models,
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    use crate::models;
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
     use super::{Description, Lifecycle, Lifecycles, Phase};
 
@@ -288,6 +296,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_empty_lifecycles() {
         let input = r#"
@@ -300,6 +309,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_full_xml_with_multiple_entries() {
         let input = r#"
@@ -331,6 +341,7 @@ pub(crate) mod test {
         assert_eq!(actual, expected);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_multiple_lifecycles() {
         let lifecycles = vec![
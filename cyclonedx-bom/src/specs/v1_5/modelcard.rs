@@ -17,23 +17,23 @@
  */
 
 use serde::{Deserialize, Serialize};
-use xml::{
-    name::OwnedName,
-    reader::{self},
-    writer,
-};
+#[cfg(feature = "xml")]
+use xml::{name::OwnedName, reader, writer};
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    models,
-    specs::common::property::Properties,
-    utilities::{convert_optional, convert_vec},
     xml::{
         optional_attribute, read_simple_tag, to_xml_read_error, to_xml_write_error,
         unexpected_element_error, write_close_tag, write_simple_tag, write_start_tag, FromXml,
         ToInnerXml, ToXml,
     },
 };
+use crate::{
+    models,
+    specs::common::property::Properties,
+    utilities::{convert_optional, convert_vec},
+};
 
 use super::component_data::{ComponentData, GraphicsCollection};
 
@@ -76,10 +76,14 @@ impl From<ModelCard> for models::modelcard::ModelCard {
     }
 }
 
+#[cfg(feature = "xml")]
 const MODEL_CARD: &str = "modelCard";
+#[cfg(feature = "xml")]
 const MODEL_PARAMETERS_TAG: &str = "modelParameters";
+#[cfg(feature = "xml")]
 const BOM_REF_ATTR: &str = "bom-ref";
 
+#[cfg(feature = "xml")]
 impl ToXml for ModelCard {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -115,6 +119,7 @@ impl ToXml for ModelCard {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ModelCard {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -220,16 +225,26 @@ impl From<ModelParameters> for models::modelcard::ModelParameters {
     }
 }
 
+#[cfg(feature = "xml")]
 const APPROACH_TAG: &str = "approach";
+#[cfg(feature = "xml")]
 const TASK_TAG: &str = "task";
+#[cfg(feature = "xml")]
 const ARCHITECTURE_FAMILY_TAG: &str = "architectureFamily";
+#[cfg(feature = "xml")]
 const MODEL_ARCHITECTURE_TAG: &str = "modelArchitecture";
+#[cfg(feature = "xml")]
 const INPUTS_TAG: &str = "inputs";
+#[cfg(feature = "xml")]
 const INPUT_TAG: &str = "input";
+#[cfg(feature = "xml")]
 const OUTPUTS_TAG: &str = "outputs";
+#[cfg(feature = "xml")]
 const OUTPUT_TAG: &str = "output";
+#[cfg(feature = "xml")]
 const FORMAT_TAG: &str = "format";
 
+#[cfg(feature = "xml")]
 impl ToXml for ModelParameters {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -271,6 +286,7 @@ impl ToXml for ModelParameters {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ModelParameters {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -388,8 +404,10 @@ impl From<ModelParametersApproach> for models::modelcard::ModelParametersApproac
     }
 }
 
+#[cfg(feature = "xml")]
 const TYPE_TAG: &str = "type";
 
+#[cfg(feature = "xml")]
 impl ToXml for ModelParametersApproach {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -406,6 +424,7 @@ impl ToXml for ModelParametersApproach {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ModelParametersApproach {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -456,6 +475,7 @@ impl From<Datasets> for models::modelcard::Datasets {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Datasets {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -473,6 +493,7 @@ impl ToXml for Datasets {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Datasets {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -537,11 +558,16 @@ impl From<Dataset> for models::modelcard::Dataset {
     }
 }
 
+#[cfg(feature = "xml")]
 const DATASETS_TAG: &str = "datasets";
+#[cfg(feature = "xml")]
 const DATASET_TAG: &str = "dataset";
+#[cfg(feature = "xml")]
 const GRAPHICS_TAG: &str = "graphics";
+#[cfg(feature = "xml")]
 const REF_TAG: &str = "ref";
 
+#[cfg(feature = "xml")]
 impl ToXml for Dataset {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -562,6 +588,7 @@ impl ToXml for Dataset {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Dataset {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -606,10 +633,14 @@ impl From<QuantitativeAnalysis> for models::modelcard::QuantitativeAnalysis {
     }
 }
 
+#[cfg(feature = "xml")]
 const QUANTITATIVE_ANALYSIS_TAG: &str = "quantitativeAnalysis";
+#[cfg(feature = "xml")]
 const PERFORMANCE_METRICS_TAG: &str = "performanceMetrics";
+#[cfg(feature = "xml")]
 const PERFORMANCE_METRIC_TAG: &str = "performanceMetric";
 
+#[cfg(feature = "xml")]
 impl ToXml for QuantitativeAnalysis {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -631,6 +662,7 @@ impl ToXml for QuantitativeAnalysis {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for QuantitativeAnalysis {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -701,6 +733,7 @@ impl From<models::modelcard::PerformanceMetrics> for PerformanceMetrics {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for PerformanceMetrics {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -718,6 +751,7 @@ impl ToXml for PerformanceMetrics {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for PerformanceMetrics {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -792,10 +826,14 @@ impl From<models::modelcard::PerformanceMetric> for PerformanceMetric {
     }
 }
 
+#[cfg(feature = "xml")]
 const VALUE_TAG: &str = "value";
+#[cfg(feature = "xml")]
 const SLICE_TAG: &str = "slice";
+#[cfg(feature = "xml")]
 const CONFIDENCE_INTERVAL_TAG: &str = "confidenceInterval";
 
+#[cfg(feature = "xml")]
 impl ToXml for PerformanceMetric {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -825,6 +863,7 @@ impl ToXml for PerformanceMetric {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for PerformanceMetric {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -912,9 +951,12 @@ impl From<models::modelcard::ConfidenceInterval> for ConfidenceInterval {
     }
 }
 
+#[cfg(feature = "xml")]
 const LOWER_BOUND_TAG: &str = "lowerBound";
+#[cfg(feature = "xml")]
 const UPPER_BOUND_TAG: &str = "upperBound";
 
+#[cfg(feature = "xml")]
 impl ToXml for ConfidenceInterval {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -936,6 +978,7 @@ impl ToXml for ConfidenceInterval {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ConfidenceInterval {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -998,8 +1041,10 @@ impl From<Considerations> for models::modelcard::Considerations {
     }
 }
 
+#[cfg(feature = "xml")]
 const CONSIDERATIONS_TAG: &str = "considerations";
 
+#[cfg(feature = "xml")]
 impl ToXml for Considerations {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -1030,6 +1075,7 @@ impl From<Inputs> for models::modelcard::Inputs {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Inputs {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -1049,6 +1095,7 @@ impl ToXml for Inputs {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Inputs {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -1102,6 +1149,7 @@ impl From<Outputs> for models::modelcard::Outputs {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for Outputs {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -1121,6 +1169,7 @@ impl ToXml for Outputs {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for Outputs {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -1190,6 +1239,7 @@ impl From<MLParameter> for models::modelcard::MLParameter {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for MLParameter {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -1203,6 +1253,7 @@ impl ToXml for MLParameter {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for MLParameter {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -1238,6 +1289,10 @@ impl FromXml for MLParameter {
 pub(crate) mod test {
     use pretty_assertions::assert_eq;
 
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{
+        read_element_from_string, write_element_to_string, write_named_element_to_string,
+    };
     use crate::{
         models,
         prelude::{NormalizedString, Uri},
@@ -1255,9 +1310,6 @@ pub(crate) mod test {
                 },
             },
         },
-        xml::test::{
-            read_element_from_string, write_element_to_string, write_named_element_to_string,
-        },
     };
 
     pub(crate) fn example_modelcard() -> ModelCard {
@@ -1421,19 +1473,23 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_model_card() {
         let xml_output = write_element_to_string(example_modelcard());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_data_governance() {
+        #[cfg(feature = "xml")]
         const GOVERNANCE_TAG: &str = "governance";
         let xml_output = write_named_element_to_string(example_governance(), GOVERNANCE_TAG);
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_model_parameters() {
         let xml_output = write_element_to_string(example_model_parameters());
@@ -1456,6 +1512,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_quantitative_analysis() {
         let input = r#"
@@ -1511,6 +1568,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_image_attachment() {
         let input = r#"
@@ -1525,6 +1583,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_graphic() {
         let input = r#"
@@ -1547,6 +1606,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_graphics() {
         let input = r#"
@@ -1581,6 +1641,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_ml_parameter() {
         let input = r#"
@@ -1593,6 +1654,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_parse_xml_inputs() {
         let input = r#"
@@ -1610,6 +1672,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_governance() {
         let input = r#"
@@ -1649,6 +1712,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_dataset() {
         let input = r#"
@@ -1729,6 +1793,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_model_parameters_approach() {
         let input = r#"
@@ -1743,6 +1808,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_model_parameters() {
         let input = r#"
@@ -1770,6 +1836,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_model_card() {
         let input = r#"
@@ -1835,6 +1902,7 @@ pub(crate) mod test {
         assert_eq!(expected, actual);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_bom_ref_attribute_in_modelcard() {
         let input = r#"
@@ -17,15 +17,17 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+#[cfg(feature = "xml")]
+use crate::xml::{
+    read_list_tag, read_simple_tag, to_xml_read_error, write_close_tag, write_simple_tag,
+    write_start_tag, FromXml, ToInnerXml, ToXml,
+};
 use crate::{
     models,
     utilities::{convert_optional, convert_vec},
-    xml::{
-        read_list_tag, read_simple_tag, to_xml_read_error, write_close_tag, write_simple_tag,
-        write_start_tag, FromXml, ToInnerXml, ToXml,
-    },
 };
 
 /// Represents the `ProofOfConcept` field.
@@ -61,12 +63,18 @@ impl From<models::vulnerability::VulnerabilityProofOfConcept> for ProofOfConcept
     }
 }
 
+#[cfg(feature = "xml")]
 const PROOF_OF_CONCEPT_TAG: &str = "proofOfConcept";
+#[cfg(feature = "xml")]
 const REPRODUCTION_STEPS_TAG: &str = "reproductionSteps";
+#[cfg(feature = "xml")]
 const ENVIRONMENT_TAG: &str = "environment";
+#[cfg(feature = "xml")]
 const SUPPORTING_MATERIAL_TAG: &str = "supportingMaterial";
+#[cfg(feature = "xml")]
 const ATTACHMENT_TAG: &str = "attachment";
 
+#[cfg(feature = "xml")]
 impl ToXml for ProofOfConcept {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -98,6 +106,7 @@ impl ToXml for ProofOfConcept {
     }
 }
 
+#[cfg(feature = "xml")]
 impl FromXml for ProofOfConcept {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -154,11 +163,9 @@ impl FromXml for ProofOfConcept {
 pub(crate) mod test {
     use pretty_assertions::assert_eq;
 
-    use crate::{
-        models,
-        specs::v1_5::attachment::Attachment,
-        xml::test::{read_element_from_string, write_element_to_string},
-    };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
+    use crate::{models, specs::v1_5::attachment::Attachment};
 
     use super::ProofOfConcept;
 
@@ -187,12 +194,14 @@ pub(crate) mod test {
         }
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml() {
         let xml_output = write_element_to_string(example_proof_of_concept());
         insta::assert_snapshot!(xml_output);
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml() {
         let input = r#"
@@ -17,18 +17,18 @@
  */
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "xml")]
 use xml::reader;
 
+#[cfg(feature = "xml")]
 use crate::{
     errors::XmlReadError,
-    external_models::uri::Uri,
-    prelude::NormalizedString,
-    utilities::convert_optional,
     xml::{
         optional_attribute, read_list_tag, to_xml_read_error, to_xml_write_error, write_close_tag,
         write_simple_tag, write_start_tag, FromXml, ToInnerXml, ToXml,
     },
 };
+use crate::{external_models::uri::Uri, prelude::NormalizedString, utilities::convert_optional};
 
 use crate::models;
 use crate::specs::v1_5::{data_governance::DataGovernance, service::DataClassification};
@@ -53,8 +53,8 @@ pub(crate) struct ServiceData {
 impl From<models::service::ServiceData> for ServiceData {
     fn from(other: models::service::ServiceData) -> Self {
         Self {
-            name: other.name.map(|n| n.0),
-            description: other.description.map(|d| d.0),
+            name: other.name.map(String::from),
+            description: other.description.map(String::from),
             classification: other.classification.into(),
             governance: convert_optional(other.governance),
             source: other
@@ -82,15 +82,24 @@ impl From<ServiceData> for models::service::ServiceData {
     }
 }
 
+#[cfg(feature = "xml")]
 const NAME_ATTR: &str = "name";
+#[cfg(feature = "xml")]
 const DESCRIPTION_ATTR: &str = "description";
+#[cfg(feature = "xml")]
 const DATAFLOW_TAG: &str = "dataflow";
+#[cfg(feature = "xml")]
 const CLASSIFICATION_TAG: &str = "classification";
+#[cfg(feature = "xml")]
 const GOVERNANCE_TAG: &str = "governance";
+#[cfg(feature = "xml")]
 const SOURCE_TAG: &str = "source";
+#[cfg(feature = "xml")]
 const DESTINATION_TAG: &str = "destination";
+#[cfg(feature = "xml")]
 const URL_TAG: &str = "url";
 
+#[cfg(feature = "xml")]
 impl FromXml for ServiceData {
     fn read_xml_element<R: std::io::Read>(
         event_reader: &mut xml::EventReader<R>,
@@ -167,6 +176,7 @@ impl FromXml for ServiceData {
     }
 }
 
+#[cfg(feature = "xml")]
 impl ToXml for ServiceData {
     fn write_xml_element<W: std::io::Write>(
         &self,
@@ -179,7 +189,7 @@ impl ToXml for ServiceData {
         }
 
         if let Some(description) = &self.description {
-            start_tag = start_tag.attr(NAME_ATTR, description);
+            start_tag = start_tag.attr(DESCRIPTION_ATTR, description);
         }
 
         writer
@@ -218,20 +228,20 @@ impl ToXml for ServiceData {
 pub(crate) mod test {
     use pretty_assertions::assert_eq;
 
-    use crate::{
-        specs::{
-            common::{
-                organization::{OrganizationalContact, OrganizationalEntity},
-                service::v1_5::{Data, DataClassification},
-            },
-            v1_5::{
-                data_governance::{DataGovernance, DataGovernanceResponsibleParty},
-                service_data::ServiceData,
-            },
+    use crate::specs::{
+        common::{
+            organization::{OrganizationalContact, OrganizationalEntity},
+            service::v1_5::{Data, DataClassification},
+        },
+        v1_5::{
+            data_governance::{DataGovernance, DataGovernanceResponsibleParty},
+            service_data::ServiceData,
         },
-        xml::test::{read_element_from_string, write_element_to_string},
     };
+    #[cfg(feature = "xml")]
+    use crate::xml::test::{read_element_from_string, write_element_to_string};
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_write_xml_15_service_data() {
         let actual = Data::ServiceData(vec![ServiceData {
@@ -259,6 +269,7 @@ pub(crate) mod test {
         insta::assert_snapshot!(write_element_to_string(actual));
     }
 
+    #[cfg(feature = "xml")]
     #[test]
     fn it_should_read_xml_service_data() {
         let input = r#"
@@ -27,6 +27,25 @@ where
     value.map(B::try_from).transpose().map_err(BomError::from)
 }
 
+/// Converts an optional boxed model struct into its specification struct.
+pub(crate) fn convert_optional_boxed<A, B: From<A>>(value: Option<Box<A>>) -> Option<B> {
+    value.map(|value| B::from(*value))
+}
+
+pub(crate) fn try_convert_optional_boxed<A, B: TryFrom<A>>(
+    value: Option<Box<A>>,
+) -> Result<Option<B>, BomError>
+where
+    BomError: From<B::Error>,
+{
+    try_convert_optional(value.map(|value| *value))
+}
+
+/// Converts an optional specification struct into its boxed model struct.
+pub(crate) fn convert_optional_into_boxed<A, B: From<A>>(value: Option<A>) -> Option<Box<B>> {
+    value.map(|value| Box::new(B::from(value)))
+}
+
 pub(crate) fn convert_vec<A, B: From<A>>(value: Vec<A>) -> Vec<B> {
     value.into_iter().map(std::convert::Into::into).collect()
 }
@@ -26,7 +26,7 @@ use indexmap::{
     IndexMap,
 };
 
-use crate::models::bom::SpecVersion;
+use crate::{errors::BuildError, models::bom::SpecVersion};
 
 /// Contains all collected validation errors.
 #[derive(Debug, Clone, PartialEq)]
@@ -98,6 +98,45 @@ impl ValidationResult {
         self.inner.into_iter()
     }
 
+    /// Flattens all errors into a list of [`Violation`]s, each with the path of the field it
+    /// was found in, e.g. `components[0].version`.
+    pub fn violations(&self) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        self.collect_violations("", &mut violations);
+        violations
+    }
+
+    fn collect_violations(&self, prefix: &str, violations: &mut Vec<Violation>) {
+        for (name, kind) in &self.inner {
+            // Collection newtypes report their items under an `inner` list, which is omitted.
+            let path = match (prefix.is_empty(), name.is_empty() || name == "inner") {
+                (true, _) => name.clone(),
+                (false, true) => prefix.to_string(),
+                (false, false) => format!("{prefix}.{name}"),
+            };
+            match kind {
+                ValidationErrorsKind::Struct(nested) => {
+                    nested.collect_violations(&path, violations)
+                }
+                ValidationErrorsKind::List(items) => {
+                    for (index, nested) in items {
+                        nested.collect_violations(&format!("{path}[{index}]"), violations);
+                    }
+                }
+                ValidationErrorsKind::Field(errors) | ValidationErrorsKind::Custom(errors) => {
+                    violations.extend(errors.iter().map(|error| Violation {
+                        path: path.clone(),
+                        message: error.message.clone(),
+                    }))
+                }
+                ValidationErrorsKind::Enum(error) => violations.push(Violation {
+                    path,
+                    message: error.message.clone(),
+                }),
+            }
+        }
+    }
+
     /// Adds a nested object kind
     fn add_nested(&mut self, nested_name: &str, errors_kind: ValidationErrorsKind) {
         if let Vacant(entry) = self.inner.entry(nested_name.to_string()) {
@@ -240,6 +279,44 @@ impl ValidationContext {
         }
         self
     }
+    /// Same as [`add_list`](Self::add_list), but validates the items on the `rayon` thread pool
+    /// if the `parallel` feature is enabled.
+    pub fn add_list_parallel<'a, I, Output>(
+        &mut self,
+        field_name: &str,
+        list: &'a [I],
+        validation: impl Fn(&'a I) -> Output + Sync,
+    ) -> &mut Self
+    where
+        I: Sync,
+        Output: Into<ValidationResult>,
+    {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            let child_errors = list
+                .par_iter()
+                .enumerate()
+                .filter_map(|(index, item)| {
+                    let result: ValidationResult = validation(item).into();
+                    result.has_errors().then_some((index, result))
+                })
+                .collect::<Vec<_>>();
+
+            if !child_errors.is_empty() {
+                self.state.add_nested(
+                    field_name,
+                    ValidationErrorsKind::List(child_errors.into_iter().collect()),
+                );
+            }
+            self
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        self.add_list(field_name, list, validation)
+    }
+
     pub fn add_unique_list<'a, T, I, Output>(
         &mut self,
         field_name: &str,
@@ -396,6 +473,34 @@ impl ValidationError {
     }
 }
 
+/// A single validation error together with the path of the field it was found in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Validates a value returned by a builder against the latest spec version, which covers all
+/// fields a builder can set.
+pub(crate) fn validate_built<T: Validate>(model: &'static str, value: T) -> Result<T, BuildError> {
+    let violations = value.validate_version(SpecVersion::V1_5).violations();
+    if violations.is_empty() {
+        Ok(value)
+    } else {
+        Err(BuildError { model, violations })
+    }
+}
+
 /// Implements possible hierarchy of a structured SBOM to collect all [`ValidationError`] in.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ValidationErrorsKind {
@@ -474,10 +579,12 @@ where
 mod tests {
     use crate::{
         models::bom::SpecVersion,
-        validation::{field, r#enum, r#struct, Validate, ValidationErrorsKind, ValidationResult},
+        validation::{
+            field, list, r#enum, r#struct, Validate, ValidationErrorsKind, ValidationResult,
+        },
     };
 
-    use super::{ValidationContext, ValidationError};
+    use super::{ValidationContext, ValidationError, Violation};
 
     #[test]
     fn has_error() {
@@ -497,6 +604,32 @@ mod tests {
         assert!(result.has_errors());
     }
 
+    #[test]
+    fn violations() {
+        let result: ValidationResult = vec![
+            field("name", "invalid name"),
+            r#struct(
+                "components",
+                list("inner", [(1, vec![field("version", "invalid version")])]),
+            ),
+        ]
+        .into();
+
+        assert_eq!(
+            result.violations(),
+            vec![
+                Violation {
+                    path: "name".to_string(),
+                    message: "invalid name".to_string(),
+                },
+                Violation {
+                    path: "components[1].version".to_string(),
+                    message: "invalid version".to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn build_validation_errors_enum() {
         let result = r#enum("hello", "world");
@@ -542,4 +675,26 @@ mod tests {
             .into()
         );
     }
+
+    #[test]
+    fn add_list_parallel() {
+        let items: Vec<u32> = (0..100).collect();
+        let validation = |item: &u32| -> Result<(), ValidationError> {
+            if item % 7 == 0 {
+                Err(ValidationError::new("divisible by seven"))
+            } else {
+                Ok(())
+            }
+        };
+
+        let sequential: ValidationResult = ValidationContext::new()
+            .add_list("items", &items, validation)
+            .into();
+        let parallel: ValidationResult = ValidationContext::new()
+            .add_list_parallel("items", &items, validation)
+            .into();
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.violations().len(), 15);
+    }
 }
@@ -1,14 +1,64 @@
 use crate::errors::{XmlReadError, XmlWriteError};
-use std::io::{Read, Write};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::{Display, Write as _},
+    io::{BufWriter, Read, Write},
+};
 use xml::{
     attribute::OwnedAttribute,
     name::OwnedName,
     namespace::{Namespace, NS_NO_PREFIX},
-    reader::{self},
+    reader::{self, ParserConfig2},
     writer::{self, EventWriter, XmlEvent},
-    EventReader,
+    EmitterConfig, EventReader, ParserConfig,
 };
 
+/// Maximum length of an attribute value accepted when reading XML.
+///
+/// Attributes only hold short values like `bom-ref`s and types, so the limit guards against
+/// untrusted input allocating huge values without restricting real documents.
+pub(crate) const MAX_ATTRIBUTE_LENGTH: usize = 1 << 16;
+
+/// The configuration used to read CycloneDX XML documents.
+pub(crate) fn parser_config() -> ParserConfig2 {
+    ParserConfig::default()
+        .trim_whitespace(true)
+        .max_attribute_length(MAX_ATTRIBUTE_LENGTH)
+}
+
+/// The configuration used to write CycloneDX XML documents.
+///
+/// Every end tag is written with its name, so release builds don't keep a copy of the names of
+/// all open elements. Debug builds keep them to check that each end tag matches its start tag.
+pub(crate) fn emitter_config() -> EmitterConfig {
+    EmitterConfig::default()
+        .perform_indent(true)
+        .keep_element_names_stack(cfg!(debug_assertions))
+}
+
+/// The size of the buffer XML documents are written through.
+///
+/// The emitter writes each piece of an event, down to a single `>`, with a call of its own, which
+/// would be a system call per piece when writing to a file or socket directly.
+const WRITE_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Writes `element` as an XML document, gathering the events in a buffer that is written to
+/// `writer` in large chunks.
+pub(crate) fn write_xml_document<T: ToXml>(
+    writer: &mut dyn Write,
+    element: &T,
+    config: EmitterConfig,
+) -> Result<(), XmlWriteError> {
+    let mut buffer = BufWriter::with_capacity(WRITE_BUFFER_SIZE, writer);
+    element.write_xml_element(&mut EventWriter::new_with_config(&mut buffer, config))?;
+    buffer
+        .flush()
+        .map_err(|error| XmlWriteError::XmlElementWriteError {
+            error: error.into(),
+            element: "bom".to_string(),
+        })
+}
+
 pub(crate) trait ToXml {
     fn write_xml_element<W: Write>(&self, writer: &mut EventWriter<W>)
         -> Result<(), XmlWriteError>;
@@ -80,11 +130,31 @@ pub(crate) fn write_simple_tag<W: Write>(
         .map_err(to_xml_write_error(tag))?;
 
     writer
-        .write(writer::XmlEvent::end_element())
+        .write(writer::XmlEvent::end_element().name(tag))
         .map_err(to_xml_write_error(tag))?;
     Ok(())
 }
 
+thread_local! {
+    /// Reused buffer that primitive values are formatted into before they are written.
+    static SCRATCH: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Write a tag of the form `<tag>value</tag>` for a primitive value like a number or a boolean,
+/// formatting it into a reused buffer instead of allocating a `String` for every value.
+pub(crate) fn write_simple_display_tag<W: Write>(
+    writer: &mut EventWriter<W>,
+    tag: &str,
+    value: impl Display,
+) -> Result<(), XmlWriteError> {
+    SCRATCH.with(|scratch| {
+        let mut scratch = scratch.borrow_mut();
+        scratch.clear();
+        write!(scratch, "{value}").expect("formatting into a String does not fail");
+        write_simple_tag(writer, tag, &scratch)
+    })
+}
+
 pub(crate) fn write_simple_option_tag<W: Write>(
     writer: &mut EventWriter<W>,
     tag: &str,
@@ -113,7 +183,7 @@ pub(crate) fn write_close_tag<W: Write>(
     tag: &str,
 ) -> Result<(), XmlWriteError> {
     writer
-        .write(XmlEvent::end_element())
+        .write(XmlEvent::end_element().name(tag))
         .map_err(to_xml_write_error(tag))
 }
 
@@ -146,11 +216,15 @@ pub(crate) fn write_list_string_tag<W: Write>(
     write_close_tag(writer, tag)
 }
 
+/// The element name is only copied if writing fails, the error mapper is created for every
+/// event written.
 pub(crate) fn to_xml_write_error(
     element: impl AsRef<str>,
 ) -> impl FnOnce(xml::writer::Error) -> XmlWriteError {
-    let element = element.as_ref().to_owned();
-    |error| XmlWriteError::XmlElementWriteError { error, element }
+    move |error| XmlWriteError::XmlElementWriteError {
+        error,
+        element: element.as_ref().to_owned(),
+    }
 }
 
 pub(crate) trait FromXmlDocument {
@@ -648,19 +722,24 @@ pub(crate) fn read_lax_validation_tag<R: Read>(
     event_reader: &mut EventReader<R>,
     element: &OwnedName,
 ) -> Result<(), XmlReadError> {
-    let mut got_end_tag = false;
-    while !got_end_tag {
+    // Skips the element iteratively and bounds its depth, as `xml-rs` slows down considerably on
+    // deeply nested elements. The reader already checks that start and end elements match.
+    let mut depth = 0usize;
+    loop {
         let next_element = event_reader
             .next()
             .map_err(to_xml_read_error(&element.local_name))?;
 
         match next_element {
-            reader::XmlEvent::StartElement { name, .. } => {
-                read_lax_validation_tag(event_reader, &name)?
-            }
-            reader::XmlEvent::EndElement { name } if &name == element => {
-                got_end_tag = true;
+            reader::XmlEvent::StartElement { .. } if depth + 1 == MAX_NESTING_DEPTH => {
+                return Err(XmlReadError::NestingTooDeep {
+                    element: element.local_name.clone(),
+                    max_depth: MAX_NESTING_DEPTH,
+                })
             }
+            reader::XmlEvent::StartElement { .. } => depth += 1,
+            reader::XmlEvent::EndElement { .. } if depth > 0 => depth -= 1,
+            reader::XmlEvent::EndElement { name } if &name == element => break,
             unexpected @ reader::XmlEvent::EndDocument => {
                 return Err(unexpected_element_error(element, unexpected))
             }
@@ -705,6 +784,55 @@ pub(crate) fn read_lax_validation_list_tag<R: Read, X: FromXml>(
     Ok(items)
 }
 
+/// Maximum depth of nested lists of components and services, as well as of unknown elements,
+/// accepted when reading XML.
+///
+/// The reader recurses into nested components and services, so the depth is bounded to keep
+/// its stack usage in check on untrusted input.
+pub(crate) const MAX_NESTING_DEPTH: usize = 32;
+
+thread_local! {
+    static NESTING_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Tracks the current nesting depth, it is decremented again when dropped.
+struct NestingGuard;
+
+impl NestingGuard {
+    fn enter(element_name: &OwnedName) -> Result<Self, XmlReadError> {
+        let depth = NESTING_DEPTH.with(|depth| {
+            depth.set(depth.get() + 1);
+            depth.get()
+        });
+        // Constructed before the check, so the depth is also restored on error.
+        let guard = NestingGuard;
+        if depth > MAX_NESTING_DEPTH {
+            return Err(XmlReadError::NestingTooDeep {
+                element: element_name.local_name.clone(),
+                max_depth: MAX_NESTING_DEPTH,
+            });
+        }
+        Ok(guard)
+    }
+}
+
+impl Drop for NestingGuard {
+    fn drop(&mut self) {
+        NESTING_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Reads a list like [`read_lax_validation_list_tag`] for elements that can contain themselves,
+/// such as `<components>`, failing if they are nested deeper than [`MAX_NESTING_DEPTH`].
+pub(crate) fn read_nested_list_tag<R: Read, X: FromXml>(
+    event_reader: &mut EventReader<R>,
+    element_name: &OwnedName,
+    inner_element_tag: &str,
+) -> Result<Vec<X>, XmlReadError> {
+    let _guard = NestingGuard::enter(element_name)?;
+    read_lax_validation_list_tag(event_reader, element_name, inner_element_tag)
+}
+
 pub(crate) fn unexpected_element_error(
     element: impl ToString,
     unexpected: reader::XmlEvent,
@@ -717,14 +845,10 @@ pub(crate) fn unexpected_element_error(
 
 #[cfg(test)]
 pub(crate) mod test {
-    use xml::{EmitterConfig, ParserConfig};
+    use xml::ParserConfig;
 
     use super::*;
 
-    fn emitter_config() -> EmitterConfig {
-        EmitterConfig::default().perform_indent(true)
-    }
-
     pub(crate) fn write_element_to_string<X: ToXml>(element: X) -> String {
         let mut output = Vec::new();
         let mut event_writer = EventWriter::new_with_config(&mut output, emitter_config());
@@ -797,6 +921,22 @@ pub(crate) mod test {
         output
     }
 
+    #[test]
+    fn it_should_write_display_values() {
+        let mut output = Vec::new();
+        let mut event_writer = EventWriter::new_with_config(&mut output, emitter_config());
+        write_start_tag(&mut event_writer, "values").unwrap();
+        write_simple_display_tag(&mut event_writer, "flag", true).unwrap();
+        write_simple_display_tag(&mut event_writer, "number", 42).unwrap();
+        write_simple_display_tag(&mut event_writer, "score", 9.5).unwrap();
+        write_close_tag(&mut event_writer, "values").unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<values>\n  <flag>true</flag>\n  <number>42</number>\n  <score>9.5</score>\n</values>"
+        );
+    }
+
     #[test]
     fn it_should_handle_invalid_lax_xml() {
         let input = r#"
@@ -828,4 +968,60 @@ pub(crate) mod test {
 
         // no end document, because it returns an error during the read_lax_validation_tag call
     }
+
+    fn bom_with_components(components: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1"><components>{components}</components></bom>"#
+        )
+    }
+
+    #[test]
+    fn it_should_reject_deeply_nested_components() {
+        let nested = |depth: usize| {
+            let open = r#"<component type="library"><name>a</name><components>"#.repeat(depth);
+            let close = "</components></component>".repeat(depth);
+            bom_with_components(&format!("{open}{close}"))
+        };
+
+        crate::models::bom::Bom::parse_from_xml_v1_5(nested(MAX_NESTING_DEPTH - 1).as_bytes())
+            .expect("Should have read nested components up to the limit");
+        let error =
+            crate::models::bom::Bom::parse_from_xml_v1_5(nested(100_000).as_bytes()).unwrap_err();
+        assert!(matches!(error, XmlReadError::NestingTooDeep { .. }));
+    }
+
+    #[test]
+    fn it_should_reject_deeply_nested_unknown_elements() {
+        let nested = |depth: usize| {
+            let unknown = format!(
+                "{}{}",
+                "<unknown>".repeat(depth),
+                "</unknown>".repeat(depth)
+            );
+            bom_with_components(&format!(
+                r#"<component type="library"><name>a</name>{unknown}</component>"#
+            ))
+        };
+
+        let bom =
+            crate::models::bom::Bom::parse_from_xml_v1_5(nested(MAX_NESTING_DEPTH).as_bytes())
+                .expect("Should have skipped the unknown elements");
+        assert_eq!(bom.components.map(|c| c.len()), Some(1));
+        let error =
+            crate::models::bom::Bom::parse_from_xml_v1_5(nested(MAX_NESTING_DEPTH + 1).as_bytes())
+                .unwrap_err();
+        assert!(matches!(error, XmlReadError::NestingTooDeep { .. }));
+    }
+
+    #[test]
+    fn it_should_reject_huge_attribute_values() {
+        let bom_ref = "a".repeat(2 * MAX_ATTRIBUTE_LENGTH);
+        let input = bom_with_components(&format!(
+            r#"<component type="library" bom-ref="{bom_ref}"><name>a</name></component>"#
+        ));
+
+        crate::models::bom::Bom::parse_from_xml_v1_5(input.as_bytes())
+            .expect_err("Should have rejected the attribute value");
+    }
 }
@@ -16,6 +16,8 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::sync::OnceLock;
+
 use jsonschema::{error::ValidationErrorKind, paths::JSONPointer, JSONSchema};
 
 use cyclonedx_bom::models::bom::SpecVersion;
@@ -68,6 +70,33 @@ pub fn validate_json_with_schema(
     json: &serde_json::Value,
     version: SpecVersion,
 ) -> Result<(), Vec<ValidationError>> {
+    let compiled_schema = compiled_schema(version);
+
+    let result = compiled_schema.validate(json);
+    if let Err(errors) = result {
+        let errors = errors.collect::<Vec<_>>();
+        dbg!(&errors);
+    }
+    compiled_schema.validate(json).map_err(|iter| {
+        iter.map(|err| ValidationError::new(err.instance.to_string(), err.kind, err.instance_path))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Returns the schema of the given [`SpecVersion`], which is compiled on first use and shared by
+/// all later validations.
+fn compiled_schema(version: SpecVersion) -> &'static JSONSchema {
+    static SCHEMAS: [OnceLock<JSONSchema>; 3] = [OnceLock::new(), OnceLock::new(), OnceLock::new()];
+
+    let index = match version {
+        SpecVersion::V1_3 => 0,
+        SpecVersion::V1_4 => 1,
+        SpecVersion::V1_5 => 2,
+    };
+    SCHEMAS[index].get_or_init(|| compile_schema(version))
+}
+
+fn compile_schema(version: SpecVersion) -> JSONSchema {
     let spdx_schema = include_str!("../schema/spdx.schema.json");
     let spdx_schema: serde_json::Value =
         serde_json::from_str(spdx_schema).expect("Failed to read spdx.schema.json");
@@ -84,7 +113,7 @@ pub fn validate_json_with_schema(
         serde_json::from_str(schema).expect("Failed to parse JSON schema file");
 
     // Fill in external schema files, handle unknown format(s)
-    let compiled_schema = JSONSchema::options()
+    JSONSchema::options()
         .with_draft(jsonschema::Draft::Draft7)
         .with_document(
             "http://cyclonedx.org/schema/spdx.schema.json".to_string(),
@@ -96,17 +125,7 @@ pub fn validate_json_with_schema(
         )
         .with_format("idn-email", with_idn_email)
         .compile(&schema)
-        .expect("Failed to compile JSON schema file");
-
-    let result = compiled_schema.validate(json);
-    if let Err(errors) = result {
-        let errors = errors.collect::<Vec<_>>();
-        dbg!(&errors);
-    }
-    compiled_schema.validate(json).map_err(|iter| {
-        iter.map(|err| ValidationError::new(err.instance.to_string(), err.kind, err.instance_path))
-            .collect::<Vec<_>>()
-    })
+        .expect("Failed to compile JSON schema file")
 }
 
 /// For now ignore the content of the given email string.
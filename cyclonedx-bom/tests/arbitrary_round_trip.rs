@@ -0,0 +1,56 @@
+use arbitrary::{Arbitrary, Unstructured};
+use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+
+/// Deterministic pseudo-random input, so failures are reproducible from the seed.
+///
+/// The bytes are limited to printable ASCII, which keeps generated strings free of control
+/// characters that XML 1.0 cannot represent.
+fn bytes(seed: u64) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..4096)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            b' ' + (state % 95) as u8
+        })
+        .collect()
+}
+
+fn arbitrary_bom(seed: u64) -> Bom {
+    Bom::arbitrary(&mut Unstructured::new(&bytes(seed))).expect("BOM should be generated")
+}
+
+fn to_json(bom: Bom) -> String {
+    let mut output = Vec::new();
+    bom.output_as_json(&mut output, SpecVersion::V1_5)
+        .expect("BOM should be written as JSON");
+    String::from_utf8(output).expect("JSON should be UTF-8")
+}
+
+fn to_xml(bom: Bom) -> String {
+    let mut output = Vec::new();
+    bom.output_as_xml(&mut output, SpecVersion::V1_5)
+        .expect("BOM should be written as XML");
+    String::from_utf8(output).expect("XML should be UTF-8")
+}
+
+#[test]
+fn it_should_round_trip_arbitrary_boms_through_json() {
+    for seed in 0..256 {
+        let json = to_json(arbitrary_bom(seed));
+        if let Err(e) = Bom::parse_from_json(json.as_bytes()) {
+            panic!("seed {seed}: {e}\n{json}");
+        }
+    }
+}
+
+#[test]
+fn it_should_round_trip_arbitrary_boms_through_xml() {
+    for seed in 0..256 {
+        let xml = to_xml(arbitrary_bom(seed));
+        if let Err(e) = Bom::parse_from_xml_v1_5(xml.as_bytes()) {
+            panic!("seed {seed}: {e}\n{xml}");
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Checks that documents parsed with `simd-json` are the same as documents parsed with
+//! `serde_json`, and that both reject the same documents.
+use cyclonedx_bom::errors::ErrorKind;
+use cyclonedx_bom::models::bom::Bom;
+
+fn assert_parity(pattern: &str) {
+    let mut checked = 0;
+    for entry in std::fs::read_dir("tests/spec").unwrap() {
+        let dir = entry.unwrap().path();
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            if !(name.starts_with(pattern) && name.ends_with(".json")) {
+                continue;
+            }
+            let input = std::fs::read(&path).unwrap();
+
+            // `parse_from_json_slice` always uses `serde_json`, `parse_from_json` uses `simd-json`
+            let expected = Bom::parse_from_json_slice(&input);
+            let actual = Bom::parse_from_json(input.as_slice());
+            match (expected, actual) {
+                (Ok(expected), Ok(actual)) => assert_eq!(expected, actual, "{path:?}"),
+                (Err(expected), Err(actual)) => {
+                    assert_eq!(expected.kind(), actual.kind(), "{path:?}")
+                }
+                (expected, actual) => panic!("{path:?}: {expected:?} but {actual:?}"),
+            }
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "no {pattern}*.json documents found");
+}
+
+#[test]
+fn it_should_parse_valid_documents_like_serde_json() {
+    assert_parity("valid");
+}
+
+#[test]
+fn it_should_reject_invalid_documents_like_serde_json() {
+    assert_parity("invalid");
+}
+
+#[test]
+fn it_should_report_syntax_errors_like_serde_json() {
+    let inputs: [&[u8]; 3] = [
+        br#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1,"#,
+        br#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 1} trailing"#,
+        br#"{"bomFormat": "CycloneDX" "specVersion": "1.5"}"#,
+    ];
+
+    for input in inputs {
+        let expected = Bom::parse_from_json_slice(input).unwrap_err();
+        let actual = Bom::parse_from_json(input).unwrap_err();
+
+        assert_eq!(expected.kind(), ErrorKind::Syntax);
+        assert_eq!(actual.kind(), ErrorKind::Syntax, "{actual}");
+    }
+}
+
+#[test]
+fn it_should_read_the_spec_version_after_other_fields() {
+    let input = br#"{"version": 4, "bomFormat": "CycloneDX", "specVersion": "1.4"}"#;
+
+    assert_eq!(Bom::parse_from_json(&input[..]).unwrap().version, 4);
+}